@@ -0,0 +1,62 @@
+//! Allocations made while generating the bundled template / 生成自带模板时的分配次数
+//!
+//! Run with `cargo bench --bench allocations`. The counting allocator is installed in this binary only / 使用 `cargo bench --bench allocations` 运行。计数分配器仅安装在此二进制中
+
+use bytes::Bytes;
+use serde_json::{Value, json};
+use simple_docx_template::DOCX;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// System allocator counting every allocation / 统计每次分配的系统分配器
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Allocations of one generation / 一次生成的分配次数
+async fn measure(template: &Bytes, placeholders: &HashMap<String, Value>) -> usize {
+    let mut docx = DOCX::default();
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    docx.generate_bytes(template.clone(), placeholders)
+        .await
+        .expect("generation failed");
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let template = Bytes::from(std::fs::read("template/test.docx").expect("template missing"));
+
+    let empty = HashMap::new();
+    let mut filled = HashMap::new();
+    filled.insert("{{name}}".to_string(), json!("Alice"));
+
+    println!(
+        "empty map:  {} allocations",
+        measure(&template, &empty).await
+    );
+    println!(
+        "filled map: {} allocations",
+        measure(&template, &filled).await
+    );
+}
@@ -0,0 +1,49 @@
+use crate::core::constant::ERR_CHANNEL_CLOSED;
+use bytes::Bytes;
+use std::io::{Error as IoError, ErrorKind};
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+use tokio::io::AsyncWrite;
+use tokio::sync::mpsc::Sender;
+use tokio_util::sync::PollSender;
+
+/// Writer sending every write as a chunk to a channel / 将每次写入作为数据块发送到通道的写入器
+///
+/// A write waits for a free slot of the channel, so a slow receiver slows generation down instead of buffering the output / 写入会等待通道的空闲位置，因此缓慢的接收方会减慢生成速度而不是缓冲输出
+pub(crate) struct ChannelWriter {
+    sender: PollSender<Bytes>,
+}
+
+impl ChannelWriter {
+    pub(crate) fn new(sender: Sender<Bytes>) -> Self {
+        Self {
+            sender: PollSender::new(sender),
+        }
+    }
+}
+
+impl AsyncWrite for ChannelWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, IoError>> {
+        let this = self.get_mut();
+        if ready!(this.sender.poll_reserve(cx)).is_err() {
+            return Poll::Ready(Err(IoError::new(ErrorKind::BrokenPipe, ERR_CHANNEL_CLOSED)));
+        }
+        match this.sender.send_item(Bytes::copy_from_slice(buf)) {
+            Ok(()) => Poll::Ready(Ok(buf.len())),
+            Err(_) => Poll::Ready(Err(IoError::new(ErrorKind::BrokenPipe, ERR_CHANNEL_CLOSED))),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), IoError>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), IoError>> {
+        self.get_mut().sender.close();
+        Poll::Ready(Ok(()))
+    }
+}
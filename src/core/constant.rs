@@ -12,16 +12,16 @@ pub(crate) const PREVIEW_BUFFER_SIZE: usize = 4096;
 pub(crate) const MIN_IMAGE_DATA_LEN: usize = 24;
 
 // Maximum image size: 5cm = 1800000 EMU / 最大图片尺寸：5厘米 = 1800000 EMU
-pub(crate) const MAX_EMU: f32 = 1800000.0;
+pub(crate) const MAX_EMU: f64 = 1800000.0;
 
 // Default image width: 2cm / 默认图片宽度：2厘米
-pub(crate) const DEFAULT_WIDTH_EMU: f32 = 720000.0;
+pub(crate) const DEFAULT_WIDTH_EMU: f64 = 720000.0;
 
 // Default image height: 2.5cm / 默认图片高度：2.5厘米
-pub(crate) const DEFAULT_HEIGHT_EMU: f32 = 900000.0;
+pub(crate) const DEFAULT_HEIGHT_EMU: f64 = 900000.0;
 
 // EMU (English Metric Units) per inch conversion factor / 每英寸的 EMU（英制公制单位）转换因子
-pub(crate) const EMU_PER_INCH: f32 = 914400.0;
+pub(crate) const EMU_PER_INCH: f64 = 914400.0;
 
 // Default DPI (dots per inch) for image rendering / 图片渲染的默认 DPI（每英寸点数）
 pub(crate) const DEFAULT_DPI: f32 = 96.0;
@@ -34,17 +34,76 @@ pub(crate) const RELS_PATH: &str = "word/_rels/document.xml.rels";
 // Path to main document XML file / 主文档 XML 文件路径
 pub(crate) const DOCUMENT_XML_PATH: &str = "word/document.xml";
 
+// Document settings path / 文档设置路径
+pub(crate) const SETTINGS_PATH: &str = "word/settings.xml";
+
+// Header and footer part path prefixes / 页眉和页脚部件路径前缀
+pub(crate) const HEADER_PATH_PREFIX: &str = "word/header";
+pub(crate) const FOOTER_PATH_PREFIX: &str = "word/footer";
+
+// Directory and extension of the relationship files of `word/` parts / `word/` 部件关系文件的目录和扩展名
+pub(crate) const WORD_PATH_PREFIX: &str = "word/";
+pub(crate) const WORD_RELS_PATH_PREFIX: &str = "word/_rels/";
+pub(crate) const RELS_EXTENSION: &str = ".rels";
+
+// Path to package content types file / 包内容类型文件路径
+pub(crate) const CONTENT_TYPES_PATH: &str = "[Content_Types].xml";
+
+// ---------- Flat OPC constants / Flat OPC 常量 ----------
+
+// Head of a Flat OPC document up to the opening package element / Flat OPC 文档开头直到包开始元素
+pub(crate) const FLAT_OPC_HEAD: &str = concat!(
+    r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+    r#"<?mso-application progid="Word.Document"?>"#,
+    r#"<pkg:package xmlns:pkg="http://schemas.microsoft.com/office/2006/xmlPackage">"#
+);
+
+// Closing package element of a Flat OPC document / Flat OPC 文档的包结束元素
+pub(crate) const FLAT_OPC_TAIL: &str = "</pkg:package>";
+
+// Content type of parts not declared in [Content_Types].xml / 未在 [Content_Types].xml 中声明的部件的内容类型
+pub(crate) const CONTENT_TYPE_OCTET_STREAM: &str = "application/octet-stream";
+
+// Suffix of XML content types, e.g. `application/xml` or `...+xml` / XML 内容类型的后缀，例如 `application/xml` 或 `...+xml`
+pub(crate) const CONTENT_TYPE_XML_SUFFIX: &str = "xml";
+
+// Content type declaration elements and attributes / 内容类型声明元素和属性
+pub(crate) const XML_TYPES_DEFAULT: &[u8] = b"Default";
+pub(crate) const XML_TYPES_OVERRIDE: &[u8] = b"Override";
+pub(crate) const XML_ATTR_EXTENSION: &[u8] = b"Extension";
+pub(crate) const XML_ATTR_PART_NAME: &[u8] = b"PartName";
+pub(crate) const XML_ATTR_CONTENT_TYPE: &[u8] = b"ContentType";
+
 // Path prefix for media files / 媒体文件路径前缀
 pub(crate) const MEDIA_PATH_PREFIX: &str = "word/media/";
 
+// Chart parts path prefix / 图表部件路径前缀
+pub(crate) const CHART_PATH_PREFIX: &str = "word/charts/";
+
+// XML part extension / XML 部件扩展名
+pub(crate) const XML_PART_EXTENSION: &str = ".xml";
+
 // Temporary file name prefix / 临时文件名前缀
 pub(crate) const TEMP_FILE_PREFIX: &str = "docx_";
 
 // Temporary file extension / 临时文件扩展名
 pub(crate) const TEMP_FILE_EXTENSION: &str = ".xml";
 
+// Extension of the output written before it is renamed into place / 重命名到位之前写入的输出的扩展名
+pub(crate) const PARTIAL_FILE_EXTENSION: &str = ".partial";
+
+// Loop rows written between cancellation checks / 两次取消检查之间写入的循环行数
+pub(crate) const CANCELLATION_CHECK_ROWS: usize = 64;
+
+// Document events read between cancellation checks / 两次取消检查之间读取的文档事件数
+pub(crate) const CANCELLATION_CHECK_EVENTS: usize = 1024;
+
 // ---------- XML element name constants / XML 元素名称常量 ----------
 
+// Run element open/close tags / 运行元素开始/结束标签
+pub(crate) const RUN_OPEN: &str = "<w:r>";
+pub(crate) const RUN_CLOSE: &str = "</w:r>";
+
 // Table element name / 表格元素名称
 pub(crate) const XML_TABLE: &str = "w:tbl";
 
@@ -57,12 +116,204 @@ pub(crate) const XML_TABLE_ROW: &[u8] = b"w:tr";
 // Table cell element name / 表格单元格元素名称
 pub(crate) const XML_TABLE_CELL: &[u8] = b"w:tc";
 
+// Table row properties element name / 表格行属性元素名称
+pub(crate) const XML_TABLE_ROW_PROPERTIES: &str = "w:trPr";
+
+// Row property keeping a row on one page / 使行保持在同一页的行属性
+pub(crate) const XML_CANT_SPLIT: &str = "w:cantSplit";
+
+// Row property repeating a row at the top of each page / 使行在每页顶部重复的行属性
+pub(crate) const XML_TABLE_HEADER: &str = "w:tblHeader";
+
 // Table cell properties element name / 表格单元格属性元素名称
 pub(crate) const XML_TABLE_CELL_PROPERTIES: &str = "w:tcPr";
 
 // Table cell v_merge tag / 表格合并标记
 pub(crate) const XML_TABLE_MERGE_TAG: &str = "w:vMerge w:val";
 
+// Table cell shading tag, completed with the fill color / 表格单元格底纹标记，以填充颜色补全
+pub(crate) const XML_SHADING_TAG: &str = r#"w:shd w:val="clear" w:color="auto" w:fill"#;
+
+// Table cell vertical merge element name / 表格单元格垂直合并元素名称
+pub(crate) const XML_TABLE_MERGE: &[u8] = b"w:vMerge";
+
+// Shading element name / 底纹元素名称
+pub(crate) const XML_SHADING: &[u8] = b"w:shd";
+
+// Cell properties that must follow w:vMerge in schema order / 按架构顺序必须位于 w:vMerge 之后的单元格属性
+pub(crate) const TABLE_CELL_PROPERTIES_AFTER_MERGE: [&[u8]; 13] = [
+    b"w:tcBorders",
+    b"w:shd",
+    b"w:noWrap",
+    b"w:tcMar",
+    b"w:textDirection",
+    b"w:tcFitText",
+    b"w:vAlign",
+    b"w:hideMark",
+    b"w:headers",
+    b"w:cellIns",
+    b"w:cellDel",
+    b"w:cellMerge",
+    b"w:tcPrChange",
+];
+
+// Cell properties that must follow w:shd in schema order / 按架构顺序必须位于 w:shd 之后的单元格属性
+pub(crate) const TABLE_CELL_PROPERTIES_AFTER_SHADING: [&[u8]; 11] = [
+    b"w:noWrap",
+    b"w:tcMar",
+    b"w:textDirection",
+    b"w:tcFitText",
+    b"w:vAlign",
+    b"w:hideMark",
+    b"w:headers",
+    b"w:cellIns",
+    b"w:cellDel",
+    b"w:cellMerge",
+    b"w:tcPrChange",
+];
+
+// Table grid column element name / 表格网格列元素名称
+pub(crate) const XML_TABLE_GRID_COL: &[u8] = b"w:gridCol";
+
+// Table cell grid span element name / 表格单元格跨列元素名称
+pub(crate) const XML_TABLE_GRID_SPAN: &str = "w:gridSpan";
+
+// Table row skipped leading grid columns element name / 表格行前置跳过网格列元素名称
+pub(crate) const XML_TABLE_GRID_BEFORE: &[u8] = b"w:gridBefore";
+
+// Value attribute name / 值属性名称
+pub(crate) const XML_ATTR_VAL: &str = "w:val";
+
+// Paragraph tag / 段落标签
+pub(crate) const XML_PARAGRAPH: &[u8] = b"w:p";
+
+// Paragraph properties tag / 段落属性标签
+pub(crate) const XML_PARAGRAPH_PROPERTIES: &str = "w:pPr";
+
+// Paragraph justification tag / 段落对齐标签
+pub(crate) const XML_JUSTIFICATION: &str = "w:jc";
+
+// Paragraph properties that must follow w:jc in schema order / 按架构顺序必须位于 w:jc 之后的段落属性
+pub(crate) const PARAGRAPH_PROPERTIES_AFTER_JC: [&[u8]; 9] = [
+    b"w:textDirection",
+    b"w:textAlignment",
+    b"w:textboxTightWrap",
+    b"w:outlineLvl",
+    b"w:divId",
+    b"w:cnfStyle",
+    b"w:rPr",
+    b"w:sectPr",
+    b"w:pPrChange",
+];
+
+// Run element name / 运行元素名称
+pub(crate) const XML_RUN: &[u8] = b"w:r";
+
+// Run style element name / 运行样式元素名称
+pub(crate) const XML_RUN_STYLE: &str = "w:rStyle";
+
+// Run color element name / 运行颜色元素名称
+pub(crate) const XML_COLOR: &str = "w:color";
+
+// Run language element name / 运行语言元素名称
+pub(crate) const XML_LANGUAGE: &str = "w:lang";
+
+// Run properties in schema order / 按架构顺序排列的运行属性
+pub(crate) const RUN_PROPERTIES_ORDER: [&[u8]; 40] = [
+    b"w:rStyle",
+    b"w:rFonts",
+    b"w:b",
+    b"w:bCs",
+    b"w:i",
+    b"w:iCs",
+    b"w:caps",
+    b"w:smallCaps",
+    b"w:strike",
+    b"w:dstrike",
+    b"w:outline",
+    b"w:shadow",
+    b"w:emboss",
+    b"w:imprint",
+    b"w:noProof",
+    b"w:snapToGrid",
+    b"w:vanish",
+    b"w:webHidden",
+    b"w:color",
+    b"w:spacing",
+    b"w:w",
+    b"w:kern",
+    b"w:position",
+    b"w:sz",
+    b"w:szCs",
+    b"w:highlight",
+    b"w:u",
+    b"w:effect",
+    b"w:bdr",
+    b"w:shd",
+    b"w:fitText",
+    b"w:vertAlign",
+    b"w:rtl",
+    b"w:cs",
+    b"w:em",
+    b"w:lang",
+    b"w:eastAsianLayout",
+    b"w:specVanish",
+    b"w:oMath",
+    b"w:rPrChange",
+];
+
+// Run properties element name / 运行属性元素名称
+pub(crate) const XML_RUN_PROPERTIES: &[u8] = b"w:rPr";
+
+// Section properties element name / 节属性元素名称
+pub(crate) const XML_SECTION_PROPERTIES: &[u8] = b"w:sectPr";
+
+// Document body element name / 文档正文元素名称
+pub(crate) const XML_BODY: &[u8] = b"w:body";
+
+// Bookmark range element names / 书签范围元素名称
+pub(crate) const XML_BOOKMARK_START: &[u8] = b"w:bookmarkStart";
+pub(crate) const XML_BOOKMARK_END: &[u8] = b"w:bookmarkEnd";
+
+// Bookmark attribute names / 书签属性名称
+pub(crate) const XML_ATTR_BOOKMARK_ID: &[u8] = b"w:id";
+pub(crate) const XML_ATTR_BOOKMARK_NAME: &[u8] = b"w:name";
+
+// Separator splitting a value into paragraphs / 将值拆分为多个段落的分隔符
+pub(crate) const PARAGRAPH_SEPARATOR: &str = "\n\n";
+
+// Inline elements closed and reopened around a paragraph split / 段落拆分时关闭并重新打开的行内元素
+pub(crate) const PARAGRAPH_INLINE_ANCESTORS: &[&[u8]] = &[
+    b"w:hyperlink",
+    b"w:sdt",
+    b"w:sdtContent",
+    b"w:ins",
+    b"w:smartTag",
+];
+
+// Simple field element name / 简单域元素名称
+pub(crate) const XML_FIELD_SIMPLE: &[u8] = b"w:fldSimple";
+
+// Field instruction attribute name / 域指令属性名称
+pub(crate) const XML_ATTR_INSTR: &[u8] = b"w:instr";
+
+// Complex field character element name / 复杂域字符元素名称
+pub(crate) const XML_FIELD_CHAR: &[u8] = b"w:fldChar";
+
+// Field character type attribute name / 域字符类型属性名称
+pub(crate) const XML_ATTR_FIELD_CHAR_TYPE: &[u8] = b"w:fldCharType";
+
+// Field instruction text element name / 域指令文本元素名称
+pub(crate) const XML_INSTR_TEXT: &[u8] = b"w:instrText";
+
+// Field character types / 域字符类型
+pub(crate) const FIELD_CHAR_BEGIN: &[u8] = b"begin";
+pub(crate) const FIELD_CHAR_SEPARATE: &[u8] = b"separate";
+pub(crate) const FIELD_CHAR_END: &[u8] = b"end";
+
+// Mail merge field instruction keyword / 邮件合并域指令关键字
+pub(crate) const MERGEFIELD_INSTRUCTION: &str = "MERGEFIELD";
+
 // ---------- Image format detection constants / 图片格式检测常量 ----------
 
 // PNG image base64 signature / PNG 图片的 base64 签名
@@ -71,6 +322,24 @@ pub(crate) const PNG_BASE64_SIGNATURE: &str = "iVBORw0KGgo";
 // JPEG image base64 signature / JPEG 图片的 base64 签名
 pub(crate) const JPEG_BASE64_SIGNATURE: &str = "/9j/";
 
+// SVG image base64 signatures (`<svg` and `<?xml`) / SVG 图片的 base64 签名（`<svg` 和 `<?xml`）
+pub(crate) const SVG_BASE64_SIGNATURES: [&str; 2] = ["PHN2Zy", "PD94bWw"];
+
+// GIF image base64 signature (`GIF8`) / GIF 图片的 base64 签名（`GIF8`）
+pub(crate) const GIF_BASE64_SIGNATURE: &str = "R0lGOD";
+
+// BMP image base64 signature (`BM`), too short alone so the header is decoded as well / BMP 图片的 base64 签名（`BM`），单独使用太短，因此还会解码头部
+pub(crate) const BMP_BASE64_SIGNATURE: &str = "Qk";
+
+// Base64 characters decoded to check a BMP file header / 检查 BMP 文件头时解码的 base64 字符数
+pub(crate) const BMP_BASE64_HEADER_LEN: usize = 16;
+
+// WebP image base64 signature (`RIFF`), shared with other RIFF files so the header is decoded as well / WebP 图片的 base64 签名（`RIFF`），与其他 RIFF 文件共用，因此还会解码头部
+pub(crate) const WEBP_BASE64_SIGNATURE: &str = "UklGR";
+
+// Base64 characters decoded to check a WebP file header / 检查 WebP 文件头时解码的 base64 字符数
+pub(crate) const WEBP_BASE64_HEADER_LEN: usize = 16;
+
 // ---------- Merge type constants / 合并类型常量 ----------
 
 // Vertical merge restart value / 垂直合并重新开始值
@@ -101,16 +370,62 @@ pub(crate) const PNG_SIG_BYTE_3: u8 = 71;
 #[allow(dead_code)]
 pub(crate) const JPEG_SIGNATURE: [u8; 3] = [0xFF, 0xD8, 0xFF];
 
+// GIF file signature bytes / GIF 文件签名字节
+pub(crate) const GIF_SIGNATURE: &[u8] = b"GIF8";
+
+// BMP file signature bytes / BMP 文件签名字节
+pub(crate) const BMP_SIGNATURE: &[u8] = b"BM";
+
+// WebP container signature, `RIFF` then the form type `WEBP` / WebP 容器签名，`RIFF` 后接格式类型 `WEBP`
+pub(crate) const RIFF_SIGNATURE: &[u8] = b"RIFF";
+pub(crate) const WEBP_SIGNATURE: &[u8] = b"WEBP";
+
 // PNG IHDR chunk marker / PNG IHDR 块标记
 pub(crate) const PNG_IHDR_MARKER: [u8; 4] = [b'I', b'H', b'D', b'R'];
 
 // Default image file extensions / 默认图片文件扩展名
 pub(crate) const IMAGE_EXT_PNG: &str = "png";
 pub(crate) const IMAGE_EXT_JPEG: &str = "jpg";
+pub(crate) const IMAGE_EXT_SVG: &str = "svg";
+pub(crate) const IMAGE_EXT_GIF: &str = "gif";
+pub(crate) const IMAGE_EXT_BMP: &str = "bmp";
+pub(crate) const IMAGE_EXT_WEBP: &str = "webp";
+
+// Image content types / 图片内容类型
+pub(crate) const CONTENT_TYPE_PNG: &str = "image/png";
+pub(crate) const CONTENT_TYPE_JPEG: &str = "image/jpeg";
+pub(crate) const CONTENT_TYPE_SVG: &str = "image/svg+xml";
+pub(crate) const CONTENT_TYPE_GIF: &str = "image/gif";
+pub(crate) const CONTENT_TYPE_BMP: &str = "image/bmp";
+pub(crate) const CONTENT_TYPE_WEBP: &str = "image/webp";
+
+// Root element of SVG images / SVG 图片的根元素
+pub(crate) const SVG_ROOT: &str = "<svg";
+
+// Bytes scanned when sniffing SVG content / 嗅探 SVG 内容时扫描的字节数
+pub(crate) const SVG_SNIFF_LEN: usize = 1024;
+
+// Base64 characters decoded to sniff an SVG root, a multiple of 4 / 为检测 SVG 根元素而解码的 base64 字符数，为 4 的倍数
+pub(crate) const SVG_BASE64_SNIFF_LEN: usize = SVG_SNIFF_LEN / 3 * 4;
+
+// Transparent 1x1 PNG used as SVG fallback when it cannot be rasterized / 无法栅格化时用作 SVG 回退的透明 1x1 PNG
+pub(crate) const SVG_FALLBACK_PNG: &[u8] = &[
+    0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00, 0x00, 0x0D, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0x1F, 0x15, 0xC4,
+    0x89, 0x00, 0x00, 0x00, 0x0B, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9C, 0x63, 0x60, 0x00, 0x02, 0x00,
+    0x00, 0x05, 0x00, 0x01, 0x7A, 0x5E, 0xAB, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4E, 0x44,
+    0xAE, 0x42, 0x60, 0x82,
+];
 
 // Image filename prefix / 图片文件名前缀
 pub(crate) const IMAGE_FILENAME_PREFIX: &str = "image_";
 
+// High bits of the image UUIDs numbered in reproducible mode / 可复现模式下编号的图片 UUID 的高位
+pub(crate) const REPRODUCIBLE_UUID_HIGH: u64 = 0x646f_6378_7465_6d70;
+
+// Year of the zip entry timestamps in reproducible mode, the start of the DOS epoch / 可复现模式下 zip 条目时间戳的年份，即 DOS 纪元的起点
+pub(crate) const REPRODUCIBLE_ZIP_YEAR: i32 = 1980;
+
 // ---------- Capacity hint constants / 容量提示常量 ----------
 
 // Typical number of images in a document / 文档中典型的图片数量
@@ -122,6 +437,9 @@ pub(crate) const IMAGE_FILENAME_CAPACITY: usize = 50;
 // Capacity for relationship XML / 关系 XML 容量
 pub(crate) const REL_XML_BASE_CAPACITY: usize = 150;
 
+// Capacity for a content type default entry / 内容类型默认条目容量
+pub(crate) const CONTENT_TYPE_BASE_CAPACITY: usize = 64;
+
 // Capacity for drawing XML / 绘图 XML 容量
 pub(crate) const DRAWING_XML_CAPACITY: usize = 850;
 
@@ -154,18 +472,202 @@ pub(crate) const XMLNS_DRAWINGML: &str = "http://schemas.openxmlformats.org/draw
 // Picture namespace / 图片命名空间
 pub(crate) const XMLNS_PICTURE: &str = "http://schemas.openxmlformats.org/drawingml/2006/picture";
 
+// SVG blip extension namespace / SVG blip 扩展命名空间
+pub(crate) const XMLNS_SVG_BLIP: &str = "http://schemas.microsoft.com/office/drawing/2016/SVG/main";
+
+// SVG blip extension URI / SVG blip 扩展 URI
+pub(crate) const SVG_BLIP_EXT_URI: &str = "{96DAC541-7B7A-43D3-8B79-37D633B846F1}";
+
 // Image relationship type / 图片关系类型
 pub(crate) const REL_TYPE_IMAGE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/image";
 
+// Relationship file without relationships / 不含任何关系的关系文件
+pub(crate) const EMPTY_RELS_XML: &str = concat!(
+    r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+    r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"></Relationships>"#
+);
+
 // ---------- Template marker constants / 模板标记常量 ----------
 
+// Placeholder opening marker / 占位符开始标记
+pub(crate) const PLACEHOLDER_OPEN: &str = "{{";
+
+// Placeholder close marker / 占位符结束标记
+pub(crate) const PLACEHOLDER_CLOSE: &str = "}}";
+
 // Loop start marker / 循环开始标记
 pub(crate) const LOOP_START_MARKER: &str = "{{#";
 
+// Escaped loop start marker, written literally as `{{#` / 转义的循环开始标记，按字面写为 `{{#`
+pub(crate) const ESCAPED_LOOP_START_MARKER: &str = "{{\\#";
+
 // Loop end marker / 循环结束标记
 pub(crate) const LOOP_END_MARKER: &str = "}}";
 
+// Separator of the options in a loop marker, e.g. {{#users|status==active|sort:age}} / 循环标记中选项的分隔符，例如 {{#users|status==active|sort:age}}
+pub(crate) const LOOP_FILTER_SEPARATOR: char = '|';
+
+// Sort option of a loop marker, e.g. {{#items|sort:price:desc}} / 循环标记的排序选项，例如 {{#items|sort:price:desc}}
+pub(crate) const LOOP_SORT_PREFIX: &str = "sort:";
+
+// Group option of a loop marker, e.g. {{#items|group:category}} / 循环标记的分组选项，例如 {{#items|group:category}}
+pub(crate) const LOOP_GROUP_PREFIX: &str = "group:";
+
+// Separator of the sort field and its direction / 排序字段与排序方向的分隔符
+pub(crate) const LOOP_SORT_DIRECTION_SEPARATOR: char = ':';
+
+// Descending sort direction / 降序排序方向
+pub(crate) const LOOP_SORT_DESCENDING: &str = "desc";
+
+// Ascending sort direction / 升序排序方向
+pub(crate) const LOOP_SORT_ASCENDING: &str = "asc";
+
+// Conditional column marker, e.g. {{col?hasDiscount}} / 条件列标记，例如 {{col?hasDiscount}}
+pub(crate) const COLUMN_CONDITION_MARKER: &str = "{{col?";
+
+// Cell alignment markers in loop data rows, e.g. [r:amount] / 循环数据行中的单元格对齐标记，例如 [r:amount]
+pub(crate) const ALIGN_LEFT_MARKER: &str = "[l:";
+pub(crate) const ALIGN_CENTER_MARKER: &str = "[c:";
+pub(crate) const ALIGN_RIGHT_MARKER: &str = "[r:";
+
+// Row skip marker in loop data rows, e.g. [skipif:qty==0] / 循环数据行中的跳过行标记，例如 [skipif:qty==0]
+pub(crate) const SKIP_IF_MARKER: &str = "[skipif:";
+
+// Group value key, a data row using it is the group header row / 分组值键，使用它的数据行即为分组标题行
+pub(crate) const GROUP_KEY: &str = "$group";
+
+// Row index key, also suffixed to nested array paths (e.g. `pets.toys.$index`) / 行索引键，也附加在嵌套数组路径之后（例如 `pets.toys.$index`）
+pub(crate) const ROW_INDEX_KEY: &str = "$index";
+
+// Loop item key holding the visible row number / 保存可见行编号的循环项键
+pub(crate) const VISIBLE_ROW_KEY: &str = "$visrow";
+
+// Image placeholder modifier / 图片占位符修饰符
+pub(crate) const IMAGE_MARKER: char = '@';
+
+// Separator between an image placeholder and its options, e.g. `[@photo|alt=Portrait]` / 图片占位符与其选项之间的分隔符，例如 `[@photo|alt=Portrait]`
+pub(crate) const IMAGE_OPTION_SEPARATOR: char = '|';
+
+// Separator between an image placeholder and its size in points, e.g. `[@photo:200x150]` / 图片占位符与其磅值尺寸之间的分隔符，例如 `[@photo:200x150]`
+pub(crate) const IMAGE_SIZE_SEPARATOR: char = ':';
+
+// Separator between the width and height of an image size / 图片尺寸的宽度与高度之间的分隔符
+pub(crate) const IMAGE_SIZE_DIMENSION_SEPARATOR: char = 'x';
+
+// Separator between an image option name and its value / 图片选项名称与值之间的分隔符
+pub(crate) const IMAGE_OPTION_ASSIGN: char = '=';
+
+// Image option setting the alternative text / 设置替代文本的图片选项
+pub(crate) const IMAGE_OPTION_ALT: &str = "alt";
+
+// Image option rotating the image clockwise in degrees / 按度数顺时针旋转图片的图片选项
+pub(crate) const IMAGE_OPTION_ROTATE: &str = "rotate";
+
+// Image option flattening transparency against an RGB hex color / 将透明度与 RGB 十六进制颜色合成的图片选项
+pub(crate) const IMAGE_OPTION_BACKGROUND: &str = "bg";
+
+// Image option drawing a border, e.g. `border=1pt:000000` / 绘制边框的图片选项，例如 `border=1pt:000000`
+pub(crate) const IMAGE_OPTION_BORDER: &str = "border";
+
+// Image option floating the image with a text wrap, e.g. `anchor=behind` / 以文字环绕方式浮动图片的图片选项，例如 `anchor=behind`
+pub(crate) const IMAGE_OPTION_ANCHOR: &str = "anchor";
+
+// Image option setting the horizontal offset of an anchored image from the column / 设置浮动图片相对于栏的水平偏移的图片选项
+pub(crate) const IMAGE_OPTION_X: &str = "x";
+
+// Image option setting the vertical offset of an anchored image from the paragraph / 设置浮动图片相对于段落的垂直偏移的图片选项
+pub(crate) const IMAGE_OPTION_Y: &str = "y";
+
+// Image option embedding a base64 image when the value is empty / 值为空时嵌入 base64 图片的图片选项
+pub(crate) const IMAGE_OPTION_DEFAULT: &str = "default";
+
+// Image option embedding an image file when the value is empty / 值为空时嵌入图片文件的图片选项
+pub(crate) const IMAGE_OPTION_DEFAULT_FILE: &str = "defaultfile";
+
+// Text wrap values of the anchor option / anchor 选项的文字环绕值
+pub(crate) const IMAGE_WRAP_SQUARE: &str = "square";
+pub(crate) const IMAGE_WRAP_TIGHT: &str = "tight";
+pub(crate) const IMAGE_WRAP_BEHIND: &str = "behind";
+pub(crate) const IMAGE_WRAP_FRONT: &str = "front";
+
+// Separator between the border width and color / 边框宽度与颜色之间的分隔符
+pub(crate) const IMAGE_BORDER_COLOR_SEPARATOR: char = ':';
+
+// Suffix of a border width given in points / 以磅为单位的边框宽度后缀
+pub(crate) const POINT_UNIT_SUFFIX: &str = "pt";
+
+// EMU per point, DrawingML line widths are in EMU / 每磅的 EMU，DrawingML 线宽以 EMU 为单位
+pub(crate) const EMU_PER_POINT: f64 = 12700.0;
+
+// Page break marker / 分页符标记
+pub(crate) const PAGE_BREAK_MARKER: &str = "[pagebreak]";
+
+// Page break markup inside a run / 运行内的分页符标记
+pub(crate) const PAGE_BREAK: &str = r#"<w:br w:type="page"/>"#;
+
+// Table of contents marker / 目录标记
+pub(crate) const TOC_MARKER: &str = "[toc]";
+
+// Table of contents field runs (begin, instruction, separate, placeholder result, end) / 目录域运行（开始、指令、分隔、占位结果、结束）
+pub(crate) const TOC_FIELD_RUNS: &str = concat!(
+    r#"<w:r><w:fldChar w:fldCharType="begin" w:dirty="true"/></w:r>"#,
+    r#"<w:r><w:instrText xml:space="preserve"> TOC \o "1-3" \h \z \u </w:instrText></w:r>"#,
+    r#"<w:r><w:fldChar w:fldCharType="separate"/></w:r>"#,
+    r#"<w:r><w:t>Update field to see table of contents</w:t></w:r>"#,
+    r#"<w:r><w:fldChar w:fldCharType="end"/></w:r>"#,
+);
+
+// Current time marker prefix, e.g. `[$now:%Y-%m-%d]` / 当前时间标记前缀，例如 `[$now:%Y-%m-%d]`
+#[cfg(feature = "dates")]
+pub(crate) const NOW_MARKER: &str = "[$now";
+
+// Format of a current time marker without pattern / 不带格式的当前时间标记的格式
+#[cfg(feature = "dates")]
+pub(crate) const NOW_DEFAULT_FORMAT: &str = "%Y-%m-%d";
+
+// Internal hyperlink marker prefix, e.g. `[ilink:bookmark|display]` / 内部超链接标记前缀，例如 `[ilink:bookmark|display]`
+pub(crate) const INTERNAL_LINK_PREFIX: &str = "[ilink:";
+
+// Bookmark marker prefix, e.g. `[bookmark:name]` / 书签标记前缀，例如 `[bookmark:name]`
+pub(crate) const BOOKMARK_PREFIX: &str = "[bookmark:";
+
+// List marker prefix rendering an array as one line per item, e.g. `[list:tags]` / 将数组渲染为每项一行的列表标记前缀，例如 `[list:tags]`
+pub(crate) const LIST_PREFIX: &str = "[list:";
+
+// Bullet written in front of every list item / 写在每个列表项前面的项目符号
+pub(crate) const LIST_BULLET: &str = "• ";
+
+// Line break between list items / 列表项之间的换行符
+pub(crate) const LINE_BREAK: &str = "<w:br/>";
+
+// Raw OOXML marker prefix writing the value verbatim, e.g. `[raw:snippet]` / 原样写入值的原始 OOXML 标记前缀，例如 `[raw:snippet]`
+pub(crate) const RAW_PREFIX: &str = "[raw:";
+
+// Include marker prefix splicing a fragment of the package, e.g. `[include:word/fragments/cover.xml]` / 拼接包内片段的包含标记前缀，例如 `[include:word/fragments/cover.xml]`
+pub(crate) const INCLUDE_PREFIX: &str = "[include:";
+
+// Deepest nesting of included fragments, stops fragments including themselves / 包含片段的最大嵌套深度，防止片段包含自身
+pub(crate) const INCLUDE_MAX_DEPTH: usize = 8;
+
+// Namespace prefix of relationship id attributes such as `r:embed` / `r:embed` 等关系 id 属性的命名空间前缀
+pub(crate) const RELATIONSHIP_ATTR_PREFIX: &[u8] = b"r";
+
+// First id of generated bookmarks, kept above ids Word assigns to template bookmarks / 生成书签的起始 id，高于 Word 为模板书签分配的 id
+pub(crate) const BOOKMARK_ID_BASE: u32 = 100_000;
+
+// Run style of inserted hyperlinks / 插入超链接的运行样式
+pub(crate) const HYPERLINK_RUN_STYLE: &str = "Hyperlink";
+
+// Separator between link target and display text / 链接目标与显示文本之间的分隔符
+pub(crate) const LINK_TEXT_SEPARATOR: char = '|';
+
+// Opening bracket of a marker / 标记的开始括号
+pub(crate) const MARKER_OPEN: char = '[';
+
+// Closing bracket of a marker / 标记的结束括号
+pub(crate) const MARKER_CLOSE: char = ']';
+
 // Relationship ID prefix / 关系 ID 前缀
 pub(crate) const REL_ID_PREFIX: &str = "rId";
 
@@ -183,12 +685,28 @@ pub(crate) const EFFECT_EXTENT_TOP: &str = "0";
 pub(crate) const EFFECT_EXTENT_RIGHT: &str = "24765";
 pub(crate) const EFFECT_EXTENT_BOTTOM: &str = "24130";
 
+// Positioning frames of anchored drawings / 浮动绘图的定位参照
+pub(crate) const ANCHOR_RELATIVE_FROM_H: &str = "column";
+pub(crate) const ANCHOR_RELATIVE_FROM_V: &str = "paragraph";
+
+// Base z-order of anchored drawings, the image id is added / 浮动绘图的基础叠放次序，加上图片 id
+pub(crate) const ANCHOR_RELATIVE_HEIGHT_BASE: u32 = 251_658_240;
+
+// Rectangular wrap polygon of tight wrapping, in the 21600 unit square / 紧密环绕的矩形环绕多边形，以 21600 单位正方形表示
+pub(crate) const WRAP_POLYGON_RECT: &str = concat!(
+    r#"<wp:wrapPolygon edited="0"><wp:start x="0" y="0"/><wp:lineTo x="0" y="21600"/>"#,
+    r#"<wp:lineTo x="21600" y="21600"/><wp:lineTo x="21600" y="0"/><wp:lineTo x="0" y="0"/></wp:wrapPolygon>"#,
+);
+
 // Lock attributes / 锁定属性
 pub(crate) const NO_CHANGE_ASPECT: &str = "1";
 
 // Coordinate values / 坐标值
 pub(crate) const COORD_ZERO: &str = "0";
 
+// DrawingML angle units per degree / 每度对应的 DrawingML 角度单位
+pub(crate) const ANGLE_UNITS_PER_DEGREE: u32 = 60_000;
+
 // ---------- JPEG marker constants / JPEG 标记常量 ----------
 
 // JPEG SOF marker range / JPEG SOF 标记范围
@@ -200,10 +718,60 @@ pub(crate) const JPEG_MARKER_DHT: u8 = 0xC4; // Define Huffman Table
 pub(crate) const JPEG_MARKER_JPG: u8 = 0xC8; // JPG extension
 pub(crate) const JPEG_MARKER_DAC: u8 = 0xCC; // Define Arithmetic Coding
 
+// JPEG markers of the metadata walk / 元数据遍历的 JPEG 标记
+pub(crate) const JPEG_MARKER_PREFIX: u8 = 0xFF;
+pub(crate) const JPEG_MARKER_SOS: u8 = 0xDA; // Start of scan, entropy-coded data follows / 扫描开始，其后为熵编码数据
+
+// JPEG segments dropped when stripping metadata: APP1 (EXIF, XMP), APP12, APP13 (IPTC) and comments / 去除元数据时丢弃的 JPEG 段：APP1（EXIF、XMP）、APP12、APP13（IPTC）和注释
+pub(crate) const JPEG_METADATA_MARKERS: &[u8] = &[0xE1, 0xEC, 0xED, 0xFE];
+
 // JPEG segment offset / JPEG 段偏移量
 pub(crate) const JPEG_INITIAL_OFFSET: usize = 2;
 pub(crate) const JPEG_MIN_SEGMENT_SIZE: usize = 9;
 
+// PNG chunk layout / PNG 块布局
+pub(crate) const PNG_SIGNATURE_LEN: usize = 8;
+pub(crate) const PNG_CHUNK_HEADER_LEN: usize = 8; // Length + type / 长度 + 类型
+pub(crate) const PNG_CHUNK_CRC_LEN: usize = 4;
+pub(crate) const PNG_IHDR_DIMENSIONS_LEN: usize = 8; // Width + height / 宽度 + 高度
+
+// PNG chunks dropped when stripping metadata / 去除元数据时丢弃的 PNG 块
+pub(crate) const PNG_METADATA_CHUNKS: &[&[u8; 4]] = &[b"tEXt", b"zTXt", b"iTXt", b"eXIf", b"tIME"];
+
+// Chunks scanned for IHDR before giving up / 放弃前扫描 IHDR 的块数
+pub(crate) const PNG_MAX_SCANNED_CHUNKS: usize = 16;
+
+// GIF logical screen size offset, little-endian u16 width then height / GIF 逻辑屏幕尺寸偏移，小端 u16 宽度后接高度
+pub(crate) const GIF_DIMENSIONS_OFFSET: usize = 6;
+
+// BMP reserved header bytes, always zero / BMP 保留的头部字节，始终为零
+pub(crate) const BMP_RESERVED_RANGE: std::ops::Range<usize> = 6..10;
+
+// BITMAPINFOHEADER size offsets, little-endian i32, a negative height meaning top-down rows / BITMAPINFOHEADER 尺寸偏移，小端 i32，负高度表示自上而下的行
+pub(crate) const BMP_WIDTH_OFFSET: usize = 18;
+pub(crate) const BMP_HEIGHT_OFFSET: usize = 22;
+
+// WebP form type and first chunk offsets / WebP 格式类型和第一个块的偏移
+pub(crate) const WEBP_FORM_RANGE: std::ops::Range<usize> = 8..12;
+pub(crate) const WEBP_CHUNK_RANGE: std::ops::Range<usize> = 12..16;
+pub(crate) const WEBP_CHUNK_DATA_OFFSET: usize = 20;
+
+// WebP bitstream chunks: lossy, lossless and extended / WebP 位流块：有损、无损和扩展
+pub(crate) const WEBP_CHUNK_VP8: &[u8] = b"VP8 ";
+pub(crate) const WEBP_CHUNK_VP8L: &[u8] = b"VP8L";
+pub(crate) const WEBP_CHUNK_VP8X: &[u8] = b"VP8X";
+
+// VP8 key frame size after the frame tag and start code, 14-bit little-endian / VP8 关键帧尺寸，位于帧标签和起始码之后，14 位小端
+pub(crate) const WEBP_VP8_SIZE_OFFSET: usize = 6;
+pub(crate) const WEBP_VP8_SIZE_MASK: u16 = 0x3FFF;
+
+// VP8L size after the signature byte, 14 bits each minus one / VP8L 尺寸位于签名字节之后，各 14 位且减一
+pub(crate) const WEBP_VP8L_SIZE_OFFSET: usize = 1;
+pub(crate) const WEBP_VP8L_SIZE_BITS: u32 = 14;
+
+// VP8X canvas size after flags and reserved bytes, 24 bits each minus one / VP8X 画布尺寸位于标志和保留字节之后，各 24 位且减一
+pub(crate) const WEBP_VP8X_SIZE_OFFSET: usize = 4;
+
 // ---------- Error message constants / 错误消息常量 ----------
 
 pub(crate) const ERR_BASE64_DECODE: &str = "Failed convert Base64 data to image";
@@ -216,15 +784,92 @@ pub(crate) const ERR_INVALID_PNG_IHDR: &str = "Invalid PNG IHDR chunk";
 pub(crate) const ERR_INVALID_JPG_MARKER: &str = "Invalid JPG marker";
 pub(crate) const ERR_NO_SOF_MARKER: &str = "No SOF marker found in JPG";
 pub(crate) const ERR_UNKNOWN_FORMAT: &str = "Unknown image format";
+pub(crate) const ERR_INVALID_BMP_HEADER: &str = "Invalid BMP header";
+pub(crate) const ERR_INVALID_WEBP_HEADER: &str = "Invalid WebP header";
+pub(crate) const ERR_DATA_NOT_OBJECT: &str = "Placeholder data must serialize to an object";
+pub(crate) const ERR_OUTPUT_LIMIT: &str = "Output size limit exceeded";
+pub(crate) const ERR_CHANNEL_CLOSED: &str = "Output channel closed by the receiver";
+pub(crate) const ERR_IMAGE_DEFAULT_DISABLED: &str = "No default image directory configured";
+pub(crate) const ERR_IMAGE_DEFAULT_OUTSIDE: &str = "Path is outside the default image directory";
 
 // ---------- Regex pattern constants / 正则表达式模式常量 ----------
 
-// Placeholder detection pattern / 占位符检测模式
-pub(crate) const REGEX_PLACEHOLDER: &str = r"\S(.+?)]";
+// Placeholder detection pattern for table rows, a bracketed field that does not start with a space or digit, so `see note]`, `[1]` or `[ ]` stay static / 表格行的占位符检测模式，即不以空格或数字开头的括号字段，因此 `see note]`、`[1]` 或 `[ ]` 保持为静态文本
+pub(crate) const REGEX_PLACEHOLDER: &str = r"\[[^\[\]{}\s\d][^\[\]{}]*\]";
+
+// A whole text that is a single `[field]` placeholder / 整段文本为单个 `[field]` 占位符
+pub(crate) const REGEX_BRACKET_FIELD: &str = r"^\s*\[([^\[\]{}]+)\]\s*$";
+
+// A `{{field}}` placeholder that is not a loop marker / 非循环标记的 `{{field}}` 占位符
+pub(crate) const REGEX_BRACE_FIELD: &str = r"\{\{([^#{}][^{}]*)\}\}";
+
+// Regex matching a whole placeholder inside joined paragraph text, `{{key}}` or `[field]` / 在拼接的段落文本中匹配完整占位符的正则表达式，`{{key}}` 或 `[field]`
+pub(crate) const REGEX_ANY_PLACEHOLDER: &str = r"\{\{[^{}]+\}\}|\[[^\[\]{}]+\]";
 
 // Relationship ID pattern / 关系 ID 模式
 pub(crate) const REGEX_REL_ID: &str = r#"Id="(rId\d+)""#;
 
+// Regex pattern for the theme font language element of settings.xml / settings.xml 主题字体语言元素的正则表达式模式
+pub(crate) const REGEX_THEME_FONT_LANG: &str = r#"<w:themeFontLang\b[^>]*?/>"#;
+
+// Regex pattern for a w:val attribute / w:val 属性的正则表达式模式
+pub(crate) const REGEX_ATTR_VAL: &str = r#"\sw:val="[^"]*""#;
+
+// Regex to split a comparison such as `qty==0` into field, operator and literal / 将 `qty==0` 等比较拆分为字段、运算符和字面量的正则表达式
+pub(crate) const REGEX_COMPARISON: &str = r"^\s*(.+?)\s*(==|!=|>=|<=|>|<)\s*(.*?)\s*$";
+
+// Regex to capture the root element of an SVG image / 捕获 SVG 图片根元素的正则表达式
+pub(crate) const REGEX_SVG_ROOT: &str = r"<svg\b[^>]*>";
+
+// Regex to capture a length attribute of the SVG root, e.g. `width="120px"` / 捕获 SVG 根元素长度属性的正则表达式，例如 `width="120px"`
+pub(crate) const REGEX_SVG_LENGTH: &str =
+    r#"\s(width|height)\s*=\s*["']\s*([0-9.]+)\s*(px)?\s*["']"#;
+
+// Regex to capture the SVG view box size / 捕获 SVG 视图框尺寸的正则表达式
+pub(crate) const REGEX_SVG_VIEW_BOX: &str =
+    r#"\sviewBox\s*=\s*["']\s*[-0-9.]+[\s,]+[-0-9.]+[\s,]+([0-9.]+)[\s,]+([0-9.]+)\s*["']"#;
+
+// ---------- Value modifier constants / 值修饰符常量 ----------
+
+// Separator between modifier name, key and arguments / 修饰符名称、键和参数之间的分隔符
+pub(crate) const MODIFIER_SEPARATOR: char = ':';
+
+// Clamp numeric value to a range, e.g. [clamp:score:0:100] / 将数值限制在范围内
+pub(crate) const MODIFIER_CLAMP: &str = "clamp";
+
+// English ordinal of an integer, e.g. [ord:rank] / 整数的英文序数形式
+pub(crate) const MODIFIER_ORDINAL: &str = "ord";
+
+// Truncate text to a number of characters, e.g. [trunc:remark:40] / 将文本截断为指定字符数
+pub(crate) const MODIFIER_TRUNCATE: &str = "trunc";
+
+// Ellipsis appended to truncated text / 截断文本后追加的省略号
+pub(crate) const TRUNCATE_ELLIPSIS: char = '…';
+
+// Accounting format with grouping and negatives in parentheses, e.g. [acct:amount] or [acct:amount:FF0000] / 带分组且负数加括号的会计格式，例如 [acct:amount] 或 [acct:amount:FF0000]
+pub(crate) const MODIFIER_ACCOUNTING: &str = "acct";
+
+// Digit group separator of the accounting format / 会计格式的数字分组分隔符
+pub(crate) const ACCOUNTING_GROUP_SEPARATOR: char = ',';
+
+// Parentheses around negative accounting values / 负会计值外围的括号
+pub(crate) const ACCOUNTING_NEGATIVE_OPEN: char = '(';
+pub(crate) const ACCOUNTING_NEGATIVE_CLOSE: char = ')';
+
+// All known named modifiers / 所有已知的命名修饰符
+pub(crate) const MODIFIERS: &[&str] = &[
+    MODIFIER_CLAMP,
+    MODIFIER_ORDINAL,
+    MODIFIER_TRUNCATE,
+    MODIFIER_ACCOUNTING,
+];
+
+// Built-in transform converting to uppercase, e.g. [upper:name] / 转换为大写的内置变换，例如 [upper:name]
+pub(crate) const TRANSFORM_UPPER: &str = "upper";
+
+// Built-in transform converting to lowercase, e.g. [lower:name] / 转换为小写的内置变换，例如 [lower:name]
+pub(crate) const TRANSFORM_LOWER: &str = "lower";
+
 // ---------- Image description constants / 图片描述常量 ----------
 
 pub(crate) const DEFAULT_IMAGE_DESCRIPTION: &str = "Generated Image";
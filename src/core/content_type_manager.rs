@@ -0,0 +1,112 @@
+use crate::core::constant::{
+    CONTENT_TYPE_BASE_CAPACITY, CONTENT_TYPE_BMP, CONTENT_TYPE_GIF, CONTENT_TYPE_JPEG,
+    CONTENT_TYPE_PNG, CONTENT_TYPE_SVG, CONTENT_TYPE_WEBP, IMAGE_EXT_BMP, IMAGE_EXT_GIF,
+    IMAGE_EXT_JPEG, IMAGE_EXT_PNG, IMAGE_EXT_SVG, IMAGE_EXT_WEBP,
+};
+use bytes::{Bytes, BytesMut};
+use std::str::from_utf8;
+
+/// Manager for the package content types ([Content_Types].xml) / 包内容类型（[Content_Types].xml）管理器
+///
+/// Collects extension defaults that must be declared and merges them into the original content types XML / 收集必须声明的扩展名默认值，并将其合并到原始内容类型 XML 中
+pub(crate) struct ContentTypeManager {
+    defaults: Vec<(String, String)>, // (extension, content type) pairs to declare / 要声明的（扩展名，内容类型）对
+    original_content: Option<Bytes>, // Original [Content_Types].xml content (zero-copy) / 原始 [Content_Types].xml 内容（零拷贝）
+}
+
+impl ContentTypeManager {
+    /// Create new content type manager / 创建新的内容类型管理器
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Self {
+            defaults: Vec::new(),
+            original_content: None,
+        }
+    }
+
+    /// Set initial content types file content / 设置初始内容类型文件内容
+    ///
+    /// # Arguments / 参数
+    /// * `content` - Original [Content_Types].xml bytes / 原始 [Content_Types].xml 字节
+    #[inline]
+    pub(crate) fn set_initial_content(&mut self, content: Bytes) {
+        self.original_content = Some(content);
+    }
+
+    /// Register an extension default / 注册扩展名默认值
+    ///
+    /// Later registrations of the same extension are ignored / 同一扩展名的后续注册将被忽略
+    ///
+    /// # Arguments / 参数
+    /// * `extension` - File extension without dot (e.g. "png") / 不带点的文件扩展名（例如 "png"）
+    /// * `content_type` - MIME content type / MIME 内容类型
+    pub(crate) fn add_default(&mut self, extension: &str, content_type: &str) {
+        let extension = extension.trim_start_matches('.');
+        if !self
+            .defaults
+            .iter()
+            .any(|(ext, _)| ext.eq_ignore_ascii_case(extension))
+        {
+            self.defaults
+                .push((extension.to_string(), content_type.to_string()));
+        }
+    }
+
+    /// Get the content type of a generated image extension / 获取生成图片扩展名的内容类型
+    #[inline]
+    pub(crate) fn image_content_type(extension: &str) -> Option<&'static str> {
+        match extension {
+            IMAGE_EXT_PNG => Some(CONTENT_TYPE_PNG),
+            IMAGE_EXT_JPEG => Some(CONTENT_TYPE_JPEG),
+            IMAGE_EXT_SVG => Some(CONTENT_TYPE_SVG),
+            IMAGE_EXT_GIF => Some(CONTENT_TYPE_GIF),
+            IMAGE_EXT_BMP => Some(CONTENT_TYPE_BMP),
+            IMAGE_EXT_WEBP => Some(CONTENT_TYPE_WEBP),
+            _ => None,
+        }
+    }
+
+    /// Generate final content types file content / 生成最终的内容类型文件内容
+    ///
+    /// Extensions that are already declared in the original content are skipped / 跳过原始内容中已声明的扩展名
+    ///
+    /// # Returns / 返回
+    /// * `Some(bytes)` - Updated [Content_Types].xml content / 更新的 [Content_Types].xml 内容
+    /// * `None` - If no original content was set / 如果未设置原始内容
+    pub(crate) fn generate_final_content(&self) -> Option<Bytes> {
+        let content = self.original_content.as_ref()?;
+        let types_str = from_utf8(content).ok()?;
+        let lowercase = types_str.to_ascii_lowercase();
+
+        // Keep only extensions not declared yet / 仅保留尚未声明的扩展名
+        let missing = self
+            .defaults
+            .iter()
+            .filter(|(ext, _)| {
+                !lowercase.contains(&format!(r#"extension="{}""#, ext.to_ascii_lowercase()))
+            })
+            .collect::<Vec<_>>();
+
+        // Fast path: nothing to add / 快速路径：无需添加
+        if missing.is_empty() {
+            return Some(content.clone());
+        }
+
+        // Find insertion point / 查找插入点
+        let insert_pos = types_str.rfind("</Types>")?;
+
+        let mut buffer =
+            BytesMut::with_capacity(types_str.len() + missing.len() * CONTENT_TYPE_BASE_CAPACITY);
+        buffer.extend_from_slice(&types_str.as_bytes()[..insert_pos]);
+        for (ext, content_type) in missing {
+            buffer.extend_from_slice(br#"<Default Extension=""#);
+            buffer.extend_from_slice(ext.as_bytes());
+            buffer.extend_from_slice(br#"" ContentType=""#);
+            buffer.extend_from_slice(content_type.as_bytes());
+            buffer.extend_from_slice(br#""/>"#);
+        }
+        buffer.extend_from_slice(&types_str.as_bytes()[insert_pos..]);
+
+        Some(buffer.freeze())
+    }
+}
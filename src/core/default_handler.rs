@@ -1,5 +1,12 @@
+use crate::core::constant::{
+    ACCOUNTING_GROUP_SEPARATOR, ACCOUNTING_NEGATIVE_CLOSE, ACCOUNTING_NEGATIVE_OPEN,
+    LOOP_END_MARKER, MODIFIER_ACCOUNTING, MODIFIER_CLAMP, MODIFIER_ORDINAL, MODIFIER_SEPARATOR,
+    MODIFIER_TRUNCATE, MODIFIERS, PLACEHOLDER_CLOSE, PLACEHOLDER_OPEN, ROW_INDEX_KEY,
+    TRUNCATE_ELLIPSIS,
+};
 use crate::public::value_extern::ValueExt;
 use serde_json::Value;
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 /// Default implementation of placeholder value handling / 占位符值处理的默认实现
@@ -10,6 +17,7 @@ use std::collections::HashMap;
 /// - Uppercase transformation (^) / 大写转换 (^)
 /// - Image placeholders (@) / 图片占位符 (@)
 /// - Index placeholders ($index) / 索引占位符 ($index)
+/// - Named modifiers (clamp, ord, trunc, acct) / 命名修饰符 (clamp, ord, trunc, acct)
 #[derive(Default)]
 pub(crate) struct DefaultValueHandler;
 
@@ -39,6 +47,131 @@ impl DefaultValueHandler {
             _ => value.to_string(),
         }
     }
+
+    /// Split `name:key:arg...` modifier syntax / 拆分 `name:key:arg...` 修饰符语法
+    ///
+    /// Surrounding `{{` `}}` are kept on the returned key so it matches the placeholder map / 返回的键保留外围的 `{{` `}}` 以匹配占位符映射
+    ///
+    /// # Returns / 返回
+    /// * `Some((name, key, args))` - Known modifier with its key and arguments / 已知修饰符及其键和参数
+    /// * `None` - Not a modifier placeholder / 不是修饰符占位符
+    fn parse_modifier(cleaned_key: &str) -> Option<(&str, String, Vec<&str>)> {
+        let (inner, braced) = match cleaned_key
+            .strip_prefix(PLACEHOLDER_OPEN)
+            .and_then(|k| k.strip_suffix(LOOP_END_MARKER))
+        {
+            Some(inner) => (inner, true),
+            None => (cleaned_key, false),
+        };
+
+        let mut parts = inner.split(MODIFIER_SEPARATOR);
+        let name = parts.next()?;
+        if !MODIFIERS.contains(&name) {
+            return None;
+        }
+        let key = parts.next()?;
+        let key = if braced {
+            format!("{}{}{}", PLACEHOLDER_OPEN, key, LOOP_END_MARKER)
+        } else {
+            key.to_string()
+        };
+        Some((name, key, parts.collect()))
+    }
+
+    /// Apply a named modifier to a value / 对值应用命名修饰符
+    ///
+    /// # Arguments / 参数
+    /// * `name` - Modifier name / 修饰符名称
+    /// * `value` - Resolved value, if any / 解析出的值（如有）
+    /// * `args` - Modifier arguments / 修饰符参数
+    fn apply_modifier(name: &str, value: Option<&Value>, args: &[&str]) -> String {
+        let Some(value) = value else {
+            return "".to_string();
+        };
+        match name {
+            // Clamp numbers to [min, max], other values pass through / 将数字限制在 [min, max]，其他值原样输出
+            MODIFIER_CLAMP => {
+                let min = args.first().and_then(|v| v.parse::<f64>().ok());
+                let max = args.get(1).and_then(|v| v.parse::<f64>().ok());
+                match (value.as_f64(), min, max) {
+                    (Some(v), Some(min), Some(max)) if min <= max => {
+                        format!("{:.2}", v.clamp(min, max))
+                    }
+                    _ => Self::handle_without_quotes(value),
+                }
+            }
+            // Integers become English ordinals, other values pass through / 整数转换为英文序数，其他值原样输出
+            MODIFIER_ORDINAL => match value.as_i64() {
+                Some(n) => Self::ordinal(n),
+                None => Self::handle_without_quotes(value),
+            },
+            // Cut text to a number of characters / 将文本截断为指定字符数
+            MODIFIER_TRUNCATE => {
+                let text = Self::handle_without_quotes(value);
+                match args.first().and_then(|v| v.parse::<usize>().ok()) {
+                    Some(limit) => Self::truncate(text, limit),
+                    None => text,
+                }
+            }
+            // Grouped numbers with negatives in parentheses, other values pass through / 分组的数字，负数加括号，其他值原样输出
+            MODIFIER_ACCOUNTING => match value.as_f64() {
+                Some(v) => Self::accounting(v),
+                None => Self::handle_without_quotes(value),
+            },
+            _ => Self::handle_without_quotes(value),
+        }
+    }
+
+    /// Format a number with 2 decimals and grouped thousands, negatives in parentheses (`(1,234.00)`) / 将数字格式化为 2 位小数并按千分组，负数加括号（`(1,234.00)`）
+    ///
+    /// Values rounding to zero are never negative / 舍入为零的值永远不是负数
+    fn accounting(v: f64) -> String {
+        let fixed = format!("{:.2}", v.abs());
+        let (integer, fraction) = fixed.split_once('.').unwrap_or((&fixed, ""));
+        let mut grouped = String::with_capacity(fixed.len() + integer.len() / 3 + 2);
+        for (index, digit) in integer.chars().enumerate() {
+            if index > 0 && (integer.len() - index) % 3 == 0 {
+                grouped.push(ACCOUNTING_GROUP_SEPARATOR);
+            }
+            grouped.push(digit);
+        }
+        grouped.push('.');
+        grouped.push_str(fraction);
+
+        if v < 0.0 && fixed.bytes().any(|b| b.is_ascii_digit() && b != b'0') {
+            format!(
+                "{}{}{}",
+                ACCOUNTING_NEGATIVE_OPEN, grouped, ACCOUNTING_NEGATIVE_CLOSE
+            )
+        } else {
+            grouped
+        }
+    }
+
+    /// Truncate text to `limit` Unicode scalar values, appending an ellipsis if cut / 将文本截断为 `limit` 个 Unicode 标量值，截断时追加省略号
+    fn truncate(text: String, limit: usize) -> String {
+        match text.char_indices().nth(limit) {
+            Some((byte_pos, _)) => {
+                let mut truncated = text[..byte_pos].to_string();
+                truncated.push(TRUNCATE_ELLIPSIS);
+                truncated
+            }
+            // Under the limit, nothing to cut / 未超出限制，无需截断
+            None => text,
+        }
+    }
+
+    /// Format an integer as English ordinal (1st, 2nd, 11th, 23rd) / 将整数格式化为英文序数
+    fn ordinal(n: i64) -> String {
+        let suffix = match (n.unsigned_abs() % 10, n.unsigned_abs() % 100) {
+            (_, 11..=13) => "th",
+            (1, _) => "st",
+            (2, _) => "nd",
+            (3, _) => "rd",
+            _ => "th",
+        };
+        format!("{}{}", n, suffix)
+    }
 }
 
 // Implementation of ValueExt trait / ValueExt trait 的实现
@@ -49,64 +182,114 @@ impl ValueExt for DefaultValueHandler {
     /// 支持特殊语法：
     /// - `[^key]` - Uppercase value / 大写值
     /// - `[@key]` - Image placeholder / 图片占位符
+    /// - `[@key:200x150]`, `[@key:200x]` - Image placeholder sized in points, a missing side keeping the aspect ratio / 以磅为单位设置尺寸的图片占位符，缺失的一边保持宽高比
+    /// - `[@key|alt=text]` - Image placeholder with alternative text / 带替代文本的图片占位符
+    /// - `[@key|rotate=90]` - Image placeholder rotated clockwise in degrees / 按度数顺时针旋转的图片占位符
+    /// - `[@key|border=1pt:000000]` - Image placeholder with a border, the width in points (`pt`) or EMU without unit / 带边框的图片占位符，宽度以磅（`pt`）或无单位的 EMU 表示
+    /// - `[@key|anchor=behind|x=36pt|y=0]` - Floating image wrapped `square`, `tight`, `behind` or in `front` of text, offset from the column and paragraph / 以 `square`、`tight`、`behind` 或 `front` 方式环绕文字的浮动图片，相对于栏和段落偏移
+    /// - `[@key|default=<base64>]`, `[@key|defaultfile=path]` - Image embedded instead when the value is empty, files are read from the directory set with `set_image_default_dir` / 值为空时改为嵌入的图片，文件从 `set_image_default_dir` 设置的目录读取
+    /// - `[@key|bg=FFFFFF]` - Image placeholder flattening transparency against a color (`image` feature) / 将透明度与颜色合成的图片占位符（`image` 特性）
     /// - `[$index]` - Row index / 行索引
+    /// - `[pets.$index]` - Position within a nested array, also for deeper paths such as `[pets.toys.$index]` / 在嵌套数组中的位置，也适用于 `[pets.toys.$index]` 等更深的路径
+    /// - `[$visrow]` - Visible row index, not counting rows whose first column continues a merge / 可见行索引，不计第一列延续合并的行
+    /// - `[clamp:key:min:max]` - Numeric value clamped to a range / 限制在范围内的数值
+    /// - `[ord:key]` - Integer as English ordinal / 整数的英文序数形式
+    /// - `[trunc:key:n]` - Text cut to n characters with an ellipsis / 截断为 n 个字符并追加省略号的文本
+    /// - `[acct:key]`, `[acct:key:FF0000]` - Accounting format such as `(1,234.00)` for negatives, optionally colored / 会计格式，负数如 `(1,234.00)`，可选颜色
     /// - `[key]` - Normal value / 普通值
     ///
     /// # Arguments / 参数
     /// * `index` - Current row index / 当前行索引
     /// * `key` - Placeholder key with brackets / 带括号的占位符键
     /// * `placeholders` - Value map / 值映射
-    fn replace_in_table(
+    fn replace_in_table<'k>(
         &self,
         index: usize,
-        key: &str,
+        key: &'k str,
         placeholders: &HashMap<String, Value>,
-    ) -> String {
-        let mut result = key.to_string();
-        // Remove brackets from key / 从键中移除括号
-        let cleaned_key = result.replace("]", "").replace("[", "");
+    ) -> Cow<'k, str> {
+        self.lookup_in_table(index, key, placeholders)
+            .unwrap_or_default()
+    }
+
+    /// Replace placeholders in regular text / 替换常规文本中的占位符
+    ///
+    /// Text that is not a placeholder is borrowed, so long static runs are returned without any allocation / 不是占位符的文本以借用方式返回，因此长静态运行不进行任何分配
+    ///
+    /// # Arguments / 参数
+    /// * `content` - Text content that may contain placeholders / 可能包含占位符的文本内容
+    /// * `placeholders` - Value map / 值映射
+    fn replace<'k>(&self, content: &'k str, placeholders: &HashMap<String, Value>) -> Cow<'k, str> {
+        self.lookup(content, placeholders).unwrap_or_default()
+    }
+
+    /// Look up placeholders in table cells, `None` when the value is missing / 在表格单元格中查找占位符，值缺失时为 `None`
+    ///
+    /// # Arguments / 参数
+    /// * `index` - Current row index / 当前行索引
+    /// * `key` - Placeholder key with brackets / 带括号的占位符键
+    /// * `placeholders` - Value map / 值映射
+    fn lookup_in_table<'k>(
+        &self,
+        index: usize,
+        key: &'k str,
+        placeholders: &HashMap<String, Value>,
+    ) -> Option<Cow<'k, str>> {
+        // Remove the surrounding brackets only, brackets inside the key are kept / 仅移除外围括号，保留键内部的括号
+        let cleaned_key = key
+            .strip_prefix('[')
+            .and_then(|k| k.strip_suffix(']'))
+            .unwrap_or(key);
 
         // Helper to get value from placeholders / 从占位符获取值的辅助函数
-        let handle = |cleaned_key: String| -> String {
-            if let Some(row) = placeholders.get(&cleaned_key) {
-                Self::handle_without_quotes(row)
-            } else {
-                "".to_string()
-            }
+        let handle = |cleaned_key: &str| -> Option<String> {
+            placeholders
+                .get(cleaned_key)
+                .map(Self::handle_without_quotes)
         };
 
+        // Handle named modifiers / 处理命名修饰符
+        let value = if let Some((name, key, args)) = Self::parse_modifier(cleaned_key) {
+            let value = placeholders.get(&key)?;
+            Self::apply_modifier(name, Some(value), &args)
+        }
         // Handle uppercase transformation / 处理大写转换
-        if cleaned_key.contains("^") {
-            result = handle(cleaned_key.replace("^", "")).to_uppercase()
+        else if cleaned_key.contains("^") {
+            handle(&cleaned_key.replace("^", ""))?.to_uppercase()
         }
         // Handle image placeholder - return base64 value / 处理图片占位符 - 返回 base64 值
         else if cleaned_key.contains("@") {
-            result = handle(cleaned_key.replace("@", ""))
+            handle(&cleaned_key.replace("@", ""))?
         }
         // Handle row index / 处理行索引
-        else if cleaned_key == "$index" {
-            result = index.to_string();
+        else if cleaned_key == ROW_INDEX_KEY {
+            index.to_string()
         }
         // Handle default content / 处理默认内容
         else {
-            result = handle(cleaned_key);
-        }
-
-        result
+            handle(cleaned_key)?
+        };
+        Some(Cow::Owned(value))
     }
 
-    /// Replace placeholders in regular text / 替换常规文本中的占位符
+    /// Look up placeholders in regular text, `None` when the value is missing / 在常规文本中查找占位符，值缺失时为 `None`
+    ///
+    /// Text that is not a placeholder is found as itself / 不是占位符的文本以其自身作为结果
     ///
     /// # Arguments / 参数
     /// * `content` - Text content that may contain placeholders / 可能包含占位符的文本内容
     /// * `placeholders` - Value map / 值映射
-    fn replace(&self, content: &str, placeholders: &HashMap<String, Value>) -> String {
+    fn lookup<'k>(
+        &self,
+        content: &'k str,
+        placeholders: &HashMap<String, Value>,
+    ) -> Option<Cow<'k, str>> {
         // If content looks like a placeholder, process it / 如果内容看起来像占位符，则处理它
-        if content.starts_with("{{") && content.ends_with("}}") {
-            return self.replace_in_table(0, content, placeholders);
+        if content.starts_with(PLACEHOLDER_OPEN) && content.ends_with(PLACEHOLDER_CLOSE) {
+            return self.lookup_in_table(0, content, placeholders);
         }
 
-        // Return original content if no match / 如果没有匹配则返回原始内容
-        content.to_string()
+        // Borrow original content if no match / 如果没有匹配则借用原始内容
+        Some(Cow::Borrowed(content))
     }
 }
@@ -0,0 +1,97 @@
+use crate::core::constant::{
+    DEFAULT_BUFFER_SIZE, REGEX_ANY_PLACEHOLDER, XML_PARAGRAPH, XML_TABLE, XML_TEXT,
+};
+use crate::public::report::GenerationWarning;
+use quick_xml::Reader;
+use quick_xml::events::Event;
+use regex::Regex;
+use std::sync::LazyLock;
+use tokio::io::AsyncBufRead;
+
+static REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(REGEX_ANY_PLACEHOLDER).unwrap());
+
+/// Scan a template for constructs that keep placeholders from being filled / 扫描模板中导致占位符无法填充的结构
+///
+/// Reports tables nested in table cells and placeholders split across runs. Merge fields are not reported since they are filled / 报告嵌套在表格单元格中的表格以及拆分到多个运行中的占位符。合并域会被填充，因此不报告
+///
+/// # Arguments / 参数
+/// * `reader` - Template document.xml / 模板 document.xml
+pub(crate) async fn scan_template<R>(reader: R) -> Result<Vec<GenerationWarning>, quick_xml::Error>
+where
+    R: AsyncBufRead + Unpin,
+{
+    let mut reader = Reader::from_reader(reader);
+    let mut buf = Vec::with_capacity(DEFAULT_BUFFER_SIZE);
+    let mut warnings = Vec::new();
+
+    let mut table_depth = 0usize;
+    let mut in_text = false;
+    // Text fragments of the open paragraphs, text boxes nest paragraphs / 已打开段落的文本片段，文本框会嵌套段落
+    let mut paragraphs: Vec<Vec<String>> = Vec::new();
+
+    loop {
+        buf.clear();
+        match reader.read_event_into_async(&mut buf).await? {
+            Event::Start(e) => match e.name().as_ref() {
+                name if name == XML_TABLE.as_bytes() => {
+                    table_depth += 1;
+                    if table_depth > 1 {
+                        warnings.push(GenerationWarning::NestedTable {
+                            position: reader.buffer_position(),
+                        });
+                    }
+                }
+                XML_PARAGRAPH => paragraphs.push(Vec::new()),
+                XML_TEXT => in_text = true,
+                _ => {}
+            },
+            Event::End(e) => match e.name().as_ref() {
+                name if name == XML_TABLE.as_bytes() => table_depth = table_depth.saturating_sub(1),
+                XML_PARAGRAPH => {
+                    if let Some(fragments) = paragraphs.pop() {
+                        for placeholder in split_placeholders(&fragments) {
+                            warnings.push(GenerationWarning::SplitPlaceholder {
+                                placeholder,
+                                position: reader.buffer_position(),
+                            });
+                        }
+                    }
+                }
+                XML_TEXT => in_text = false,
+                _ => {}
+            },
+            Event::Text(text) if in_text => {
+                if let Some(fragments) = paragraphs.last_mut() {
+                    fragments.push(text.decode()?.into_owned());
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(warnings)
+}
+
+/// Placeholders of a paragraph that span more than one text fragment / 段落中跨越多个文本片段的占位符
+fn split_placeholders(fragments: &[String]) -> Vec<String> {
+    if fragments.len() < 2 {
+        return Vec::new();
+    }
+    // Fragment end offsets in the joined text / 片段在拼接文本中的结束偏移
+    let mut ends = Vec::with_capacity(fragments.len());
+    let mut end = 0;
+    for fragment in fragments {
+        end += fragment.len();
+        ends.push(end);
+    }
+    let joined = fragments.concat();
+    REGEX
+        .find_iter(&joined)
+        .filter(|found| {
+            // A boundary strictly inside the match splits it / 严格位于匹配内部的边界会将其拆分
+            ends.iter()
+                .any(|&end| end > found.start() && end < found.end())
+        })
+        .map(|found| found.as_str().to_string())
+        .collect()
+}
@@ -0,0 +1,83 @@
+use crate::core::constant::{DEFAULT_BUFFER_SIZE, TEMP_FILE_EXTENSION, TEMP_FILE_PREFIX};
+use std::borrow::Cow;
+use std::env::temp_dir;
+use std::io;
+use std::path::PathBuf;
+use tokio::fs::{File as AsyncFile, read, write};
+use tokio::io::{AsyncRead, AsyncReadExt, BufReader};
+use tokio_util::either::Either;
+use uuid::Uuid;
+
+/// Template document.xml kept between reading the package and processing it / 在读取包与处理之间保存的模板 document.xml
+pub(crate) enum DocumentBuffer {
+    /// Temporary file, named uniquely per generation / 临时文件，每次生成使用唯一名称
+    File(PathBuf),
+    /// In-memory copy, the filesystem is not touched / 内存副本，不访问文件系统
+    Memory(Vec<u8>),
+}
+
+impl DocumentBuffer {
+    /// Buffer the document read from `reader` / 缓冲从 `reader` 读取的文档
+    ///
+    /// # Arguments / 参数
+    /// * `reader` - Reader of the document.xml entry / document.xml 条目的读取器
+    /// * `in_memory` - Keep the document in memory instead of a temporary file / 将文档保存在内存中而不是临时文件中
+    pub(crate) async fn read_from<R>(reader: &mut R, in_memory: bool) -> io::Result<Self>
+    where
+        R: AsyncRead + Unpin,
+    {
+        if in_memory {
+            let mut content = Vec::with_capacity(DEFAULT_BUFFER_SIZE);
+            reader.read_to_end(&mut content).await?;
+            return Ok(Self::Memory(content));
+        }
+
+        // A v7 UUID keeps concurrent generations apart / v7 UUID 使并发生成互不冲突
+        let uuid = Uuid::now_v7().to_string();
+        let path = temp_dir().join(format!(
+            "{}{}{}",
+            TEMP_FILE_PREFIX, uuid, TEMP_FILE_EXTENSION
+        ));
+        let mut file = AsyncFile::create(&path).await?;
+        // Built first so a failed copy deletes the file / 先构建，以便复制失败时删除文件
+        let buffer = Self::File(path);
+        tokio::io::copy(reader, &mut file).await?;
+        Ok(buffer)
+    }
+
+    /// Open a buffered reader over the document / 打开文档的缓冲读取器
+    pub(crate) async fn reader(&self) -> io::Result<Either<BufReader<AsyncFile>, &[u8]>> {
+        match self {
+            Self::File(path) => Ok(Either::Left(BufReader::new(AsyncFile::open(path).await?))),
+            Self::Memory(content) => Ok(Either::Right(content.as_slice())),
+        }
+    }
+
+    /// Read the whole document / 读取整个文档
+    pub(crate) async fn content(&self) -> io::Result<Cow<'_, [u8]>> {
+        match self {
+            Self::File(path) => Ok(Cow::Owned(read(path).await?)),
+            Self::Memory(content) => Ok(Cow::Borrowed(content)),
+        }
+    }
+
+    /// Replace the document / 替换文档
+    pub(crate) async fn replace(&mut self, content: Vec<u8>) -> io::Result<()> {
+        match self {
+            Self::File(path) => write(path, content).await,
+            Self::Memory(buffered) => {
+                *buffered = content;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Delete the temporary file, also when generation fails or its future is dropped / 删除临时文件，生成失败或其 future 被丢弃时同样删除
+impl Drop for DocumentBuffer {
+    fn drop(&mut self) {
+        if let Self::File(path) = self {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
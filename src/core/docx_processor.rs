@@ -1,24 +1,66 @@
 use crate::core::constant::{
-    DEFAULT_BUFFER_SIZE, DEFAULT_IMAGE_DESCRIPTION, ERR_NESTED_TABLE, ERR_PICTURE_NAME,
-    IMAGE_NAME_PREFIX, JPEG_BASE64_SIGNATURE, LOOP_END_MARKER, LOOP_START_MARKER, MERGE_CONTINUE,
-    MERGE_RESTART, MERGE_TYPE_CONTINUE, MERGE_TYPE_RESTART, PICTURE_NAME_CAPACITY,
-    PNG_BASE64_SIGNATURE, PREVIEW_BUFFER_SIZE, REGEX_PLACEHOLDER, TYPICAL_COLUMN_COUNT,
-    TYPICAL_DATA_ROW_COUNT, TYPICAL_HEADER_ROW_COUNT, TYPICAL_OTHER_EVENT_COUNT,
-    TYPICAL_ROW_EVENT_COUNT, XML_TABLE, XML_TABLE_CELL, XML_TABLE_CELL_PROPERTIES,
-    XML_TABLE_MERGE_TAG, XML_TABLE_ROW, XML_TEXT,
+    ACCOUNTING_NEGATIVE_OPEN, ALIGN_CENTER_MARKER, ALIGN_LEFT_MARKER, ALIGN_RIGHT_MARKER,
+    BOOKMARK_PREFIX, CANCELLATION_CHECK_EVENTS, CANCELLATION_CHECK_ROWS, COLUMN_CONDITION_MARKER,
+    DEFAULT_BUFFER_SIZE, DEFAULT_IMAGE_DESCRIPTION, ERR_IMAGE_DEFAULT_DISABLED,
+    ERR_IMAGE_DEFAULT_OUTSIDE, ERR_NESTED_TABLE, ERR_PICTURE_NAME, FIELD_CHAR_BEGIN,
+    GIF_BASE64_SIGNATURE, GROUP_KEY, HYPERLINK_RUN_STYLE, IMAGE_MARKER, IMAGE_NAME_PREFIX,
+    INTERNAL_LINK_PREFIX, JPEG_BASE64_SIGNATURE, LINE_BREAK, LINK_TEXT_SEPARATOR, LIST_BULLET,
+    LIST_PREFIX, LOOP_END_MARKER, LOOP_FILTER_SEPARATOR, LOOP_GROUP_PREFIX, LOOP_SORT_PREFIX,
+    LOOP_START_MARKER, MARKER_CLOSE, MARKER_OPEN, MERGE_CONTINUE, MERGE_RESTART,
+    MERGE_TYPE_CONTINUE, MERGE_TYPE_RESTART, MODIFIER_ACCOUNTING, MODIFIER_SEPARATOR, PAGE_BREAK,
+    PAGE_BREAK_MARKER, PARAGRAPH_PROPERTIES_AFTER_JC, PARAGRAPH_SEPARATOR, PICTURE_NAME_CAPACITY,
+    PLACEHOLDER_CLOSE, PLACEHOLDER_OPEN, PNG_BASE64_SIGNATURE, PREVIEW_BUFFER_SIZE, RAW_PREFIX,
+    REGEX_PLACEHOLDER, RUN_CLOSE, RUN_OPEN, RUN_PROPERTIES_ORDER, SKIP_IF_MARKER,
+    TABLE_CELL_PROPERTIES_AFTER_MERGE, TABLE_CELL_PROPERTIES_AFTER_SHADING, TOC_FIELD_RUNS,
+    TOC_MARKER, TYPICAL_COLUMN_COUNT, VISIBLE_ROW_KEY, XML_ATTR_INSTR, XML_ATTR_VAL,
+    XML_CANT_SPLIT, XML_COLOR, XML_FIELD_SIMPLE, XML_JUSTIFICATION, XML_LANGUAGE, XML_PARAGRAPH,
+    XML_PARAGRAPH_PROPERTIES, XML_RUN_PROPERTIES, XML_RUN_STYLE, XML_SECTION_PROPERTIES,
+    XML_SHADING, XML_SHADING_TAG, XML_TABLE, XML_TABLE_CELL, XML_TABLE_CELL_PROPERTIES,
+    XML_TABLE_GRID_BEFORE, XML_TABLE_GRID_COL, XML_TABLE_GRID_SPAN, XML_TABLE_HEADER,
+    XML_TABLE_MERGE, XML_TABLE_MERGE_TAG, XML_TABLE_ROW, XML_TABLE_ROW_PROPERTIES, XML_TEXT,
 };
-use crate::core::image_manager::ImageManager;
+use crate::core::fallback::{Fallback, lookup, resolve};
+use crate::core::field::{
+    ComplexField, FieldStep, field_char_type, merge_field_name, render_merge_field,
+};
+use crate::core::image_manager::{ImageManager, InsertedImage};
+use crate::core::image_options::{ImageDefault, ImageOptions};
+use crate::core::paragraph::ParagraphContext;
 use crate::core::relationship_manager::RelationshipManager;
-use crate::core::utils::flatten_json;
+use crate::core::replay_reader::ReplayReader;
+use crate::core::transform::Transforms;
+#[cfg(feature = "dates")]
+use crate::core::utils::format_now;
+use crate::core::utils::{
+    braces_to_brackets, bracket_field, brackets_to_braces, check_well_formed, evaluate_condition,
+    flatten_json, get_image_dimensions, group_by_field, is_base64_bmp, is_base64_svg,
+    is_base64_webp, is_svg, is_truthy, map_columns, map_values, sort_by_field,
+    unescape_loop_marker,
+};
+use crate::public::alignment::CellAlignment;
+use crate::public::capacity::CapacityHints;
+use crate::public::error::{DocxError, Limit};
+use crate::public::inspect::{Change, TableInfo};
+use crate::public::report::{ColumnMerge, GenerationReport, GenerationWarning, TableMergeSummary};
 use crate::public::value_extern::ValueExt;
+use bytes::Bytes;
+use quick_xml::errors::IllFormedError;
+use quick_xml::escape::escape;
 use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
 use quick_xml::{Reader, Writer};
 use regex::Regex;
 use serde_json::Value;
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{HashMap, VecDeque};
 use std::fmt::Write;
-use std::sync::LazyLock;
+use std::iter::Peekable;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::{Arc, LazyLock};
+use std::time::Instant;
+use tokio::fs::{canonicalize, read};
 use tokio::io::{AsyncBufRead, AsyncWrite, AsyncWriteExt};
+use tokio_util::sync::CancellationToken;
 
 /// Regex pattern for placeholder detection / 用于占位符检测的正则表达式模式
 ///
@@ -28,18 +70,140 @@ pub(crate) static REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(REGEX_PLA
 /// Table content structure / 表格内容结构
 struct TableContent<'a> {
     header_rows: Vec<Vec<Event<'a>>>,
-    data_rows: Vec<Event<'a>>,
+    leading_rows: usize, // Header rows above the data row / 数据行之上的标题行数
+    data_rows: Vec<Event<'a>>, // Repeating unit, one or more consecutive w:tr / 重复单元，一个或多个连续的 w:tr
     other_events: Vec<Event<'a>>,
     first_col: Option<String>, // First column placeholder key / 第一列占位符键
+    loop_options: Option<String>, // Filters and sort options of the loop marker / 循环标记的过滤条件和排序选项
+    conditional_columns: Vec<(Range<usize>, String)>, // Grid columns shown only when the key is truthy / 仅当键为真时显示的网格列
+    skip_conditions: Vec<String>, // Loop items matching any condition are skipped / 匹配任一条件的循环项被跳过
+}
+
+/// Resolved content of a w:t element / w:t 元素的解析内容
+enum RunContent {
+    /// Plain text written inside w:t / 写入 w:t 内的纯文本
+    Text(String),
+    /// Base64 image replacing the run / 替换运行的 base64 图片
+    Image(String, ImageOptions),
+    /// Resolved image bytes replacing the run / 替换运行的已解析图片字节
+    ImageBytes(Bytes, ImageOptions),
+    /// `defaultfile=` image read when the run is written / 写入运行时读取的 `defaultfile=` 图片
+    ImageFile(String, ImageOptions),
+    /// Run-level markup replacing the run / 替换运行的运行级标记
+    Markup(String),
+    /// Caller-supplied OOXML replacing the run / 调用方提供的替换运行的 OOXML
+    Raw(String),
 }
 
+/// Position of a table cell inside a buffered row / 缓冲行中表格单元格的位置
+struct CellLayout {
+    events: Range<usize>, // Event indices of the cell within the row / 单元格在行中的事件索引
+    grid: Range<usize>,   // Grid columns covered by the cell / 单元格覆盖的网格列
+}
+
+/// Callback resolving an image reference to encoded image bytes / 将图片引用解析为编码图片字节的回调
+pub(crate) type ImageResolver = Arc<dyn Fn(&str) -> Option<Vec<u8>> + Send + Sync>;
+
+/// Callback transforming a flattened loop item before it is rendered / 在渲染前转换展平循环项的回调
+pub(crate) type ItemTransform = Arc<dyn Fn(&mut Value) + Send + Sync>;
+
+/// Image bytes of one generation by placeholder key, shared with the caller / 单次生成的图片字节，按占位符键索引，与调用方共享
+pub(crate) type ImageMap = Arc<HashMap<String, Bytes>>;
+
 /// XML processor running in blocking thread / 在阻塞线程中运行的 XML 处理器
 pub(crate) struct DocxProcessor {
     // Custom cell value handler / 自定义单元格值处理器
-    pub(crate) cell_handler: Box<dyn ValueExt + Send>,
+    pub(crate) cell_handler: Arc<dyn ValueExt + Send>,
+
+    // Sources consulted when the cell handler does not find a placeholder / 单元格处理器未找到占位符时查询的来源
+    pub(crate) fallbacks: Arc<Vec<Fallback>>,
+
+    // Named transforms such as `[upper:key]` / `[upper:key]` 等命名变换
+    pub(crate) transforms: Arc<Transforms>,
 
     // Flag to skip w:t events during image processing / 在图片处理期间跳过 w:t 事件的标志
     pub(crate) skip_w_t_events: bool,
+
+    // Substitute placeholders found in element attributes / 替换元素属性中的占位符
+    pub(crate) replace_in_attributes: bool,
+
+    // Report collected while processing / 处理期间收集的报告
+    pub(crate) report: GenerationReport,
+
+    // Resolver for image references / 图片引用解析器
+    pub(crate) image_resolver: Option<ImageResolver>,
+
+    // Image bytes by placeholder key / 按占位符键索引的图片字节
+    pub(crate) image_bytes: ImageMap,
+
+    // Directory `defaultfile=` images are read from / 读取 `defaultfile=` 图片的目录
+    pub(crate) image_default_dir: Option<PathBuf>,
+
+    // Fail on loop items with inconsistent key sets / 循环项键集不一致时失败
+    pub(crate) strict_loop_keys: bool,
+
+    // Collect placeholders without a value / 收集没有值的占位符
+    pub(crate) strict: bool,
+
+    // Drop loop rows whose cells all resolve to empty / 丢弃所有单元格都解析为空的循环行
+    pub(crate) skip_empty_rows: bool,
+
+    // Compare merge candidates trimmed and case-folded / 合并候选值去除空白并忽略大小写后比较
+    pub(crate) normalize_merge: bool,
+
+    // Maximum generated loop rows over all tables / 所有表格生成的最大循环行数
+    pub(crate) max_rows: Option<usize>,
+
+    // Capacities preallocated while processing / 处理期间预分配的容量
+    pub(crate) capacity_hints: CapacityHints,
+
+    // Transform applied to each flattened loop item / 应用于每个展平循环项的转换
+    pub(crate) item_transform: Option<ItemTransform>,
+
+    // Cell alignment of loop table columns, keyed by loop key / 循环表格列的单元格对齐方式，按循环键索引
+    pub(crate) column_alignments: HashMap<String, Vec<(usize, CellAlignment)>>,
+
+    // Template key to data key of loop table columns, keyed by loop key / 循环表格列的模板键到数据键映射，按循环键索引
+    pub(crate) column_maps: HashMap<String, HashMap<String, String>>,
+
+    // Display text of mapped values per key / 每个键的映射值显示文本
+    pub(crate) value_maps: HashMap<String, HashMap<String, String>>,
+
+    // Keep every generated loop row on one page / 使每个生成的循环行保持在同一页
+    pub(crate) cant_split_rows: bool,
+
+    // Repeat the header rows of loop tables on each page / 在每页重复循环表格的标题行
+    pub(crate) repeat_header_rows: bool,
+
+    // Fill color of every other generated loop row / 每隔一个生成循环行的填充颜色
+    pub(crate) zebra_shading: Option<String>,
+
+    // Continue past ill-formed XML instead of failing / 遇到格式错误的 XML 时继续而不是失败
+    pub(crate) lenient_xml: bool,
+
+    // Accept `{{field}}` and `[field]` in every context / 在任何上下文中都接受 `{{field}}` 和 `[field]`
+    pub(crate) unified_syntax: bool,
+
+    // Next id of generated bookmarks / 下一个生成书签的 id
+    pub(crate) bookmark_id: u32,
+
+    // Language of inserted runs / 插入运行的语言
+    pub(crate) language: Option<String>,
+
+    // Write `[raw:key]` values as literal OOXML / 将 `[raw:key]` 的值作为字面 OOXML 写入
+    pub(crate) allow_raw_ooxml: bool,
+
+    // Reject raw OOXML values that are not well-formed / 拒绝格式不正确的原始 OOXML 值
+    pub(crate) validate_raw_ooxml: bool,
+
+    // Token aborting processing when cancelled / 取消时中止处理的令牌
+    pub(crate) cancellation: Option<CancellationToken>,
+
+    // Text replacements collected instead of only written, `None` when not previewing / 收集的文本替换，非预览时为 `None`
+    pub(crate) changes: Option<Vec<Change>>,
+
+    // Properties of the paragraph and run being written / 正在写入的段落和运行的属性
+    pub(crate) paragraph: ParagraphContext,
 }
 
 impl DocxProcessor {
@@ -60,14 +224,16 @@ impl DocxProcessor {
         placeholders: &HashMap<String, Value>,
         rel_manager: &mut RelationshipManager,
         img_manager: &mut ImageManager<'a>,
-    ) -> Result<(), quick_xml::Error>
+    ) -> Result<(), DocxError>
     where
         W: AsyncWrite + Unpin,
         R: AsyncBufRead + Unpin,
     {
         // Create XML writer wrapping the output writer / 创建包装输出写入器的 XML 写入器
         let mut xml_writer = Writer::new(writer);
-        let mut reader = Reader::from_reader(reader);
+        let mut reader = Reader::from_reader(ReplayReader::new(reader));
+        // End tags are matched here in lenient mode / 宽松模式下在此处匹配结束标签
+        reader.config_mut().check_end_names = !self.lenient_xml;
 
         // Buffers for XML event processing / XML 事件处理的缓冲区
         let buf = &mut Vec::with_capacity(DEFAULT_BUFFER_SIZE);
@@ -76,16 +242,50 @@ impl DocxProcessor {
         // State tracking variables / 状态跟踪变量
         let mut inside_text_tag = false; // Currently inside w:t tag / 当前在 w:t 标签内
         let mut skip_current_event = false; // Skip writing current event / 跳过写入当前事件
-        let mut pending_event: Option<Event> = None; // Lookahead event / 前瞻事件
+        let mut pending_events: VecDeque<Event> = VecDeque::new(); // Lookahead events / 前瞻事件
+        let mut open_elements: Vec<Vec<u8>> = Vec::new(); // Open elements in lenient mode / 宽松模式下的已打开元素
+
+        let mut events_read = 0usize; // Events since the last cancellation check / 自上次取消检查以来的事件数
 
         // Main event processing loop / 主事件处理循环
         loop {
+            events_read += 1;
+            if events_read.is_multiple_of(CANCELLATION_CHECK_EVENTS) {
+                self.check_cancelled()?;
+            }
+
             // Get next event (either pending or read new) / 获取下一个事件（待处理或读取新的）
-            let event = if let Some(e) = pending_event.take() {
+            let event = if let Some(e) = pending_events.pop_front() {
                 e
             } else {
-                reader.read_event_into_async(buf).await?
+                match reader.read_event_into_async(buf).await {
+                    Ok(event) => event,
+                    // The reader loses track of depth after a stray end tag / 遇到多余结束标签后读取器会丢失深度
+                    Err(quick_xml::Error::IllFormed(IllFormedError::UnmatchedEndTag(name)))
+                        if self.lenient_xml
+                            && open_elements.iter().any(|open| open == name.as_bytes()) =>
+                    {
+                        Event::End(BytesEnd::new(name))
+                    }
+                    // Keep going past ill-formed but readable constructs / 越过格式错误但可读取的结构继续处理
+                    Err(quick_xml::Error::IllFormed(e)) if self.lenient_xml => {
+                        self.report.warnings.push(GenerationWarning::MalformedXml {
+                            position: reader.error_position(),
+                            message: e.to_string(),
+                        });
+                        continue;
+                    }
+                    Err(e) => return Err(e.into()),
+                }
             };
+            // Section breaks of paragraphs split by a value move to the last split / 被值拆分的段落的分节符移到最后一个拆分段落
+            if let Event::Start(e) = &event
+                && e.name().as_ref() == XML_PARAGRAPH_PROPERTIES.as_bytes()
+                && !self.lenient_xml
+            {
+                self.hold_section(&mut reader, placeholders).await?;
+            }
+            self.paragraph.observe(&event);
 
             match event {
                 // Start tag event / 开始标签事件
@@ -101,7 +301,24 @@ impl DocxProcessor {
                             img_manager,
                         )
                         .await?;
+                    } else if let Some(value) = self.merge_field_value(&e, None, placeholders)? {
+                        // Buffer the cached field result up to the end of the field / 缓冲缓存的域结果直到域结束
+                        let mut result = Vec::new();
+                        let mut field_buf = Vec::new();
+                        loop {
+                            match reader.read_event_into_async(&mut field_buf).await? {
+                                Event::End(end) if end.name().as_ref() == XML_FIELD_SIMPLE => break,
+                                Event::Eof => break,
+                                event => result.push(event.into_owned()),
+                            }
+                            field_buf.clear();
+                        }
+                        let markup = render_merge_field(&result, value)?;
+                        xml_writer.get_mut().write_all(&markup).await?;
                     } else {
+                        if self.lenient_xml {
+                            open_elements.push(e.name().as_ref().to_vec());
+                        }
                         // Handle text elements / 处理文本元素
                         if e.name().as_ref() == XML_TEXT {
                             // Skip if we're in image processing mode / 如果在图片处理模式则跳过
@@ -109,52 +326,55 @@ impl DocxProcessor {
                                 continue;
                             }
 
-                            // Check if text contains base64 image / 检查文本是否包含 base64 图片
-                            let mut is_base64_image = false;
-                            let mut base64_data = None;
+                            // Peek at next event to check for run-level content / 查看下一个事件以检查运行级内容
+                            let mut replacement = None;
                             preview_buf.clear();
-                            {
-                                // Peek at next event to check for image / 查看下一个事件以检查图片
-                                match reader.read_event_into_async(preview_buf).await {
-                                    Ok(Event::Text(text)) => {
-                                        // Replace placeholders in text / 替换文本中的占位符
-                                        let replaced = self
-                                            .cell_handler
-                                            .replace(&text.decode()?, placeholders);
-
-                                        // Check for image signatures / 检查图片签名
-                                        if replaced.starts_with(PNG_BASE64_SIGNATURE)
-                                            || replaced.starts_with(JPEG_BASE64_SIGNATURE)
-                                        {
-                                            is_base64_image = true;
-                                            base64_data = Some(replaced);
-                                        } else {
-                                            // Not an image, save for later processing / 不是图片，保存以供后续处理
-                                            pending_event = Some(Event::Text(text.into_owned()));
+                            match reader.read_event_into_async(preview_buf).await {
+                                Ok(Event::Text(text)) => {
+                                    let text = text.into_owned();
+                                    let original = text.decode()?.into_owned();
+                                    match self.resolve_run_content(&original, None, placeholders) {
+                                        // Plain text, save for later processing / 纯文本，保存以供后续处理
+                                        RunContent::Text(_) => {
+                                            pending_events.push_back(Event::Text(text));
+                                        }
+                                        content => {
+                                            // Only a w:t holding nothing but the marker is replaced, entities split the text / 仅替换只含标记的 w:t，实体会拆分文本
+                                            preview_buf.clear();
+                                            let next = reader
+                                                .read_event_into_async(preview_buf)
+                                                .await?
+                                                .into_owned();
+                                            if matches!(&next, Event::End(end) if end.name().as_ref() == XML_TEXT)
+                                            {
+                                                self.record_change(&original, "");
+                                                replacement = Some(content);
+                                            } else {
+                                                pending_events.push_back(Event::Text(text));
+                                            }
+                                            pending_events.push_back(next);
                                         }
                                     }
-                                    Ok(e) => {
-                                        pending_event = Some(e.into_owned());
-                                    }
-                                    Err(e) => return Err(e),
-                                };
-                            }
+                                }
+                                Ok(e) => {
+                                    pending_events.push_back(e.into_owned());
+                                }
+                                Err(e) => return Err(e.into()),
+                            };
 
-                            // Process base64 image if detected / 如果检测到 base64 图片则处理
-                            if is_base64_image {
-                                self.skip_w_t_events = true;
+                            // Replace the whole w:t element by run-level content / 用运行级内容替换整个 w:t 元素
+                            if let Some(content) = replacement {
                                 inside_text_tag = false;
-                                if let Some(base64_str) = base64_data {
-                                    self.process_base64_image(
-                                        &base64_str,
-                                        &mut xml_writer,
-                                        rel_manager,
-                                        img_manager,
-                                    )
-                                    .await?;
-                                }
-                                self.skip_w_t_events = false;
-                                continue; // Skip normal text processing / 跳过正常文本处理
+                                self.write_run_replacement(
+                                    &mut xml_writer,
+                                    content,
+                                    rel_manager,
+                                    img_manager,
+                                )
+                                .await?;
+                                // Skip the rest of this w:t element / 跳过此 w:t 元素的剩余部分
+                                self.skip_w_t_events = true;
+                                continue;
                             } else {
                                 inside_text_tag = true; // Enter text tag / 进入文本标签
                             }
@@ -162,6 +382,8 @@ impl DocxProcessor {
                         // Write start tag if not skipped / 如果未跳过则写入开始标签
                         if skip_current_event {
                             skip_current_event = false;
+                        } else if let Some(replaced) = self.process_attributes(&e, placeholders)? {
+                            xml_writer.write_event_async(Event::Start(replaced)).await?;
                         } else {
                             xml_writer.write_event_async(Event::Start(e)).await?;
                         }
@@ -170,15 +392,38 @@ impl DocxProcessor {
                 // Text content event / 文本内容事件
                 Event::Text(text) => {
                     // Skip if in image processing mode / 如果在图片处理模式则跳过
-                    if self.skip_w_t_events && inside_text_tag {
+                    if self.skip_w_t_events {
                         continue;
                     }
                     // Replace placeholders in text tags / 替换文本标签中的占位符
                     if inside_text_tag {
-                        let replaced = self.cell_handler.replace(&text.decode()?, placeholders);
-                        xml_writer
-                            .write_event_async(Event::Text(BytesText::from_escaped(replaced)))
-                            .await?;
+                        let original = text.decode()?;
+                        self.record_missing(&original, None, placeholders);
+                        let replaced = self.replace_text(&original, placeholders);
+                        self.record_change(&original, &replaced);
+                        #[cfg(feature = "tracing")]
+                        if replaced != original {
+                            tracing::trace!(placeholder = %original, "placeholder replaced");
+                        }
+                        if replaced.contains(PARAGRAPH_SEPARATOR) {
+                            // Continue each further paragraph with the same properties / 以相同属性续写后续每个段落
+                            for (index, segment) in replaced.split(PARAGRAPH_SEPARATOR).enumerate()
+                            {
+                                if index > 0 {
+                                    let continuation = self.paragraph.continuation()?;
+                                    xml_writer.get_mut().write_all(&continuation).await?;
+                                }
+                                xml_writer
+                                    .write_event_async(Event::Text(BytesText::from_escaped(
+                                        segment,
+                                    )))
+                                    .await?;
+                            }
+                        } else {
+                            xml_writer
+                                .write_event_async(Event::Text(BytesText::from_escaped(replaced)))
+                                .await?;
+                        }
                     } else {
                         // Pass through non-text-tag content / 传递非文本标签内容
                         xml_writer.write_event_async(Event::Text(text)).await?;
@@ -186,17 +431,65 @@ impl DocxProcessor {
                 }
                 // End tag event / 结束标签事件
                 Event::End(e) => {
+                    // Stray end tags are dropped and reported / 多余的结束标签被丢弃并报告
+                    if self.lenient_xml {
+                        match open_elements
+                            .iter()
+                            .rposition(|name| name == e.name().as_ref())
+                        {
+                            Some(index) => open_elements.truncate(index),
+                            None => {
+                                self.report.warnings.push(GenerationWarning::MalformedXml {
+                                    position: reader.buffer_position(),
+                                    message: format!(
+                                        "close tag `</{}>` does not match any open tag",
+                                        String::from_utf8_lossy(e.name().as_ref())
+                                    ),
+                                });
+                                buf.clear();
+                                continue;
+                            }
+                        }
+                    }
                     // Reset state when exiting text tag / 退出文本标签时重置状态
                     if e.name().as_ref() == XML_TEXT {
                         inside_text_tag = false;
-                        self.skip_w_t_events = false;
+                        // The replaced w:t element is dropped entirely / 被替换的 w:t 元素被整体丢弃
+                        if self.skip_w_t_events {
+                            self.skip_w_t_events = false;
+                            continue;
+                        }
                     }
                     // Skip if in image processing mode / 如果在图片处理模式则跳过
                     if self.skip_w_t_events {
                         continue;
                     }
+                    if e.name().as_ref() == XML_PARAGRAPH
+                        && let Some(markup) = self.paragraph.release_section()?
+                    {
+                        xml_writer.get_mut().write_all(&markup).await?;
+                    }
                     xml_writer.write_event_async(Event::End(e)).await?;
                 }
+                // Empty element event / 空元素事件
+                Event::Empty(e) => {
+                    if field_char_type(&e).as_deref() == Some(FIELD_CHAR_BEGIN) {
+                        let begin = e.into_owned();
+                        self.process_complex_field(
+                            &mut reader,
+                            &mut xml_writer,
+                            begin,
+                            placeholders,
+                        )
+                        .await?;
+                    } else if let Some(replaced) = self.process_attributes(&e, placeholders)? {
+                        xml_writer.write_event_async(Event::Empty(replaced)).await?;
+                    } else {
+                        xml_writer.write_event_async(Event::Empty(e)).await?;
+                    }
+                }
+                // Entity references such as `&#8217;` arrive as separate events / `&#8217;` 等实体引用作为单独事件到达
+                Event::GeneralRef(_) if self.skip_w_t_events => continue,
                 // End of file / 文件结束
                 Event::Eof => break,
                 // Pass through all other events / 传递所有其他事件
@@ -207,13 +500,880 @@ impl DocxProcessor {
         Ok(())
     }
 
+    /// Hold the section break of a paragraph that a value will split / 保留将被值拆分的段落的分节符
+    ///
+    /// Reads the properties starting at the current `w:pPr` ahead, and the rest of the paragraph when they hold a `w:sectPr`, then hands the markup back to the reader / 向前读取从当前 `w:pPr` 开始的属性，若其中有 `w:sectPr` 则读取段落的剩余部分，然后将标记交回读取器
+    ///
+    /// A held `w:sectPr` is left out of the markup handed back and written with the last split paragraph / 被保留的 `w:sectPr` 不包含在交回的标记中，而是随最后一个拆分段落写入
+    async fn hold_section<R>(
+        &mut self,
+        reader: &mut Reader<ReplayReader<R>>,
+        values: &HashMap<String, Value>,
+    ) -> Result<(), DocxError>
+    where
+        R: AsyncBufRead + Unpin,
+    {
+        // A separate reader leaves the open tags of the main one untouched / 独立的读取器不影响主读取器的已打开标签
+        let mut config = reader.config().clone();
+        config.check_end_names = false;
+        config.allow_unmatched_ends = true;
+        let mut ahead = Reader::from_reader(reader.get_mut());
+        *ahead.config_mut() = config;
+        let mut buf = Vec::new();
+
+        // Properties up to the end of the w:pPr, with the w:sectPr apart / 直到 w:pPr 结束的属性，w:sectPr 单独存放
+        let mut properties = Vec::new();
+        let mut section = Vec::new();
+        let mut section_at = 0;
+        let mut in_section = false;
+        let mut depth = 1;
+        loop {
+            let event = ahead.read_event_into_async(&mut buf).await?.into_owned();
+            buf.clear();
+            match &event {
+                Event::Eof => break,
+                Event::End(e) if in_section && e.name().as_ref() == XML_SECTION_PROPERTIES => {
+                    in_section = false;
+                    section.push(event);
+                    continue;
+                }
+                _ if in_section => {
+                    section.push(event);
+                    continue;
+                }
+                Event::Start(e) | Event::Empty(e)
+                    if depth == 1 && e.name().as_ref() == XML_SECTION_PROPERTIES =>
+                {
+                    in_section = matches!(event, Event::Start(_));
+                    section_at = properties.len();
+                    section.push(event);
+                    continue;
+                }
+                Event::Start(e) if e.name().as_ref() == XML_PARAGRAPH_PROPERTIES.as_bytes() => {
+                    depth += 1
+                }
+                Event::End(e) if e.name().as_ref() == XML_PARAGRAPH_PROPERTIES.as_bytes() => {
+                    depth -= 1
+                }
+                _ => {}
+            }
+            properties.push(event);
+            if depth == 0 {
+                break;
+            }
+        }
+
+        // Values of the paragraph splitting it / 拆分段落的值
+        let mut rest = Vec::new();
+        let mut splits = 0;
+        if !section.is_empty() {
+            let mut depth = 1;
+            let mut inside_text = false;
+            loop {
+                let event = ahead.read_event_into_async(&mut buf).await?.into_owned();
+                buf.clear();
+                match &event {
+                    Event::Eof => break,
+                    Event::Start(e) if e.name().as_ref() == XML_PARAGRAPH => depth += 1,
+                    Event::End(e) if e.name().as_ref() == XML_PARAGRAPH => depth -= 1,
+                    Event::Start(e) if e.name().as_ref() == XML_TEXT => inside_text = true,
+                    Event::End(e) if e.name().as_ref() == XML_TEXT => inside_text = false,
+                    Event::Text(text) if inside_text => {
+                        splits += self
+                            .replace_text(&text.decode()?, values)
+                            .matches(PARAGRAPH_SEPARATOR)
+                            .count();
+                    }
+                    _ => {}
+                }
+                rest.push(event);
+                if depth == 0 {
+                    break;
+                }
+            }
+        }
+
+        let mut writer = Writer::new(Vec::new());
+        if splits > 0 {
+            self.paragraph.hold_section(section, splits);
+            for event in properties.into_iter().chain(rest) {
+                writer.write_event(event)?;
+            }
+        } else {
+            // The w:sectPr goes back where it was / w:sectPr 放回原处
+            properties.splice(section_at..section_at, section);
+            for event in properties.into_iter().chain(rest) {
+                writer.write_event(event)?;
+            }
+        }
+        reader.get_mut().replay(writer.into_inner());
+        Ok(())
+    }
+
+    /// Detect placeholders in element attributes / 检测元素属性中的占位符
+    ///
+    /// Every placeholder found is reported as a warning; when attribute replacement is enabled a rewritten element is returned / 发现的每个占位符都会作为警告报告；启用属性替换时返回重写后的元素
+    ///
+    /// # Returns / 返回
+    /// * `Some(element)` - Element with substituted attributes / 替换属性后的元素
+    /// * `None` - Element should be written unchanged / 元素应原样写入
+    fn process_attributes(
+        &mut self,
+        e: &BytesStart,
+        placeholders: &HashMap<String, Value>,
+    ) -> Result<Option<BytesStart<'static>>, quick_xml::Error> {
+        // Fast path: no attribute looks like a placeholder / 快速路径：没有属性看起来像占位符
+        let has_placeholder = e.attributes().flatten().any(|attr| {
+            let value = attr.value.as_ref();
+            (value.windows(2).any(|w| w == PLACEHOLDER_OPEN.as_bytes())
+                && value.windows(2).any(|w| w == LOOP_END_MARKER.as_bytes()))
+                || value.contains(&(MARKER_OPEN as u8))
+        });
+        if !has_placeholder {
+            return Ok(None);
+        }
+
+        let element = String::from_utf8_lossy(e.name().as_ref()).into_owned();
+        let mut replaced = BytesStart::new(element.clone());
+        for attr in e.attributes() {
+            let attr = attr?;
+            let value = attr.unescape_value()?;
+            // A whole `[field]` value is read as `{{field}}`, as in body text / 整个 `[field]` 值按 `{{field}}` 读取，与正文文本一致
+            let key = brackets_to_braces(&value);
+            if key.contains(PLACEHOLDER_OPEN) && key.contains(LOOP_END_MARKER) {
+                self.report
+                    .warnings
+                    .push(GenerationWarning::PlaceholderInAttribute {
+                        element: element.clone(),
+                        attribute: String::from_utf8_lossy(attr.key.as_ref()).into_owned(),
+                        value: value.to_string(),
+                    });
+
+                if self.replace_in_attributes {
+                    // push_attribute escapes the replaced value / push_attribute 会转义替换后的值
+                    let new_value = self.value_of(&key, placeholders);
+                    let key = String::from_utf8_lossy(attr.key.as_ref()).into_owned();
+                    replaced.push_attribute((key.as_str(), new_value.as_ref()));
+                    continue;
+                }
+            }
+            replaced.push_attribute(attr);
+        }
+
+        Ok(self.replace_in_attributes.then_some(replaced))
+    }
+
+    /// Replace a placeholder of regular text through the cell handler and its fallbacks / 通过单元格处理器及其后备来源替换常规文本的占位符
+    #[inline]
+    fn value_of<'k>(&self, key: &'k str, values: &HashMap<String, Value>) -> Cow<'k, str> {
+        resolve(
+            self.cell_handler.as_ref(),
+            &self.fallbacks,
+            &self.transforms,
+            None,
+            key,
+            values,
+        )
+    }
+
+    /// Replace a placeholder of a loop row through the cell handler and its fallbacks / 通过单元格处理器及其后备来源替换循环行的占位符
+    #[inline]
+    fn value_in_table<'k>(
+        &self,
+        index: usize,
+        key: &'k str,
+        values: &HashMap<String, Value>,
+    ) -> Cow<'k, str> {
+        resolve(
+            self.cell_handler.as_ref(),
+            &self.fallbacks,
+            &self.transforms,
+            Some(index),
+            key,
+            values,
+        )
+    }
+
+    /// Rewrite a `[field]` text into `{{field}}` when unified syntax is enabled / 启用统一语法时将 `[field]` 文本改写为 `{{field}}`
+    #[inline]
+    fn normalize_placeholder<'t>(&self, text: &'t str) -> Cow<'t, str> {
+        if self.unified_syntax {
+            brackets_to_braces(text)
+        } else {
+            Cow::Borrowed(text)
+        }
+    }
+
+    /// Replace the placeholder of a regular text / 替换常规文本的占位符
+    ///
+    /// Built-in markers like `[$now:%Y]` are resolved before the value handler is asked / 在调用值处理器之前先解析 `[$now:%Y]` 等内置标记
+    fn replace_text<'r>(&self, raw: &'r str, values: &HashMap<String, Value>) -> Cow<'r, str> {
+        #[cfg(feature = "dates")]
+        if let Some(now) = format_now(raw) {
+            return Cow::Owned(now);
+        }
+        // Escaped loop markers are literal text, not placeholders / 转义的循环标记是字面文本而非占位符
+        if let Some(literal) = unescape_loop_marker(raw) {
+            return Cow::Owned(literal);
+        }
+        match self.normalize_placeholder(raw) {
+            Cow::Borrowed(raw) => self.value_of(raw, values),
+            Cow::Owned(normalized) => Cow::Owned(self.value_of(&normalized, values).into_owned()),
+        }
+    }
+
+    /// Resolve the text of a w:t element / 解析 w:t 元素的文本
+    ///
+    /// Markers such as `[pagebreak]`, `[toc]`, `[ilink:...]`, `[bookmark:...]` and base64 images replace the whole run, everything else stays text / `[pagebreak]`、`[toc]`、`[ilink:...]`、`[bookmark:...]` 等标记和 base64 图片会替换整个运行，其他内容保持为文本
+    ///
+    /// # Arguments / 参数
+    /// * `raw` - Original text of the element / 元素的原始文本
+    /// * `row_index` - Row index inside a loop table, `None` for regular text / 循环表格中的行索引，常规文本为 `None`
+    /// * `values` - Placeholder values or loop item / 占位符值或循环项
+    fn resolve_run_content(
+        &mut self,
+        raw: &str,
+        row_index: Option<usize>,
+        values: &HashMap<String, Value>,
+    ) -> RunContent {
+        // Built-in markers / 内置标记
+        match raw.trim() {
+            PAGE_BREAK_MARKER => {
+                return RunContent::Markup(format!(
+                    "{}{}{}{}",
+                    RUN_OPEN,
+                    self.run_properties(&[]),
+                    PAGE_BREAK,
+                    RUN_CLOSE
+                ));
+            }
+            TOC_MARKER => return RunContent::Markup(TOC_FIELD_RUNS.to_string()),
+            _ => {}
+        }
+        if let Some(markup) = self.internal_link(raw.trim()) {
+            return RunContent::Markup(markup);
+        }
+        if let Some(markup) = self.bookmark(raw.trim()) {
+            return RunContent::Markup(markup);
+        }
+        if let Some(markup) = self.list(raw.trim(), row_index, values) {
+            return RunContent::Markup(markup);
+        }
+        if let Some(markup) = self.raw_ooxml(raw.trim(), row_index, values) {
+            return RunContent::Raw(markup);
+        }
+
+        // Image options such as `[@photo|alt=Portrait]` / `[@photo|alt=Portrait]` 等图片选项
+        let (raw, mut options) = match ImageOptions::parse(raw) {
+            Some((placeholder, options)) => (Cow::Owned(placeholder), options),
+            None => (Cow::Borrowed(raw), ImageOptions::default()),
+        };
+        let raw = raw.as_ref();
+
+        #[cfg(feature = "dates")]
+        if let Some(now) = format_now(raw) {
+            return RunContent::Text(now);
+        }
+
+        // Images passed as bytes beside the placeholders / 与占位符一起以字节形式传入的图片
+        if let Some(bytes) = self.image_bytes_of(raw) {
+            return RunContent::ImageBytes(bytes.clone(), options);
+        }
+
+        let replaced = match row_index {
+            Some(index) => self.value_in_table(index, raw, values),
+            None => self.replace_text(raw, values),
+        }
+        .into_owned();
+
+        // Absent images fall back to the default graphic, an unreadable file embeds nothing / 缺失的图片回退到默认图形，无法读取的文件不嵌入任何内容
+        if replaced.is_empty()
+            && let Some(default) = options.default.take()
+        {
+            return match default {
+                ImageDefault::Base64(data) => RunContent::Image(data, options),
+                ImageDefault::File(path) => RunContent::ImageFile(path, options),
+            };
+        }
+
+        self.record_missing(raw, row_index, values);
+
+        // Check for image signatures / 检查图片签名
+        if replaced.starts_with(PNG_BASE64_SIGNATURE)
+            || replaced.starts_with(JPEG_BASE64_SIGNATURE)
+            || replaced.starts_with(GIF_BASE64_SIGNATURE)
+            || is_base64_bmp(&replaced)
+            || is_base64_webp(&replaced)
+            || is_base64_svg(&replaced)
+        {
+            return RunContent::Image(replaced, options);
+        }
+
+        // Resolve image references lazily / 延迟解析图片引用
+        if let Some(resolver) = &self.image_resolver
+            && !replaced.is_empty()
+            && Self::is_image_marker(raw)
+            && let Some(bytes) = resolver(&replaced)
+        {
+            return RunContent::ImageBytes(Bytes::from(bytes), options);
+        }
+
+        // Negative accounting values in their color / 以其颜色显示负会计值
+        if replaced.starts_with(ACCOUNTING_NEGATIVE_OPEN)
+            && let Some(color) = Self::accounting_color(raw)
+        {
+            return RunContent::Markup(format!(
+                r#"{}{}<w:t xml:space="preserve">{}</w:t>{}"#,
+                RUN_OPEN,
+                self.run_properties(&[
+                    BytesStart::new(XML_COLOR).with_attributes([(XML_ATTR_VAL, color)])
+                ]),
+                replaced,
+                RUN_CLOSE
+            ));
+        }
+
+        RunContent::Text(replaced)
+    }
+
+    /// Color argument of an accounting placeholder such as `[acct:amount:FF0000]` / `[acct:amount:FF0000]` 等会计占位符的颜色参数
+    fn accounting_color(raw: &str) -> Option<&str> {
+        let raw = raw.trim();
+        let inner = match raw.strip_prefix(PLACEHOLDER_OPEN) {
+            Some(inner) => inner.strip_suffix(PLACEHOLDER_CLOSE)?,
+            None => raw.strip_prefix(MARKER_OPEN)?.strip_suffix(MARKER_CLOSE)?,
+        };
+        let mut parts = inner.split(MODIFIER_SEPARATOR);
+        if parts.next()? != MODIFIER_ACCOUNTING {
+            return None;
+        }
+        let color = parts.nth(1)?.trim();
+        (color.len() == 6 && color.bytes().all(|b| b.is_ascii_hexdigit())).then_some(color)
+    }
+
+    /// Process a complex field starting at its `begin` field character / 从 `begin` 域字符开始处理复杂域
+    ///
+    /// A `MERGEFIELD` is buffered up to its `end` field character and replaced by a run holding the value. Any other field is written unchanged once its instruction is read, and its result continues through the regular event loop / `MERGEFIELD` 缓冲到其 `end` 域字符并被替换为包含值的运行。其他域在读取指令后原样写入，其结果继续经由常规事件循环处理
+    async fn process_complex_field<R, W>(
+        &mut self,
+        reader: &mut Reader<R>,
+        writer: &mut Writer<W>,
+        begin: BytesStart<'static>,
+        placeholders: &HashMap<String, Value>,
+    ) -> Result<(), quick_xml::Error>
+    where
+        R: AsyncBufRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        let mut field = ComplexField::default();
+        let mut field_buf = Vec::new();
+        let step = loop {
+            field_buf.clear();
+            let event = reader.read_event_into_async(&mut field_buf).await?;
+            if matches!(event, Event::Eof) {
+                break FieldStep::Replay;
+            }
+            match field.push(event.into_owned())? {
+                FieldStep::Pending => {}
+                step => break step,
+            }
+        };
+        self.write_complex_field(writer, begin, field, step, None, placeholders)
+            .await
+    }
+
+    /// Write a buffered complex field / 写入缓冲的复杂域
+    ///
+    /// # Arguments / 参数
+    /// * `step` - Final step of the buffering, only `Merge` replaces the field / 缓冲的最终步骤，仅 `Merge` 会替换域
+    /// * `row_index` - Row index inside a loop table, where the field is looked up as `[name]` / 循环表格中的行索引，此时域按 `[name]` 查找
+    async fn write_complex_field<W>(
+        &mut self,
+        writer: &mut Writer<W>,
+        begin: BytesStart<'_>,
+        field: ComplexField,
+        step: FieldStep,
+        row_index: Option<usize>,
+        values: &HashMap<String, Value>,
+    ) -> Result<(), quick_xml::Error>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        if let FieldStep::Merge = step
+            && let Some(name) = field.name()
+        {
+            // Close the begin run, write the value and reopen a run for the rest of the end run / 关闭 begin 运行，写入值并为 end 运行的剩余部分重新打开运行
+            let value = self.merge_field_lookup(name, row_index, values);
+            let markup = field.render(value)?;
+            writer.get_mut().write_all(RUN_CLOSE.as_bytes()).await?;
+            writer.get_mut().write_all(&markup).await?;
+            writer.get_mut().write_all(RUN_OPEN.as_bytes()).await?;
+        } else {
+            // Not a merge field, replay unchanged / 不是合并域，原样回放
+            writer.write_event_async(Event::Empty(begin)).await?;
+            for event in field.into_events() {
+                writer.write_event_async(event).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Buffer a complex field from the events of a buffered row / 从缓冲行的事件中缓冲复杂域
+    ///
+    /// Consumes the events following the `begin` field character up to the final step / 消耗 `begin` 域字符之后直到最终步骤的事件
+    fn buffer_complex_field<'e, 'b: 'e, I>(
+        events: &mut Peekable<I>,
+    ) -> Result<(ComplexField, FieldStep), quick_xml::Error>
+    where
+        I: Iterator<Item = &'e Event<'b>>,
+    {
+        let mut field = ComplexField::default();
+        for event in events.by_ref() {
+            match field.push(event.clone().into_owned())? {
+                FieldStep::Pending => {}
+                step => return Ok((field, step)),
+            }
+        }
+        Ok((field, FieldStep::Replay))
+    }
+
+    /// Look up the value of a merge field / 查找合并域的值
+    ///
+    /// `MERGEFIELD name` is the placeholder `{{name}}`, or the loop field `[name]` inside a loop row / `MERGEFIELD name` 即占位符 `{{name}}`，在循环行中为循环字段 `[name]`
+    fn merge_field_lookup(
+        &mut self,
+        name: &str,
+        row_index: Option<usize>,
+        values: &HashMap<String, Value>,
+    ) -> String {
+        let key = match row_index {
+            Some(_) => format!("{}{}{}", MARKER_OPEN, name, MARKER_CLOSE),
+            None => format!("{{{{{}}}}}", name),
+        };
+        self.record_missing(&key, row_index, values);
+        match row_index {
+            Some(index) => self.value_in_table(index, &key, values).into_owned(),
+            None => self.value_of(&key, values).into_owned(),
+        }
+    }
+
+    /// Resolve the value of a `w:fldSimple` merge field / 解析 `w:fldSimple` 合并域的值
+    ///
+    /// `MERGEFIELD name` is looked up like a complex merge field / `MERGEFIELD name` 的查找方式与复杂合并域相同
+    ///
+    /// # Returns / 返回
+    /// * `Some(value)` - Element is a merge field / 元素是合并域
+    /// * `None` - Element is not a merge field / 元素不是合并域
+    fn merge_field_value(
+        &mut self,
+        e: &BytesStart,
+        row_index: Option<usize>,
+        values: &HashMap<String, Value>,
+    ) -> Result<Option<String>, quick_xml::Error> {
+        if e.name().as_ref() != XML_FIELD_SIMPLE {
+            return Ok(None);
+        }
+        let Some(instruction) = e.try_get_attribute(XML_ATTR_INSTR)? else {
+            return Ok(None);
+        };
+        let instruction = instruction.unescape_value()?;
+        Ok(merge_field_name(&instruction)
+            .map(|name| self.merge_field_lookup(name, row_index, values)))
+    }
+
+    /// Build the properties of an inserted run / 构建插入运行的属性
+    ///
+    /// Starts from the properties of the template run and sets each of `overrides` and the configured language in schema order, replacing the same property of the template / 以模板运行的属性为基础，按架构顺序设置 `overrides` 中的每一项和配置的语言，替换模板中的同名属性
+    ///
+    /// # Returns / 返回
+    /// `w:rPr` markup, or an empty string when there is nothing to set / `w:rPr` 标记，无需设置时为空字符串
+    fn run_properties(&self, overrides: &[BytesStart<'_>]) -> String {
+        let mut children = self.paragraph.run_property_children().to_vec();
+        let language = self.language.as_deref().map(|language| {
+            BytesStart::new(XML_LANGUAGE).with_attributes([(XML_ATTR_VAL, language)])
+        });
+        for property in overrides.iter().chain(language.as_ref()) {
+            let name = property.name();
+            let after = RUN_PROPERTIES_ORDER
+                .iter()
+                .position(|tag| *tag == name.as_ref())
+                .map_or(&[][..], |index| &RUN_PROPERTIES_ORDER[index + 1..]);
+            children = Self::place_property(
+                children,
+                Event::Empty(property.borrow().into_owned()),
+                name.as_ref(),
+                after,
+            );
+        }
+        if children.is_empty() {
+            return String::new();
+        }
+        let mut writer = Writer::new(Vec::new());
+        let tag = String::from_utf8_lossy(XML_RUN_PROPERTIES);
+        // Writing to a vector cannot fail / 写入向量不会失败
+        writer
+            .write_event(Event::Start(BytesStart::new(tag.as_ref())))
+            .ok();
+        for event in children {
+            writer.write_event(event).ok();
+        }
+        writer
+            .write_event(Event::End(BytesEnd::new(tag.as_ref())))
+            .ok();
+        String::from_utf8_lossy(&writer.into_inner()).into_owned()
+    }
+
+    /// Build an internal hyperlink from an `[ilink:bookmark|display]` marker / 根据 `[ilink:bookmark|display]` 标记构建内部超链接
+    ///
+    /// The display text defaults to the bookmark name / 显示文本默认为书签名称
+    fn internal_link(&self, marker: &str) -> Option<String> {
+        let link = marker
+            .strip_prefix(INTERNAL_LINK_PREFIX)?
+            .strip_suffix(MARKER_CLOSE)?;
+        let (anchor, display) = link.split_once(LINK_TEXT_SEPARATOR).unwrap_or((link, link));
+
+        // Text is already escaped, only quotes need escaping in the attribute / 文本已转义，属性中仅需转义引号
+        Some(format!(
+            r#"<w:hyperlink w:anchor="{}" w:history="1"><w:r>{}<w:t xml:space="preserve">{}</w:t></w:r></w:hyperlink>"#,
+            anchor.trim().replace('"', "&quot;"),
+            self.run_properties(&[BytesStart::new(XML_RUN_STYLE)
+                .with_attributes([(XML_ATTR_VAL, HYPERLINK_RUN_STYLE)])]),
+            display
+        ))
+    }
+
+    /// Build a bookmark from a `[bookmark:name]` marker / 根据 `[bookmark:name]` 标记构建书签
+    ///
+    /// Each bookmark gets a new id from a document-wide counter / 每个书签从文档级计数器获取新的 id
+    fn bookmark(&mut self, marker: &str) -> Option<String> {
+        let name = marker
+            .strip_prefix(BOOKMARK_PREFIX)?
+            .strip_suffix(MARKER_CLOSE)?
+            .trim();
+        let id = self.bookmark_id;
+        self.bookmark_id += 1;
+
+        Some(format!(
+            r#"<w:bookmarkStart w:id="{}" w:name="{}"/><w:bookmarkEnd w:id="{}"/>"#,
+            id,
+            name.replace('"', "&quot;"),
+            id
+        ))
+    }
+
+    /// Build a bulleted run from a `[list:key]` marker, one line per array item / 根据 `[list:key]` 标记构建项目符号运行，每个数组项一行
+    ///
+    /// The key is a field inside loop rows and a `{{key}}` placeholder elsewhere; values that are not arrays render as a single item / 在循环行中键为字段，其他位置为 `{{key}}` 占位符；非数组的值渲染为单个项
+    fn list(
+        &self,
+        marker: &str,
+        row_index: Option<usize>,
+        values: &HashMap<String, Value>,
+    ) -> Option<String> {
+        let key = marker
+            .strip_prefix(LIST_PREFIX)?
+            .strip_suffix(MARKER_CLOSE)?
+            .trim();
+        let value = match row_index {
+            Some(_) => values.get(key),
+            None => values.get(&format!("{}{}{}", PLACEHOLDER_OPEN, key, PLACEHOLDER_CLOSE)),
+        };
+        let items = match value {
+            Some(Value::Array(items)) => items.as_slice(),
+            Some(Value::Null) | None => &[],
+            Some(value) => std::slice::from_ref(value),
+        };
+
+        let mut markup = format!("{}{}", RUN_OPEN, self.run_properties(&[]));
+        for (index, item) in items.iter().enumerate() {
+            if index > 0 {
+                markup.push_str(LINE_BREAK);
+            }
+            let text = match item {
+                Value::String(text) => Cow::Borrowed(text.as_str()),
+                Value::Null => Cow::Borrowed(""),
+                item => Cow::Owned(item.to_string()),
+            };
+            write!(
+                &mut markup,
+                r#"<w:t xml:space="preserve">{}{}</w:t>"#,
+                LIST_BULLET,
+                escape(text.as_ref())
+            )
+            .ok();
+        }
+        markup.push_str(RUN_CLOSE);
+        Some(markup)
+    }
+
+    /// Take the value of a `[raw:key]` marker as literal OOXML, only when raw OOXML is allowed / 将 `[raw:key]` 标记的值作为字面 OOXML，仅在允许原始 OOXML 时生效
+    ///
+    /// The value replaces the run unchecked, so it must be well-formed run-level markup such as `<w:r>...</w:r>` / 值不经检查地替换运行，因此必须是格式正确的运行级标记，例如 `<w:r>...</w:r>`
+    fn raw_ooxml(
+        &self,
+        marker: &str,
+        row_index: Option<usize>,
+        values: &HashMap<String, Value>,
+    ) -> Option<String> {
+        if !self.allow_raw_ooxml {
+            return None;
+        }
+        let key = marker
+            .strip_prefix(RAW_PREFIX)?
+            .strip_suffix(MARKER_CLOSE)?
+            .trim();
+        let value = match row_index {
+            Some(_) => values.get(key),
+            None => values.get(&format!("{}{}{}", PLACEHOLDER_OPEN, key, PLACEHOLDER_CLOSE)),
+        };
+        match value {
+            Some(Value::String(markup)) => Some(markup.clone()),
+            _ => Some(String::new()),
+        }
+    }
+
+    /// Check whether a text is an image placeholder such as `{{@key}}` or `[@key]` / 检查文本是否为 `{{@key}}` 或 `[@key]` 等图片占位符
+    #[inline]
+    fn is_image_marker(raw: &str) -> bool {
+        let raw = raw.trim();
+        raw.strip_prefix(PLACEHOLDER_OPEN)
+            .or_else(|| raw.strip_prefix('['))
+            .is_some_and(|rest| rest.starts_with(IMAGE_MARKER))
+    }
+
+    /// Bytes passed for an image placeholder such as `{{@logo}}` or `[@logo]` / 为 `{{@logo}}` 或 `[@logo]` 等图片占位符传入的字节
+    fn image_bytes_of(&self, raw: &str) -> Option<&Bytes> {
+        if self.image_bytes.is_empty() {
+            return None;
+        }
+        let raw = raw.trim();
+        let inner = match raw.strip_prefix(PLACEHOLDER_OPEN) {
+            Some(inner) => inner.strip_suffix(PLACEHOLDER_CLOSE)?,
+            None => raw.strip_prefix(MARKER_OPEN)?.strip_suffix(MARKER_CLOSE)?,
+        };
+        self.image_bytes
+            .get(inner.strip_prefix(IMAGE_MARKER)?.trim())
+    }
+
+    /// Fail with `DocxError::Cancelled` once the cancellation token is triggered / 取消令牌触发后以 `DocxError::Cancelled` 失败
+    fn check_cancelled(&self) -> Result<(), DocxError> {
+        match &self.cancellation {
+            Some(token) if token.is_cancelled() => Err(DocxError::Cancelled),
+            _ => Ok(()),
+        }
+    }
+
+    /// Record a placeholder without a value in strict mode / 在严格模式下记录没有值的占位符
+    ///
+    /// Loop rows check their `[field]` texts, other text its `{{key}}` placeholders. Each placeholder is recorded once / 循环行检查其 `[field]` 文本，其他文本检查其 `{{key}}` 占位符。每个占位符只记录一次
+    fn record_missing(
+        &mut self,
+        raw: &str,
+        row_index: Option<usize>,
+        values: &HashMap<String, Value>,
+    ) {
+        if !self.strict {
+            return;
+        }
+        #[cfg(feature = "dates")]
+        if format_now(raw).is_some() {
+            return;
+        }
+        let key = match row_index {
+            Some(_) => {
+                let trimmed = raw.trim();
+                if !(trimmed.starts_with(MARKER_OPEN) && trimmed.ends_with(MARKER_CLOSE)) {
+                    return;
+                }
+                Cow::Borrowed(raw)
+            }
+            None if unescape_loop_marker(raw).is_some() => return,
+            None => self.normalize_placeholder(raw),
+        };
+        let found = lookup(
+            self.cell_handler.as_ref(),
+            &self.fallbacks,
+            &self.transforms,
+            row_index,
+            &key,
+            values,
+        )
+        .is_some();
+        let missing = &mut self.report.missing_placeholders;
+        if !found && !missing.iter().any(|placeholder| placeholder == raw) {
+            missing.push(raw.to_string());
+        }
+    }
+
+    /// Record a text replacement when previewing / 预览时记录文本替换
+    ///
+    /// Unchanged text is not recorded / 未改变的文本不会被记录
+    fn record_change(&mut self, original: &str, replaced: &str) {
+        if let Some(changes) = &mut self.changes
+            && original != replaced
+        {
+            changes.push(Change {
+                original: original.to_string(),
+                replaced: replaced.to_string(),
+            });
+        }
+    }
+
+    /// Write run-level content in place of the current run / 写入运行级内容以替换当前运行
+    ///
+    /// The enclosing w:r is closed before the content and reopened after it, so the caller must drop the w:t element / 在内容之前关闭外层 w:r，之后重新打开，因此调用方必须丢弃 w:t 元素
+    async fn write_run_replacement<'a, W>(
+        &mut self,
+        writer: &mut Writer<W>,
+        content: RunContent,
+        rel_manager: &mut RelationshipManager,
+        img_manager: &mut ImageManager<'a>,
+    ) -> Result<(), quick_xml::Error>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        writer.get_mut().write_all(RUN_CLOSE.as_bytes()).await?;
+        let started = Instant::now();
+        match content {
+            RunContent::Image(base64_data, options) => {
+                self.process_base64_image(&base64_data, &options, writer, rel_manager, img_manager)
+                    .await?;
+                self.report.timings.images += started.elapsed();
+            }
+            RunContent::ImageBytes(bytes, options) => {
+                if let Ok(image) =
+                    img_manager.process_image_bytes(bytes, options.background, rel_manager)
+                {
+                    self.write_drawing(writer, image, &options).await?;
+                }
+                self.report.timings.images += started.elapsed();
+            }
+            RunContent::ImageFile(path, options) => {
+                match self.read_image_default(&path).await {
+                    Ok(bytes) => {
+                        if let Ok(image) =
+                            img_manager.process_image_bytes(bytes, options.background, rel_manager)
+                        {
+                            self.write_drawing(writer, image, &options).await?;
+                        }
+                    }
+                    Err(message) => self
+                        .report
+                        .warnings
+                        .push(GenerationWarning::ImageDefaultSkipped { path, message }),
+                }
+                self.report.timings.images += started.elapsed();
+            }
+            RunContent::Markup(markup) => {
+                writer.get_mut().write_all(markup.as_bytes()).await?;
+            }
+            RunContent::Raw(markup) => {
+                if self.validate_raw_ooxml {
+                    check_well_formed(&markup)?;
+                }
+                writer.get_mut().write_all(markup.as_bytes()).await?;
+            }
+            RunContent::Text(text) => {
+                writer
+                    .write_event_async(Event::Text(BytesText::from_escaped(text)))
+                    .await?;
+            }
+        }
+        // The rest of the run keeps the template formatting / 运行的其余部分保持模板格式
+        writer.get_mut().write_all(RUN_OPEN.as_bytes()).await?;
+        for event in self.paragraph.run_properties() {
+            writer.write_event_async(event.borrow()).await?;
+        }
+        Ok(())
+    }
+
+    /// Read a `defaultfile=` image from the configured directory / 从配置的目录读取 `defaultfile=` 图片
+    ///
+    /// Returns the reason the file is skipped when no directory is set, the path leaves it, reading fails or the file is not an image / 未设置目录、路径超出目录、读取失败或文件不是图片时返回跳过该文件的原因
+    async fn read_image_default(&self, path: &str) -> Result<Bytes, String> {
+        let Some(dir) = &self.image_default_dir else {
+            return Err(ERR_IMAGE_DEFAULT_DISABLED.to_string());
+        };
+        let dir = canonicalize(dir).await.map_err(|e| e.to_string())?;
+        let file = canonicalize(dir.join(path))
+            .await
+            .map_err(|e| e.to_string())?;
+        if !file.starts_with(&dir) {
+            return Err(ERR_IMAGE_DEFAULT_OUTSIDE.to_string());
+        }
+        let bytes = read(&file).await.map_err(|e| e.to_string())?;
+        // Embed only files recognized as images / 仅嵌入可识别为图片的文件
+        if !is_svg(&bytes) {
+            get_image_dimensions(&bytes).map_err(str::to_string)?;
+        }
+        Ok(Bytes::from(bytes))
+    }
+
+    /// Write a buffered w:t element, replacing its text or the whole run / 写入缓冲的 w:t 元素，替换其文本或整个运行
+    ///
+    /// Consumes the events of the element up to its end tag when run-level content is produced / 生成运行级内容时消耗该元素直到其结束标签的事件
+    #[allow(clippy::too_many_arguments)]
+    async fn write_text_element<'a, 'e, 'b: 'e, W, I>(
+        &mut self,
+        writer: &mut Writer<W>,
+        start: &BytesStart<'_>,
+        events: &mut Peekable<I>,
+        row_index: Option<usize>,
+        values: &HashMap<String, Value>,
+        rel_manager: &mut RelationshipManager,
+        img_manager: &mut ImageManager<'a>,
+    ) -> Result<(), quick_xml::Error>
+    where
+        W: AsyncWrite + Unpin,
+        I: Iterator<Item = &'e Event<'b>>,
+    {
+        let Some(Event::Text(text)) = events.peek() else {
+            // Empty element, write unchanged / 空元素，原样写入
+            writer
+                .write_event_async(Event::Start(start.borrow()))
+                .await?;
+            return Ok(());
+        };
+        let original = text.decode()?;
+        let content = self.resolve_run_content(&original, row_index, values);
+        events.next();
+
+        match content {
+            RunContent::Text(replaced) => {
+                self.record_change(&original, &replaced);
+                writer
+                    .write_event_async(Event::Start(start.borrow()))
+                    .await?;
+                writer
+                    .write_event_async(Event::Text(BytesText::from_escaped(replaced)))
+                    .await?;
+            }
+            content => {
+                self.record_change(&original, "");
+                self.write_run_replacement(writer, content, rel_manager, img_manager)
+                    .await?;
+                // Drop the rest of the w:t element / 丢弃 w:t 元素的剩余部分
+                for event in events.by_ref() {
+                    if let Event::End(e) = event
+                        && e.name().as_ref() == XML_TEXT
+                    {
+                        break;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Process base64 image and insert into document / 处理 base64 图片并插入文档
     ///
     /// Decodes base64 image data and generates XML drawing elements / 解码 base64 图片数据并生成 XML 绘图元素
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     async fn process_base64_image<'a, W>(
         &mut self,
         base64_data: &str,
+        options: &ImageOptions,
         writer: &mut Writer<W>,
         rel_manager: &mut RelationshipManager,
         img_manager: &mut ImageManager<'a>,
@@ -222,33 +1382,76 @@ impl DocxProcessor {
         W: AsyncWrite + Unpin,
     {
         // Try to process base64 image data / 尝试处理 base64 图片数据
-        if let Ok((rel_id, image_id, width, height)) =
-            img_manager.process_base64(base64_data, rel_manager)
+        if let Ok(image) = img_manager.process_base64(base64_data, options.background, rel_manager)
         {
-            let mut name = String::with_capacity(PICTURE_NAME_CAPACITY);
-            write!(&mut name, "{}{}", IMAGE_NAME_PREFIX, image_id).map_err(|_e| {
-                quick_xml::errors::IllFormedError::UnmatchedEndTag(ERR_PICTURE_NAME.to_string())
-            })?;
-
-            // Generate XML drawing markup for the image / 为图片生成 XML 绘图标记
-            let xml_inner = ImageManager::generate_xml_drawing_inner(
-                &rel_id,
-                image_id,
-                width,
-                height,
-                &name,
-                DEFAULT_IMAGE_DESCRIPTION,
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                rel_id = %image.rel_id,
+                width = image.width,
+                height = image.height,
+                "image embedded"
             );
-            // Write XML directly to output / 直接将 XML 写入输出
-            writer.get_mut().write_all(xml_inner.as_bytes()).await?;
+            self.write_drawing(writer, image, options).await?;
         }
         Ok(())
     }
 
+    /// Write the drawing markup of a registered image / 写入已注册图片的绘图标记
+    ///
+    /// # Arguments / 参数
+    /// * `image` - Image registered by the image manager / 图片管理器注册的图片
+    /// * `options` - Options of the image placeholder / 图片占位符的选项
+    async fn write_drawing<W>(
+        &mut self,
+        writer: &mut Writer<W>,
+        image: InsertedImage,
+        options: &ImageOptions,
+    ) -> Result<(), quick_xml::Error>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let mut name = String::with_capacity(PICTURE_NAME_CAPACITY);
+        write!(&mut name, "{}{}", IMAGE_NAME_PREFIX, image.image_id).map_err(|_e| {
+            quick_xml::errors::IllFormedError::UnmatchedEndTag(ERR_PICTURE_NAME.to_string())
+        })?;
+
+        // Viewers without SVG support show the placeholder fallback / 不支持 SVG 的查看器显示占位回退图片
+        if image.blank_fallback {
+            self.report
+                .warnings
+                .push(GenerationWarning::BlankSvgFallback { name: name.clone() });
+        }
+
+        // An explicit size replaces the computed one / 显式尺寸替换计算出的尺寸
+        let (width, height) = match options.size {
+            Some(size) => size.resolve((image.width, image.height)),
+            None => (image.width, image.height),
+        };
+
+        // Generate XML drawing markup for the image / 为图片生成 XML 绘图标记
+        let xml_inner = ImageManager::generate_xml_drawing_inner(
+            &image.rel_id,
+            image.image_id,
+            width,
+            height,
+            &name,
+            options.alt.as_deref().unwrap_or(DEFAULT_IMAGE_DESCRIPTION),
+            &self.run_properties(&[]),
+            options.rotate,
+            image.svg_rel_id.as_deref(),
+            options.border,
+            options.anchor,
+        );
+        // Write XML directly to output / 直接将 XML 写入输出
+        writer.get_mut().write_all(xml_inner.as_bytes()).await?;
+        Ok(())
+    }
+
     /// Process table element and handle dynamic rows / 处理表格元素并处理动态行
     ///
     /// Tables can contain placeholder arrays that generate multiple rows / 表格可以包含生成多行的占位符数组
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     async fn process_table<'a, R, W>(
         &mut self,
         reader: &mut Reader<R>,
@@ -257,13 +1460,26 @@ impl DocxProcessor {
         placeholders: &HashMap<String, Value>,
         rel_manager: &mut RelationshipManager,
         img_manager: &mut ImageManager<'a>,
-    ) -> Result<(), quick_xml::Error>
+    ) -> Result<(), DocxError>
     where
         R: AsyncBufRead + Unpin,
         W: AsyncWrite + Unpin,
     {
         // Collect all table content (headers, data rows, properties) / 收集所有表格内容（标题、数据行、属性）
-        let table_content = Self::collect_table_content(reader, buf).await?;
+        let mut table_content =
+            Self::collect_table_content(reader, buf, self.unified_syntax, &self.capacity_hints)
+                .await?;
+
+        // Remove conditional columns whose condition is false / 移除条件为假的条件列
+        let hidden_cols = table_content
+            .conditional_columns
+            .iter()
+            .filter(|(_, key)| !is_truthy(placeholders.get(key)))
+            .flat_map(|(cols, _)| cols.clone())
+            .collect::<Vec<_>>();
+        if !hidden_cols.is_empty() {
+            Self::hide_table_columns(&mut table_content, &hidden_cols);
+        }
 
         // Write table start tag / 写入表格开始标签
         writer
@@ -276,78 +1492,391 @@ impl DocxProcessor {
         }
 
         let table_key = table_content.first_col;
+        // Mark the loop table headers above the data row as repeating / 将数据行之上的循环表格标题标记为重复
+        if self.repeat_header_rows && table_key.is_some() {
+            let leading_rows = table_content.leading_rows;
+            table_content.header_rows = table_content
+                .header_rows
+                .into_iter()
+                .enumerate()
+                .map(|(index, row)| {
+                    if index < leading_rows {
+                        Self::add_row_property(row, XML_TABLE_HEADER)
+                    } else {
+                        row
+                    }
+                })
+                .collect();
+        }
         // Check if table has dynamic data (array placeholder) / 检查表格是否有动态数据（数组占位符）
         if let Some(table_key) = &table_key
             && let Some(Value::Array(list)) = placeholders.get(table_key)
             && !table_content.data_rows.is_empty()
         {
+            // Align configured columns of the data rows / 对齐数据行中配置的列
+            let data_rows = match self.column_alignments.get(table_key) {
+                Some(alignments) => Self::align_cells(table_content.data_rows, alignments),
+                None => table_content.data_rows,
+            };
+            let data_rows = if self.cant_split_rows {
+                Self::add_row_property(data_rows, XML_CANT_SPLIT)
+            } else {
+                data_rows
+            };
+
             // Write header rows / 写入标题行
             for mut header_row in table_content.header_rows {
                 for event in header_row.drain(..) {
-                    writer.write_event_async(event).await?;
+                    match &event {
+                        Event::Text(text) => match unescape_loop_marker(&text.decode()?) {
+                            Some(literal) => {
+                                writer
+                                    .write_event_async(Event::Text(BytesText::from_escaped(
+                                        literal,
+                                    )))
+                                    .await?
+                            }
+                            None => writer.write_event_async(event).await?,
+                        },
+                        _ => writer.write_event_async(event).await?,
+                    }
                 }
             }
 
+            // Sort options reorder the array, the other options filter it / 排序选项对数组重新排序，其他选项对其过滤
+            let mut list = list.iter().collect::<Vec<_>>();
+            let mut filters = Vec::new();
+            let mut group = None;
+            for option in table_content
+                .loop_options
+                .iter()
+                .flat_map(|options| options.split(LOOP_FILTER_SEPARATOR))
+                .map(str::trim)
+            {
+                if let Some(sort) = option.strip_prefix(LOOP_SORT_PREFIX) {
+                    sort_by_field(&mut list, sort);
+                } else if let Some(field) = option.strip_prefix(LOOP_GROUP_PREFIX) {
+                    group = Some(field.trim());
+                } else {
+                    filters.push(option);
+                }
+            }
+
+            // Rows written by earlier loop tables / 之前的循环表格已写入的行数
+            let written = self
+                .report
+                .tables
+                .iter()
+                .map(|table| table.rows)
+                .sum::<usize>();
+            // Without skip conditions or an item transform every filtered record becomes a row / 没有跳过条件和循环项转换时，每条过滤后的记录都会成为一行
+            let all_written =
+                table_content.skip_conditions.is_empty() && self.item_transform.is_none();
+            // Each item flattens to at least one record, so the limit is checked before flattening / 每项至少展平为一条记录，因此在展平前检查限制
+            if let Some(max) = self.max_rows
+                && all_written
+                && filters.is_empty()
+                && written + list.len() > max
+            {
+                return Err(DocxError::LimitExceeded(Limit::Rows {
+                    max,
+                    requested: written + list.len(),
+                }));
+            }
+            let bound = match self.max_rows {
+                Some(max) if all_written => max.saturating_sub(written) + 1,
+                _ => usize::MAX,
+            };
+
             // Flatten JSON array and generate rows with merging / 展平 JSON 数组并生成带合并的行
-            let items = list.iter().flat_map(flatten_json).collect::<Vec<_>>();
-            self.write_rows_with_merge(
-                writer,
-                &table_content.data_rows,
-                items.into_iter(),
-                rel_manager,
-                img_manager,
-            )
-            .await?;
+            let mut items = list
+                .into_iter()
+                .flat_map(flatten_json)
+                // Keep the items matching every filter of the loop marker / 保留匹配循环标记所有过滤条件的项
+                .filter(|item| {
+                    filters
+                        .iter()
+                        .all(|filter| evaluate_condition(filter, item))
+                })
+                // Flattening stops one row past the limit / 展平在超出限制一行时停止
+                .take(bound)
+                .collect::<Vec<_>>();
+            self.validate_loop_keys(table_key, &items)?;
+            // Translate template keys to data keys / 将模板键转换为数据键
+            if let Some(columns) = self.column_maps.get(table_key) {
+                items.iter_mut().for_each(|item| map_columns(item, columns));
+            }
+            if !self.value_maps.is_empty() {
+                items
+                    .iter_mut()
+                    .for_each(|item| map_values(item, &self.value_maps, false));
+            }
+            self.transform_items(table_key, &mut items);
+            // Skipped items take no part in indexing or merging / 被跳过的项不参与索引或合并
+            items.retain(|item| {
+                !table_content
+                    .skip_conditions
+                    .iter()
+                    .any(|condition| evaluate_condition(condition, item))
+            });
+            // Only rows that are written count against the limit / 仅实际写入的行计入限制
+            if let Some(max) = self.max_rows
+                && written + items.len() > max
+            {
+                return Err(DocxError::LimitExceeded(Limit::Rows {
+                    max,
+                    requested: written + items.len(),
+                }));
+            }
+            let (rows, columns) = match group {
+                Some(field) => {
+                    self.write_groups(
+                        writer,
+                        data_rows,
+                        group_by_field(items, field),
+                        rel_manager,
+                        img_manager,
+                    )
+                    .await?
+                }
+                None => {
+                    self.write_rows_with_merge(
+                        writer,
+                        &data_rows,
+                        items.into_iter(),
+                        rel_manager,
+                        img_manager,
+                    )
+                    .await?
+                }
+            };
+            #[cfg(feature = "tracing")]
+            tracing::debug!(key = %table_key, rows, "loop table rendered");
+            self.report.tables.push(TableMergeSummary {
+                key: table_key.clone(),
+                rows,
+                columns,
+            });
         } else {
-            for mut header_row in table_content.header_rows {
-                for event in header_row.drain(..) {
+            for header_row in &table_content.header_rows {
+                let mut events = header_row.iter().peekable();
+                while let Some(event) = events.next() {
+                    self.paragraph.observe(event);
                     match event {
+                        Event::Start(e) if e.name().as_ref() == XML_FIELD_SIMPLE => {
+                            match self.merge_field_value(e, None, placeholders)? {
+                                Some(value) => {
+                                    let result = events
+                                        .by_ref()
+                                        .take_while(|event| {
+                                            !matches!(event, Event::End(end) if end.name().as_ref() == XML_FIELD_SIMPLE)
+                                        })
+                                        .cloned()
+                                        .collect::<Vec<_>>();
+                                    let markup = render_merge_field(&result, value)?;
+                                    writer.get_mut().write_all(&markup).await?;
+                                }
+                                None => writer.write_event_async(Event::Start(e.borrow())).await?,
+                            }
+                        }
+                        Event::Empty(e)
+                            if field_char_type(e).as_deref() == Some(FIELD_CHAR_BEGIN) =>
+                        {
+                            let (field, step) = Self::buffer_complex_field(&mut events)?;
+                            self.write_complex_field(
+                                writer,
+                                e.borrow(),
+                                field,
+                                step,
+                                None,
+                                placeholders,
+                            )
+                            .await?;
+                        }
+                        Event::Start(e) if e.name().as_ref() == XML_TEXT => {
+                            self.write_text_element(
+                                writer,
+                                e,
+                                &mut events,
+                                None,
+                                placeholders,
+                                rel_manager,
+                                img_manager,
+                            )
+                            .await?;
+                        }
                         Event::Text(text) => {
-                            let replaced = self.cell_handler.replace(&text.decode()?, placeholders);
-                            if replaced.starts_with("iVBORw0KGgo") || replaced.starts_with("/9j/") {
-                                self.process_base64_image(
-                                    replaced.as_str(),
-                                    writer,
-                                    rel_manager,
-                                    img_manager,
-                                )
+                            let original = text.decode()?;
+                            self.record_missing(&original, None, placeholders);
+                            let replaced = self.replace_text(&original, placeholders);
+                            self.record_change(&original, &replaced);
+                            writer
+                                .write_event_async(Event::Text(BytesText::from_escaped(replaced)))
                                 .await?;
-                            } else {
-                                writer
-                                    .write_event_async(Event::Text(BytesText::from_escaped(
-                                        replaced,
-                                    )))
-                                    .await?;
+                        }
+                        Event::Start(e) => match self.process_attributes(e, placeholders)? {
+                            Some(replaced) => {
+                                writer.write_event_async(Event::Start(replaced)).await?
                             }
+                            None => writer.write_event_async(Event::Start(e.borrow())).await?,
+                        },
+                        Event::Empty(e) => match self.process_attributes(e, placeholders)? {
+                            Some(replaced) => {
+                                writer.write_event_async(Event::Empty(replaced)).await?
+                            }
+                            None => writer.write_event_async(Event::Empty(e.borrow())).await?,
+                        },
+                        _ => writer.write_event_async(event.borrow()).await?,
+                    }
+                }
+            }
+        }
+
+        writer
+            .write_event_async(Event::End(BytesEnd::new(XML_TABLE)))
+            .await?;
+        Ok(())
+    }
+
+    /// Apply the item transform to each flattened loop item / 对每个展平循环项应用循环项转换
+    ///
+    /// Items the transform turns into a non-object value are reported and dropped / 被转换为非对象值的项会被报告并丢弃
+    fn transform_items(&mut self, table_key: &str, items: &mut Vec<HashMap<String, Value>>) {
+        let Some(transform) = self.item_transform.clone() else {
+            return;
+        };
+        let mut index = 0;
+        items.retain_mut(|item| {
+            index += 1;
+            let mut value = Value::Object(std::mem::take(item).into_iter().collect());
+            transform(&mut value);
+            match value {
+                Value::Object(map) => {
+                    *item = map.into_iter().collect();
+                    true
+                }
+                _ => {
+                    self.report
+                        .warnings
+                        .push(GenerationWarning::ItemTransformNotObject {
+                            table: table_key.to_string(),
+                            item: index,
+                        });
+                    false
+                }
+            }
+        });
+    }
+
+    /// Compare the key sets of loop items against the first item / 将循环项的键集与第一项进行比较
+    ///
+    /// Every discrepancy is reported; in strict mode the first one aborts generation / 每处差异都会被报告；严格模式下第一处差异会中止生成
+    fn validate_loop_keys(
+        &mut self,
+        table_key: &str,
+        items: &[HashMap<String, Value>],
+    ) -> Result<(), DocxError> {
+        let Some((first, rest)) = items.split_first() else {
+            return Ok(());
+        };
+
+        for (index, item) in rest.iter().enumerate() {
+            let mut missing = first
+                .keys()
+                .filter(|key| !item.contains_key(*key))
+                .cloned()
+                .collect::<Vec<_>>();
+            let mut extra = item
+                .keys()
+                .filter(|key| !first.contains_key(*key))
+                .cloned()
+                .collect::<Vec<_>>();
+            if missing.is_empty() && extra.is_empty() {
+                continue;
+            }
+            missing.sort();
+            extra.sort();
+
+            let warning = GenerationWarning::InconsistentLoopKeys {
+                table: table_key.to_string(),
+                item: index + 1,
+                missing,
+                extra,
+            };
+            if self.strict_loop_keys {
+                return Err(DocxError::Validation(warning));
+            }
+            self.report.warnings.push(warning);
+        }
+        Ok(())
+    }
+
+    /// Detect the tables of a document part without rendering it / 检测文档部件中的表格而不进行渲染
+    ///
+    /// Rows are classified as during generation, so the result matches what a loop table would expand / 行的分类方式与生成时相同，因此结果与循环表格的展开方式一致
+    pub(crate) async fn inspect_tables<R>(reader: R) -> Result<Vec<TableInfo>, quick_xml::Error>
+    where
+        R: AsyncBufRead + Unpin,
+    {
+        let mut reader = Reader::from_reader(reader);
+        let buf = &mut Vec::with_capacity(DEFAULT_BUFFER_SIZE);
+        let mut tables = Vec::new();
+        loop {
+            buf.clear();
+            match reader.read_event_into_async(buf).await? {
+                Event::Start(e) if e.name().as_ref() == XML_TABLE.as_bytes() => {
+                    let content = Self::collect_table_content(
+                        &mut reader,
+                        buf,
+                        false,
+                        &CapacityHints::default(),
+                    )
+                    .await?;
+                    let mut column_keys = Vec::new();
+                    for event in &content.data_rows {
+                        if let Event::Text(text) = event
+                            && let Some(field) = bracket_field(&text.decode()?)
+                        {
+                            column_keys.push(field.trim().to_string());
                         }
-                        _ => writer.write_event_async(event).await?,
                     }
+                    tables.push(TableInfo {
+                        loop_key: content.first_col,
+                        column_keys,
+                    });
                 }
+                Event::Eof => break,
+                _ => {}
             }
         }
-
-        writer
-            .write_event_async(Event::End(BytesEnd::new(XML_TABLE)))
-            .await?;
-        Ok(())
+        Ok(tables)
     }
 
     /// Collect and categorize table content into headers and data rows / 收集并分类表格内容为标题行和数据行
     ///
-    /// Separates rows with placeholders (data rows) from rows without (header rows) / 将包含占位符的行（数据行）与不包含的行（标题行）分离
+    /// Separates rows with placeholders (data rows) from rows without (header rows). Consecutive data rows form one repeating unit / 将包含占位符的行（数据行）与不包含的行（标题行）分离。连续的数据行组成一个重复单元
     #[inline]
     async fn collect_table_content<R>(
         reader: &mut Reader<R>,
         buf: &mut Vec<u8>,
+        unified_syntax: bool,
+        hints: &CapacityHints,
     ) -> Result<TableContent<'static>, quick_xml::Error>
     where
         R: AsyncBufRead + Unpin,
     {
         // Storage for different table components / 不同表格组件的存储
-        let mut header_rows = Vec::with_capacity(TYPICAL_HEADER_ROW_COUNT);
-        let mut data_rows = Vec::with_capacity(TYPICAL_DATA_ROW_COUNT);
-        let mut other_events = Vec::with_capacity(TYPICAL_OTHER_EVENT_COUNT);
+        let mut header_rows = Vec::with_capacity(hints.header_rows);
+        let mut data_rows = Vec::with_capacity(hints.data_rows);
+        let mut other_events = Vec::with_capacity(hints.other_events);
         let mut table_key = None; // First column placeholder key / 第一列占位符键
+        let mut loop_options = None; // Options of the loop marker / 循环标记的选项
+        let mut conditional_columns = Vec::new(); // Conditional grid columns / 条件网格列
+        let mut row_conditions = Vec::new(); // Conditional cells of the current row / 当前行的条件单元格
+        let mut previous_is_data = false; // Whether the previous row was a data row / 前一行是否为数据行
+        let mut skip_conditions = Vec::new(); // Row skip conditions of the data rows / 数据行的跳过条件
+        let mut row_alignments = Vec::new(); // Aligned cells of the current row / 当前行中需要对齐的单元格
+        let mut leading_rows = 0; // Header rows above the data row / 数据行之上的标题行数
 
         // Read all table events / 读取所有表格事件
         loop {
@@ -363,20 +1892,47 @@ impl DocxProcessor {
                 // Process table row / 处理表格行
                 Ok(Event::Start(e)) if e.name().as_ref() == XML_TABLE_ROW => {
                     let start_owned = e.into_owned();
-                    let (row_events, has_placeholder) = Self::process_table_row_internal(
+                    let (mut row_events, has_placeholder) = Self::process_table_row_internal(
                         reader,
                         buf,
                         Event::Start(start_owned),
-                        &mut table_key,
+                        (&mut table_key, &mut loop_options),
+                        &mut row_conditions,
+                        &mut row_alignments,
+                        &mut skip_conditions,
+                        unified_syntax,
+                        hints.row_events,
                     )
                     .await?;
 
+                    // Apply alignment markers of the row / 应用行中的对齐标记
+                    if !row_alignments.is_empty() {
+                        row_events = Self::align_cells(row_events, &row_alignments);
+                        row_alignments.clear();
+                    }
+
+                    // Translate conditional cells into grid columns / 将条件单元格转换为网格列
+                    if !row_conditions.is_empty() {
+                        let layout = Self::cell_layout(&row_events);
+                        for (tc_index, key) in row_conditions.drain(..) {
+                            if let Some(cell) = layout.get(tc_index) {
+                                conditional_columns.push((cell.grid.clone(), key));
+                            }
+                        }
+                    }
+
                     // Categorize row based on placeholder presence / 根据是否包含占位符对行进行分类
-                    if has_placeholder {
+                    if has_placeholder && previous_is_data {
+                        data_rows.extend(row_events); // Extend the repeating unit / 扩展重复单元
+                    } else if has_placeholder {
                         data_rows = row_events; // Data template row / 数据模板行
                     } else {
+                        if data_rows.is_empty() {
+                            leading_rows += 1;
+                        }
                         header_rows.push(row_events); // Header row / 标题行
                     }
+                    previous_is_data = has_placeholder;
                 }
                 // End of table / 表格结束
                 Ok(Event::End(e)) if e.name().as_ref() == XML_TABLE.as_bytes() => {
@@ -392,31 +1948,384 @@ impl DocxProcessor {
 
         Ok(TableContent {
             header_rows,
+            leading_rows,
             data_rows,
             other_events,
             first_col: table_key,
+            loop_options,
+            conditional_columns,
+            skip_conditions,
         })
     }
 
+    /// Remove hidden grid columns from table grid and all rows / 从表格网格和所有行中移除隐藏的网格列
+    ///
+    /// Cells fully covered by hidden columns are dropped, partially covered cells have their span reduced / 完全被隐藏列覆盖的单元格被删除，部分覆盖的单元格缩减跨列数
+    fn hide_table_columns(table_content: &mut TableContent<'static>, hidden_cols: &[usize]) {
+        // Drop hidden w:gridCol entries from w:tblGrid / 从 w:tblGrid 中删除隐藏的 w:gridCol
+        let mut grid_index = 0;
+        table_content.other_events.retain(|event| match event {
+            Event::Empty(e) | Event::Start(e) if e.name().as_ref() == XML_TABLE_GRID_COL => {
+                grid_index += 1;
+                !hidden_cols.contains(&(grid_index - 1))
+            }
+            _ => true,
+        });
+
+        for row in table_content.header_rows.iter_mut() {
+            *row = Self::remove_hidden_cells(std::mem::take(row), hidden_cols);
+        }
+        if !table_content.data_rows.is_empty() {
+            table_content.data_rows = Self::remove_hidden_cells(
+                std::mem::take(&mut table_content.data_rows),
+                hidden_cols,
+            );
+        }
+    }
+
+    /// Remove cells covering hidden grid columns from a row / 从行中移除覆盖隐藏网格列的单元格
+    fn remove_hidden_cells(row: Vec<Event<'static>>, hidden_cols: &[usize]) -> Vec<Event<'static>> {
+        let layout = Self::cell_layout(&row);
+        let mut result = Vec::with_capacity(row.len());
+        let mut cells = layout.iter().peekable();
+
+        for (index, event) in row.into_iter().enumerate() {
+            // Skip forward to the cell containing this event / 前进到包含此事件的单元格
+            while cells.peek().is_some_and(|cell| cell.events.end <= index) {
+                cells.next();
+            }
+
+            match cells.peek() {
+                Some(cell) if cell.events.contains(&index) => {
+                    let span = cell.grid.len();
+                    let hidden = cell
+                        .grid
+                        .clone()
+                        .filter(|c| hidden_cols.contains(c))
+                        .count();
+                    if hidden == span {
+                        continue; // Whole cell hidden / 整个单元格被隐藏
+                    }
+                    match event {
+                        // Shrink span of partially hidden cell / 缩减部分隐藏单元格的跨列数
+                        Event::Empty(e)
+                            if hidden > 0
+                                && e.name().as_ref() == XML_TABLE_GRID_SPAN.as_bytes() =>
+                        {
+                            let mut grid_span = BytesStart::new(XML_TABLE_GRID_SPAN);
+                            grid_span.push_attribute((
+                                XML_ATTR_VAL,
+                                (span - hidden).to_string().as_str(),
+                            ));
+                            result.push(Event::Empty(grid_span));
+                        }
+                        other => result.push(other),
+                    }
+                }
+                _ => result.push(event),
+            }
+        }
+        result
+    }
+
+    /// Set the `w:jc` of the paragraphs in the given cells / 设置指定单元格中段落的 `w:jc`
+    ///
+    /// Cells are counted per row, so every row of a multi-row template is aligned. An existing `w:jc` is replaced and the new one keeps schema order inside `w:pPr` / 单元格按行计数，因此多行模板的每一行都会对齐。已有的 `w:jc` 会被替换，新元素在 `w:pPr` 中保持架构顺序
+    ///
+    /// # Arguments / 参数
+    /// * `row` - Row template events / 行模板事件
+    /// * `alignments` - (cell index, alignment) pairs / （单元格索引，对齐方式）对
+    fn align_cells(
+        row: Vec<Event<'static>>,
+        alignments: &[(usize, CellAlignment)],
+    ) -> Vec<Event<'static>> {
+        let mut result = Vec::with_capacity(row.len() + alignments.len() * 4);
+        let mut events = row.into_iter().peekable();
+        let mut tc_depth = 0;
+        let mut tc_index = 0;
+        let mut alignment = None; // Alignment of the current cell / 当前单元格的对齐方式
+
+        let jc = |alignment: CellAlignment| {
+            let mut jc = BytesStart::new(XML_JUSTIFICATION);
+            jc.push_attribute((XML_ATTR_VAL, alignment.jc_value()));
+            Event::Empty(jc)
+        };
+
+        while let Some(event) = events.next() {
+            match &event {
+                Event::Start(e) if tc_depth == 0 && e.name().as_ref() == XML_TABLE_ROW => {
+                    tc_index = 0;
+                }
+                Event::Start(e) if e.name().as_ref() == XML_TABLE_CELL => {
+                    if tc_depth == 0 {
+                        alignment = alignments
+                            .iter()
+                            .find(|(index, _)| *index == tc_index)
+                            .map(|(_, alignment)| *alignment);
+                        tc_index += 1;
+                    }
+                    tc_depth += 1;
+                }
+                Event::End(e) if e.name().as_ref() == XML_TABLE_CELL => {
+                    tc_depth -= 1;
+                }
+                Event::Start(e) if tc_depth == 1 && e.name().as_ref() == XML_PARAGRAPH => {
+                    let Some(alignment) = alignment else {
+                        result.push(event);
+                        continue;
+                    };
+                    result.push(event);
+                    match events.peek() {
+                        // Rewrite existing paragraph properties / 重写已有的段落属性
+                        Some(Event::Start(p))
+                            if p.name().as_ref() == XML_PARAGRAPH_PROPERTIES.as_bytes() =>
+                        {
+                            result.extend(events.next());
+                            let mut depth = 0;
+                            let children = events
+                                .by_ref()
+                                .take_while(|event| {
+                                    match event {
+                                        Event::Start(_) => depth += 1,
+                                        Event::End(_) if depth == 0 => return false,
+                                        Event::End(_) => depth -= 1,
+                                        _ => {}
+                                    }
+                                    true
+                                })
+                                .collect::<Vec<_>>();
+                            result.extend(Self::justify_properties(children, jc(alignment)));
+                            result.push(Event::End(BytesEnd::new(XML_PARAGRAPH_PROPERTIES)));
+                        }
+                        // Expand empty paragraph properties / 展开空的段落属性
+                        Some(Event::Empty(p))
+                            if p.name().as_ref() == XML_PARAGRAPH_PROPERTIES.as_bytes() =>
+                        {
+                            let properties = p.to_owned();
+                            events.next();
+                            result.push(Event::Start(properties));
+                            result.push(jc(alignment));
+                            result.push(Event::End(BytesEnd::new(XML_PARAGRAPH_PROPERTIES)));
+                        }
+                        // Add paragraph properties / 添加段落属性
+                        _ => {
+                            result.push(Event::Start(BytesStart::new(XML_PARAGRAPH_PROPERTIES)));
+                            result.push(jc(alignment));
+                            result.push(Event::End(BytesEnd::new(XML_PARAGRAPH_PROPERTIES)));
+                        }
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+            result.push(event);
+        }
+        result
+    }
+
+    /// Add an empty property to the `w:trPr` of every top-level row / 向每个顶层行的 `w:trPr` 添加空属性
+    ///
+    /// Row properties are created when missing; a property already set by the template is kept as is / 缺少行属性时创建；模板已设置的属性保持不变
+    fn add_row_property(row: Vec<Event<'static>>, property: &'static str) -> Vec<Event<'static>> {
+        let mut result = Vec::with_capacity(row.len() + 3);
+        let mut tc_depth = 0;
+        let mut missing = false; // Current row still lacks the property / 当前行仍缺少该属性
+        let mut in_properties = false; // Inside the w:trPr of the current row / 位于当前行的 w:trPr 内
+
+        for event in row {
+            match &event {
+                Event::Start(e) if tc_depth == 0 && e.name().as_ref() == XML_TABLE_ROW => {
+                    missing = true;
+                }
+                // Expand empty row properties / 展开空的行属性
+                Event::Empty(e)
+                    if tc_depth == 0
+                        && missing
+                        && e.name().as_ref() == XML_TABLE_ROW_PROPERTIES.as_bytes() =>
+                {
+                    result.push(Event::Start(e.to_owned()));
+                    result.push(Event::Empty(BytesStart::new(property)));
+                    result.push(Event::End(BytesEnd::new(XML_TABLE_ROW_PROPERTIES)));
+                    missing = false;
+                    continue;
+                }
+                Event::Start(e)
+                    if tc_depth == 0
+                        && e.name().as_ref() == XML_TABLE_ROW_PROPERTIES.as_bytes() =>
+                {
+                    in_properties = true;
+                }
+                Event::Start(e) | Event::Empty(e)
+                    if in_properties && e.name().as_ref() == property.as_bytes() =>
+                {
+                    missing = false;
+                }
+                Event::End(e)
+                    if in_properties
+                        && e.name().as_ref() == XML_TABLE_ROW_PROPERTIES.as_bytes() =>
+                {
+                    in_properties = false;
+                    if missing {
+                        result.push(Event::Empty(BytesStart::new(property)));
+                        missing = false;
+                    }
+                }
+                // Add row properties before the first cell / 在第一个单元格之前添加行属性
+                Event::Start(e) if e.name().as_ref() == XML_TABLE_CELL => {
+                    if tc_depth == 0 && missing {
+                        result.push(Event::Start(BytesStart::new(XML_TABLE_ROW_PROPERTIES)));
+                        result.push(Event::Empty(BytesStart::new(property)));
+                        result.push(Event::End(BytesEnd::new(XML_TABLE_ROW_PROPERTIES)));
+                        missing = false;
+                    }
+                    tc_depth += 1;
+                }
+                Event::End(e) if e.name().as_ref() == XML_TABLE_CELL => {
+                    tc_depth -= 1;
+                }
+                _ => {}
+            }
+            result.push(event);
+        }
+        result
+    }
+
+    /// Replace the `w:jc` among the children of a `w:pPr` element / 替换 `w:pPr` 元素子节点中的 `w:jc`
+    ///
+    /// The new element is placed before the first property that follows `w:jc` in schema order / 新元素放在按架构顺序位于 `w:jc` 之后的第一个属性之前
+    fn justify_properties(
+        children: Vec<Event<'static>>,
+        jc: Event<'static>,
+    ) -> Vec<Event<'static>> {
+        Self::place_property(
+            children,
+            jc,
+            XML_JUSTIFICATION.as_bytes(),
+            &PARAGRAPH_PROPERTIES_AFTER_JC,
+        )
+    }
+
+    /// Replace a property among the children of a properties element / 替换属性元素子节点中的某个属性
+    ///
+    /// Any previous `name` element is dropped and `property` is placed before the first child listed in `after` / 丢弃原有的 `name` 元素，并将 `property` 放在 `after` 所列的第一个子节点之前
+    fn place_property(
+        children: Vec<Event<'static>>,
+        property: Event<'static>,
+        name: &[u8],
+        after: &[&[u8]],
+    ) -> Vec<Event<'static>> {
+        let mut result = Vec::with_capacity(children.len() + 1);
+        let mut property = Some(property);
+        let mut depth = 0;
+        let mut dropping = false; // Inside the previous element / 位于原有元素内
+        for event in children {
+            if depth == 0 {
+                match &event {
+                    Event::Empty(e) if e.name().as_ref() == name => continue,
+                    Event::Start(e) if e.name().as_ref() == name => dropping = true,
+                    Event::Start(e) | Event::Empty(e) if after.contains(&e.name().as_ref()) => {
+                        result.extend(property.take());
+                    }
+                    _ => {}
+                }
+            }
+            match &event {
+                Event::Start(_) => depth += 1,
+                Event::End(_) => depth -= 1,
+                _ => {}
+            }
+            if dropping {
+                dropping = depth > 0;
+                continue;
+            }
+            result.push(event);
+        }
+        result.extend(property);
+        result
+    }
+
+    /// Compute event and grid ranges of each top-level cell in a row / 计算行中每个顶层单元格的事件范围和网格范围
+    fn cell_layout(row: &[Event]) -> Vec<CellLayout> {
+        let mut layout = Vec::with_capacity(TYPICAL_COLUMN_COUNT);
+        let mut grid_pos = 0;
+        let mut depth = 0;
+        let mut cell_start = 0;
+        let mut span = 1;
+
+        for (index, event) in row.iter().enumerate() {
+            match event {
+                // Each row of a multi-row template starts at the first grid column / 多行模板的每一行都从第一个网格列开始
+                Event::Start(e) if depth == 0 && e.name().as_ref() == XML_TABLE_ROW => {
+                    grid_pos = 0;
+                }
+                Event::Start(e) if e.name().as_ref() == XML_TABLE_CELL => {
+                    if depth == 0 {
+                        cell_start = index;
+                        span = 1;
+                    }
+                    depth += 1;
+                }
+                Event::End(e) if e.name().as_ref() == XML_TABLE_CELL => {
+                    depth -= 1;
+                    if depth == 0 {
+                        layout.push(CellLayout {
+                            events: cell_start..index + 1,
+                            grid: grid_pos..grid_pos + span,
+                        });
+                        grid_pos += span;
+                    }
+                }
+                // Column span of the current cell / 当前单元格的跨列数
+                Event::Empty(e)
+                    if depth == 1 && e.name().as_ref() == XML_TABLE_GRID_SPAN.as_bytes() =>
+                {
+                    span = Self::attribute_as_usize(e).unwrap_or(1).max(1);
+                }
+                // Grid columns skipped before the first cell / 第一个单元格之前跳过的网格列
+                Event::Empty(e) if depth == 0 && e.name().as_ref() == XML_TABLE_GRID_BEFORE => {
+                    grid_pos += Self::attribute_as_usize(e).unwrap_or(0);
+                }
+                _ => {}
+            }
+        }
+        layout
+    }
+
+    /// Read the w:val attribute as a number / 将 w:val 属性读取为数字
+    #[inline]
+    fn attribute_as_usize(e: &BytesStart) -> Option<usize> {
+        let attr = e.try_get_attribute(XML_ATTR_VAL).ok()??;
+        std::str::from_utf8(&attr.value).ok()?.parse().ok()
+    }
+
     /// Process a single table row and detect placeholders / 处理单个表格行并检测占位符
     ///
     /// Returns row events and whether the row contains placeholders / 返回行事件以及该行是否包含占位符
     #[inline]
+    #[allow(clippy::too_many_arguments)]
     async fn process_table_row_internal<R>(
         reader: &mut Reader<R>,
         buf: &mut Vec<u8>,
         start_event: Event<'static>,
-        table_key: &mut Option<String>,
+        (table_key, loop_options): (&mut Option<String>, &mut Option<String>),
+        conditions: &mut Vec<(usize, String)>,
+        alignments: &mut Vec<(usize, CellAlignment)>,
+        skip_conditions: &mut Vec<String>,
+        unified_syntax: bool,
+        row_event_capacity: usize,
     ) -> Result<(Vec<Event<'static>>, bool), quick_xml::Error>
     where
         R: AsyncBufRead + Unpin,
     {
         // Storage for row events and state / 行事件和状态的存储
-        let mut row_events = Vec::with_capacity(TYPICAL_ROW_EVENT_COUNT);
+        let mut row_events = Vec::with_capacity(row_event_capacity);
         row_events.push(start_event);
         let mut has_placeholder = false; // Track if row contains placeholders / 跟踪行是否包含占位符
         let mut row_depth = 1; // Track nesting depth for nested rows / 跟踪嵌套行的深度
         let mut is_first_text = true; // Track first text element / 跟踪第一个文本元素
+        let mut tc_index = 0; // Number of cells opened so far / 已打开的单元格数量
+        let below_loop = table_key.is_some(); // Row below the loop marker row / 位于循环标记行下方的行
+        let in_loop = unified_syntax && below_loop; // Row below a loop marker with unified syntax / 启用统一语法时循环标记下方的行
 
         // Process all events in the row / 处理行中的所有事件
         loop {
@@ -426,6 +2335,8 @@ impl DocxProcessor {
                 Ok(Event::Start(row_e)) => {
                     if row_e.name().as_ref() == XML_TABLE_ROW {
                         row_depth += 1; // Track nesting / 跟踪嵌套
+                    } else if row_e.name().as_ref() == XML_TABLE_CELL {
+                        tc_index += 1;
                     }
                     row_events.push(Event::Start(row_e.into_owned()));
                 }
@@ -443,9 +2354,66 @@ impl DocxProcessor {
                 }
                 // Handle text content / 处理文本内容
                 Ok(Event::Text(row_e)) => {
-                    let text = row_e.decode()?;
-                    // Check for placeholder pattern / 检查占位符模式
-                    if REGEX.is_match(&text) {
+                    let mut text = row_e.decode()?;
+
+                    // Loop rows use `[field]`, so `{{field}}` is rewritten / 循环行使用 `[field]`，因此改写 `{{field}}`
+                    if in_loop && let Cow::Owned(rewritten) = braces_to_brackets(&text) {
+                        text = rewritten.into();
+                    }
+
+                    // Extract conditional column marker / 提取条件列标记
+                    if let Some(start) = text.find(COLUMN_CONDITION_MARKER)
+                        && let Some(len) = text[start..].find(LOOP_END_MARKER)
+                    {
+                        let condition = &text[start + COLUMN_CONDITION_MARKER.len()..start + len];
+                        conditions.push((tc_index.max(1) - 1, format!("{{{{{}}}}}", condition)));
+                        let stripped = format!(
+                            "{}{}",
+                            &text[..start],
+                            &text[start + len + LOOP_END_MARKER.len()..]
+                        );
+                        text = stripped.into();
+                    }
+
+                    // Extract cell alignment marker below the loop marker, `[r:amount]` becomes `[amount]` / 在循环标记下方提取单元格对齐标记，`[r:amount]` 变为 `[amount]`
+                    if below_loop
+                        && let Some((start, marker, alignment)) = [
+                            (ALIGN_LEFT_MARKER, CellAlignment::Left),
+                            (ALIGN_CENTER_MARKER, CellAlignment::Center),
+                            (ALIGN_RIGHT_MARKER, CellAlignment::Right),
+                        ]
+                        .into_iter()
+                        .find_map(|(marker, alignment)| {
+                            text.find(marker).map(|start| (start, marker, alignment))
+                        })
+                    {
+                        alignments.push((tc_index.max(1) - 1, alignment));
+                        let stripped = format!(
+                            "{}{}{}",
+                            &text[..start],
+                            MARKER_OPEN,
+                            &text[start + marker.len()..]
+                        );
+                        text = stripped.into();
+                    }
+
+                    // Extract row skip marker, it makes the row a data row / 提取跳过行标记，该标记使行成为数据行
+                    if let Some(start) = text.find(SKIP_IF_MARKER)
+                        && let Some(len) = text[start..].find(MARKER_CLOSE)
+                    {
+                        skip_conditions
+                            .push(text[start + SKIP_IF_MARKER.len()..start + len].to_string());
+                        let stripped = format!("{}{}", &text[..start], &text[start + len + 1..]);
+                        text = stripped.into();
+                        has_placeholder = true;
+                    }
+
+                    // Check for placeholder pattern, outside loop tables a list or raw marker alone keeps the row static / 检查占位符模式，在循环表格之外单独的列表或原始标记保持行为静态行
+                    let marker = text.trim_start();
+                    if REGEX.is_match(&text)
+                        && (table_key.is_some()
+                            || !(marker.starts_with(LIST_PREFIX) || marker.starts_with(RAW_PREFIX)))
+                    {
                         has_placeholder = true;
                     }
 
@@ -457,9 +2425,22 @@ impl DocxProcessor {
                     {
                         let first_col = &text[..pos + 2];
                         let first = text.replace(first_col, "");
-                        *table_key = Some(first_col.to_string());
+                        // Split `{{#users|status==active}}` into key and options / 将 `{{#users|status==active}}` 拆分为键和选项
+                        match first_col.split_once(LOOP_FILTER_SEPARATOR) {
+                            Some((key, options)) => {
+                                *table_key = Some(format!("{}{}", key, LOOP_END_MARKER));
+                                *loop_options = Some(
+                                    options[..options.len() - LOOP_END_MARKER.len()]
+                                        .trim()
+                                        .to_string(),
+                                );
+                            }
+                            None => *table_key = Some(first_col.to_string()),
+                        }
 
                         row_events.push(Event::Text(BytesText::from_escaped(first)));
+                    } else if let std::borrow::Cow::Owned(stripped) = text {
+                        row_events.push(Event::Text(BytesText::from_escaped(stripped)));
                     } else {
                         row_events.push(Event::Text(row_e.into_owned()));
                     }
@@ -481,7 +2462,10 @@ impl DocxProcessor {
 
     /// Write table rows with vertical cell merging / 写入带垂直单元格合并的表格行
     ///
-    /// Handles automatic cell merging for consecutive rows with identical values / 处理具有相同值的连续行的自动单元格合并
+    /// Handles automatic cell merging for consecutive rows with identical values. Multi-row templates are never merged since their cells are not adjacent / 处理具有相同值的连续行的自动单元格合并。多行模板的单元格不相邻，因此从不合并
+    ///
+    /// # Returns / 返回
+    /// Number of rows written and the columns where merging was applied, with the row span of each merged run / 写入的行数，以及应用了合并的列和每个合并区域跨越的行数
     #[inline]
     async fn write_rows_with_merge<'a, W, I>(
         &mut self,
@@ -490,7 +2474,7 @@ impl DocxProcessor {
         items: I,
         rel_manager: &mut RelationshipManager,
         img_manager: &mut ImageManager<'a>,
-    ) -> Result<(), quick_xml::Error>
+    ) -> Result<(usize, Vec<ColumnMerge>), DocxError>
     where
         W: AsyncWrite + Unpin,
         I: Iterator<Item = HashMap<String, Value>>,
@@ -500,21 +2484,66 @@ impl DocxProcessor {
         let mut prev_row_values: Option<Vec<String>> = None; // Previous row values for comparison / 用于比较的前一行值
         let mut merging_cols: Vec<bool> = Vec::new(); // Track which columns are currently merging / 跟踪当前正在合并的列
         let mut row_index = 0; // Current row index / 当前行索引
+        let mut spans: Vec<Vec<usize>> = Vec::new(); // Merged run lengths per column / 每列的合并区域长度
+        let mut span_shading: Vec<bool> = Vec::new(); // Shading of the merged run per column / 每列合并区域的底纹
+        let single_row = row_template
+            .iter()
+            .filter(|event| matches!(event, Event::Start(e) if e.name().as_ref() == XML_TABLE_ROW))
+            .count()
+            <= 1;
+
+        // Visible row numbering, only maintained when the template uses it / 可见行编号，仅在模板使用时维护
+        let uses_visible_row = row_template.iter().any(|event| {
+            matches!(event, Event::Text(text) if text.windows(VISIBLE_ROW_KEY.len()).any(|w| w == VISIBLE_ROW_KEY.as_bytes()))
+        });
+        let mut visible_row = 0; // Rows not continuing a merge in the first column / 第一列中非合并延续的行数
+        let mut prev_first_value: Option<String> = None; // First column value of the previous row / 前一行的第一列值
 
         // Process each data row / 处理每个数据行
-        while let Some(item) = iter.next() {
+        let mut items_read = 0usize; // Items since the last cancellation check / 自上次取消检查以来的项数
+        while let Some(mut item) = iter.next() {
+            items_read += 1;
+            if items_read.is_multiple_of(CANCELLATION_CHECK_ROWS) {
+                self.check_cancelled()?;
+            }
+
+            if self.skip_empty_rows && self.is_empty_row(row_template, row_index, &item)? {
+                continue;
+            }
+
+            // A row is visible unless its first column continues a merge / 除非第一列延续合并，否则行可见
+            let first_value = if uses_visible_row {
+                self.first_column_value(row_template, row_index, &item)?
+            } else {
+                None
+            };
+            if uses_visible_row {
+                let continues = self.continues_merge(
+                    single_row,
+                    prev_first_value.as_deref(),
+                    first_value.as_deref(),
+                );
+                if row_index > 0 && !continues {
+                    visible_row += 1;
+                }
+                item.insert(
+                    VISIBLE_ROW_KEY.to_string(),
+                    Value::String(visible_row.to_string()),
+                );
+            }
+
             // Compute current row values by replacing placeholders / 通过替换占位符计算当前行值
             // Pre-allocate based on previous row or estimate / 根据前一行或估计预分配
             let capacity = prev_row_values
                 .as_ref()
                 .map(|v| v.len())
-                .unwrap_or(TYPICAL_COLUMN_COUNT);
+                .unwrap_or(self.capacity_hints.columns);
             let mut current_values = Vec::with_capacity(capacity);
             for event in row_template.iter() {
                 if let Event::Text(text) = event {
-                    let replaced =
-                        self.cell_handler
-                            .replace_in_table(row_index, &text.decode()?, &item);
+                    let replaced = self
+                        .value_in_table(row_index, &text.decode()?, &item)
+                        .into_owned();
                     current_values.push(replaced);
                 }
             }
@@ -525,16 +2554,35 @@ impl DocxProcessor {
             }
 
             // Peek next row values for merge detection / 窥视下一行值以检测合并
-            let next_values = if let Some(next_item) = iter.peek() {
+            let next_values = if !single_row {
+                None // Never merge multi-row templates / 从不合并多行模板
+            } else if let Some(next_item) = iter.peek() {
+                // The next row needs its visible number for merge detection / 下一行需要其可见编号以检测合并
+                let next_item = if uses_visible_row {
+                    let next_first =
+                        self.first_column_value(row_template, row_index + 1, next_item)?;
+                    let next_continues = self.continues_merge(
+                        single_row,
+                        first_value.as_deref(),
+                        next_first.as_deref(),
+                    );
+                    let next_visible = visible_row + usize::from(!next_continues);
+                    let mut next_item = next_item.clone();
+                    next_item.insert(
+                        VISIBLE_ROW_KEY.to_string(),
+                        Value::String(next_visible.to_string()),
+                    );
+                    Cow::Owned(next_item)
+                } else {
+                    Cow::Borrowed(next_item)
+                };
                 // Pre-allocate with known capacity / 使用已知容量预分配
                 let mut values = Vec::with_capacity(current_values.len());
                 for event in row_template.iter() {
                     if let Event::Text(text) = event {
-                        let replaced = self.cell_handler.replace_in_table(
-                            row_index + 1,
-                            &text.decode()?,
-                            next_item,
-                        );
+                        let replaced = self
+                            .value_in_table(row_index + 1, &text.decode()?, &next_item)
+                            .into_owned();
                         values.push(replaced);
                     }
                 }
@@ -561,12 +2609,12 @@ impl DocxProcessor {
                 // Optimized merge state logic with pattern matching / 使用模式匹配优化的合并状态逻辑
                 match (merging_cols[col_idx], prev_val, next_val) {
                     // Currently merging and same as previous - continue merge / 当前在合并且与前一个相同 - 继续合并
-                    (true, Some(p), _) if p == val => {
+                    (true, Some(p), _) if self.same_merge_value(p, val) => {
                         merge_info[col_idx] = Some(MERGE_CONTINUE);
                         // merging_cols[col_idx] remains true / merging_cols[col_idx] 保持为 true
                     }
                     // Start new merge (when next equals current and not empty) / 开始新合并（当下一个等于当前且非空）
-                    (_, _, Some(n)) if n == val && !val.is_empty() => {
+                    (_, _, Some(n)) if self.same_merge_value(n, val) && !val.is_empty() => {
                         merge_info[col_idx] = Some(MERGE_RESTART);
                         merging_cols[col_idx] = true;
                     }
@@ -577,12 +2625,38 @@ impl DocxProcessor {
                 }
             }
 
+            // Aggregate merge runs for the report / 为报告汇总合并区域
+            if spans.len() < merge_info.len() {
+                spans.resize_with(merge_info.len(), Vec::new);
+            }
+            for (col_idx, merge) in merge_info.iter().enumerate() {
+                match *merge {
+                    Some(MERGE_RESTART) => spans[col_idx].push(1),
+                    Some(_) => {
+                        if let Some(span) = spans[col_idx].last_mut() {
+                            *span += 1;
+                        }
+                    }
+                    None => {}
+                }
+            }
+
+            // Odd rows are shaded, continued merges keep the shading of their first row / 奇数行添加底纹，延续的合并保持其首行的底纹
+            let row_shaded = row_index % 2 == 1;
+            span_shading.resize(merge_info.len(), false);
+            for (col_idx, merge) in merge_info.iter().enumerate() {
+                if *merge != Some(MERGE_CONTINUE) {
+                    span_shading[col_idx] = row_shaded;
+                }
+            }
+
             // Write row with merge information / 使用合并信息写入行
             self.write_row_with_merge_fixed(
                 writer,
                 row_template,
                 &item,
                 &merge_info,
+                (row_shaded, &span_shading),
                 row_index,
                 rel_manager,
                 img_manager,
@@ -591,15 +2665,192 @@ impl DocxProcessor {
 
             // Update state for next iteration / 更新状态以供下次迭代
             prev_row_values = Some(current_values);
+            prev_first_value = first_value;
             row_index += 1;
         }
 
-        Ok(())
+        let columns = spans
+            .into_iter()
+            .enumerate()
+            .filter(|(_, spans)| !spans.is_empty())
+            .map(|(column, spans)| ColumnMerge { column, spans })
+            .collect();
+        Ok((row_index, columns))
+    }
+
+    /// Write grouped loop items, each group preceded by its header row / 写入分组的循环项，每个分组前写入其标题行
+    ///
+    /// The data row using `[$group]` is the group header row, rendered with the first item of the group; the other data rows repeat for every item. Merges do not cross groups / 使用 `[$group]` 的数据行为分组标题行，使用分组的第一项渲染；其他数据行对每一项重复。合并不会跨越分组
+    ///
+    /// # Returns / 返回
+    /// * `(rows, columns)` - Item rows written, excluding group headers, and the merges of all groups / 写入的项行数（不含分组标题）及所有分组的合并
+    #[allow(clippy::too_many_arguments)]
+    async fn write_groups<'a, W>(
+        &mut self,
+        writer: &mut Writer<W>,
+        data_rows: Vec<Event<'static>>,
+        groups: Vec<(String, Vec<HashMap<String, Value>>)>,
+        rel_manager: &mut RelationshipManager,
+        img_manager: &mut ImageManager<'a>,
+    ) -> Result<(usize, Vec<ColumnMerge>), DocxError>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let (header_row, item_rows) = Self::split_group_row(data_rows);
+        let mut total = 0;
+        let mut merges: Vec<ColumnMerge> = Vec::new();
+
+        for (value, items) in groups {
+            if !header_row.is_empty() {
+                let mut first = items[0].clone();
+                first.insert(GROUP_KEY.to_string(), Value::String(value.clone()));
+                self.write_rows_with_merge(
+                    writer,
+                    &header_row,
+                    std::iter::once(first),
+                    rel_manager,
+                    img_manager,
+                )
+                .await?;
+            }
+
+            let items = items.into_iter().map(|mut item| {
+                item.insert(GROUP_KEY.to_string(), Value::String(value.clone()));
+                item
+            });
+            let (rows, columns) = self
+                .write_rows_with_merge(writer, &item_rows, items, rel_manager, img_manager)
+                .await?;
+            total += rows;
+            for column in columns {
+                match merges
+                    .iter_mut()
+                    .find(|merge| merge.column == column.column)
+                {
+                    Some(merge) => merge.spans.extend(column.spans),
+                    None => merges.push(column),
+                }
+            }
+        }
+        merges.sort_by_key(|merge| merge.column);
+        Ok((total, merges))
+    }
+
+    /// Split the group header row off the data rows / 从数据行中分离分组标题行
+    ///
+    /// # Returns / 返回
+    /// * `(header, items)` - Row using `[$group]`, empty when there is none, and the remaining rows / 使用 `[$group]` 的行（不存在时为空）以及其余行
+    fn split_group_row(rows: Vec<Event<'static>>) -> (Vec<Event<'static>>, Vec<Event<'static>>) {
+        let mut header = Vec::new();
+        let mut items = Vec::with_capacity(rows.len());
+        let mut current = Vec::new();
+        let mut in_row = false;
+        let mut is_group = false;
+
+        for event in rows {
+            match &event {
+                Event::Start(e) if e.name().as_ref() == XML_TABLE_ROW => {
+                    in_row = true;
+                    is_group = false;
+                }
+                Event::Text(text)
+                    if header.is_empty()
+                        && text
+                            .windows(GROUP_KEY.len())
+                            .any(|w| w == GROUP_KEY.as_bytes()) =>
+                {
+                    is_group = true;
+                }
+                _ => {}
+            }
+            if !in_row {
+                items.push(event);
+                continue;
+            }
+
+            let row_end = matches!(&event, Event::End(e) if e.name().as_ref() == XML_TABLE_ROW);
+            current.push(event);
+            if row_end {
+                in_row = false;
+                if is_group {
+                    header = std::mem::take(&mut current);
+                } else {
+                    items.append(&mut current);
+                }
+            }
+        }
+        (header, items)
+    }
+
+    /// Whether two cell values merge, trimmed and case-folded when normalization is on / 两个单元格值是否合并，启用规范化时去除空白并忽略大小写
+    #[inline]
+    fn same_merge_value(&self, a: &str, b: &str) -> bool {
+        if self.normalize_merge {
+            a.trim().to_lowercase() == b.trim().to_lowercase()
+        } else {
+            a == b
+        }
+    }
+
+    /// Whether a row's first column continues the merge of the previous row, by the rules of [`Self::write_rows_with_merge`] / 按 [`Self::write_rows_with_merge`] 的规则判断行的第一列是否延续前一行的合并
+    ///
+    /// Multi-row templates never merge, and an empty value never starts a merge / 多行模板从不合并，空值从不开始合并
+    fn continues_merge(
+        &self,
+        single_row: bool,
+        previous: Option<&str>,
+        value: Option<&str>,
+    ) -> bool {
+        match (previous, value) {
+            (Some(previous), Some(value)) => {
+                single_row && !previous.is_empty() && self.same_merge_value(previous, value)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether every text of a row template resolves to empty / 行模板中的每个文本是否都解析为空
+    fn is_empty_row(
+        &self,
+        row_template: &[Event],
+        row_index: usize,
+        item: &HashMap<String, Value>,
+    ) -> Result<bool, quick_xml::Error> {
+        for event in row_template {
+            if let Event::Text(text) = event
+                && !self
+                    .value_in_table(row_index, &text.decode()?, item)
+                    .trim()
+                    .is_empty()
+            {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Compute the value of the first text of a row template / 计算行模板中第一个文本的值
+    #[inline]
+    fn first_column_value(
+        &self,
+        row_template: &[Event],
+        row_index: usize,
+        item: &HashMap<String, Value>,
+    ) -> Result<Option<String>, quick_xml::Error> {
+        for event in row_template {
+            if let Event::Text(text) = event {
+                return Ok(Some(
+                    self.value_in_table(row_index, &text.decode()?, item)
+                        .into_owned(),
+                ));
+            }
+        }
+        Ok(None)
     }
 
     /// Write a single row with merge information / 使用合并信息写入单行
     ///
-    /// Applies vertical merge markers to cells based on merge state / 根据合并状态将垂直合并标记应用于单元格
+    /// Applies vertical merge markers to cells based on merge state, and the zebra shading given per column with a fallback for the whole row / 根据合并状态将垂直合并标记应用于单元格，并应用按列给出、以整行为后备的斑马纹底纹
     #[inline]
     #[allow(clippy::too_many_arguments)]
     async fn write_row_with_merge_fixed<'a, W>(
@@ -608,6 +2859,7 @@ impl DocxProcessor {
         row: &[Event<'a>],
         item: &HashMap<String, Value>,
         merge_info: &[Option<u32>],
+        (row_shaded, cell_shading): (bool, &[bool]),
         row_index: usize,
         rel_manager: &mut RelationshipManager,
         img_manager: &mut ImageManager<'a>,
@@ -621,8 +2873,65 @@ impl DocxProcessor {
         let mut current_tc_is_continue = false; // Current cell is continuation of merge / 当前单元格是合并的延续
 
         // Process all events in row / 处理行中的所有事件
-        for event in row {
+        let mut events = row.iter().peekable();
+        while let Some(event) = events.next() {
+            self.paragraph.observe(event);
             match event {
+                // Handle text elements outside continuation cells / 处理延续单元格之外的文本元素
+                Event::Start(bytes_start)
+                    if bytes_start.name().as_ref() == XML_TEXT && !current_tc_is_continue =>
+                {
+                    self.write_text_element(
+                        writer,
+                        bytes_start,
+                        &mut events,
+                        Some(row_index),
+                        item,
+                        rel_manager,
+                        img_manager,
+                    )
+                    .await?;
+                }
+                // Handle complex fields outside continuation cells / 处理延续单元格之外的复杂域
+                Event::Empty(bytes_start)
+                    if !current_tc_is_continue
+                        && field_char_type(bytes_start).as_deref() == Some(FIELD_CHAR_BEGIN) =>
+                {
+                    let (field, step) = Self::buffer_complex_field(&mut events)?;
+                    self.write_complex_field(
+                        writer,
+                        bytes_start.borrow(),
+                        field,
+                        step,
+                        Some(row_index),
+                        item,
+                    )
+                    .await?;
+                }
+                // Handle simple merge fields outside continuation cells / 处理延续单元格之外的简单合并域
+                Event::Start(bytes_start)
+                    if !current_tc_is_continue
+                        && bytes_start.name().as_ref() == XML_FIELD_SIMPLE =>
+                {
+                    match self.merge_field_value(bytes_start, Some(row_index), item)? {
+                        Some(value) => {
+                            let result = events
+                                .by_ref()
+                                .take_while(|event| {
+                                    !matches!(event, Event::End(end) if end.name().as_ref() == XML_FIELD_SIMPLE)
+                                })
+                                .cloned()
+                                .collect::<Vec<_>>();
+                            let markup = render_merge_field(&result, value)?;
+                            writer.get_mut().write_all(&markup).await?;
+                        }
+                        None => {
+                            writer
+                                .write_event_async(Event::Start(bytes_start.borrow()))
+                                .await?
+                        }
+                    }
+                }
                 // Handle start tags / 处理开始标签
                 Event::Start(bytes_start) => {
                     // Borrow from bytes_start instead of cloning event / 从 bytes_start 借用而不是克隆事件
@@ -635,32 +2944,90 @@ impl DocxProcessor {
                         in_tc = true;
                         tc_index += 1;
                         let merge_val = merge_info.get(tc_index as usize).and_then(|&v| v);
+                        let fill = self.zebra_shading.as_deref().filter(|_| {
+                            cell_shading
+                                .get(tc_index as usize)
+                                .copied()
+                                .unwrap_or(row_shaded)
+                        });
 
-                        // Add merge properties if needed / 如果需要添加合并属性
-                        if let Some(span) = merge_val {
-                            let merge_type = if span == MERGE_RESTART {
-                                MERGE_TYPE_RESTART
-                            } else {
-                                MERGE_TYPE_CONTINUE
-                            };
-                            let merge_tag =
-                                format!(r#"<{}="{}"/>"#, XML_TABLE_MERGE_TAG, merge_type);
+                        // Add merge and shading properties if needed / 如果需要添加合并和底纹属性
+                        if merge_val.is_some() || fill.is_some() {
+                            // Merge into the template's w:tcPr instead of adding a second one / 合并到模板的 w:tcPr 中而不是添加第二个
+                            let mut children = Vec::new();
+                            match events.peek() {
+                                Some(Event::Start(e))
+                                    if e.name().as_ref()
+                                        == XML_TABLE_CELL_PROPERTIES.as_bytes() =>
+                                {
+                                    events.next();
+                                    let mut depth = 0;
+                                    for event in events.by_ref() {
+                                        match event {
+                                            Event::Start(_) => depth += 1,
+                                            Event::End(_) if depth == 0 => break,
+                                            Event::End(_) => depth -= 1,
+                                            _ => {}
+                                        }
+                                        children.push(event.clone().into_owned());
+                                    }
+                                }
+                                Some(Event::Empty(e))
+                                    if e.name().as_ref()
+                                        == XML_TABLE_CELL_PROPERTIES.as_bytes() =>
+                                {
+                                    events.next();
+                                }
+                                _ => {}
+                            }
+                            if let Some(span) = merge_val {
+                                let merge_type = if span == MERGE_RESTART {
+                                    MERGE_TYPE_RESTART
+                                } else {
+                                    MERGE_TYPE_CONTINUE
+                                };
+                                let merge_tag =
+                                    format!(r#"{}="{}""#, XML_TABLE_MERGE_TAG, merge_type);
+                                children = Self::place_property(
+                                    children,
+                                    Event::Empty(BytesStart::from_content(
+                                        merge_tag,
+                                        XML_TABLE_MERGE.len(),
+                                    )),
+                                    XML_TABLE_MERGE,
+                                    &TABLE_CELL_PROPERTIES_AFTER_MERGE,
+                                );
+
+                                // Mark as continuation cell (skip content) / 标记为延续单元格（跳过内容）
+                                if span == MERGE_CONTINUE {
+                                    current_tc_is_continue = true;
+                                }
+                            }
+                            if let Some(fill) = fill {
+                                let shading_tag = format!(r#"{}="{}""#, XML_SHADING_TAG, fill);
+                                children = Self::place_property(
+                                    children,
+                                    Event::Empty(BytesStart::from_content(
+                                        shading_tag,
+                                        XML_SHADING.len(),
+                                    )),
+                                    XML_SHADING,
+                                    &TABLE_CELL_PROPERTIES_AFTER_SHADING,
+                                );
+                            }
                             writer
                                 .write_event_async(Event::Start(BytesStart::new(
                                     XML_TABLE_CELL_PROPERTIES,
                                 )))
                                 .await?;
-                            writer.get_mut().write_all(merge_tag.as_bytes()).await?;
+                            for event in children {
+                                writer.write_event_async(event).await?;
+                            }
                             writer
                                 .write_event_async(Event::End(BytesEnd::new(
                                     XML_TABLE_CELL_PROPERTIES,
                                 )))
                                 .await?;
-
-                            // Mark as continuation cell (skip content) / 标记为延续单元格（跳过内容）
-                            if span == MERGE_CONTINUE {
-                                current_tc_is_continue = true;
-                            }
                         }
                     }
                 }
@@ -670,26 +3037,14 @@ impl DocxProcessor {
                     if in_tc && current_tc_is_continue {
                         // skip
                     } else {
-                        // Replace placeholders and handle images / 替换占位符并处理图片
-                        let replaced =
-                            self.cell_handler
-                                .replace_in_table(row_index, &text.decode()?, item);
-                        // Check for base64 image / 检查 base64 图片
-                        if replaced.starts_with(PNG_BASE64_SIGNATURE)
-                            || replaced.starts_with(JPEG_BASE64_SIGNATURE)
-                        {
-                            self.process_base64_image(
-                                replaced.as_str(),
-                                writer,
-                                rel_manager,
-                                img_manager,
-                            )
+                        // Replace placeholders / 替换占位符
+                        let original = text.decode()?;
+                        self.record_missing(&original, Some(row_index), item);
+                        let replaced = self.value_in_table(row_index, &original, item);
+                        self.record_change(&original, &replaced);
+                        writer
+                            .write_event_async(Event::Text(BytesText::from_escaped(replaced)))
                             .await?;
-                        } else {
-                            writer
-                                .write_event_async(Event::Text(BytesText::from_escaped(replaced)))
-                                .await?;
-                        }
                     }
                 }
                 // Handle end tags / 处理结束标签
@@ -0,0 +1,98 @@
+use crate::core::default_handler::DefaultValueHandler;
+use crate::core::transform::{Transforms, split_transform};
+use crate::public::value_extern::ValueExt;
+use serde_json::Value;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Source consulted when the primary handler does not find a placeholder / 主处理器未找到占位符时查询的来源
+#[derive(Clone)]
+pub(crate) enum Fallback {
+    /// Another handler, given the same values / 另一个处理器，使用相同的值
+    Handler(Arc<dyn ValueExt + Send>),
+    /// A map of default values, read by the default handler / 默认值映射，由默认处理器读取
+    Values(HashMap<String, Value>),
+}
+
+impl Fallback {
+    /// Look up a placeholder in this source / 在此来源中查找占位符
+    ///
+    /// # Arguments / 参数
+    /// * `index` - Row index inside a loop table, `None` for regular text / 循环表格中的行索引，常规文本为 `None`
+    /// * `key` - Placeholder key / 占位符键
+    /// * `placeholders` - Values of the primary lookup / 主查找的值
+    fn lookup<'k>(
+        &self,
+        index: Option<usize>,
+        key: &'k str,
+        placeholders: &HashMap<String, Value>,
+    ) -> Option<Cow<'k, str>> {
+        let (handler, values): (&dyn ValueExt, _) = match self {
+            Fallback::Handler(handler) => (handler.as_ref(), placeholders),
+            Fallback::Values(values) => (&DefaultValueHandler, values),
+        };
+        match index {
+            Some(index) => handler.lookup_in_table(index, key, values),
+            None => handler.lookup(key, values),
+        }
+    }
+}
+
+/// Resolve a placeholder through the primary handler, then each fallback in order, then blank / 依次通过主处理器、各后备来源解析占位符，最后置空
+///
+/// Without fallbacks the primary handler replaces the placeholder directly. A registered transform name in front of the key (e.g. `[reverse:name]`) is applied to the resolved value / 没有后备来源时由主处理器直接替换占位符。键前的已注册变换名称（例如 `[reverse:name]`）会应用于解析出的值
+///
+/// # Arguments / 参数
+/// * `primary` - Registered cell handler / 已注册的单元格处理器
+/// * `fallbacks` - Fallback sources in order / 按顺序排列的后备来源
+/// * `transforms` - Registered transforms / 已注册的变换
+/// * `index` - Row index inside a loop table, `None` for regular text / 循环表格中的行索引，常规文本为 `None`
+/// * `key` - Placeholder key / 占位符键
+/// * `placeholders` - Value map / 值映射
+pub(crate) fn resolve<'k>(
+    primary: &dyn ValueExt,
+    fallbacks: &[Fallback],
+    transforms: &Transforms,
+    index: Option<usize>,
+    key: &'k str,
+    placeholders: &HashMap<String, Value>,
+) -> Cow<'k, str> {
+    if let Some((transform, key)) = split_transform(key, transforms) {
+        let value = resolve(primary, fallbacks, transforms, index, &key, placeholders);
+        return Cow::Owned(transform(&value));
+    }
+    if fallbacks.is_empty() {
+        return match index {
+            Some(index) => primary.replace_in_table(index, key, placeholders),
+            None => primary.replace(key, placeholders),
+        };
+    }
+    lookup(primary, fallbacks, transforms, index, key, placeholders).unwrap_or_default()
+}
+
+/// Look up a placeholder like [`resolve`], `None` when neither the primary handler nor a fallback finds it / 与 [`resolve`] 相同地查找占位符，主处理器和后备来源都未找到时为 `None`
+///
+/// Handlers that do not implement the lookup methods find every key / 未实现查找方法的处理器视所有键为已找到
+pub(crate) fn lookup<'k>(
+    primary: &dyn ValueExt,
+    fallbacks: &[Fallback],
+    transforms: &Transforms,
+    index: Option<usize>,
+    key: &'k str,
+    placeholders: &HashMap<String, Value>,
+) -> Option<Cow<'k, str>> {
+    if let Some((transform, key)) = split_transform(key, transforms) {
+        let value = lookup(primary, fallbacks, transforms, index, &key, placeholders)?;
+        return Some(Cow::Owned(transform(&value)));
+    }
+    let found = match index {
+        Some(index) => primary.lookup_in_table(index, key, placeholders),
+        None => primary.lookup(key, placeholders),
+    };
+    found.or_else(|| {
+        fallbacks
+            .iter()
+            .find_map(|fallback| fallback.lookup(index, key, placeholders))
+    })
+}
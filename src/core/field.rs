@@ -0,0 +1,192 @@
+use crate::core::constant::{
+    FIELD_CHAR_BEGIN, FIELD_CHAR_END, FIELD_CHAR_SEPARATE, MERGEFIELD_INSTRUCTION,
+    XML_ATTR_FIELD_CHAR_TYPE, XML_FIELD_CHAR, XML_INSTR_TEXT, XML_RUN, XML_TEXT,
+};
+use quick_xml::Writer;
+use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+
+/// Extract the field name of a `MERGEFIELD` instruction / 提取 `MERGEFIELD` 指令的域名称
+///
+/// Switches such as `\* MERGEFORMAT` are ignored / 忽略 `\* MERGEFORMAT` 等开关
+///
+/// # Arguments / 参数
+/// * `instruction` - Field instruction (e.g. ` MERGEFIELD FirstName \* MERGEFORMAT `) / 域指令
+///
+/// # Returns / 返回
+/// * `Some(name)` - Field name without quotes / 不带引号的域名称
+/// * `None` - Not a merge field / 不是合并域
+pub(crate) fn merge_field_name(instruction: &str) -> Option<&str> {
+    let mut tokens = instruction.split_whitespace();
+    if !tokens.next()?.eq_ignore_ascii_case(MERGEFIELD_INSTRUCTION) {
+        return None;
+    }
+    let name = tokens.next()?.trim_matches('"');
+    (!name.is_empty()).then_some(name)
+}
+
+/// Render a merge field result as a single run / 将合并域结果渲染为单个运行
+///
+/// Keeps the properties of the first run of the field result and drops the field itself / 保留域结果中第一个运行的属性并丢弃域本身
+///
+/// # Arguments / 参数
+/// * `result` - Events of the cached field result / 缓存的域结果事件
+/// * `value` - Resolved value / 解析后的值
+pub(crate) fn render_merge_field(
+    result: &[Event],
+    value: String,
+) -> Result<Vec<u8>, quick_xml::Error> {
+    let mut writer = Writer::new(Vec::new());
+    let mut in_run = false;
+
+    // Copy the first run up to its text / 复制第一个运行直到其文本
+    for event in result {
+        match event {
+            Event::Start(e) if !in_run && e.name().as_ref() == XML_RUN => {
+                writer.write_event(Event::Start(e.borrow()))?;
+                in_run = true;
+            }
+            Event::Start(e) | Event::Empty(e) if in_run && e.name().as_ref() == XML_TEXT => break,
+            Event::End(e) if in_run && e.name().as_ref() == XML_RUN => break,
+            _ if in_run => writer.write_event(event.borrow())?,
+            _ => {}
+        }
+    }
+    if !in_run {
+        writer.write_event(Event::Start(BytesStart::new("w:r")))?;
+    }
+
+    let mut text = BytesStart::new("w:t");
+    text.push_attribute(("xml:space", "preserve"));
+    writer.write_event(Event::Start(text))?;
+    writer.write_event(Event::Text(BytesText::from_escaped(value)))?;
+    writer.write_event(Event::End(BytesEnd::new("w:t")))?;
+    writer.write_event(Event::End(BytesEnd::new("w:r")))?;
+    Ok(writer.into_inner())
+}
+
+/// Step of a complex field buffered event by event / 逐事件缓冲的复杂域的步骤
+pub(crate) enum FieldStep {
+    /// More events are needed / 需要更多事件
+    Pending,
+    /// Not a merge field, the buffered events are replayed and the rest streams through / 不是合并域，回放缓冲的事件，其余部分流式通过
+    Replay,
+    /// Merge field buffered up to its `end` field character / 缓冲到其 `end` 域字符的合并域
+    Merge,
+}
+
+/// Complex field buffered from its `begin` field character / 从 `begin` 域字符开始缓冲的复杂域
+///
+/// Only the instruction of a field is buffered until it is known to be a `MERGEFIELD`, so the results of `HYPERLINK`, `REF`, `PAGE` or `TOC` fields keep streaming through the regular text path / 仅在确认是 `MERGEFIELD` 之前缓冲域的指令，因此 `HYPERLINK`、`REF`、`PAGE` 或 `TOC` 域的结果仍经由常规文本路径流式处理
+#[derive(Default)]
+pub(crate) struct ComplexField {
+    events: Vec<Event<'static>>,
+    depth: usize,
+    name: Option<String>,
+}
+
+impl ComplexField {
+    /// Buffer the next event of the field / 缓冲域的下一个事件
+    pub(crate) fn push(&mut self, event: Event<'static>) -> Result<FieldStep, quick_xml::Error> {
+        let outer = match &event {
+            Event::Empty(e) => match field_char_type(e).as_deref() {
+                Some(FIELD_CHAR_BEGIN) => {
+                    self.depth += 1;
+                    None
+                }
+                Some(FIELD_CHAR_END) if self.depth > 0 => {
+                    self.depth -= 1;
+                    None
+                }
+                Some(FIELD_CHAR_END) => Some(FIELD_CHAR_END),
+                Some(FIELD_CHAR_SEPARATE) if self.depth == 0 => Some(FIELD_CHAR_SEPARATE),
+                _ => None,
+            },
+            _ => None,
+        };
+        self.events.push(event);
+        let Some(outer) = outer else {
+            return Ok(FieldStep::Pending);
+        };
+        // The instruction is complete once the field's own separator or end is reached / 到达域自身的分隔符或结束符时指令完整
+        if self.name.is_none() {
+            let (instruction, _) = split_complex_field(&self.events)?;
+            match merge_field_name(&instruction) {
+                Some(name) => self.name = Some(name.to_string()),
+                None => return Ok(FieldStep::Replay),
+            }
+        }
+        Ok(if outer == FIELD_CHAR_END {
+            FieldStep::Merge
+        } else {
+            FieldStep::Pending
+        })
+    }
+
+    /// Name of the merge field, once its instruction is known / 指令已知后合并域的名称
+    pub(crate) fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Render the merge field as a single run holding `value` / 将合并域渲染为包含 `value` 的单个运行
+    pub(crate) fn render(&self, value: String) -> Result<Vec<u8>, quick_xml::Error> {
+        let (_, result) = split_complex_field(&self.events)?;
+        render_merge_field(result, value)
+    }
+
+    /// Buffered events after the `begin` field character / `begin` 域字符之后的缓冲事件
+    pub(crate) fn into_events(self) -> Vec<Event<'static>> {
+        self.events
+    }
+}
+
+/// Get the type of a `w:fldChar` element / 获取 `w:fldChar` 元素的类型
+///
+/// # Returns / 返回
+/// * `Some(type)` - `begin`, `separate` or `end` / `begin`、`separate` 或 `end`
+/// * `None` - Not a field character / 不是域字符
+pub(crate) fn field_char_type(e: &BytesStart) -> Option<Vec<u8>> {
+    if e.name().as_ref() != XML_FIELD_CHAR {
+        return None;
+    }
+    e.try_get_attribute(XML_ATTR_FIELD_CHAR_TYPE)
+        .ok()
+        .flatten()
+        .map(|attr| attr.value.into_owned())
+}
+
+/// Split the events of a complex field into instruction and cached result / 将复杂域的事件拆分为指令和缓存结果
+///
+/// `events` starts right after the `begin` field character and ends with the matching `end` field character; nested fields stay part of the outer instruction or result / `events` 从 `begin` 域字符之后开始，以匹配的 `end` 域字符结束；嵌套域保留在外层指令或结果中
+///
+/// # Returns / 返回
+/// * `(instruction, result)` - Concatenated instruction text and result events / 拼接的指令文本和结果事件
+pub(crate) fn split_complex_field<'e, 'a>(
+    events: &'e [Event<'a>],
+) -> Result<(String, &'e [Event<'a>]), quick_xml::Error> {
+    let mut instruction = String::new();
+    let mut depth = 0;
+    let mut in_instruction = false;
+    let mut result_start = None;
+
+    for (index, event) in events.iter().enumerate() {
+        match event {
+            Event::Empty(e) => match field_char_type(e).as_deref() {
+                Some(FIELD_CHAR_BEGIN) => depth += 1,
+                Some(FIELD_CHAR_END) if depth > 0 => depth -= 1,
+                Some(FIELD_CHAR_END) => {
+                    let start = result_start.unwrap_or(index);
+                    return Ok((instruction, &events[start..index]));
+                }
+                Some(FIELD_CHAR_SEPARATE) if depth == 0 => result_start = Some(index + 1),
+                _ => {}
+            },
+            Event::Start(e) if e.name().as_ref() == XML_INSTR_TEXT => in_instruction = true,
+            Event::End(e) if e.name().as_ref() == XML_INSTR_TEXT => in_instruction = false,
+            Event::Text(text) if in_instruction && depth == 0 && result_start.is_none() => {
+                instruction.push_str(&text.decode()?);
+            }
+            _ => {}
+        }
+    }
+    Ok((instruction, &events[events.len()..]))
+}
@@ -0,0 +1,132 @@
+use crate::core::constant::{
+    CONTENT_TYPE_OCTET_STREAM, CONTENT_TYPE_XML_SUFFIX, CONTENT_TYPES_PATH, FLAT_OPC_HEAD,
+    FLAT_OPC_TAIL, XML_ATTR_CONTENT_TYPE, XML_ATTR_EXTENSION, XML_ATTR_PART_NAME,
+    XML_TYPES_DEFAULT, XML_TYPES_OVERRIDE,
+};
+use base64::Engine;
+use base64::engine::general_purpose;
+use quick_xml::Reader;
+use quick_xml::escape::escape;
+use quick_xml::events::{BytesStart, Event};
+use std::collections::HashMap;
+
+/// Content types declared by [Content_Types].xml / [Content_Types].xml 声明的内容类型
+#[derive(Debug, Default)]
+struct ContentTypes {
+    defaults: HashMap<String, String>, // Lowercase extension to content type / 小写扩展名到内容类型
+    overrides: HashMap<String, String>, // Part name with leading `/` to content type / 带前导 `/` 的部件名称到内容类型
+}
+
+impl ContentTypes {
+    /// Parse the `Default` and `Override` declarations / 解析 `Default` 和 `Override` 声明
+    fn parse(content: &[u8]) -> Result<Self, quick_xml::Error> {
+        let mut types = Self::default();
+        let mut reader = Reader::from_reader(content);
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) | Event::Empty(e) => match e.local_name().as_ref() {
+                    XML_TYPES_DEFAULT => {
+                        if let (Some(extension), Some(content_type)) = (
+                            attribute(&e, XML_ATTR_EXTENSION),
+                            attribute(&e, XML_ATTR_CONTENT_TYPE),
+                        ) {
+                            types
+                                .defaults
+                                .insert(extension.to_ascii_lowercase(), content_type);
+                        }
+                    }
+                    XML_TYPES_OVERRIDE => {
+                        if let (Some(part_name), Some(content_type)) = (
+                            attribute(&e, XML_ATTR_PART_NAME),
+                            attribute(&e, XML_ATTR_CONTENT_TYPE),
+                        ) {
+                            types.overrides.insert(part_name, content_type);
+                        }
+                    }
+                    _ => {}
+                },
+                Event::Eof => break,
+                _ => {}
+            }
+            buf.clear();
+        }
+        Ok(types)
+    }
+
+    /// Content type of a part, the override first, then the extension default / 部件的内容类型，先查覆盖，再查扩展名默认值
+    fn of(&self, part_name: &str) -> &str {
+        self.overrides
+            .get(part_name)
+            .or_else(|| {
+                let (_, extension) = part_name.rsplit_once('.')?;
+                self.defaults.get(&extension.to_ascii_lowercase())
+            })
+            .map_or(CONTENT_TYPE_OCTET_STREAM, String::as_str)
+    }
+}
+
+/// Unescaped value of an attribute / 属性的反转义值
+fn attribute(element: &BytesStart, name: &[u8]) -> Option<String> {
+    element
+        .try_get_attribute(name)
+        .ok()??
+        .unescape_value()
+        .ok()
+        .map(|value| value.into_owned())
+}
+
+/// Drop the byte order mark and XML declaration of a part so it can be embedded / 去除部件的字节顺序标记和 XML 声明，以便嵌入
+fn strip_declaration(content: &[u8]) -> &[u8] {
+    let content = content.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(content);
+    if content.starts_with(b"<?xml")
+        && let Some(end) = content.windows(2).position(|window| window == b"?>")
+    {
+        return &content[end + 2..];
+    }
+    content
+}
+
+/// Serialize package parts into a single Flat OPC XML document / 将包部件序列化为单个 Flat OPC XML 文档
+///
+/// [Content_Types].xml only provides the content type of each part and is not written itself; XML parts are embedded as `pkg:xmlData`, other parts as base64 `pkg:binaryData` / [Content_Types].xml 仅提供每个部件的内容类型，本身不写入；XML 部件嵌入为 `pkg:xmlData`，其他部件嵌入为 base64 `pkg:binaryData`
+///
+/// # Arguments / 参数
+/// * `parts` - Part names inside the package and their content / 包内的部件名称及其内容
+pub(crate) fn write_flat_opc(parts: &[(String, Vec<u8>)]) -> Result<Vec<u8>, quick_xml::Error> {
+    let types = match parts.iter().find(|(name, _)| name == CONTENT_TYPES_PATH) {
+        Some((_, content)) => ContentTypes::parse(content)?,
+        None => ContentTypes::default(),
+    };
+
+    let size = parts
+        .iter()
+        .map(|(_, content)| content.len())
+        .sum::<usize>();
+    let mut output = Vec::with_capacity(size + FLAT_OPC_HEAD.len() + FLAT_OPC_TAIL.len());
+    output.extend_from_slice(FLAT_OPC_HEAD.as_bytes());
+    for (name, content) in parts.iter().filter(|(name, _)| name != CONTENT_TYPES_PATH) {
+        let part_name = format!("/{}", name);
+        let content_type = types.of(&part_name);
+        output.extend_from_slice(
+            format!(
+                r#"<pkg:part pkg:name="{}" pkg:contentType="{}""#,
+                escape(part_name.as_str()),
+                escape(content_type)
+            )
+            .as_bytes(),
+        );
+        if content_type.ends_with(CONTENT_TYPE_XML_SUFFIX) {
+            output.extend_from_slice(b"><pkg:xmlData>");
+            output.extend_from_slice(strip_declaration(content));
+            output.extend_from_slice(b"</pkg:xmlData>");
+        } else {
+            output.extend_from_slice(br#" pkg:compression="store"><pkg:binaryData>"#);
+            output.extend_from_slice(general_purpose::STANDARD.encode(content).as_bytes());
+            output.extend_from_slice(b"</pkg:binaryData>");
+        }
+        output.extend_from_slice(b"</pkg:part>");
+    }
+    output.extend_from_slice(FLAT_OPC_TAIL.as_bytes());
+    Ok(output)
+}
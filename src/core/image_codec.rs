@@ -0,0 +1,55 @@
+use image::codecs::jpeg::JpegEncoder;
+use image::{ImageFormat, Rgb, RgbImage};
+use std::io::Cursor;
+
+/// Re-encode JPEG bytes at the given quality / 以指定质量重新编码 JPEG 字节
+///
+/// The original bytes are kept when decoding fails or the result is not smaller / 解码失败或结果不更小时保留原始字节
+///
+/// # Arguments / 参数
+/// * `bytes` - Original JPEG bytes / 原始 JPEG 字节
+/// * `quality` - Encoder quality, clamped to 1..=100 / 编码质量，限制在 1..=100
+pub(crate) fn recompress_jpeg(bytes: Vec<u8>, quality: u8) -> Vec<u8> {
+    let Ok(image) = image::load_from_memory_with_format(&bytes, ImageFormat::Jpeg) else {
+        return bytes;
+    };
+
+    let mut encoded = Vec::with_capacity(bytes.len());
+    let encoder = JpegEncoder::new_with_quality(&mut encoded, quality.clamp(1, 100));
+    match image.write_with_encoder(encoder) {
+        Ok(()) if encoded.len() < bytes.len() => encoded,
+        _ => bytes,
+    }
+}
+
+/// Flatten a transparent PNG against an opaque background color / 将透明 PNG 与不透明背景色合成
+///
+/// The original bytes are kept when decoding or encoding fails, or the image has no alpha channel / 解码或编码失败、或图片没有 alpha 通道时保留原始字节
+///
+/// # Arguments / 参数
+/// * `bytes` - Original PNG bytes / 原始 PNG 字节
+/// * `color` - Background RGB color / 背景 RGB 颜色
+pub(crate) fn flatten_png(bytes: Vec<u8>, color: [u8; 3]) -> Vec<u8> {
+    let Ok(image) = image::load_from_memory_with_format(&bytes, ImageFormat::Png) else {
+        return bytes;
+    };
+    if !image.color().has_alpha() {
+        return bytes;
+    }
+
+    let rgba = image.to_rgba8();
+    let flattened = RgbImage::from_fn(rgba.width(), rgba.height(), |x, y| {
+        let [r, g, b, a] = rgba.get_pixel(x, y).0;
+        let blend = |source: u8, matte: u8| {
+            ((u16::from(source) * u16::from(a) + u16::from(matte) * (255 - u16::from(a)) + 127)
+                / 255) as u8
+        };
+        Rgb([blend(r, color[0]), blend(g, color[1]), blend(b, color[2])])
+    });
+
+    let mut encoded = Cursor::new(Vec::with_capacity(bytes.len()));
+    match flattened.write_to(&mut encoded, ImageFormat::Png) {
+        Ok(()) => encoded.into_inner(),
+        Err(_) => bytes,
+    }
+}
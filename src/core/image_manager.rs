@@ -1,41 +1,129 @@
 use crate::core::constant::{
-    COORD_ZERO, DEFAULT_HEIGHT_EMU, DEFAULT_WIDTH_EMU, DRAWING_DIST_BOTTOM, DRAWING_DIST_LEFT,
-    DRAWING_DIST_RIGHT, DRAWING_DIST_TOP, DRAWING_XML_CAPACITY, EFFECT_EXTENT_BOTTOM,
-    EFFECT_EXTENT_LEFT, EFFECT_EXTENT_RIGHT, EFFECT_EXTENT_TOP, EMU_PER_INCH, ERR_BASE64_DECODE,
-    IMAGE_EXT_JPEG, IMAGE_EXT_PNG, IMAGE_FILENAME_CAPACITY, IMAGE_FILENAME_PREFIX, MAX_EMU,
-    NO_CHANGE_ASPECT, TYPICAL_IMAGE_COUNT, XMLNS_DRAWINGML, XMLNS_PICTURE,
+    ANCHOR_RELATIVE_FROM_H, ANCHOR_RELATIVE_FROM_V, ANCHOR_RELATIVE_HEIGHT_BASE,
+    ANGLE_UNITS_PER_DEGREE, BMP_SIGNATURE, COORD_ZERO, DEFAULT_HEIGHT_EMU, DEFAULT_WIDTH_EMU,
+    DRAWING_DIST_BOTTOM, DRAWING_DIST_LEFT, DRAWING_DIST_RIGHT, DRAWING_DIST_TOP,
+    DRAWING_XML_CAPACITY, EFFECT_EXTENT_BOTTOM, EFFECT_EXTENT_LEFT, EFFECT_EXTENT_RIGHT,
+    EFFECT_EXTENT_TOP, EMU_PER_INCH, ERR_BASE64_DECODE, GIF_SIGNATURE, IMAGE_EXT_BMP,
+    IMAGE_EXT_GIF, IMAGE_EXT_JPEG, IMAGE_EXT_PNG, IMAGE_EXT_SVG, IMAGE_EXT_WEBP,
+    IMAGE_FILENAME_CAPACITY, IMAGE_FILENAME_PREFIX, MAX_EMU, NO_CHANGE_ASPECT,
+    REPRODUCIBLE_UUID_HIGH, SVG_BLIP_EXT_URI, SVG_FALLBACK_PNG, WRAP_POLYGON_RECT, XMLNS_DRAWINGML,
+    XMLNS_PICTURE, XMLNS_SVG_BLIP,
 };
+use crate::core::image_options::{ImageAnchor, ImageBorder, ImageWrap};
+use crate::core::metadata::{strip_jpeg_metadata, strip_png_metadata};
 use crate::core::relationship_manager::RelationshipManager;
-use crate::core::utils::get_image_dimensions;
+use crate::core::utils::{get_image_dimensions, get_svg_dimensions, is_svg, is_webp};
 use base64::Engine;
 use base64::engine::general_purpose;
 use bytes::Bytes;
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::Arc;
 use uuid::Uuid;
 
+/// Image registered for embedding / 已注册待嵌入的图片
+pub(crate) struct InsertedImage {
+    pub(crate) rel_id: String, // Relationship ID of the picture / 图片的关系 ID
+    pub(crate) image_id: u32,  // Unique drawing ID / 唯一绘图 ID
+    pub(crate) width: u32,     // Width in EMU / 宽度（EMU）
+    pub(crate) height: u32,    // Height in EMU / 高度（EMU）
+    pub(crate) svg_rel_id: Option<String>, // Relationship ID of the SVG source, if any / SVG 源的关系 ID（如有）
+    pub(crate) blank_fallback: bool, // SVG fallback is the transparent placeholder / SVG 回退图片为透明占位图
+}
+
+/// Callback post-processing the bytes of every embedded image / 对每个嵌入图片的字节进行后处理的回调
+pub(crate) type ImageTransform = Arc<dyn Fn(&[u8]) -> Vec<u8> + Send + Sync>;
+
 /// Manager for handling images in DOCX documents / DOCX 文档中图片处理的管理器
 ///
 /// Tracks all images to be embedded, generates unique filenames, and creates XML markup for image display / 跟踪所有要嵌入的图片，生成唯一文件名，并创建图片显示的 XML 标记
 pub(crate) struct ImageManager<'a> {
     dpi: f32,                                  // DPI for size calculation / 用于尺寸计算的 DPI
+    autoscale: bool,     // Scale images down to the size limit / 将图片缩小到尺寸上限
+    max_emu: (f64, f64), // Size limit of the longer side for landscape and portrait images / 横向和纵向图片较长边的尺寸上限
+    max_box: Option<(f64, f64)>, // Width and height limits replacing the longer side limits / 替代较长边上限的宽度和高度上限
     images: HashMap<String, (Bytes, &'a str)>, // Pre-allocated hashmap (zero-copy) / 预分配的哈希映射（零拷贝）
+    transform: Option<ImageTransform>, // Post-processing of decoded images / 解码图片的后处理
+    strip_metadata: bool,              // Drop EXIF and text metadata / 丢弃 EXIF 和文本元数据
+    numbered_names: bool, // Name images by their order instead of a time-based UUID / 按顺序而非基于时间的 UUID 命名图片
+    #[cfg(feature = "image")]
+    jpeg_quality: Option<u8>, // JPEG recompression quality / JPEG 重新压缩质量
 }
 
 impl<'a> ImageManager<'a> {
     /// Create new image manager / 创建新的图片管理器
     ///
-    /// Pre-allocates space for typical number of images / 为典型图片数量预分配空间
+    /// Pre-allocates space for the expected number of images / 为预期图片数量预分配空间
     ///
     /// # Arguments / 参数
     /// * `dpi` - DPI for image size calculation / 用于图片尺寸计算的 DPI
+    /// * `image_capacity` - Expected number of images / 预期图片数量
     #[inline]
-    pub(crate) fn new(dpi: f32) -> Self {
+    pub(crate) fn new(dpi: f32, image_capacity: usize) -> Self {
         Self {
             dpi,
-            images: HashMap::with_capacity(TYPICAL_IMAGE_COUNT),
+            autoscale: true,
+            max_emu: (MAX_EMU, MAX_EMU),
+            max_box: None,
+            images: HashMap::with_capacity(image_capacity),
+            transform: None,
+            strip_metadata: false,
+            numbered_names: false,
+            #[cfg(feature = "image")]
+            jpeg_quality: None,
         }
     }
 
+    /// Set the DPI of the images processed next, e.g. when moving to another part / 设置接下来处理的图片的 DPI，例如切换到另一个部件时
+    #[inline]
+    pub(crate) fn set_dpi(&mut self, dpi: f32) {
+        self.dpi = dpi;
+    }
+
+    /// Enable or disable scaling images down to the size limit / 启用或禁用将图片缩小到尺寸上限
+    #[inline]
+    pub(crate) fn set_autoscale(&mut self, enabled: bool) {
+        self.autoscale = enabled;
+    }
+
+    /// Set the size limits of landscape and portrait images / 设置横向和纵向图片的尺寸上限
+    #[inline]
+    pub(crate) fn set_max_emu(&mut self, landscape: f64, portrait: f64) {
+        self.max_emu = (landscape, portrait);
+    }
+
+    /// Set width and height limits replacing the longer side limits, `f64::INFINITY` leaving a side unlimited / 设置替代较长边上限的宽度和高度上限，`f64::INFINITY` 表示该边不受限制
+    #[inline]
+    pub(crate) fn set_max_box(&mut self, max_box: Option<(f64, f64)>) {
+        self.max_box = max_box;
+    }
+
+    /// Set the callback post-processing decoded images before they are registered / 设置在注册前对解码图片进行后处理的回调
+    #[inline]
+    pub(crate) fn set_transform(&mut self, transform: Option<ImageTransform>) {
+        self.transform = transform;
+    }
+
+    /// Enable or disable removing metadata from JPEG and PNG images / 启用或禁用移除 JPEG 和 PNG 图片中的元数据
+    #[inline]
+    pub(crate) fn set_strip_metadata(&mut self, enabled: bool) {
+        self.strip_metadata = enabled;
+    }
+
+    /// Name images by their order so the same input always yields the same names / 按顺序命名图片，使相同输入始终产生相同名称
+    #[inline]
+    pub(crate) fn set_numbered_names(&mut self, enabled: bool) {
+        self.numbered_names = enabled;
+    }
+
+    /// Enable JPEG recompression at the given quality / 以指定质量启用 JPEG 重新压缩
+    #[cfg(feature = "image")]
+    #[inline]
+    pub(crate) fn set_jpeg_quality(&mut self, quality: Option<u8>) {
+        self.jpeg_quality = quality;
+    }
+
     /// Get all managed images / 获取所有管理的图片
     #[inline]
     pub(crate) fn get_images(&self) -> &HashMap<String, (Bytes, &'a str)> {
@@ -48,19 +136,62 @@ impl<'a> ImageManager<'a> {
     ///
     /// # Arguments / 参数
     /// * `base64_data` - Base64 encoded image data / Base64 编码的图片数据
+    /// * `background` - Matte color for transparent PNGs / 透明 PNG 的底色
     /// * `rel_manager` - Relationship manager / 关系管理器
     ///
     /// # Returns / 返回
-    /// * `Ok((rel_id, image_id, width_emu, height_emu))` - Image info / 图片信息
+    /// * `Ok(image)` - Registered image / 已注册的图片
     /// * `Err` - If base64 decode fails / 如果 base64 解码失败
     pub(crate) fn process_base64(
         &mut self,
         base64_data: &str,
+        background: Option<[u8; 3]>,
         rel_manager: &mut RelationshipManager,
-    ) -> Result<(String, u32, u32, u32), quick_xml::Error> {
-        let image_bytes = general_purpose::STANDARD.decode(base64_data).map_err(|_| {
-            quick_xml::errors::IllFormedError::UnmatchedEndTag(ERR_BASE64_DECODE.to_string())
-        })?;
+    ) -> Result<InsertedImage, quick_xml::Error> {
+        // Strip line breaks and other whitespace (e.g. CRLF from text files) / 去除换行和其他空白（例如文本文件中的 CRLF）
+        let base64_data = if base64_data.bytes().any(|b| b.is_ascii_whitespace()) {
+            Cow::Owned(
+                base64_data
+                    .chars()
+                    .filter(|c| !c.is_ascii_whitespace())
+                    .collect::<String>(),
+            )
+        } else {
+            Cow::Borrowed(base64_data)
+        };
+        let image_bytes = general_purpose::STANDARD
+            .decode(base64_data.as_ref())
+            .map_err(|_| {
+                quick_xml::errors::IllFormedError::UnmatchedEndTag(ERR_BASE64_DECODE.to_string())
+            })?;
+
+        self.process_image_bytes(Bytes::from(image_bytes), background, rel_manager)
+    }
+
+    /// Process raw image bytes and prepare for embedding / 处理原始图片字节并准备嵌入
+    ///
+    /// Detects format, generates unique filename, calculates dimensions, and registers with relationship manager / 检测格式，生成唯一文件名，计算尺寸，并在关系管理器中注册
+    ///
+    /// # Arguments / 参数
+    /// * `image_bytes` - Encoded image file bytes, shared until they are modified / 编码的图片文件字节，修改前保持共享
+    /// * `background` - Matte color for transparent PNGs, applied with the `image` feature / 透明 PNG 的底色，启用 `image` 特性时生效
+    /// * `rel_manager` - Relationship manager / 关系管理器
+    ///
+    /// # Returns / 返回
+    /// * `Ok(image)` - Registered image / 已注册的图片
+    pub(crate) fn process_image_bytes(
+        &mut self,
+        image_bytes: Bytes,
+        background: Option<[u8; 3]>,
+        rel_manager: &mut RelationshipManager,
+    ) -> Result<InsertedImage, quick_xml::Error> {
+        let image_bytes = match &self.transform {
+            Some(transform) => Bytes::from(transform(&image_bytes)),
+            None => image_bytes,
+        };
+        if is_svg(&image_bytes) {
+            return Ok(self.process_svg(image_bytes, rel_manager));
+        }
 
         // Fast format detection / 快速格式检测
         let extension = if image_bytes.len() >= 4
@@ -76,53 +207,166 @@ impl<'a> ImageManager<'a> {
             && image_bytes[2] == 0xFF
         {
             IMAGE_EXT_JPEG
+        } else if image_bytes.starts_with(GIF_SIGNATURE) {
+            IMAGE_EXT_GIF
+        } else if image_bytes.starts_with(BMP_SIGNATURE) {
+            IMAGE_EXT_BMP
+        } else if is_webp(&image_bytes) {
+            IMAGE_EXT_WEBP
         } else {
             IMAGE_EXT_PNG // Safe default / 安全默认值
         };
 
-        // Generate unique filename / 生成唯一文件名
-        let uuid = Uuid::now_v7();
-        let mut filename = String::with_capacity(IMAGE_FILENAME_CAPACITY);
-        filename.push_str(IMAGE_FILENAME_PREFIX);
-        filename.push_str(&uuid.to_string());
-        filename.push('.');
-        filename.push_str(extension);
+        // Drop metadata segments before any re-encoding / 在任何重新编码之前丢弃元数据段
+        let image_bytes = match extension {
+            IMAGE_EXT_JPEG if self.strip_metadata => {
+                Bytes::from(strip_jpeg_metadata(image_bytes.into()))
+            }
+            IMAGE_EXT_PNG if self.strip_metadata => {
+                Bytes::from(strip_png_metadata(image_bytes.into()))
+            }
+            _ => image_bytes,
+        };
 
-        // Register image in relationship manager / 在关系管理器中注册图片
-        let (rel_id, image_id) = rel_manager.add_image_relationship(&filename);
+        // Recompress JPEG images if enabled / 如果启用则重新压缩 JPEG 图片
+        #[cfg(feature = "image")]
+        let image_bytes = match self.jpeg_quality {
+            Some(quality) if extension == IMAGE_EXT_JPEG => Bytes::from(
+                crate::core::image_codec::recompress_jpeg(image_bytes.into(), quality),
+            ),
+            _ => image_bytes,
+        };
+
+        // Flatten transparency against the matte color / 将透明度与底色合成
+        #[cfg(feature = "image")]
+        let image_bytes = match background {
+            Some(color) if extension == IMAGE_EXT_PNG => Bytes::from(
+                crate::core::image_codec::flatten_png(image_bytes.into(), color),
+            ),
+            _ => image_bytes,
+        };
+        #[cfg(not(feature = "image"))]
+        let _ = background;
 
         // Calculate image dimensions with fast path / 使用快速路径计算图片尺寸
-        let (mut width_emu, mut height_emu) = match get_image_dimensions(&image_bytes) {
-            Ok((width_px, height_px)) => {
-                let dpi_inv = 1.0 / self.dpi;
+        let pixels = get_image_dimensions(&image_bytes)
+            .ok()
+            .map(|(width, height)| (f64::from(width), f64::from(height)));
+        let (width, height) = self.emu_size(pixels);
+
+        let (rel_id, image_id) = self.store_image(image_bytes, extension, rel_manager);
+        Ok(InsertedImage {
+            rel_id,
+            image_id,
+            width,
+            height,
+            svg_rel_id: None,
+            blank_fallback: false,
+        })
+    }
+
+    /// Register an SVG image together with its PNG fallback / 注册 SVG 图片及其 PNG 回退图片
+    ///
+    /// The fallback is rasterized with the `svg` feature, otherwise a transparent placeholder is used and flagged on the returned image / 启用 `svg` 特性时栅格化回退图片，否则使用透明占位图并在返回的图片上标记
+    fn process_svg(
+        &mut self,
+        svg_bytes: Bytes,
+        rel_manager: &mut RelationshipManager,
+    ) -> InsertedImage {
+        let pixels = get_svg_dimensions(&svg_bytes);
+        let (width, height) = self.emu_size(pixels);
+
+        #[cfg(feature = "svg")]
+        let rasterized = pixels.and_then(|(w, h)| {
+            crate::core::svg_codec::rasterize_svg(&svg_bytes, w.round() as u32, h.round() as u32)
+        });
+        #[cfg(not(feature = "svg"))]
+        let rasterized: Option<Vec<u8>> = None;
+        let blank_fallback = rasterized.is_none();
+        let fallback = rasterized.map_or_else(|| Bytes::from_static(SVG_FALLBACK_PNG), Bytes::from);
+
+        let (rel_id, image_id) = self.store_image(fallback, IMAGE_EXT_PNG, rel_manager);
+        let (svg_rel_id, _) = self.store_image(svg_bytes, IMAGE_EXT_SVG, rel_manager);
+        InsertedImage {
+            rel_id,
+            image_id,
+            width,
+            height,
+            svg_rel_id: Some(svg_rel_id),
+            blank_fallback,
+        }
+    }
+
+    /// Convert a pixel size to EMU at the configured DPI / 按配置的 DPI 将像素尺寸转换为 EMU
+    ///
+    /// f64 keeps EMU values in the millions exact. With autoscale the longer side is scaled down to the size limit of the image orientation, images wider than high being landscape / f64 可使百万级 EMU 值保持精确。启用自动缩放时较长边缩小到图片方向对应的尺寸上限，宽大于高的图片为横向
+    fn emu_size(&self, pixels: Option<(f64, f64)>) -> (u32, u32) {
+        let (mut width_emu, mut height_emu) = match pixels {
+            Some((width_px, height_px)) => {
+                let dpi = f64::from(self.dpi);
                 (
-                    width_px * EMU_PER_INCH * dpi_inv,
-                    height_px * EMU_PER_INCH * dpi_inv,
+                    width_px * EMU_PER_INCH / dpi,
+                    height_px * EMU_PER_INCH / dpi,
                 )
             }
-            Err(_) => (DEFAULT_WIDTH_EMU, DEFAULT_HEIGHT_EMU),
+            None => (DEFAULT_WIDTH_EMU, DEFAULT_HEIGHT_EMU),
         };
 
-        // Scale down if needed / 如果需要缩小
-        let scale = (width_emu / MAX_EMU).max(height_emu / MAX_EMU);
-        if scale > 1.0 {
-            let scale_inv = 1.0 / scale;
-            width_emu *= scale_inv;
-            height_emu *= scale_inv;
+        // Fit into the width and height limits, keeping the aspect ratio / 在保持宽高比的情况下适应宽度和高度上限
+        if let Some((max_width, max_height)) = self.max_box {
+            let scale = (max_width / width_emu).min(max_height / height_emu);
+            if self.autoscale && scale < 1.0 {
+                width_emu *= scale;
+                height_emu *= scale;
+            }
+            return (width_emu.round() as u32, height_emu.round() as u32);
         }
 
-        // Store image bytes (zero-copy via Bytes) / 存储图片字节（通过 Bytes 零拷贝）
-        self.images.insert(filename, (Bytes::from(image_bytes), ""));
+        // Scale down if needed, the longer side lands exactly on the limit / 如果需要则缩小，较长边正好落在上限
+        let longest = width_emu.max(height_emu);
+        let limit = if width_emu > height_emu {
+            self.max_emu.0
+        } else {
+            self.max_emu.1
+        };
+        if self.autoscale && longest > limit {
+            width_emu = width_emu * limit / longest;
+            height_emu = height_emu * limit / longest;
+        }
+        (width_emu.round() as u32, height_emu.round() as u32)
+    }
 
-        Ok((
-            rel_id,
-            image_id,
-            width_emu.round() as u32,
-            height_emu.round() as u32,
-        ))
+    /// Store image bytes under a unique filename and register the relationship / 以唯一文件名存储图片字节并注册关系
+    ///
+    /// # Returns / 返回
+    /// * `(rel_id, image_id)` - Relationship ID and numeric ID / 关系 ID 和数字 ID
+    fn store_image(
+        &mut self,
+        image_bytes: Bytes,
+        extension: &str,
+        rel_manager: &mut RelationshipManager,
+    ) -> (String, u32) {
+        // Generate unique filename / 生成唯一文件名
+        let uuid = if self.numbered_names {
+            Uuid::from_u64_pair(REPRODUCIBLE_UUID_HIGH, self.images.len() as u64 + 1)
+        } else {
+            Uuid::now_v7()
+        };
+        let mut filename = String::with_capacity(IMAGE_FILENAME_CAPACITY);
+        filename.push_str(IMAGE_FILENAME_PREFIX);
+        filename.push_str(&uuid.to_string());
+        filename.push('.');
+        filename.push_str(extension);
+
+        // Register image in relationship manager / 在关系管理器中注册图片
+        let ids = rel_manager.add_image_relationship(&filename);
+
+        // Store image bytes (zero-copy via Bytes) / 存储图片字节（通过 Bytes 零拷贝）
+        self.images.insert(filename, (image_bytes, ""));
+        ids
     }
 
-    /// Generate OOXML markup for inline image / 生成内联图片的 OOXML 标记
+    /// Generate OOXML markup for an inline or anchored image / 生成内联或浮动图片的 OOXML 标记
     ///
     /// Creates complete XML structure for displaying an image inline in the document / 创建用于在文档中内联显示图片的完整 XML 结构
     ///
@@ -133,10 +377,16 @@ impl<'a> ImageManager<'a> {
     /// * `height` - Height in EMU / 高度（EMU）
     /// * `name` - Image name / 图片名称
     /// * `descr` - Image description / 图片描述
+    /// * `run_properties` - `w:rPr` markup of the run, may be empty / 运行的 `w:rPr` 标记，可为空
+    /// * `rotate` - Clockwise rotation in degrees (0..360) / 顺时针旋转角度（0..360）
+    /// * `svg_relationship_id` - Relationship ID of the SVG source, if any / SVG 源的关系 ID（如有）
+    /// * `border` - Outline of the picture, the width in EMU / 图片的轮廓，宽度以 EMU 为单位
+    /// * `anchor` - Floating placement and text wrap, inline when `None` / 浮动放置与文字环绕，为 `None` 时内联
     ///
     /// # Returns / 返回
     /// Complete XML string for the image / 图片的完整 XML 字符串
     #[inline]
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn generate_xml_drawing_inner(
         relationship_id: &str,
         image_id: u32,
@@ -144,15 +394,34 @@ impl<'a> ImageManager<'a> {
         height: u32,
         name: &str,
         descr: &str,
+        run_properties: &str,
+        rotate: u32,
+        svg_relationship_id: Option<&str>,
+        border: Option<ImageBorder>,
+        anchor: Option<ImageAnchor>,
     ) -> String {
         let doc_pr_id = image_id;
+        // Quarter turns swap the space taken in the line / 四分之一圈旋转会交换行内占用的空间
+        let (extent_width, extent_height) = if rotate % 180 == 90 {
+            (height, width)
+        } else {
+            (width, height)
+        };
 
-        let capacity =
-            DRAWING_XML_CAPACITY + relationship_id.len() + name.len() * 2 + descr.len() * 2;
+        let capacity = DRAWING_XML_CAPACITY
+            + relationship_id.len()
+            + name.len() * 2
+            + descr.len() * 2
+            + run_properties.len();
         let mut xml = String::with_capacity(capacity);
 
         // Build XML string efficiently / 高效构建 XML 字符串
-        xml.push_str(r#"<w:r><w:drawing><wp:inline distT=""#);
+        xml.push_str("<w:r>");
+        xml.push_str(run_properties);
+        let element = if anchor.is_some() { "anchor" } else { "inline" };
+        xml.push_str(r#"<w:drawing><wp:"#);
+        xml.push_str(element);
+        xml.push_str(r#" distT=""#);
         xml.push_str(DRAWING_DIST_TOP);
         xml.push_str(r#"" distB=""#);
         xml.push_str(DRAWING_DIST_BOTTOM);
@@ -160,10 +429,24 @@ impl<'a> ImageManager<'a> {
         xml.push_str(DRAWING_DIST_LEFT);
         xml.push_str(r#"" distR=""#);
         xml.push_str(DRAWING_DIST_RIGHT);
-        xml.push_str(r#""><wp:extent cx=""#);
-        xml.push_str(&width.to_string());
+        xml.push('"');
+        if let Some(anchor) = anchor {
+            write!(
+                &mut xml,
+                r#" simplePos="0" relativeHeight="{}" behindDoc="{}" locked="0" layoutInCell="1" allowOverlap="1"><wp:simplePos x="0" y="0"/><wp:positionH relativeFrom="{}"><wp:posOffset>{}</wp:posOffset></wp:positionH><wp:positionV relativeFrom="{}"><wp:posOffset>{}</wp:posOffset></wp:positionV"#,
+                ANCHOR_RELATIVE_HEIGHT_BASE.saturating_add(image_id),
+                u8::from(anchor.wrap == ImageWrap::Behind),
+                ANCHOR_RELATIVE_FROM_H,
+                anchor.x,
+                ANCHOR_RELATIVE_FROM_V,
+                anchor.y
+            )
+            .ok();
+        }
+        xml.push_str(r#"><wp:extent cx=""#);
+        xml.push_str(&extent_width.to_string());
         xml.push_str(r#"" cy=""#);
-        xml.push_str(&height.to_string());
+        xml.push_str(&extent_height.to_string());
         xml.push_str(r#""/><wp:effectExtent l=""#);
         xml.push_str(EFFECT_EXTENT_LEFT);
         xml.push_str(r#"" t=""#);
@@ -172,7 +455,19 @@ impl<'a> ImageManager<'a> {
         xml.push_str(EFFECT_EXTENT_RIGHT);
         xml.push_str(r#"" b=""#);
         xml.push_str(EFFECT_EXTENT_BOTTOM);
-        xml.push_str(r#""/><wp:docPr id=""#);
+        xml.push_str(r#""/>"#);
+        if let Some(anchor) = anchor {
+            match anchor.wrap {
+                ImageWrap::Square => xml.push_str(r#"<wp:wrapSquare wrapText="bothSides"/>"#),
+                ImageWrap::Tight => {
+                    xml.push_str(r#"<wp:wrapTight wrapText="bothSides">"#);
+                    xml.push_str(WRAP_POLYGON_RECT);
+                    xml.push_str("</wp:wrapTight>");
+                }
+                ImageWrap::Behind | ImageWrap::Front => xml.push_str("<wp:wrapNone/>"),
+            }
+        }
+        xml.push_str(r#"<wp:docPr id=""#);
         xml.push_str(&doc_pr_id.to_string());
         xml.push_str(r#"" name=""#);
         xml.push_str(name);
@@ -198,9 +493,26 @@ impl<'a> ImageManager<'a> {
         xml.push_str(NO_CHANGE_ASPECT);
         xml.push_str(r#""/></pic:cNvPicPr></pic:nvPicPr><pic:blipFill><a:blip r:embed=""#);
         xml.push_str(relationship_id);
-        xml.push_str(
-            r#""/><a:stretch><a:fillRect/></a:stretch></pic:blipFill><pic:spPr><a:xfrm><a:off x=""#,
-        );
+        xml.push('"');
+        match svg_relationship_id {
+            Some(svg_id) => {
+                xml.push_str(r#"><a:extLst><a:ext uri=""#);
+                xml.push_str(SVG_BLIP_EXT_URI);
+                xml.push_str(r#""><asvg:svgBlip xmlns:asvg=""#);
+                xml.push_str(XMLNS_SVG_BLIP);
+                xml.push_str(r#"" r:embed=""#);
+                xml.push_str(svg_id);
+                xml.push_str(r#""/></a:ext></a:extLst></a:blip>"#);
+            }
+            None => xml.push_str("/>"),
+        }
+        xml.push_str(r#"<a:stretch><a:fillRect/></a:stretch></pic:blipFill><pic:spPr><a:xfrm"#);
+        if rotate != 0 {
+            xml.push_str(r#" rot=""#);
+            xml.push_str(&(rotate * ANGLE_UNITS_PER_DEGREE).to_string());
+            xml.push('"');
+        }
+        xml.push_str(r#"><a:off x=""#);
         xml.push_str(COORD_ZERO);
         xml.push_str(r#"" y=""#);
         xml.push_str(COORD_ZERO);
@@ -208,7 +520,19 @@ impl<'a> ImageManager<'a> {
         xml.push_str(&width.to_string());
         xml.push_str(r#"" cy=""#);
         xml.push_str(&height.to_string());
-        xml.push_str(r#""/></a:xfrm><a:prstGeom prst="rect"><a:avLst/></a:prstGeom></pic:spPr></pic:pic></a:graphicData></a:graphic></wp:inline></w:drawing></w:r>"#);
+        xml.push_str(r#""/></a:xfrm><a:prstGeom prst="rect"><a:avLst/></a:prstGeom>"#);
+        if let Some(border) = border {
+            let [r, g, b] = border.color;
+            write!(
+                &mut xml,
+                r#"<a:ln w="{}"><a:solidFill><a:srgbClr val="{:02X}{:02X}{:02X}"/></a:solidFill></a:ln>"#,
+                border.width, r, g, b
+            )
+            .ok();
+        }
+        xml.push_str(r#"</pic:spPr></pic:pic></a:graphicData></a:graphic></wp:"#);
+        xml.push_str(element);
+        xml.push_str("></w:drawing></w:r>");
 
         xml
     }
@@ -0,0 +1,258 @@
+use crate::core::constant::{
+    EMU_PER_POINT, IMAGE_BORDER_COLOR_SEPARATOR, IMAGE_MARKER, IMAGE_OPTION_ALT,
+    IMAGE_OPTION_ANCHOR, IMAGE_OPTION_ASSIGN, IMAGE_OPTION_BACKGROUND, IMAGE_OPTION_BORDER,
+    IMAGE_OPTION_DEFAULT, IMAGE_OPTION_DEFAULT_FILE, IMAGE_OPTION_ROTATE, IMAGE_OPTION_SEPARATOR,
+    IMAGE_OPTION_X, IMAGE_OPTION_Y, IMAGE_SIZE_DIMENSION_SEPARATOR, IMAGE_SIZE_SEPARATOR,
+    IMAGE_WRAP_BEHIND, IMAGE_WRAP_FRONT, IMAGE_WRAP_SQUARE, IMAGE_WRAP_TIGHT, PLACEHOLDER_CLOSE,
+    PLACEHOLDER_OPEN, POINT_UNIT_SUFFIX,
+};
+use quick_xml::escape::{escape, unescape};
+
+/// Options attached to an image placeholder, e.g. `[@photo|alt=Portrait]` / 附加在图片占位符上的选项，例如 `[@photo|alt=Portrait]`
+#[derive(Debug, Default, Clone)]
+pub(crate) struct ImageOptions {
+    /// Alternative text, already escaped for attributes / 替代文本，已按属性转义
+    pub(crate) alt: Option<String>,
+    /// Clockwise rotation in degrees, normalized to 0..360 / 顺时针旋转角度，归一化到 0..360
+    pub(crate) rotate: u32,
+    /// Matte color flattened behind transparent pixels / 合成在透明像素后面的底色
+    pub(crate) background: Option<[u8; 3]>,
+    /// Border drawn around the picture / 图片周围绘制的边框
+    pub(crate) border: Option<ImageBorder>,
+    /// Floating placement, inline when `None` / 浮动放置，为 `None` 时内联
+    pub(crate) anchor: Option<ImageAnchor>,
+    /// Image embedded when the value is empty / 值为空时嵌入的图片
+    pub(crate) default: Option<ImageDefault>,
+    /// Size overriding the one computed from the pixels / 覆盖根据像素计算的尺寸
+    pub(crate) size: Option<ImageSize>,
+}
+
+/// Explicit size of an image, a missing side keeping the aspect ratio / 图片的显式尺寸，缺失的一边保持宽高比
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ImageSize {
+    /// Width in EMU / 宽度（EMU）
+    pub(crate) width: Option<f64>,
+    /// Height in EMU / 高度（EMU）
+    pub(crate) height: Option<f64>,
+}
+
+impl ImageSize {
+    /// Parse `WxH`, `Wx` or `xH` in points / 解析以磅为单位的 `WxH`、`Wx` 或 `xH`
+    ///
+    /// # Arguments / 参数
+    /// * `value` - Size suffix of the placeholder (e.g. "200x150") / 占位符的尺寸后缀（例如 "200x150"）
+    fn parse(value: &str) -> Option<Self> {
+        let (width, height) = value.split_once(IMAGE_SIZE_DIMENSION_SEPARATOR)?;
+        let side = |side: &str| -> Option<Option<f64>> {
+            let side = side.trim();
+            if side.is_empty() {
+                return Some(None);
+            }
+            let points = side.parse::<f64>().ok()?;
+            (points.is_finite() && points > 0.0).then_some(Some(points * EMU_PER_POINT))
+        };
+        let size = Self {
+            width: side(width)?,
+            height: side(height)?,
+        };
+        (size.width.is_some() || size.height.is_some()).then_some(size)
+    }
+
+    /// Resolve the size in EMU against the computed one / 根据计算出的尺寸解析 EMU 尺寸
+    ///
+    /// # Arguments / 参数
+    /// * `computed` - Width and height computed from the pixels in EMU / 根据像素计算出的宽度和高度（EMU）
+    pub(crate) fn resolve(&self, computed: (u32, u32)) -> (u32, u32) {
+        let (computed_width, computed_height) = (f64::from(computed.0), f64::from(computed.1));
+        let (width, height) = match (self.width, self.height) {
+            (Some(width), Some(height)) => (width, height),
+            (Some(width), None) if computed_width > 0.0 => {
+                (width, computed_height * width / computed_width)
+            }
+            (None, Some(height)) if computed_height > 0.0 => {
+                (computed_width * height / computed_height, height)
+            }
+            (width, height) => (
+                width.unwrap_or(computed_width),
+                height.unwrap_or(computed_height),
+            ),
+        };
+        (width.round() as u32, height.round() as u32)
+    }
+}
+
+/// Fallback of an image placeholder whose value is empty / 值为空的图片占位符的回退图片
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ImageDefault {
+    /// Base64 image data / base64 图片数据
+    Base64(String),
+    /// Path of an image file / 图片文件路径
+    File(String),
+}
+
+/// Text wrap of an anchored image / 浮动图片的文字环绕方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ImageWrap {
+    /// Text wraps around the bounding box / 文字环绕边界框
+    Square,
+    /// Text wraps close to the picture / 文字紧密环绕图片
+    Tight,
+    /// Picture sits behind the text / 图片位于文字下方
+    Behind,
+    /// Picture sits in front of the text / 图片位于文字上方
+    Front,
+}
+
+impl ImageWrap {
+    /// Parse a wrap name such as `behind` / 解析 `behind` 等环绕方式名称
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            IMAGE_WRAP_SQUARE => Some(Self::Square),
+            IMAGE_WRAP_TIGHT => Some(Self::Tight),
+            IMAGE_WRAP_BEHIND => Some(Self::Behind),
+            IMAGE_WRAP_FRONT => Some(Self::Front),
+            _ => None,
+        }
+    }
+}
+
+/// Floating placement of an image / 图片的浮动放置
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ImageAnchor {
+    /// Text wrap / 文字环绕方式
+    pub(crate) wrap: ImageWrap,
+    /// Horizontal offset from the column in EMU / 相对于栏的水平偏移（EMU）
+    pub(crate) x: i64,
+    /// Vertical offset from the paragraph in EMU / 相对于段落的垂直偏移（EMU）
+    pub(crate) y: i64,
+}
+
+/// Border line of an image / 图片的边框线
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ImageBorder {
+    /// Line width in EMU / 线宽（EMU）
+    pub(crate) width: u32,
+    /// Line color / 线条颜色
+    pub(crate) color: [u8; 3],
+}
+
+impl ImageBorder {
+    /// Parse `width[:color]`, the width in points with a `pt` suffix or in EMU without unit, the color defaults to black / 解析 `width[:color]`，宽度带 `pt` 后缀时以磅为单位，无单位时以 EMU 为单位，颜色默认为黑色
+    ///
+    /// # Arguments / 参数
+    /// * `value` - Option value (e.g. "1pt:000000" or "12700") / 选项值（例如 "1pt:000000" 或 "12700"）
+    fn parse(value: &str) -> Option<Self> {
+        let (width, color) = match value.split_once(IMAGE_BORDER_COLOR_SEPARATOR) {
+            Some((width, color)) => (width.trim(), parse_hex_color(color.trim())?),
+            None => (value, [0, 0, 0]),
+        };
+        let width = parse_length(width)?;
+        if width <= 0.0 {
+            return None;
+        }
+        Some(Self {
+            width: width.round() as u32,
+            color,
+        })
+    }
+}
+
+impl ImageOptions {
+    /// Split an image placeholder into its plain form, size and options / 将图片占位符拆分为普通形式、尺寸和选项
+    ///
+    /// Unknown options are ignored / 忽略未知选项
+    ///
+    /// # Arguments / 参数
+    /// * `raw` - Escaped text of the element (e.g. `[@photo:200x150|alt=Portrait]`) / 元素的转义文本
+    ///
+    /// # Returns / 返回
+    /// * `Some((placeholder, options))` - Placeholder without size and options (e.g. `[@photo]`) and parsed options / 不带尺寸和选项的占位符及解析后的选项
+    /// * `None` - Not an image placeholder with a size or options / 不是带尺寸或选项的图片占位符
+    pub(crate) fn parse(raw: &str) -> Option<(String, Self)> {
+        let raw = raw.trim();
+        let (open, close) = if raw.starts_with(PLACEHOLDER_OPEN) {
+            (PLACEHOLDER_OPEN, PLACEHOLDER_CLOSE)
+        } else {
+            ("[", "]")
+        };
+        let inner = raw.strip_prefix(open)?.strip_suffix(close)?;
+        if !inner.starts_with(IMAGE_MARKER) {
+            return None;
+        }
+        let (key, options) = match inner.split_once(IMAGE_OPTION_SEPARATOR) {
+            Some((key, options)) => (key, Some(options)),
+            None => (inner, None),
+        };
+        let (key, size) = match key
+            .rsplit_once(IMAGE_SIZE_SEPARATOR)
+            .and_then(|(key, size)| Some((key, ImageSize::parse(size)?)))
+        {
+            Some((key, size)) => (key, Some(size)),
+            None => (key, None),
+        };
+        if options.is_none() && size.is_none() {
+            return None;
+        }
+
+        let mut parsed = Self {
+            size,
+            ..Self::default()
+        };
+        let (mut wrap, mut x, mut y) = (None, 0.0, 0.0);
+        for option in options.unwrap_or_default().split(IMAGE_OPTION_SEPARATOR) {
+            let Some((name, value)) = option.split_once(IMAGE_OPTION_ASSIGN) else {
+                continue;
+            };
+            let value = value.trim();
+            match name.trim() {
+                IMAGE_OPTION_ALT => {
+                    // Text content may leave quotes unescaped / 文本内容中的引号可能未转义
+                    let text = unescape(value).unwrap_or_else(|_| value.into());
+                    parsed.alt = Some(escape(text.as_ref()).into_owned());
+                }
+                IMAGE_OPTION_ROTATE => {
+                    if let Ok(degrees) = value.parse::<i64>() {
+                        parsed.rotate = degrees.rem_euclid(360) as u32;
+                    }
+                }
+                IMAGE_OPTION_BACKGROUND => parsed.background = parse_hex_color(value),
+                IMAGE_OPTION_BORDER => parsed.border = ImageBorder::parse(value),
+                IMAGE_OPTION_ANCHOR => wrap = ImageWrap::parse(value),
+                IMAGE_OPTION_X => x = parse_length(value).unwrap_or(x),
+                IMAGE_OPTION_Y => y = parse_length(value).unwrap_or(y),
+                IMAGE_OPTION_DEFAULT => parsed.default = Some(ImageDefault::Base64(value.into())),
+                IMAGE_OPTION_DEFAULT_FILE => {
+                    let path = unescape(value).unwrap_or_else(|_| value.into());
+                    parsed.default = Some(ImageDefault::File(path.into_owned()));
+                }
+                _ => {}
+            }
+        }
+        // Offsets only apply to anchored images / 偏移仅适用于浮动图片
+        parsed.anchor = wrap.map(|wrap| ImageAnchor {
+            wrap,
+            x: x.round() as i64,
+            y: y.round() as i64,
+        });
+        Some((format!("{}{}{}", open, key, close), parsed))
+    }
+}
+
+/// Parse a length in points with a `pt` suffix or in EMU without unit / 解析带 `pt` 后缀的磅值或无单位的 EMU 长度
+fn parse_length(value: &str) -> Option<f64> {
+    let length = match value.strip_suffix(POINT_UNIT_SUFFIX) {
+        Some(points) => points.trim().parse::<f64>().ok()? * EMU_PER_POINT,
+        None => value.parse::<f64>().ok()?,
+    };
+    length.is_finite().then_some(length)
+}
+
+/// Parse an `RRGGBB` color, with or without a leading `#` / 解析 `RRGGBB` 颜色，可带或不带前导 `#`
+pub(crate) fn parse_hex_color(value: &str) -> Option<[u8; 3]> {
+    let hex = value.strip_prefix('#').unwrap_or(value);
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16).ok();
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
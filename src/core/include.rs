@@ -0,0 +1,242 @@
+use crate::core::constant::{
+    INCLUDE_MAX_DEPTH, INCLUDE_PREFIX, MARKER_CLOSE, RELATIONSHIP_ATTR_PREFIX, XML_BODY,
+    XML_PARAGRAPH, XML_PARAGRAPH_PROPERTIES, XML_SECTION_PROPERTIES, XML_TEXT,
+};
+use quick_xml::events::Event;
+use quick_xml::{Reader, Writer};
+use std::collections::HashMap;
+use std::io::Write;
+
+/// Package paths named by `[include:path]` markers, in order of appearance / `[include:path]` 标记指定的包路径，按出现顺序排列
+///
+/// # Arguments / 参数
+/// * `xml` - Document or fragment XML / 文档或片段 XML
+pub(crate) fn include_paths(xml: &[u8]) -> Vec<String> {
+    let text = String::from_utf8_lossy(xml);
+    let mut paths: Vec<String> = Vec::new();
+    for (start, _) in text.match_indices(INCLUDE_PREFIX) {
+        let rest = &text[start + INCLUDE_PREFIX.len()..];
+        if let Some(end) = rest.find(MARKER_CLOSE) {
+            let path = rest[..end].trim();
+            // Markers split by markup are not markers / 被标记拆分的不是标记
+            if !path.is_empty() && !path.contains('<') && !paths.iter().any(|p| p == path) {
+                paths.push(path.to_string());
+            }
+        }
+    }
+    paths
+}
+
+/// Relationship ids referenced by a fragment, such as the `r:embed` of its images / 片段引用的关系 id，例如其图片的 `r:embed`
+///
+/// # Arguments / 参数
+/// * `xml` - Fragment XML / 片段 XML
+pub(crate) fn relationship_ids(xml: &[u8]) -> Result<Vec<String>, quick_xml::Error> {
+    let mut reader = Reader::from_reader(xml);
+    let mut buf = Vec::new();
+    let mut ids: Vec<String> = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Eof => break,
+            Event::Start(e) | Event::Empty(e) => {
+                for attr in e.attributes() {
+                    let attr = attr?;
+                    if attr
+                        .key
+                        .prefix()
+                        .is_some_and(|prefix| prefix.as_ref() == RELATIONSHIP_ATTR_PREFIX)
+                    {
+                        let id = attr.unescape_value()?;
+                        if !ids.iter().any(|known| *known == id) {
+                            ids.push(id.into_owned());
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+        buf.clear();
+    }
+    Ok(ids)
+}
+
+/// Replace body paragraphs holding only an `[include:path]` marker by the body of that fragment / 用片段的正文替换仅包含 `[include:path]` 标记的正文段落
+///
+/// Fragments are document parts of the same package; their body blocks are spliced without the final `w:sectPr`, so the document keeps a single body-level `w:sectPr`, and markers inside them are expanded as well. A section break on the marker paragraph is kept after the fragment. Paragraphs naming an unknown fragment, or nested deeper than `INCLUDE_MAX_DEPTH`, are kept unchanged / 片段是同一包中的文档部件；其正文块在去掉最后的 `w:sectPr` 后拼接，因此文档只保留一个正文级 `w:sectPr`，其中的标记同样会被展开。标记段落上的分节符保留在片段之后。指定未知片段或嵌套深度超过 `INCLUDE_MAX_DEPTH` 的段落保持不变
+///
+/// # Arguments / 参数
+/// * `xml` - Template document.xml / 模板 document.xml
+/// * `fragments` - Fragment parts keyed by package path / 按包路径索引的片段部件
+///
+/// # Returns / 返回
+/// The spliced document and the paths of the markers left unresolved / 拼接后的文档和未解析标记的路径
+pub(crate) fn splice_includes(
+    xml: &[u8],
+    fragments: &HashMap<String, Vec<u8>>,
+) -> Result<(Vec<u8>, Vec<String>), quick_xml::Error> {
+    let mut reader = Reader::from_reader(xml);
+    let mut writer = Writer::new(Vec::with_capacity(xml.len()));
+    let mut buf = Vec::new();
+    let mut unresolved = Vec::new();
+
+    let mut body: Option<Vec<Event<'static>>> = None; // Body blocks being collected / 正在收集的正文块
+    let mut depth = 0usize; // Depth inside the body / 正文内的深度
+    loop {
+        let event = reader.read_event_into(&mut buf)?;
+        match (&mut body, &event) {
+            (_, Event::Eof) => break,
+            (None, Event::Start(e)) if e.name().as_ref() == XML_BODY => {
+                writer.write_event(event)?;
+                body = Some(Vec::new());
+            }
+            (None, _) => writer.write_event(event)?,
+            (Some(_), Event::End(e)) if depth == 0 && e.name().as_ref() == XML_BODY => {
+                let blocks = body.take().unwrap_or_default();
+                write_blocks(&mut writer, blocks, fragments, 0, &mut unresolved)?;
+                writer.write_event(event)?;
+            }
+            (Some(blocks), _) => {
+                match &event {
+                    Event::Start(_) => depth += 1,
+                    Event::End(_) => depth = depth.saturating_sub(1),
+                    _ => {}
+                }
+                blocks.push(event.into_owned());
+            }
+        }
+        buf.clear();
+    }
+    Ok((writer.into_inner(), unresolved))
+}
+
+/// Write body blocks, expanding include paragraphs / 写入正文块，展开包含段落
+fn write_blocks<W: Write>(
+    writer: &mut Writer<W>,
+    events: Vec<Event<'static>>,
+    fragments: &HashMap<String, Vec<u8>>,
+    depth: usize,
+    unresolved: &mut Vec<String>,
+) -> Result<(), quick_xml::Error> {
+    let mut block = Vec::new();
+    let mut level = 0usize; // Depth inside the current block / 当前块内的深度
+    for event in events {
+        match &event {
+            Event::Start(_) => level += 1,
+            Event::End(_) => level = level.saturating_sub(1),
+            _ => {}
+        }
+        block.push(event);
+        if level > 0 {
+            continue;
+        }
+
+        // A body block is complete / 正文块已完整
+        if let Some(path) = include_marker(&block) {
+            match fragments.get(&path) {
+                Some(fragment) if depth < INCLUDE_MAX_DEPTH => {
+                    let blocks = fragment_blocks(fragment)?;
+                    write_blocks(writer, blocks, fragments, depth + 1, unresolved)?;
+                    // A section break on the marker paragraph ends the section after the fragment / 标记段落上的分节符在片段之后结束该节
+                    if let Some(properties) = section_break(&block) {
+                        let paragraph = [&block[0]].into_iter().chain(properties);
+                        for event in paragraph.chain(block.last()) {
+                            writer.write_event(event.borrow())?;
+                        }
+                    }
+                    block.clear();
+                }
+                _ => unresolved.push(path),
+            }
+        }
+        for event in block.drain(..) {
+            writer.write_event(event)?;
+        }
+    }
+    for event in block {
+        writer.write_event(event)?;
+    }
+    Ok(())
+}
+
+/// Path of a paragraph whose whole text is an `[include:path]` marker / 整个文本为 `[include:path]` 标记的段落的路径
+fn include_marker(block: &[Event]) -> Option<String> {
+    if !matches!(block.first(), Some(Event::Start(e)) if e.name().as_ref() == XML_PARAGRAPH) {
+        return None;
+    }
+    let mut text = String::new();
+    let mut in_text = false;
+    for event in block {
+        match event {
+            Event::Start(e) if e.name().as_ref() == XML_TEXT => in_text = true,
+            Event::End(e) if e.name().as_ref() == XML_TEXT => in_text = false,
+            Event::Text(t) if in_text => text.push_str(&t.decode().ok()?),
+            _ => {}
+        }
+    }
+    let path = text
+        .trim()
+        .strip_prefix(INCLUDE_PREFIX)?
+        .strip_suffix(MARKER_CLOSE)?
+        .trim();
+    (!path.is_empty()).then(|| path.to_string())
+}
+
+/// Paragraph properties of a paragraph ending a section, i.e. holding a `w:sectPr` / 结束一节的段落的段落属性，即包含 `w:sectPr` 的段落属性
+fn section_break<'b>(paragraph: &'b [Event<'static>]) -> Option<&'b [Event<'static>]> {
+    let properties = XML_PARAGRAPH_PROPERTIES.as_bytes();
+    let start = paragraph
+        .iter()
+        .position(|event| matches!(event, Event::Start(e) if e.name().as_ref() == properties))?;
+    let len = paragraph[start..]
+        .iter()
+        .position(|event| matches!(event, Event::End(e) if e.name().as_ref() == properties))?;
+    let properties = &paragraph[start..=start + len];
+    properties
+        .iter()
+        .any(|event| match event {
+            Event::Start(e) | Event::Empty(e) => e.name().as_ref() == XML_SECTION_PROPERTIES,
+            _ => false,
+        })
+        .then_some(properties)
+}
+
+/// Body blocks of a fragment without its section properties / 片段的正文块，不含其节属性
+fn fragment_blocks(fragment: &[u8]) -> Result<Vec<Event<'static>>, quick_xml::Error> {
+    let mut reader = Reader::from_reader(fragment);
+    let mut buf = Vec::new();
+    let mut blocks = Vec::new();
+
+    let mut in_body = false;
+    let mut depth = 0usize; // Depth inside the body / 正文内的深度
+    let mut skip = false; // Whether the current block is the section properties / 当前块是否为节属性
+    loop {
+        let event = reader.read_event_into(&mut buf)?;
+        match &event {
+            Event::Eof => break,
+            Event::Start(e) if !in_body => in_body = e.name().as_ref() == XML_BODY,
+            _ if !in_body => {}
+            Event::End(_) if depth == 0 => break,
+            Event::Start(e) | Event::Empty(e) => {
+                if depth == 0 {
+                    skip = e.name().as_ref() == XML_SECTION_PROPERTIES;
+                }
+                if matches!(event, Event::Start(_)) {
+                    depth += 1;
+                }
+                if !skip {
+                    blocks.push(event.into_owned());
+                }
+            }
+            Event::End(_) => {
+                depth -= 1;
+                if !skip {
+                    blocks.push(event.into_owned());
+                }
+            }
+            _ if !skip => blocks.push(event.into_owned()),
+            _ => {}
+        }
+        buf.clear();
+    }
+    Ok(blocks)
+}
@@ -0,0 +1,61 @@
+use crate::core::constant::{
+    JPEG_INITIAL_OFFSET, JPEG_MARKER_PREFIX, JPEG_MARKER_SOS, JPEG_METADATA_MARKERS,
+    PNG_CHUNK_CRC_LEN, PNG_CHUNK_HEADER_LEN, PNG_METADATA_CHUNKS, PNG_SIGNATURE_LEN,
+};
+
+/// Remove EXIF, XMP, IPTC and comment segments from a JPEG without re-encoding / 在不重新编码的情况下移除 JPEG 中的 EXIF、XMP、IPTC 和注释段
+///
+/// Segments are copied up to the start of scan, the compressed data is kept as is. Malformed input is returned unchanged / 段复制到扫描开始为止，压缩数据保持不变。格式错误的输入原样返回
+pub(crate) fn strip_jpeg_metadata(bytes: Vec<u8>) -> Vec<u8> {
+    let mut stripped = Vec::with_capacity(bytes.len());
+    stripped.extend_from_slice(&bytes[..JPEG_INITIAL_OFFSET.min(bytes.len())]);
+    let mut offset = JPEG_INITIAL_OFFSET;
+    loop {
+        // Marker and segment length / 标记和段长度
+        let Some(&[prefix, marker, high, low]) = bytes.get(offset..offset + 4) else {
+            return bytes;
+        };
+        if prefix != JPEG_MARKER_PREFIX {
+            return bytes;
+        }
+        if marker == JPEG_MARKER_SOS {
+            stripped.extend_from_slice(&bytes[offset..]);
+            return stripped;
+        }
+        let end = offset + 2 + usize::from(u16::from_be_bytes([high, low]));
+        if end > bytes.len() {
+            return bytes;
+        }
+        if !JPEG_METADATA_MARKERS.contains(&marker) {
+            stripped.extend_from_slice(&bytes[offset..end]);
+        }
+        offset = end;
+    }
+}
+
+/// Remove text, EXIF and time chunks from a PNG without re-encoding / 在不重新编码的情况下移除 PNG 中的文本、EXIF 和时间块
+///
+/// Malformed input is returned unchanged / 格式错误的输入原样返回
+pub(crate) fn strip_png_metadata(bytes: Vec<u8>) -> Vec<u8> {
+    let mut stripped = Vec::with_capacity(bytes.len());
+    stripped.extend_from_slice(&bytes[..PNG_SIGNATURE_LEN.min(bytes.len())]);
+    let mut offset = PNG_SIGNATURE_LEN;
+    while offset < bytes.len() {
+        let Some(header) = bytes.get(offset..offset + PNG_CHUNK_HEADER_LEN) else {
+            return bytes;
+        };
+        let length = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+        let end = offset + PNG_CHUNK_HEADER_LEN + length + PNG_CHUNK_CRC_LEN;
+        if end > bytes.len() {
+            return bytes;
+        }
+        if !PNG_METADATA_CHUNKS
+            .iter()
+            .any(|chunk| chunk.as_slice() == &header[4..])
+        {
+            stripped.extend_from_slice(&bytes[offset..end]);
+        }
+        offset = end;
+    }
+    stripped
+}
@@ -1,6 +1,26 @@
+pub(crate) mod channel_writer;
 pub(crate) mod constant;
+pub(crate) mod content_type_manager;
 pub(crate) mod default_handler;
+pub(crate) mod diagnostics;
+pub(crate) mod document_buffer;
 pub(crate) mod docx_processor;
+pub(crate) mod fallback;
+pub(crate) mod field;
+pub(crate) mod flat_opc;
+#[cfg(feature = "image")]
+pub(crate) mod image_codec;
 pub(crate) mod image_manager;
+pub(crate) mod image_options;
+pub(crate) mod include;
+pub(crate) mod metadata;
+pub(crate) mod paragraph;
+pub(crate) mod part_processor;
+pub(crate) mod part_sink;
 pub(crate) mod relationship_manager;
+pub(crate) mod replay_reader;
+pub(crate) mod section;
+#[cfg(feature = "svg")]
+pub(crate) mod svg_codec;
+pub(crate) mod transform;
 pub(crate) mod utils;
@@ -0,0 +1,195 @@
+use crate::core::constant::{
+    PARAGRAPH_INLINE_ANCESTORS, XML_PARAGRAPH, XML_PARAGRAPH_PROPERTIES, XML_RUN,
+    XML_RUN_PROPERTIES, XML_SECTION_PROPERTIES, XML_TEXT,
+};
+use quick_xml::Writer;
+use quick_xml::events::{BytesEnd, BytesStart, Event};
+
+/// Properties of the paragraph and run being written / 正在写入的段落和运行的属性
+///
+/// Used to split a paragraph when a value holds several paragraphs / 用于在值包含多个段落时拆分段落
+#[derive(Default)]
+pub(crate) struct ParagraphContext {
+    paragraph_properties: Vec<Event<'static>>, // w:pPr of the current paragraph / 当前段落的 w:pPr
+    run_properties: Vec<Event<'static>>,       // w:rPr of the current run / 当前运行的 w:rPr
+    in_run: bool,                              // Inside a w:r element / 位于 w:r 元素内
+    recording: Option<(bool, usize)>, // Properties being copied (paragraph?, depth) / 正在复制的属性（是否为段落，深度）
+    ancestors: Vec<BytesStart<'static>>, // Open inline elements around the run / 运行外层已打开的行内元素
+    section: Vec<Event<'static>>, // w:sectPr held for the last split paragraph / 为最后一个拆分段落保留的 w:sectPr
+    splits: usize, // Splits left before the held w:sectPr is written / 写入保留的 w:sectPr 之前剩余的拆分次数
+}
+
+impl ParagraphContext {
+    /// Follow an event of the document / 跟踪文档中的一个事件
+    pub(crate) fn observe(&mut self, event: &Event) {
+        if let Some((paragraph, depth)) = &mut self.recording {
+            let tag = Self::properties_tag(*paragraph);
+            match event {
+                Event::Start(e) if e.name().as_ref() == tag => *depth += 1,
+                Event::End(e) if e.name().as_ref() == tag => *depth -= 1,
+                _ => {}
+            }
+            let done = *depth == 0;
+            let target = if *paragraph {
+                &mut self.paragraph_properties
+            } else {
+                &mut self.run_properties
+            };
+            target.push(event.clone().into_owned());
+            if done {
+                self.recording = None;
+            }
+            return;
+        }
+
+        match event {
+            Event::Start(e) if e.name().as_ref() == XML_PARAGRAPH => {
+                self.paragraph_properties.clear();
+                self.ancestors.clear();
+                self.in_run = false;
+            }
+            Event::Start(e) if e.name().as_ref() == XML_RUN => {
+                self.run_properties.clear();
+                self.in_run = true;
+            }
+            Event::End(e) if e.name().as_ref() == XML_RUN => self.in_run = false,
+            Event::Start(e) if PARAGRAPH_INLINE_ANCESTORS.contains(&e.name().as_ref()) => {
+                self.ancestors.push(e.clone().into_owned());
+            }
+            Event::End(e)
+                if self
+                    .ancestors
+                    .last()
+                    .is_some_and(|open| open.name() == e.name()) =>
+            {
+                self.ancestors.pop();
+            }
+            Event::Start(e) | Event::Empty(e)
+                if e.name().as_ref() == Self::properties_tag(!self.in_run) =>
+            {
+                let paragraph = !self.in_run;
+                let target = if paragraph {
+                    &mut self.paragraph_properties
+                } else {
+                    &mut self.run_properties
+                };
+                target.clear();
+                target.push(event.clone().into_owned());
+                if matches!(event, Event::Start(_)) {
+                    self.recording = Some((paragraph, 1));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Hold the `w:sectPr` of the current paragraph back for the last of its splits / 保留当前段落的 `w:sectPr`，留给其最后一个拆分段落
+    pub(crate) fn hold_section(&mut self, section: Vec<Event<'static>>, splits: usize) {
+        self.section = section;
+        self.splits = splits;
+    }
+
+    /// Markup closing the current text, run, inline elements and paragraph and opening new ones with the same properties / 关闭当前文本、运行、行内元素和段落并以相同属性打开新元素的标记
+    ///
+    /// A held section break goes to the last split paragraph only / 保留的分节符仅写入最后一个拆分段落
+    pub(crate) fn continuation(&mut self) -> Result<Vec<u8>, quick_xml::Error> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_event(Event::End(BytesEnd::new("w:t")))?;
+        writer.write_event(Event::End(BytesEnd::new("w:r")))?;
+        for ancestor in self.ancestors.iter().rev() {
+            writer.write_event(Event::End(ancestor.to_end()))?;
+        }
+        writer.write_event(Event::End(BytesEnd::new("w:p")))?;
+        writer.write_event(Event::Start(BytesStart::new("w:p")))?;
+
+        self.splits = self.splits.saturating_sub(1);
+        let section = if self.splits == 0 {
+            std::mem::take(&mut self.section)
+        } else {
+            Vec::new()
+        };
+        let mut section_depth = 0;
+        let properties = self
+            .paragraph_properties
+            .iter()
+            .filter(|event| match event {
+                Event::Start(e) if e.name().as_ref() == XML_SECTION_PROPERTIES => {
+                    section_depth += 1;
+                    false
+                }
+                Event::End(e) if e.name().as_ref() == XML_SECTION_PROPERTIES => {
+                    section_depth -= 1;
+                    false
+                }
+                Event::Empty(e) => {
+                    section_depth == 0 && e.name().as_ref() != XML_SECTION_PROPERTIES
+                }
+                _ => section_depth == 0,
+            })
+            .collect::<Vec<_>>();
+        let children = match properties.as_slice() {
+            [Event::Start(_), children @ .., Event::End(_)] => children,
+            _ => &[],
+        };
+        // Properties left empty by the section are dropped / 仅含节属性的属性被丢弃
+        if !children.is_empty() || !section.is_empty() {
+            writer.write_event(Event::Start(BytesStart::new(XML_PARAGRAPH_PROPERTIES)))?;
+            for event in children.iter().copied().chain(&section) {
+                writer.write_event(event.borrow())?;
+            }
+            writer.write_event(Event::End(BytesEnd::new(XML_PARAGRAPH_PROPERTIES)))?;
+        }
+
+        for ancestor in &self.ancestors {
+            writer.write_event(Event::Start(ancestor.borrow()))?;
+        }
+        writer.write_event(Event::Start(BytesStart::new("w:r")))?;
+        for event in &self.run_properties {
+            writer.write_event(event.borrow())?;
+        }
+        let mut text = BytesStart::new(String::from_utf8_lossy(XML_TEXT));
+        text.push_attribute(("xml:space", "preserve"));
+        writer.write_event(Event::Start(text))?;
+        Ok(writer.into_inner())
+    }
+
+    /// Markup ending the paragraph early to keep a held section break that no split wrote / 提前结束段落以保留未被拆分写入的分节符的标记
+    pub(crate) fn release_section(&mut self) -> Result<Option<Vec<u8>>, quick_xml::Error> {
+        if self.section.is_empty() {
+            return Ok(None);
+        }
+        self.splits = 0;
+        let mut writer = Writer::new(Vec::new());
+        writer.write_event(Event::End(BytesEnd::new("w:p")))?;
+        writer.write_event(Event::Start(BytesStart::new("w:p")))?;
+        writer.write_event(Event::Start(BytesStart::new(XML_PARAGRAPH_PROPERTIES)))?;
+        for event in std::mem::take(&mut self.section) {
+            writer.write_event(event)?;
+        }
+        writer.write_event(Event::End(BytesEnd::new(XML_PARAGRAPH_PROPERTIES)))?;
+        Ok(Some(writer.into_inner()))
+    }
+
+    /// Recorded `w:rPr` element of the current run, empty when it has none / 当前运行记录的 `w:rPr` 元素，没有时为空
+    pub(crate) fn run_properties(&self) -> &[Event<'static>] {
+        &self.run_properties
+    }
+
+    /// Children of the recorded `w:rPr` element / 记录的 `w:rPr` 元素的子节点
+    pub(crate) fn run_property_children(&self) -> &[Event<'static>] {
+        match self.run_properties.as_slice() {
+            [Event::Start(_), children @ .., Event::End(_)] => children,
+            _ => &[],
+        }
+    }
+
+    /// Tag of the paragraph or run properties / 段落或运行属性的标签
+    #[inline]
+    fn properties_tag(paragraph: bool) -> &'static [u8] {
+        if paragraph {
+            XML_PARAGRAPH_PROPERTIES.as_bytes()
+        } else {
+            XML_RUN_PROPERTIES
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use crate::core::fallback::{Fallback, resolve};
+use crate::core::transform::Transforms;
+use crate::public::value_extern::ValueExt;
+use quick_xml::events::{BytesText, Event};
+use quick_xml::{Reader, Writer};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Replace regular placeholders in the text of an auxiliary XML part / 替换辅助 XML 部件文本中的常规占位符
+///
+/// Used for parts such as charts, where only text content is substituted and the structure is kept / 用于图表等部件，仅替换文本内容并保留结构
+///
+/// # Arguments / 参数
+/// * `content` - Part XML bytes / 部件 XML 字节
+/// * `cell_handler` - Value handler / 值处理器
+/// * `fallbacks` - Sources consulted when the handler does not find a placeholder / 处理器未找到占位符时查询的来源
+/// * `transforms` - Registered transforms / 已注册的变换
+/// * `placeholders` - Placeholder values / 占位符值
+pub(crate) fn replace_part_text(
+    content: &[u8],
+    cell_handler: &dyn ValueExt,
+    fallbacks: &[Fallback],
+    transforms: &Transforms,
+    placeholders: &HashMap<String, Value>,
+) -> Result<Vec<u8>, quick_xml::Error> {
+    let mut reader = Reader::from_reader(content);
+    let mut writer = Writer::new(Vec::with_capacity(content.len()));
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Text(text) => {
+                let original = text.decode()?;
+                let replaced = resolve(
+                    cell_handler,
+                    fallbacks,
+                    transforms,
+                    None,
+                    &original,
+                    placeholders,
+                );
+                writer.write_event(Event::Text(BytesText::from_escaped(replaced)))?;
+            }
+            Event::Eof => break,
+            event => writer.write_event(event)?,
+        }
+        buf.clear();
+    }
+    Ok(writer.into_inner())
+}
@@ -0,0 +1,180 @@
+use crate::core::constant::ERR_OUTPUT_LIMIT;
+use crate::public::error::{DocxError, Limit};
+use async_zip::tokio::write::ZipFileWriter;
+use async_zip::{Compression, ZipDateTime, ZipEntryBuilder};
+use std::io::{Error as IoError, ErrorKind};
+use std::path::{Component, Path, PathBuf};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::fs::{create_dir_all, write};
+use tokio::io::AsyncWrite;
+
+/// Destination of the generated package parts / 生成的包部件的目标
+pub(crate) enum PartSink<'w, W: AsyncWrite + Unpin> {
+    /// Parts are zipped into a DOCX package, with the modification time of the entries / 部件被压缩为 DOCX 包，并带有条目的修改时间
+    Zip(&'w mut ZipFileWriter<W>, Option<ZipDateTime>),
+    /// Parts are written as plain files under a directory / 部件作为普通文件写入目录
+    Directory(&'w Path),
+    /// Parts are collected in memory in writing order / 部件按写入顺序收集在内存中
+    Memory(&'w mut Vec<(String, Vec<u8>)>),
+}
+
+impl<W: AsyncWrite + Unpin> PartSink<'_, W> {
+    /// Write a whole part / 写入整个部件
+    ///
+    /// # Arguments / 参数
+    /// * `name` - Part name inside the package / 包内的部件名称
+    /// * `content` - Part content / 部件内容
+    /// * `compression` - Zip compression, ignored outside zips / zip 压缩方式，非 zip 输出时忽略
+    pub(crate) async fn write_part(
+        &mut self,
+        name: &str,
+        content: &[u8],
+        compression: Compression,
+    ) -> Result<(), DocxError> {
+        match self {
+            PartSink::Zip(writer, modified) => {
+                let options = zip_entry(name, compression, *modified);
+                writer.write_entry_whole(options, content).await?;
+            }
+            PartSink::Directory(dir) => write(part_path(dir, name).await?, content).await?,
+            PartSink::Memory(parts) => parts.push((name.to_string(), content.to_vec())),
+        }
+        Ok(())
+    }
+}
+
+/// Build the zip entry of a part / 构建部件的 zip 条目
+///
+/// Entries keep the zero timestamp of `async_zip` when no modification time is set / 未设置修改时间时条目保留 `async_zip` 的零时间戳
+pub(crate) fn zip_entry(
+    name: &str,
+    compression: Compression,
+    modified: Option<ZipDateTime>,
+) -> ZipEntryBuilder {
+    let options = ZipEntryBuilder::new(name.to_string().into(), compression);
+    match modified {
+        Some(modified) => options.last_modification_date(modified),
+        None => options,
+    }
+}
+
+/// Resolve the file of a part under `dir`, creating its parent directories / 解析部件在 `dir` 下的文件，并创建其父目录
+///
+/// Part names that would escape `dir` are rejected / 拒绝会逃出 `dir` 的部件名称
+pub(crate) async fn part_path(dir: &Path, name: &str) -> Result<PathBuf, DocxError> {
+    let relative = Path::new(name);
+    if !relative
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+    {
+        return Err(IoError::new(
+            ErrorKind::InvalidInput,
+            format!("part name `{}` escapes the output directory", name),
+        )
+        .into());
+    }
+    let path = dir.join(relative);
+    if let Some(parent_dir) = path.parent() {
+        create_dir_all(parent_dir).await?;
+    }
+    Ok(path)
+}
+
+/// Running total of the uncompressed bytes written to the output / 写入输出的未压缩字节的累计
+#[derive(Debug, Default)]
+pub(crate) struct OutputBudget {
+    /// Bytes written so far / 目前已写入的字节数
+    written: usize,
+    /// Maximum bytes, unlimited when `None` / 最大字节数，为 `None` 时不限
+    max: Option<usize>,
+    /// Whether a write was refused / 是否拒绝过写入
+    exceeded: bool,
+}
+
+impl OutputBudget {
+    /// Create a budget / 创建预算
+    ///
+    /// # Arguments / 参数
+    /// * `max` - Maximum bytes, unlimited when `None` / 最大字节数，为 `None` 时不限
+    pub(crate) fn new(max: Option<usize>) -> Self {
+        Self {
+            max,
+            ..Self::default()
+        }
+    }
+
+    /// Whether writing `len` more bytes would exceed the limit, remembering a refusal / 再写入 `len` 字节是否会超出限制，并记录拒绝
+    fn exceeds(&mut self, len: usize) -> bool {
+        if self
+            .max
+            .is_some_and(|max| self.written.saturating_add(len) > max)
+        {
+            self.exceeded = true;
+        }
+        self.exceeded
+    }
+
+    /// Account for a whole part of `len` bytes / 计入 `len` 字节的整个部件
+    pub(crate) fn charge(&mut self, len: usize) -> Result<(), DocxError> {
+        if self.exceeds(len) {
+            return Err(self.error());
+        }
+        self.written += len;
+        Ok(())
+    }
+
+    /// Fail if a streamed write was refused / 如果流式写入被拒绝则失败
+    ///
+    /// The refusal surfaces as an I/O error of the writer, this turns it into the limit error / 拒绝表现为写入器的 I/O 错误，此方法将其转换为限制错误
+    pub(crate) fn check(&self) -> Result<(), DocxError> {
+        if self.exceeded {
+            return Err(self.error());
+        }
+        Ok(())
+    }
+
+    fn error(&self) -> DocxError {
+        DocxError::LimitExceeded(Limit::OutputBytes {
+            max: self.max.unwrap_or_default(),
+        })
+    }
+}
+
+/// Writer charging every write against an [`OutputBudget`] / 将每次写入计入 [`OutputBudget`] 的写入器
+pub(crate) struct LimitedWriter<'b, W> {
+    inner: W,
+    budget: &'b mut OutputBudget,
+}
+
+impl<'b, W> LimitedWriter<'b, W> {
+    pub(crate) fn new(inner: W, budget: &'b mut OutputBudget) -> Self {
+        Self { inner, budget }
+    }
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for LimitedWriter<'_, W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, IoError>> {
+        let this = self.get_mut();
+        if this.budget.exceeds(buf.len()) {
+            return Poll::Ready(Err(IoError::other(ERR_OUTPUT_LIMIT)));
+        }
+        let poll = Pin::new(&mut this.inner).poll_write(cx, buf);
+        if let Poll::Ready(Ok(written)) = poll {
+            this.budget.written += written;
+        }
+        poll
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), IoError>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), IoError>> {
+        Pin::new(&mut self.get_mut().inner).poll_shutdown(cx)
+    }
+}
@@ -1,6 +1,4 @@
-use crate::core::constant::{
-    REL_ID_PREFIX, REL_TYPE_IMAGE, REL_XML_BASE_CAPACITY, TYPICAL_IMAGE_COUNT,
-};
+use crate::core::constant::{EMPTY_RELS_XML, REL_ID_PREFIX, REL_TYPE_IMAGE, REL_XML_BASE_CAPACITY};
 use crate::core::utils::parse_next_rid_from_rels;
 use bytes::{Bytes, BytesMut};
 use std::str::from_utf8;
@@ -12,21 +10,37 @@ pub(crate) struct RelationshipManager {
     current_rid: u32,      // Next available relationship ID / 下一个可用的关系 ID
     new_rels: Vec<String>, // New relationships to add (pre-allocated) / 要添加的新关系（预分配）
     original_rels_content: Option<Bytes>, // Original .rels file content (zero-copy) / 原始 .rels 文件内容（零拷贝）
+    drawing_id: Option<u32>, // Next drawing ID when not following rIds / 不跟随 rId 时的下一个绘图 ID
 }
 
 impl RelationshipManager {
     /// Create new relationship manager / 创建新的关系管理器
     ///
-    /// Pre-allocates space for typical number of images / 为典型图片数量预分配空间
+    /// Pre-allocates space for the expected number of images / 为预期图片数量预分配空间
     #[inline]
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(image_capacity: usize) -> Self {
         Self {
             current_rid: 1,
-            new_rels: Vec::with_capacity(TYPICAL_IMAGE_COUNT),
+            new_rels: Vec::with_capacity(image_capacity),
             original_rels_content: None,
+            drawing_id: None,
         }
     }
 
+    /// Number drawings from `start` instead of following the rIds / 从 `start` 开始为绘图编号，而不跟随 rId
+    ///
+    /// Keeps drawing IDs of several parts unique within the package / 使多个部件的绘图 ID 在包内保持唯一
+    #[inline]
+    pub(crate) fn set_drawing_id_start(&mut self, start: u32) {
+        self.drawing_id = Some(start);
+    }
+
+    /// Drawing ID the next image will get / 下一张图片将获得的绘图 ID
+    #[inline]
+    pub(crate) fn next_drawing_id(&self) -> u32 {
+        self.drawing_id.unwrap_or(self.current_rid)
+    }
+
     /// Set initial relationship file content / 设置初始关系文件内容
     ///
     /// Parses existing relationships to determine next available ID / 解析现有关系以确定下一个可用 ID
@@ -42,6 +56,14 @@ impl RelationshipManager {
         self.original_rels_content = Some(content);
     }
 
+    /// Whether the original relationships define `id` / 原始关系是否定义了 `id`
+    pub(crate) fn has_relationship(&self, id: &str) -> bool {
+        self.original_rels_content
+            .as_deref()
+            .and_then(|content| from_utf8(content).ok())
+            .is_some_and(|rels| rels.contains(&format!(r#"Id="{}""#, id)))
+    }
+
     /// Add new image relationship / 添加新的图片关系
     ///
     /// Generates unique relationship ID and registers the image  / 生成唯一的关系 ID 并注册图片
@@ -53,7 +75,10 @@ impl RelationshipManager {
     /// * `(rel_id, image_id)` - Relationship ID and numeric ID / 关系 ID 和数字 ID
     #[inline]
     pub(crate) fn add_image_relationship(&mut self, filename: &str) -> (String, u32) {
-        let image_id = self.current_rid;
+        let image_id = self.next_drawing_id();
+        if let Some(drawing_id) = &mut self.drawing_id {
+            *drawing_id += 1;
+        }
 
         let mut rel_id = String::with_capacity(8);
         rel_id.push_str(REL_ID_PREFIX);
@@ -84,9 +109,15 @@ impl RelationshipManager {
     ///
     /// # Returns / 返回
     /// * `Some(bytes)` - Updated .rels file content (zero-copy) / 更新的 .rels 文件内容（零拷贝）
-    /// * `None` - If no original content was set / 如果未设置原始内容
+    /// * `None` - If neither original content nor new relationships exist / 如果既没有原始内容也没有新关系
     pub(crate) fn generate_final_rels_content(&self) -> Option<Bytes> {
-        let content = self.original_rels_content.as_ref()?;
+        // Parts without a .rels file get a new one once they reference images / 没有 .rels 文件的部件在引用图片后获得新文件
+        let empty = Bytes::from_static(EMPTY_RELS_XML.as_bytes());
+        let content = match &self.original_rels_content {
+            Some(content) => content,
+            None if self.new_rels.is_empty() => return None,
+            None => &empty,
+        };
 
         // Fast path: if no new relationships, return cloned Bytes (cheap) / 快速路径：如果没有新关系，返回克隆的 Bytes（廉价）
         if self.new_rels.is_empty() {
@@ -0,0 +1,66 @@
+use std::io::Error as IoError;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::io::{AsyncBufRead, AsyncRead, ReadBuf};
+
+/// Reader serving handed back bytes before the rest of its input / 在其余输入之前提供交回字节的读取器
+///
+/// Lets the event loop read ahead and return the markup it looked at unchanged / 使事件循环可以向前读取并原样交回所查看的标记
+pub(crate) struct ReplayReader<R> {
+    replay: Vec<u8>, // Bytes handed back / 交回的字节
+    position: usize, // Bytes of `replay` already read / `replay` 中已读取的字节数
+    inner: R,
+}
+
+impl<R> ReplayReader<R> {
+    pub(crate) fn new(inner: R) -> Self {
+        Self {
+            replay: Vec::new(),
+            position: 0,
+            inner,
+        }
+    }
+
+    /// Hand bytes back to be read before anything else / 交回字节，使其在其他内容之前被读取
+    pub(crate) fn replay(&mut self, mut bytes: Vec<u8>) {
+        bytes.extend_from_slice(&self.replay[self.position..]);
+        self.replay = bytes;
+        self.position = 0;
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for ReplayReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<Result<(), IoError>> {
+        let this = self.get_mut();
+        if this.position < this.replay.len() {
+            let len = buf.remaining().min(this.replay.len() - this.position);
+            buf.put_slice(&this.replay[this.position..this.position + len]);
+            this.position += len;
+            return Poll::Ready(Ok(()));
+        }
+        Pin::new(&mut this.inner).poll_read(cx, buf)
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> AsyncBufRead for ReplayReader<R> {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<&[u8], IoError>> {
+        let this = self.get_mut();
+        if this.position < this.replay.len() {
+            return Poll::Ready(Ok(&this.replay[this.position..]));
+        }
+        Pin::new(&mut this.inner).poll_fill_buf(cx)
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        let this = self.get_mut();
+        if this.position < this.replay.len() {
+            this.position += amt;
+        } else {
+            Pin::new(&mut this.inner).consume(amt);
+        }
+    }
+}
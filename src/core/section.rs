@@ -0,0 +1,122 @@
+use crate::core::constant::{
+    XML_ATTR_BOOKMARK_ID, XML_ATTR_BOOKMARK_NAME, XML_BODY, XML_BOOKMARK_END, XML_BOOKMARK_START,
+    XML_SECTION_PROPERTIES,
+};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::{Reader, Writer};
+
+/// Bookmark range found in a body block / 在正文块中找到的书签范围
+#[derive(Default)]
+struct Section {
+    /// Id of the named bookmark once its start is seen / 看到命名书签起点后的 id
+    id: Option<Vec<u8>>,
+    /// Whether the blocks being read are inside the range / 正在读取的块是否在范围内
+    inside: bool,
+    /// Whether the range has ended / 范围是否已结束
+    done: bool,
+}
+
+impl Section {
+    /// Track bookmark boundaries, returns whether the block touches the range / 跟踪书签边界，返回该块是否触及范围
+    fn observe(&mut self, element: &BytesStart, name: &str) -> bool {
+        let attribute = |key: &[u8]| {
+            element
+                .attributes()
+                .flatten()
+                .find(|attr| attr.key.as_ref() == key)
+                .map(|attr| attr.value.into_owned())
+        };
+        match element.name().as_ref() {
+            XML_BOOKMARK_START
+                if !self.done
+                    && self.id.is_none()
+                    && attribute(XML_ATTR_BOOKMARK_NAME).as_deref() == Some(name.as_bytes()) =>
+            {
+                self.id = attribute(XML_ATTR_BOOKMARK_ID);
+                self.inside = true;
+                true
+            }
+            XML_BOOKMARK_END
+                if self.inside
+                    && self.id.is_some()
+                    && attribute(XML_ATTR_BOOKMARK_ID) == self.id =>
+            {
+                self.inside = false;
+                self.done = true;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Keep only the body blocks spanned by a bookmark / 仅保留书签跨越的正文块
+///
+/// Paragraphs and tables containing the bookmark start or end, and every block between them, are kept together with the final `w:sectPr`, so the result is still a valid document. An unknown bookmark yields an empty body / 包含书签起点或终点的段落和表格及其间的所有块与最后的 `w:sectPr` 一起保留，因此结果仍是有效文档。未知书签产生空正文
+///
+/// Works on the rendered document, so the caller holds all of it in memory; the output is at most as large again / 作用于渲染后的文档，因此调用方需将其全部保存在内存中；输出最多再占用同样大小
+///
+/// # Arguments / 参数
+/// * `xml` - Generated document.xml / 生成的 document.xml
+/// * `bookmark` - Name of the bookmark / 书签名称
+pub(crate) fn extract_section(xml: &[u8], bookmark: &str) -> Result<Vec<u8>, quick_xml::Error> {
+    let mut reader = Reader::from_reader(xml);
+    let mut writer = Writer::new(Vec::with_capacity(xml.len()));
+    let mut buf = Vec::new();
+
+    let mut section = Section::default();
+    let mut in_body = false;
+    let mut depth = 0usize; // Depth inside the current body block / 当前正文块内的深度
+    let mut block: Vec<Event<'static>> = Vec::new();
+    let mut keep = false; // Whether the current block is kept / 是否保留当前块
+
+    loop {
+        let event = reader.read_event_into(&mut buf)?;
+        if let Event::Eof = event {
+            break;
+        }
+        if !in_body {
+            if matches!(&event, Event::Start(e) if e.name().as_ref() == XML_BODY) {
+                in_body = true;
+            }
+            writer.write_event(event)?;
+            buf.clear();
+            continue;
+        }
+
+        match &event {
+            Event::Start(e) | Event::Empty(e) => {
+                if depth == 0 {
+                    keep = section.inside || e.name().as_ref() == XML_SECTION_PROPERTIES;
+                }
+                keep |= section.observe(e, bookmark);
+                if matches!(event, Event::Start(_)) {
+                    depth += 1;
+                }
+            }
+            Event::End(e) if depth == 0 && e.name().as_ref() == XML_BODY => {
+                in_body = false;
+                writer.write_event(event)?;
+                buf.clear();
+                continue;
+            }
+            Event::End(_) => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        block.push(event.into_owned());
+
+        // A body block is complete / 正文块已完整
+        if depth == 0 {
+            if keep {
+                for event in block.drain(..) {
+                    writer.write_event(event)?;
+                }
+            } else {
+                block.clear();
+            }
+            keep = false;
+        }
+        buf.clear();
+    }
+    Ok(writer.into_inner())
+}
@@ -0,0 +1,26 @@
+use resvg::{tiny_skia, usvg};
+
+/// Rasterize an SVG image into PNG bytes / 将 SVG 图片栅格化为 PNG 字节
+///
+/// Used as the fallback picture for readers without SVG support / 用作不支持 SVG 的阅读器的回退图片
+///
+/// # Arguments / 参数
+/// * `bytes` - SVG document bytes / SVG 文档字节
+/// * `width` - Target width in pixels / 目标宽度（像素）
+/// * `height` - Target height in pixels / 目标高度（像素）
+///
+/// # Returns / 返回
+/// * `Some(png)` - Encoded PNG bytes / 编码后的 PNG 字节
+/// * `None` - If the SVG cannot be parsed or rendered / 如果无法解析或渲染 SVG
+pub(crate) fn rasterize_svg(bytes: &[u8], width: u32, height: u32) -> Option<Vec<u8>> {
+    let tree = usvg::Tree::from_data(bytes, &usvg::Options::default()).ok()?;
+    let mut pixmap = tiny_skia::Pixmap::new(width.max(1), height.max(1))?;
+
+    let size = tree.size();
+    let transform = tiny_skia::Transform::from_scale(
+        pixmap.width() as f32 / size.width(),
+        pixmap.height() as f32 / size.height(),
+    );
+    resvg::render(&tree, transform, &mut pixmap.as_mut());
+    pixmap.encode_png().ok()
+}
@@ -0,0 +1,51 @@
+use crate::core::constant::{
+    MODIFIER_SEPARATOR, PLACEHOLDER_CLOSE, PLACEHOLDER_OPEN, TRANSFORM_LOWER, TRANSFORM_UPPER,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Named transform applied to a resolved value / 应用于解析值的命名变换
+pub(crate) type Transform = Arc<dyn Fn(&str) -> String + Send + Sync>;
+
+/// Transforms by name / 按名称索引的变换
+pub(crate) type Transforms = HashMap<String, Transform>;
+
+/// Transforms registered by default / 默认注册的变换
+pub(crate) fn builtin_transforms() -> Transforms {
+    let mut transforms = Transforms::new();
+    transforms.insert(
+        TRANSFORM_UPPER.to_string(),
+        Arc::new(|value: &str| value.to_uppercase()),
+    );
+    transforms.insert(
+        TRANSFORM_LOWER.to_string(),
+        Arc::new(|value: &str| value.to_lowercase()),
+    );
+    transforms
+}
+
+/// Split `[name:key]` or `{{name:key}}` into a registered transform and the plain placeholder / 将 `[name:key]` 或 `{{name:key}}` 拆分为已注册的变换和普通占位符
+///
+/// Only a key that is one whole placeholder is split, so static text around it is never transformed. Only allocates when the name is registered, so plain text stays cheap / 仅拆分整体为单个占位符的键，因此其周围的静态文本不会被变换。仅在名称已注册时分配，因此普通文本开销很小
+///
+/// # Returns / 返回
+/// * `Some((transform, key))` - Transform and the placeholder it applies to (e.g. `[key]`) / 变换及其作用的占位符（例如 `[key]`）
+/// * `None` - No registered transform / 没有已注册的变换
+pub(crate) fn split_transform<'t>(
+    key: &str,
+    transforms: &'t Transforms,
+) -> Option<(&'t Transform, String)> {
+    let (open, close) = if key.starts_with(PLACEHOLDER_OPEN) {
+        (PLACEHOLDER_OPEN, PLACEHOLDER_CLOSE)
+    } else {
+        ("[", "]")
+    };
+    let inner = key.strip_prefix(open)?.strip_suffix(close)?;
+    // Text holding several placeholders or static text is not a single placeholder / 含多个占位符或静态文本的文本不是单个占位符
+    if inner.contains(['[', ']', '{', '}']) {
+        return None;
+    }
+    let (name, rest) = inner.split_once(MODIFIER_SEPARATOR)?;
+    let transform = transforms.get(name.trim())?;
+    Some((transform, format!("{}{}{}", open, rest, close)))
+}
@@ -1,18 +1,39 @@
 use crate::core::constant::{
-    ERR_INVALID_JPG_MARKER, ERR_INVALID_PNG_IHDR, ERR_NO_SOF_MARKER, ERR_SLICE_TOO_SHORT,
-    ERR_UNKNOWN_FORMAT, FLATTEN_RECORDS_CAPACITY, JPEG_INITIAL_OFFSET, JPEG_MARKER_DAC,
+    BMP_BASE64_HEADER_LEN, BMP_BASE64_SIGNATURE, BMP_HEIGHT_OFFSET, BMP_RESERVED_RANGE,
+    BMP_SIGNATURE, BMP_WIDTH_OFFSET, ERR_INVALID_BMP_HEADER, ERR_INVALID_JPG_MARKER,
+    ERR_INVALID_PNG_IHDR, ERR_INVALID_WEBP_HEADER, ERR_NO_SOF_MARKER, ERR_SLICE_TOO_SHORT,
+    ERR_UNKNOWN_FORMAT, ESCAPED_LOOP_START_MARKER, FLATTEN_RECORDS_CAPACITY, FOOTER_PATH_PREFIX,
+    GIF_DIMENSIONS_OFFSET, GIF_SIGNATURE, HEADER_PATH_PREFIX, JPEG_INITIAL_OFFSET, JPEG_MARKER_DAC,
     JPEG_MARKER_DHT, JPEG_MARKER_JPG, JPEG_MIN_SEGMENT_SIZE, JPEG_SOF_MARKER_END,
-    JPEG_SOF_MARKER_START, MIN_IMAGE_DATA_LEN, PNG_IHDR_MARKER, PNG_SIG_BYTE_0, PNG_SIG_BYTE_1,
-    PNG_SIG_BYTE_2, PNG_SIG_BYTE_3, REGEX_REL_ID, REL_ID_PREFIX,
+    JPEG_SOF_MARKER_START, LOOP_END_MARKER, LOOP_SORT_ASCENDING, LOOP_SORT_DESCENDING,
+    LOOP_SORT_DIRECTION_SEPARATOR, LOOP_START_MARKER, MIN_IMAGE_DATA_LEN, PLACEHOLDER_CLOSE,
+    PLACEHOLDER_OPEN, PNG_CHUNK_CRC_LEN, PNG_CHUNK_HEADER_LEN, PNG_IHDR_DIMENSIONS_LEN,
+    PNG_IHDR_MARKER, PNG_MAX_SCANNED_CHUNKS, PNG_SIG_BYTE_0, PNG_SIG_BYTE_1, PNG_SIG_BYTE_2,
+    PNG_SIG_BYTE_3, PNG_SIGNATURE_LEN, REGEX_ATTR_VAL, REGEX_BRACE_FIELD, REGEX_BRACKET_FIELD,
+    REGEX_COMPARISON, REGEX_REL_ID, REGEX_SVG_LENGTH, REGEX_SVG_ROOT, REGEX_SVG_VIEW_BOX,
+    REGEX_THEME_FONT_LANG, REL_ID_PREFIX, RELS_EXTENSION, RIFF_SIGNATURE, ROW_INDEX_KEY,
+    SVG_BASE64_SIGNATURES, SVG_BASE64_SNIFF_LEN, SVG_ROOT, SVG_SNIFF_LEN, WEBP_BASE64_HEADER_LEN,
+    WEBP_BASE64_SIGNATURE, WEBP_CHUNK_DATA_OFFSET, WEBP_CHUNK_RANGE, WEBP_CHUNK_VP8,
+    WEBP_CHUNK_VP8L, WEBP_CHUNK_VP8X, WEBP_FORM_RANGE, WEBP_SIGNATURE, WEBP_VP8_SIZE_MASK,
+    WEBP_VP8_SIZE_OFFSET, WEBP_VP8L_SIZE_BITS, WEBP_VP8L_SIZE_OFFSET, WEBP_VP8X_SIZE_OFFSET,
+    WORD_PATH_PREFIX, WORD_RELS_PATH_PREFIX, XML_PART_EXTENSION,
 };
+use base64::Engine;
+use base64::engine::general_purpose;
+use quick_xml::Reader;
+use quick_xml::errors::IllFormedError;
+use quick_xml::events::Event;
 use regex::Regex;
-use serde_json::Value;
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::sync::LazyLock;
+use std::time::{Duration, Instant};
 
-/// Extract image dimensions from PNG or JPEG bytes / 从 PNG 或 JPEG 字节中提取图片尺寸
+/// Extract image dimensions from PNG, JPEG, GIF, BMP or WebP bytes / 从 PNG、JPEG、GIF、BMP 或 WebP 字节中提取图片尺寸
 ///
-/// Supports PNG and JPEG formats by parsing their headers  / 通过解析头部支持 PNG 和 JPEG 格式
+/// Supports PNG, JPEG, GIF, BMP and WebP formats by parsing their headers / 通过解析头部支持 PNG、JPEG、GIF、BMP 和 WebP 格式
 ///
 /// # Arguments / 参数
 /// * `bytes` - Image file bytes / 图片文件字节
@@ -21,7 +42,7 @@ use std::sync::LazyLock;
 /// * `Ok((width, height))` - Image dimensions in pixels / 图片尺寸（像素）
 /// * `Err(msg)` - Error message if format is unsupported / 如果格式不支持则返回错误消息
 #[inline]
-pub(crate) fn get_image_dimensions<'a>(bytes: &[u8]) -> Result<(f32, f32), &'a str> {
+pub(crate) fn get_image_dimensions<'a>(bytes: &[u8]) -> Result<(u32, u32), &'a str> {
     // Minimum size check / 最小尺寸检查
     if bytes.len() < MIN_IMAGE_DATA_LEN {
         return Err(ERR_SLICE_TOO_SHORT);
@@ -34,17 +55,30 @@ pub(crate) fn get_image_dimensions<'a>(bytes: &[u8]) -> Result<(f32, f32), &'a s
         && bytes[2] == PNG_SIG_BYTE_2
         && bytes[3] == PNG_SIG_BYTE_3
     {
-        // PNG: Check IHDR chunk (skip signature check for perf) / PNG：检查 IHDR 块（跳过签名检查以提升性能）
-        if bytes[12] == PNG_IHDR_MARKER[0]
-            && bytes[13] == PNG_IHDR_MARKER[1]
-            && bytes[14] == PNG_IHDR_MARKER[2]
-            && bytes[15] == PNG_IHDR_MARKER[3]
-        {
-            // Width: bytes 16-19 (big-endian u32) / 宽度
-            let width = u32::from_be_bytes([bytes[16], bytes[17], bytes[18], bytes[19]]) as f32;
-            // Height: bytes 20-23 (big-endian u32) / 高度
-            let height = u32::from_be_bytes([bytes[20], bytes[21], bytes[22], bytes[23]]) as f32;
-            return Ok((width, height));
+        // PNG: Walk the chunks up to IHDR, which may follow leading metadata / PNG：遍历块直到 IHDR，其前面可能有元数据
+        let mut offset = PNG_SIGNATURE_LEN;
+        for _ in 0..PNG_MAX_SCANNED_CHUNKS {
+            let Some(header) = bytes.get(offset..offset + PNG_CHUNK_HEADER_LEN) else {
+                break;
+            };
+            let chunk_len = u32::from_be_bytes([header[0], header[1], header[2], header[3]]);
+            let data = offset + PNG_CHUNK_HEADER_LEN;
+            if header[4..] == PNG_IHDR_MARKER {
+                let Some(ihdr) = bytes.get(data..data + PNG_IHDR_DIMENSIONS_LEN) else {
+                    break;
+                };
+                // Width and height are big-endian u32 / 宽度和高度为大端 u32
+                let width = u32::from_be_bytes([ihdr[0], ihdr[1], ihdr[2], ihdr[3]]);
+                let height = u32::from_be_bytes([ihdr[4], ihdr[5], ihdr[6], ihdr[7]]);
+                return Ok((width, height));
+            }
+            match data
+                .checked_add(chunk_len as usize)
+                .and_then(|end| end.checked_add(PNG_CHUNK_CRC_LEN))
+            {
+                Some(next) => offset = next,
+                None => break,
+            }
         }
         return Err(ERR_INVALID_PNG_IHDR);
     }
@@ -69,8 +103,8 @@ pub(crate) fn get_image_dimensions<'a>(bytes: &[u8]) -> Result<(f32, f32), &'a s
                 && marker != JPEG_MARKER_JPG
                 && marker != JPEG_MARKER_DAC
             {
-                let height = u16::from_be_bytes([bytes[offset + 5], bytes[offset + 6]]) as f32;
-                let width = u16::from_be_bytes([bytes[offset + 7], bytes[offset + 8]]) as f32;
+                let height = u16::from_be_bytes([bytes[offset + 5], bytes[offset + 6]]) as u32;
+                let width = u16::from_be_bytes([bytes[offset + 7], bytes[offset + 8]]) as u32;
                 return Ok((width, height));
             }
 
@@ -79,12 +113,223 @@ pub(crate) fn get_image_dimensions<'a>(bytes: &[u8]) -> Result<(f32, f32), &'a s
         return Err(ERR_NO_SOF_MARKER);
     }
 
+    // GIF: Logical screen descriptor / GIF：逻辑屏幕描述符
+    if bytes.starts_with(GIF_SIGNATURE) {
+        let size = &bytes[GIF_DIMENSIONS_OFFSET..GIF_DIMENSIONS_OFFSET + 4];
+        let width = u16::from_le_bytes([size[0], size[1]]);
+        let height = u16::from_le_bytes([size[2], size[3]]);
+        return Ok((u32::from(width), u32::from(height)));
+    }
+
+    // BMP: BITMAPINFOHEADER following the file header / BMP：文件头之后的 BITMAPINFOHEADER
+    if bytes.starts_with(BMP_SIGNATURE) {
+        let field = |offset: usize| {
+            let value = bytes.get(offset..offset + 4)?;
+            Some(i32::from_le_bytes([value[0], value[1], value[2], value[3]]).unsigned_abs())
+        };
+        return match (field(BMP_WIDTH_OFFSET), field(BMP_HEIGHT_OFFSET)) {
+            (Some(width), Some(height)) => Ok((width, height)),
+            _ => Err(ERR_INVALID_BMP_HEADER),
+        };
+    }
+
+    // WebP: Size from the first bitstream chunk / WebP：从第一个位流块读取尺寸
+    if is_webp(bytes) {
+        return webp_dimensions(bytes).ok_or(ERR_INVALID_WEBP_HEADER);
+    }
+
     Err(ERR_UNKNOWN_FORMAT)
 }
 
+/// Check whether bytes start with a WebP RIFF header / 检查字节是否以 WebP RIFF 头开始
+pub(crate) fn is_webp(bytes: &[u8]) -> bool {
+    bytes.starts_with(RIFF_SIGNATURE) && bytes.get(WEBP_FORM_RANGE) == Some(WEBP_SIGNATURE)
+}
+
+/// Read the pixel size from the VP8, VP8L or VP8X chunk of a WebP file / 从 WebP 文件的 VP8、VP8L 或 VP8X 块读取像素尺寸
+fn webp_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    let data = bytes.get(WEBP_CHUNK_DATA_OFFSET..)?;
+    match bytes.get(WEBP_CHUNK_RANGE)? {
+        WEBP_CHUNK_VP8 => {
+            let size = data.get(WEBP_VP8_SIZE_OFFSET..WEBP_VP8_SIZE_OFFSET + 4)?;
+            let width = u16::from_le_bytes([size[0], size[1]]) & WEBP_VP8_SIZE_MASK;
+            let height = u16::from_le_bytes([size[2], size[3]]) & WEBP_VP8_SIZE_MASK;
+            Some((u32::from(width), u32::from(height)))
+        }
+        WEBP_CHUNK_VP8L => {
+            let size = data.get(WEBP_VP8L_SIZE_OFFSET..WEBP_VP8L_SIZE_OFFSET + 4)?;
+            let bits = u32::from_le_bytes([size[0], size[1], size[2], size[3]]);
+            let mask = (1 << WEBP_VP8L_SIZE_BITS) - 1;
+            Some((
+                (bits & mask) + 1,
+                ((bits >> WEBP_VP8L_SIZE_BITS) & mask) + 1,
+            ))
+        }
+        WEBP_CHUNK_VP8X => {
+            let size = data.get(WEBP_VP8X_SIZE_OFFSET..WEBP_VP8X_SIZE_OFFSET + 6)?;
+            let width = u32::from_le_bytes([size[0], size[1], size[2], 0]);
+            let height = u32::from_le_bytes([size[3], size[4], size[5], 0]);
+            Some((width + 1, height + 1))
+        }
+        _ => None,
+    }
+}
+
+/// Check whether base64 data starts with a WebP file header / 检查 base64 数据是否以 WebP 文件头开始
+///
+/// `RIFF` also starts audio and video files, so the header is decoded and its form type must be `WEBP` / `RIFF` 也是音频和视频文件的开头，因此会解码头部，且其格式类型必须为 `WEBP`
+pub(crate) fn is_base64_webp(data: &str) -> bool {
+    data.starts_with(WEBP_BASE64_SIGNATURE)
+        && data
+            .get(..WEBP_BASE64_HEADER_LEN)
+            .and_then(|header| general_purpose::STANDARD.decode(header).ok())
+            .is_some_and(|header| is_webp(&header))
+}
+
+/// Check whether base64 data starts with a BMP file header / 检查 base64 数据是否以 BMP 文件头开始
+///
+/// `Qk` alone is a common text prefix, so the header is decoded and its reserved bytes must be zero / 单独的 `Qk` 是常见的文本前缀，因此会解码头部，且其保留字节必须为零
+pub(crate) fn is_base64_bmp(data: &str) -> bool {
+    if !data.starts_with(BMP_BASE64_SIGNATURE) {
+        return false;
+    }
+    let Some(header) = data.get(..BMP_BASE64_HEADER_LEN) else {
+        return false;
+    };
+    general_purpose::STANDARD
+        .decode(header)
+        .is_ok_and(|header| header[BMP_RESERVED_RANGE].iter().all(|byte| *byte == 0))
+}
+
+/// Check whether bytes hold an SVG image / 检查字节是否为 SVG 图片
+///
+/// Accepts an `<svg` root, optionally preceded by an XML declaration or comments / 接受 `<svg` 根元素，前面可以有 XML 声明或注释
+#[inline]
+pub(crate) fn is_svg(bytes: &[u8]) -> bool {
+    let head = &bytes[..bytes.len().min(SVG_SNIFF_LEN)];
+    let head = String::from_utf8_lossy(head);
+    let head = head.trim_start_matches('\u{FEFF}').trim_start();
+    head.starts_with(SVG_ROOT) || (head.starts_with("<?xml") && head.contains(SVG_ROOT))
+}
+
+/// Check whether base64 data holds an SVG image / 检查 base64 数据是否为 SVG 图片
+///
+/// `<?xml` starts any XML document, so the head is decoded and must hold an `<svg` root / `<?xml` 是任何 XML 文档的开头，因此会解码头部，且其中必须有 `<svg` 根元素
+pub(crate) fn is_base64_svg(data: &str) -> bool {
+    if !SVG_BASE64_SIGNATURES
+        .iter()
+        .any(|signature| data.starts_with(signature))
+    {
+        return false;
+    }
+    let mut head = data
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace())
+        .take(SVG_BASE64_SNIFF_LEN)
+        .collect::<String>();
+    head.truncate(head.len() / 4 * 4);
+    general_purpose::STANDARD
+        .decode(head)
+        .is_ok_and(|head| is_svg(&head))
+}
+
+/// Extract the size of an SVG image in pixels / 提取 SVG 图片的像素尺寸
+///
+/// Uses the `width`/`height` of the root element, falling back to its `viewBox` / 使用根元素的 `width`/`height`，否则回退到其 `viewBox`
+///
+/// # Returns / 返回
+/// * `Some((width, height))` - Size in pixels / 像素尺寸
+/// * `None` - No usable size declared / 未声明可用尺寸
+pub(crate) fn get_svg_dimensions(bytes: &[u8]) -> Option<(f64, f64)> {
+    let text = String::from_utf8_lossy(bytes);
+    let root = REGEX_SVG_ROOT_TAG.find(&text)?.as_str();
+
+    let (mut width, mut height) = (None, None);
+    for caps in REGEX_SVG_LENGTH_ATTR.captures_iter(root) {
+        let value = caps[2].parse::<f64>().ok().filter(|v| *v > 0.0);
+        match &caps[1] {
+            "width" => width = value,
+            _ => height = value,
+        }
+    }
+    let view_box = REGEX_SVG_VIEW_BOX_ATTR
+        .captures(root)
+        .and_then(|caps| Some((caps[1].parse::<f64>().ok()?, caps[2].parse::<f64>().ok()?)));
+
+    match (width, height, view_box) {
+        (Some(w), Some(h), _) => Some((w, h)),
+        // Keep the view box ratio when only one side is given / 仅给出一边时保持视图框比例
+        (Some(w), None, Some((vw, vh))) if vw > 0.0 => Some((w, w * vh / vw)),
+        (None, Some(h), Some((vw, vh))) if vh > 0.0 => Some((h * vw / vh, h)),
+        (_, _, Some((vw, vh))) if vw > 0.0 && vh > 0.0 => Some((vw, vh)),
+        _ => None,
+    }
+}
+
 // Regex to find all rId patterns - compiled once / 正则表达式 - 仅编译一次
 static REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(REGEX_REL_ID).unwrap());
 
+static REGEX_THEME_LANG: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(REGEX_THEME_FONT_LANG).unwrap());
+
+static REGEX_VAL: LazyLock<Regex> = LazyLock::new(|| Regex::new(REGEX_ATTR_VAL).unwrap());
+
+static REGEX_CONDITION: LazyLock<Regex> = LazyLock::new(|| Regex::new(REGEX_COMPARISON).unwrap());
+
+static REGEX_SVG_ROOT_TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(REGEX_SVG_ROOT).unwrap());
+
+static REGEX_SVG_LENGTH_ATTR: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(REGEX_SVG_LENGTH).unwrap());
+
+static REGEX_SVG_VIEW_BOX_ATTR: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(REGEX_SVG_VIEW_BOX).unwrap());
+
+static REGEX_BRACKET: LazyLock<Regex> = LazyLock::new(|| Regex::new(REGEX_BRACKET_FIELD).unwrap());
+
+static REGEX_BRACE: LazyLock<Regex> = LazyLock::new(|| Regex::new(REGEX_BRACE_FIELD).unwrap());
+
+/// Rewrite a text that is a single `[field]` into `{{field}}` / 将单个 `[field]` 文本改写为 `{{field}}`
+///
+/// Used outside loop tables, other texts are returned unchanged / 用于循环表格之外，其他文本原样返回
+pub(crate) fn brackets_to_braces(text: &str) -> Cow<'_, str> {
+    REGEX_BRACKET.replace(text, "{{$1}}")
+}
+
+/// Field name of a text that is a single `[field]` / 单个 `[field]` 文本的字段名
+pub(crate) fn bracket_field(text: &str) -> Option<&str> {
+    REGEX_BRACKET
+        .captures(text)
+        .and_then(|caps| caps.get(1))
+        .map(|field| field.as_str())
+}
+
+/// Turn escaped loop markers such as `{{\#items}}` into literal `{{#items}}` text / 将 `{{\#items}}` 等转义的循环标记转换为字面 `{{#items}}` 文本
+///
+/// Returns `None` for texts without an escaped marker / 不含转义标记的文本返回 `None`
+pub(crate) fn unescape_loop_marker(text: &str) -> Option<String> {
+    text.contains(ESCAPED_LOOP_START_MARKER)
+        .then(|| text.replace(ESCAPED_LOOP_START_MARKER, LOOP_START_MARKER))
+}
+
+/// Rewrite every `{{field}}` into `[field]`, loop markers are kept / 将每个 `{{field}}` 改写为 `[field]`，保留循环标记
+///
+/// Used inside loop table rows / 用于循环表格行内
+pub(crate) fn braces_to_brackets(text: &str) -> Cow<'_, str> {
+    REGEX_BRACE.replace_all(text, "[$1]")
+}
+
+/// Await `future` and add the time it took to `total` / 等待 `future` 并将其耗时累加到 `total`
+///
+/// # Arguments / 参数
+/// * `total` - Accumulated duration of a stage / 某阶段的累计耗时
+/// * `future` - Work belonging to the stage / 属于该阶段的工作
+pub(crate) async fn timed<T>(total: &mut Duration, future: impl Future<Output = T>) -> T {
+    let started = Instant::now();
+    let output = future.await;
+    *total += started.elapsed();
+    output
+}
+
 /// Parse relationship XML content to get next available rId / 解析关系 XML 内容以获取下一个可用的 rId
 ///
 /// Scans all existing rId values and returns the next sequential ID / 扫描所有现有的 rId 值并返回下一个顺序 ID
@@ -115,9 +360,76 @@ pub(crate) fn parse_next_rid_from_rels(rels_content: &str) -> u32 {
     max_id + 1
 }
 
+/// Whether a package entry is a header or footer part / 包条目是否为页眉或页脚部件
+///
+/// Matches `word/header*.xml` and `word/footer*.xml` / 匹配 `word/header*.xml` 和 `word/footer*.xml`
+pub(crate) fn is_header_footer_part(name: &str) -> bool {
+    [HEADER_PATH_PREFIX, FOOTER_PATH_PREFIX]
+        .iter()
+        .any(|prefix| {
+            name.strip_prefix(prefix)
+                .and_then(|rest| rest.strip_suffix(XML_PART_EXTENSION))
+                .is_some_and(|stem| !stem.contains('/'))
+        })
+}
+
+/// Part a `word/_rels/*.rels` file belongs to / `word/_rels/*.rels` 文件所属的部件
+///
+/// # Returns / 返回
+/// `word/header1.xml` for `word/_rels/header1.xml.rels`, `None` for other entries / 对 `word/_rels/header1.xml.rels` 返回 `word/header1.xml`，其他条目返回 `None`
+pub(crate) fn rels_owner(rels_path: &str) -> Option<String> {
+    let file = rels_path
+        .strip_prefix(WORD_RELS_PATH_PREFIX)?
+        .strip_suffix(RELS_EXTENSION)?;
+    Some(format!("{}{}", WORD_PATH_PREFIX, file))
+}
+
+/// Relationship file path of a `word/` part / `word/` 部件的关系文件路径
+pub(crate) fn part_rels_path(part: &str) -> String {
+    let file = part.strip_prefix(WORD_PATH_PREFIX).unwrap_or(part);
+    format!("{}{}{}", WORD_RELS_PATH_PREFIX, file, RELS_EXTENSION)
+}
+
+/// Map the fields of a serialized struct to placeholder keys / 将序列化结构体的字段映射为占位符键
+///
+/// Arrays become loop data under `{{#field}}`, nested objects are joined with dots (`{{address.city}}`) and every other value becomes `{{field}}` / 数组作为循环数据放在 `{{#field}}` 下，嵌套对象用点连接（`{{address.city}}`），其他值作为 `{{field}}`
+///
+/// # Arguments / 参数
+/// * `prefix` - Dotted path of the enclosing object, empty at the top level / 外层对象的点路径，顶层为空
+/// * `object` - Fields to map / 要映射的字段
+/// * `placeholders` - Map receiving the placeholders / 接收占位符的映射
+pub(crate) fn collect_placeholders(
+    prefix: &str,
+    object: Map<String, Value>,
+    placeholders: &mut HashMap<String, Value>,
+) {
+    for (field, value) in object {
+        let path = if prefix.is_empty() {
+            field
+        } else {
+            format!("{}.{}", prefix, field)
+        };
+        match value {
+            Value::Object(nested) => collect_placeholders(&path, nested, placeholders),
+            Value::Array(_) => {
+                placeholders.insert(
+                    format!("{}{}{}", LOOP_START_MARKER, path, LOOP_END_MARKER),
+                    value,
+                );
+            }
+            _ => {
+                placeholders.insert(
+                    format!("{}{}{}", PLACEHOLDER_OPEN, path, PLACEHOLDER_CLOSE),
+                    value,
+                );
+            }
+        }
+    }
+}
+
 /// Flatten nested JSON structure into flat records / 将嵌套的 JSON 结构展平成扁平记录
 ///
-/// Converts nested objects and arrays into a list of flat key-value maps / 将嵌套对象和数组转换为扁平键值映射列表
+/// Converts nested objects and arrays into a list of flat key-value maps. Each record also keeps its position within every nested array under `<path>.$index` (e.g. `pets.toys.$index`) / 将嵌套对象和数组转换为扁平键值映射列表。每条记录还会在 `<path>.$index`（例如 `pets.toys.$index`）下保存其在各嵌套数组中的位置
 ///
 /// # Example / 示例
 /// ```ignore
@@ -148,9 +460,14 @@ pub(crate) fn flatten_json(value: &Value) -> Vec<HashMap<String, Value>> {
                 match val {
                     // Arrays and objects are processed recursively / 数组和对象递归处理
                     Value::Array(arr) if !arr.is_empty() => {
-                        for item in arr {
+                        for (position, item) in arr.iter().enumerate() {
                             // Recursively flatten / 递归展平
                             for mut sub_record in flatten_json(item) {
+                                // Position within this array / 在此数组中的位置
+                                sub_record.insert(
+                                    ROW_INDEX_KEY.to_string(),
+                                    Value::String(position.to_string()),
+                                );
                                 merge_record_with_prefix(key, &mut record, &mut sub_record);
                                 new_records.push(record.clone());
                             }
@@ -213,3 +530,267 @@ fn merge_record_with_prefix(
         base.insert(new_key, v);
     }
 }
+
+/// Sort loop items by a field, e.g. `price:desc` / 按字段对循环项排序，例如 `price:desc`
+///
+/// Numbers compare numerically and other values as text. Items without the field are placed last. The direction defaults to ascending / 数字按数值比较，其他值按文本比较。缺少该字段的项排在最后。排序方向默认为升序
+///
+/// # Arguments / 参数
+/// * `items` - Loop items / 循环项
+/// * `directive` - Field path, optionally followed by `:asc` or `:desc` / 字段路径，可选地后跟 `:asc` 或 `:desc`
+pub(crate) fn sort_by_field(items: &mut [&Value], directive: &str) {
+    let (field, descending) = match directive.rsplit_once(LOOP_SORT_DIRECTION_SEPARATOR) {
+        Some((field, LOOP_SORT_DESCENDING)) => (field, true),
+        Some((field, LOOP_SORT_ASCENDING)) => (field, false),
+        _ => (directive, false),
+    };
+    let lookup = |item: &Value| -> Option<Value> {
+        field
+            .split('.')
+            .try_fold(item, |value, key| value.get(key))
+            .filter(|value| !value.is_null())
+            .cloned()
+    };
+
+    // Stable sort keeps the data order of equal fields / 稳定排序保持相同字段值的数据顺序
+    items.sort_by_cached_key(|item| SortKey(lookup(item), descending));
+}
+
+/// Ordering of a sort field, missing values last in both directions / 排序字段的顺序，两种方向下缺失值都排在最后
+struct SortKey(Option<Value>, bool);
+
+impl PartialEq for SortKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for SortKey {}
+
+impl PartialOrd for SortKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SortKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let ordering = match (&self.0, &other.0) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Greater,
+            (Some(_), None) => return Ordering::Less,
+            (Some(Value::Number(a)), Some(Value::Number(b))) => {
+                let (a, b) = (a.as_f64().unwrap_or(0.0), b.as_f64().unwrap_or(0.0));
+                a.total_cmp(&b)
+            }
+            (Some(Value::String(a)), Some(Value::String(b))) => a.cmp(b),
+            (Some(a), Some(b)) => a.to_string().cmp(&b.to_string()),
+        };
+        if self.1 { ordering.reverse() } else { ordering }
+    }
+}
+
+/// Partition loop items by the value of a field, in order of first appearance / 按字段值对循环项分组，按首次出现的顺序排列
+///
+/// Items keep their order within a group. Items without the field form a group with an empty value / 组内各项保持原有顺序。缺少该字段的项组成值为空的分组
+///
+/// # Arguments / 参数
+/// * `items` - Flattened loop items / 展平后的循环项
+/// * `field` - Flattened key to group by / 用于分组的展平键
+pub(crate) fn group_by_field(
+    items: Vec<HashMap<String, Value>>,
+    field: &str,
+) -> Vec<(String, Vec<HashMap<String, Value>>)> {
+    let mut groups: Vec<(String, Vec<HashMap<String, Value>>)> = Vec::new();
+    for item in items {
+        let value = match item.get(field) {
+            None | Some(Value::Null) => String::new(),
+            Some(Value::String(s)) => s.clone(),
+            Some(value) => value.to_string(),
+        };
+        match groups.iter_mut().find(|(group, _)| *group == value) {
+            Some((_, members)) => members.push(item),
+            None => groups.push((value, vec![item])),
+        }
+    }
+    groups
+}
+
+/// Copy data fields of a loop item under the template keys mapped to them / 将循环项的数据字段复制到映射给它们的模板键下
+///
+/// Template keys may be written with or without brackets; data keys are the flattened item keys such as `product.name` / 模板键可带或不带方括号；数据键为展平后的项键，例如 `product.name`
+///
+/// # Arguments / 参数
+/// * `item` - Flattened loop item / 展平后的循环项
+/// * `columns` - Template key to data key / 模板键到数据键的映射
+pub(crate) fn map_columns(item: &mut HashMap<String, Value>, columns: &HashMap<String, String>) {
+    for (template_key, data_key) in columns {
+        if let Some(value) = item.get(data_key).cloned() {
+            item.insert(template_key.trim_matches(['[', ']']).to_string(), value);
+        }
+    }
+}
+
+/// Replace values of mapped keys by their configured display text / 将映射键的值替换为配置的显示文本
+///
+/// Strings are matched as is, booleans and numbers by their JSON text (e.g. `true`); other values and unmatched ones are kept / 字符串按原样匹配，布尔值和数字按其 JSON 文本（例如 `true`）匹配；其他值和未匹配的值保持不变
+///
+/// # Arguments / 参数
+/// * `values` - Placeholder values or a flattened loop item / 占位符值或展平后的循环项
+/// * `maps` - Key to value mapping, keys may be written with or without brackets / 键到值映射的映射，键可带或不带括号
+/// * `braced` - Whether `values` is keyed by `{{key}}` placeholders / `values` 是否以 `{{key}}` 占位符为键
+pub(crate) fn map_values(
+    values: &mut HashMap<String, Value>,
+    maps: &HashMap<String, HashMap<String, String>>,
+    braced: bool,
+) {
+    for (key, map) in maps {
+        let key = key.trim_matches(['[', ']', '{', '}']);
+        let value = if braced {
+            values.get_mut(&format!("{}{}{}", PLACEHOLDER_OPEN, key, PLACEHOLDER_CLOSE))
+        } else {
+            values.get_mut(key)
+        };
+        let Some(value) = value else {
+            continue;
+        };
+        let text = match value {
+            Value::String(text) => Cow::Borrowed(text.as_str()),
+            Value::Bool(_) | Value::Number(_) => Cow::Owned(value.to_string()),
+            _ => continue,
+        };
+        if let Some(mapped) = map.get(text.as_ref()).cloned() {
+            *value = Value::String(mapped);
+        }
+    }
+}
+
+/// Format the current local time for a `[$now:pattern]` marker / 为 `[$now:pattern]` 标记格式化当前本地时间
+///
+/// `[$now]` uses `%Y-%m-%d`. Texts that are not a marker or carry an invalid strftime pattern return `None` / `[$now]` 使用 `%Y-%m-%d`。不是标记或 strftime 格式无效的文本返回 `None`
+///
+/// # Arguments / 参数
+/// * `text` - Whole text of the element / 元素的完整文本
+#[cfg(feature = "dates")]
+pub(crate) fn format_now(text: &str) -> Option<String> {
+    use crate::core::constant::{MARKER_CLOSE, MODIFIER_SEPARATOR, NOW_DEFAULT_FORMAT, NOW_MARKER};
+    use chrono::format::{Item, StrftimeItems};
+
+    let rest = text
+        .trim()
+        .strip_prefix(NOW_MARKER)?
+        .strip_suffix(MARKER_CLOSE)?;
+    let pattern = match rest.strip_prefix(MODIFIER_SEPARATOR) {
+        Some(pattern) => pattern,
+        None if rest.is_empty() => NOW_DEFAULT_FORMAT,
+        None => return None,
+    };
+    let items = StrftimeItems::new(pattern).collect::<Vec<_>>();
+    if items.iter().any(|item| matches!(item, Item::Error)) {
+        return None;
+    }
+    Some(
+        chrono::Local::now()
+            .format_with_items(items.into_iter())
+            .to_string(),
+    )
+}
+
+/// Evaluate a JSON value as a boolean condition / 将 JSON 值作为布尔条件求值
+///
+/// Missing, null, `false`, zero, empty strings, empty arrays and empty objects are falsy / 缺失、null、`false`、零、空字符串、空数组和空对象为假
+///
+/// # Arguments / 参数
+/// * `value` - Optional JSON value / 可选的 JSON 值
+#[inline]
+pub(crate) fn is_truthy(value: Option<&Value>) -> bool {
+    match value {
+        None | Some(Value::Null) => false,
+        Some(Value::Bool(b)) => *b,
+        Some(Value::Number(n)) => n.as_f64().map(|v| v != 0.0).unwrap_or(false),
+        Some(Value::String(s)) => !s.is_empty(),
+        Some(Value::Array(arr)) => !arr.is_empty(),
+        Some(Value::Object(obj)) => !obj.is_empty(),
+    }
+}
+
+/// Evaluate a simple comparison against a loop item / 针对循环项求值简单比较
+///
+/// Supports `==`, `!=`, `>`, `>=`, `<` and `<=`. Both sides are compared as numbers when they parse as numbers, otherwise as text. Without an operator the field is tested for truthiness / 支持 `==`、`!=`、`>`、`>=`、`<` 和 `<=`。两侧均可解析为数字时按数字比较，否则按文本比较。没有运算符时检测字段是否为真
+///
+/// # Arguments / 参数
+/// * `condition` - Condition such as `qty==0` or `status!='done'` / 条件，例如 `qty==0` 或 `status!='done'`
+/// * `item` - Flattened loop item / 展平后的循环项
+pub(crate) fn evaluate_condition(condition: &str, item: &HashMap<String, Value>) -> bool {
+    let Some(caps) = REGEX_CONDITION.captures(condition) else {
+        return is_truthy(item.get(condition.trim()));
+    };
+    let left = match item.get(&caps[1]) {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(value) => value.to_string(),
+    };
+    let right = caps[3].trim_matches(|c| c == '\'' || c == '"');
+
+    let ordering = match (left.trim().parse::<f64>(), right.parse::<f64>()) {
+        (Ok(l), Ok(r)) => l.partial_cmp(&r),
+        _ => Some(left.as_str().cmp(right)),
+    };
+    let Some(ordering) = ordering else {
+        return false;
+    };
+    match &caps[2] {
+        "==" => ordering.is_eq(),
+        "!=" => ordering.is_ne(),
+        ">" => ordering.is_gt(),
+        ">=" => ordering.is_ge(),
+        "<" => ordering.is_lt(),
+        _ => ordering.is_le(),
+    }
+}
+
+/// Set the language of `w:themeFontLang` in settings XML / 设置 settings XML 中 `w:themeFontLang` 的语言
+///
+/// Settings without the element are returned unchanged / 不包含该元素的设置原样返回
+///
+/// # Arguments / 参数
+/// * `settings` - Content of word/settings.xml / word/settings.xml 的内容
+/// * `language` - Language tag (e.g. "en-US") / 语言标签（例如 "en-US"）
+pub(crate) fn patch_theme_font_lang(settings: &str, language: &str) -> String {
+    let value = format!(r#" w:val="{}""#, language.replace('"', "&quot;"));
+    REGEX_THEME_LANG
+        .replace(settings, |caps: &regex::Captures| {
+            let element = &caps[0];
+            if REGEX_VAL.is_match(element) {
+                REGEX_VAL.replace(element, value.as_str()).into_owned()
+            } else {
+                element.replacen("<w:themeFontLang", &format!("<w:themeFontLang{}", value), 1)
+            }
+        })
+        .into_owned()
+}
+
+/// Check that an OOXML fragment is well-formed, every element being closed in order / 检查 OOXML 片段格式正确，每个元素都按顺序闭合
+///
+/// Namespace prefixes are not resolved, so fragments may use `w:` without declaring it / 不解析命名空间前缀，因此片段可以使用未声明的 `w:`
+///
+/// # Arguments / 参数
+/// * `fragment` - Markup to check (e.g. `<w:r><w:t>Text</w:t></w:r>`) / 要检查的标记
+pub(crate) fn check_well_formed(fragment: &str) -> Result<(), quick_xml::Error> {
+    let mut reader = Reader::from_str(fragment);
+    let mut open = Vec::new();
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) => open.push(String::from_utf8_lossy(e.name().as_ref()).into_owned()),
+            Event::End(_) => {
+                open.pop();
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    match open.pop() {
+        Some(name) => Err(IllFormedError::MissingEndTag(name).into()),
+        None => Ok(()),
+    }
+}
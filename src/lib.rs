@@ -3,6 +3,17 @@ mod public;
 #[cfg(test)]
 mod tests;
 
+pub use public::alignment::CellAlignment;
+pub use public::capacity::CapacityHints;
+pub use public::compiled::CompiledTemplate;
 pub use public::docx::DOCX;
-pub use public::error::DocxError;
+pub use public::error::{DocxError, Limit};
+pub use public::inspect::{Change, TableInfo};
+pub use public::report::{
+    ColumnMerge, GenerationReport, GenerationWarning, StageTimings, TableMergeSummary,
+};
 pub use public::value_extern::ValueExt;
+// Zip timestamps for [`DOCX::set_entry_modified`] / 用于 [`DOCX::set_entry_modified`] 的 zip 时间戳
+pub use async_zip::{ZipDateTime, ZipDateTimeBuilder};
+// Token for [`DOCX::set_cancellation_token`] / 用于 [`DOCX::set_cancellation_token`] 的令牌
+pub use tokio_util::sync::CancellationToken;
@@ -0,0 +1,25 @@
+/// Horizontal alignment of the paragraphs in a table cell / 表格单元格中段落的水平对齐方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CellAlignment {
+    /// Align to the left edge / 左对齐
+    Left,
+    /// Center between the edges / 居中
+    Center,
+    /// Align to the right edge, e.g. for numbers / 右对齐，例如用于数字
+    Right,
+    /// Justify between both edges / 两端对齐
+    Justify,
+}
+
+impl CellAlignment {
+    /// Value of the `w:jc` element / `w:jc` 元素的值
+    #[inline]
+    pub(crate) fn jc_value(self) -> &'static str {
+        match self {
+            CellAlignment::Left => "left",
+            CellAlignment::Center => "center",
+            CellAlignment::Right => "right",
+            CellAlignment::Justify => "both",
+        }
+    }
+}
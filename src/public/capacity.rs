@@ -0,0 +1,37 @@
+use crate::core::constant::{
+    TYPICAL_COLUMN_COUNT, TYPICAL_DATA_ROW_COUNT, TYPICAL_HEADER_ROW_COUNT, TYPICAL_IMAGE_COUNT,
+    TYPICAL_OTHER_EVENT_COUNT, TYPICAL_ROW_EVENT_COUNT,
+};
+
+/// Capacities preallocated while processing, they only affect memory use and never the output / 处理期间预分配的容量，仅影响内存使用，不影响输出
+///
+/// Lower them for tiny documents or tight memory budgets, collections still grow as needed. Start from [`CapacityHints::default`] and adjust the fields / 对于很小的文档或内存预算紧张时可调低，集合仍会按需增长。从 [`CapacityHints::default`] 开始并调整各字段
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CapacityHints {
+    /// Header rows of a table / 表格的标题行数
+    pub header_rows: usize,
+    /// Data rows of a table / 表格的数据行数
+    pub data_rows: usize,
+    /// Non-row events of a table such as its properties / 表格的非行事件数，例如其属性
+    pub other_events: usize,
+    /// Events of a table row / 表格行的事件数
+    pub row_events: usize,
+    /// Cell values of a generated row / 生成行的单元格值数
+    pub columns: usize,
+    /// Images embedded in the document / 文档中嵌入的图片数
+    pub images: usize,
+}
+
+impl Default for CapacityHints {
+    fn default() -> Self {
+        Self {
+            header_rows: TYPICAL_HEADER_ROW_COUNT,
+            data_rows: TYPICAL_DATA_ROW_COUNT,
+            other_events: TYPICAL_OTHER_EVENT_COUNT,
+            row_events: TYPICAL_ROW_EVENT_COUNT,
+            columns: TYPICAL_COLUMN_COUNT,
+            images: TYPICAL_IMAGE_COUNT,
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::public::docx::DOCX;
+use crate::public::error::DocxError;
+use async_zip::tokio::read::seek::ZipFileReader;
+use bytes::Bytes;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::Arc;
+
+// Settings applied to the generator of every render / 应用于每次渲染生成器的设置
+type Configure = Arc<dyn Fn(&mut DOCX) + Send + Sync>;
+
+/// Template read once and rendered many times / 读取一次并多次渲染的模板
+///
+/// The template bytes are shared, every render creates its own generator with fresh relationship and image state, so one `Arc<CompiledTemplate>` can be rendered from many tasks at once / 模板字节共享，每次渲染创建自己的生成器以及全新的关系和图片状态，因此一个 `Arc<CompiledTemplate>` 可以同时在多个任务中渲染
+///
+/// # Example / 示例
+/// ```ignore
+/// let template = Arc::new(CompiledTemplate::compile("template/invoice.docx").await?);
+/// let bytes = template.render(&placeholders).await?;
+/// ```
+#[derive(Clone)]
+pub struct CompiledTemplate {
+    // Validated template package / 已校验的模板包
+    template: Bytes,
+
+    // Settings of the generator, if any / 生成器的设置（如有）
+    configure: Option<Configure>,
+}
+
+impl CompiledTemplate {
+    /// Read and validate a template file / 读取并校验模板文件
+    ///
+    /// # Arguments / 参数
+    /// * `input_path` - Path to the template DOCX file / 模板 DOCX 文件路径
+    ///
+    /// # Returns / 返回
+    /// * `Result<Self, DocxError>` - Compiled template or error, `DocxError::InvalidPackage` if the file is not a valid zip / 编译后的模板或错误，文件不是有效 zip 时为 `DocxError::InvalidPackage`
+    pub async fn compile(input_path: &str) -> Result<Self, DocxError> {
+        let template = tokio::fs::read(input_path).await?;
+        Self::from_bytes(Bytes::from(template)).await
+    }
+
+    /// Validate a template held in memory / 校验内存中的模板
+    ///
+    /// # Arguments / 参数
+    /// * `template` - Template DOCX bytes / 模板 DOCX 字节
+    pub async fn from_bytes(template: Bytes) -> Result<Self, DocxError> {
+        ZipFileReader::with_tokio(Cursor::new(template.clone()))
+            .await
+            .map_err(DocxError::from_package_error)?;
+        Ok(Self {
+            template,
+            configure: None,
+        })
+    }
+
+    /// Set up the generator of every render, e.g. to register a value handler / 设置每次渲染的生成器，例如注册值处理器
+    ///
+    /// # Arguments / 参数
+    /// * `configure` - Called with a fresh [`DOCX`] before each render / 每次渲染前以新的 [`DOCX`] 调用
+    pub fn with_options<F>(mut self, configure: F) -> Self
+    where
+        F: Fn(&mut DOCX) + Send + Sync + 'static,
+    {
+        self.configure = Some(Arc::new(configure));
+        self
+    }
+
+    /// Render the template into DOCX bytes / 将模板渲染为 DOCX 字节
+    ///
+    /// # Arguments / 参数
+    /// * `placeholders` - HashMap of placeholder values / 占位符值的 HashMap
+    ///
+    /// # Returns / 返回
+    /// * `Result<Vec<u8>, DocxError>` - Generated DOCX bytes or error / 生成的 DOCX 字节或错误
+    pub async fn render(
+        &self,
+        placeholders: &HashMap<String, Value>,
+    ) -> Result<Vec<u8>, DocxError> {
+        let mut docx = DOCX::default();
+        if let Some(configure) = &self.configure {
+            configure(&mut docx);
+        }
+        docx.generate_bytes(self.template.clone(), placeholders)
+            .await
+    }
+}
@@ -1,22 +1,54 @@
+use crate::core::channel_writer::ChannelWriter;
 use crate::core::constant::*;
+use crate::core::content_type_manager::ContentTypeManager;
 use crate::core::default_handler::DefaultValueHandler;
-use crate::core::docx_processor::DocxProcessor;
-use crate::core::image_manager::ImageManager;
+use crate::core::diagnostics::scan_template;
+use crate::core::document_buffer::DocumentBuffer;
+use crate::core::docx_processor::{DocxProcessor, ImageMap, ImageResolver, ItemTransform};
+use crate::core::fallback::Fallback;
+use crate::core::flat_opc::write_flat_opc;
+use crate::core::image_manager::{ImageManager, ImageTransform};
+use crate::core::image_options::parse_hex_color;
+use crate::core::include::{include_paths, relationship_ids, splice_includes};
+use crate::core::paragraph::ParagraphContext;
+use crate::core::part_processor::replace_part_text;
+use crate::core::part_sink::{LimitedWriter, OutputBudget, PartSink, part_path, zip_entry};
 use crate::core::relationship_manager::RelationshipManager;
+use crate::core::section::extract_section;
+use crate::core::transform::{Transforms, builtin_transforms};
+use crate::core::utils::{
+    collect_placeholders, is_header_footer_part, map_values, part_rels_path, patch_theme_font_lang,
+    rels_owner, timed,
+};
+use crate::public::alignment::CellAlignment;
+use crate::public::capacity::CapacityHints;
+use crate::public::error::DocxError;
+use crate::public::inspect::{Change, TableInfo};
+use crate::public::report::{GenerationReport, GenerationWarning};
 use crate::public::value_extern::ValueExt;
-use async_zip::error::ZipError;
 use async_zip::tokio::read::seek::ZipFileReader;
 use async_zip::tokio::write::ZipFileWriter;
-use async_zip::{Compression, ZipEntryBuilder};
+use async_zip::{Compression, ZipDateTime, ZipDateTimeBuilder};
 use bytes::Bytes;
+use serde::Serialize;
+use serde::ser::Error as _;
 use serde_json::Value;
+use std::borrow::Cow;
 use std::collections::HashMap;
-use std::env::temp_dir;
+use std::io::Cursor;
 use std::marker::PhantomData;
+use std::mem::take;
 use std::path::{Path, PathBuf};
-use tokio::fs::{File as AsyncFile, create_dir_all, remove_file};
-use tokio::io::{AsyncReadExt, BufReader, BufWriter};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::fs::{File as AsyncFile, create_dir_all, remove_file, rename, write};
+use tokio::io::{
+    AsyncBufRead, AsyncReadExt, AsyncSeek, AsyncWrite, AsyncWriteExt, BufReader, BufWriter, Sink,
+    sink,
+};
+use tokio::sync::mpsc::Sender;
 use tokio_util::compat::{FuturesAsyncReadCompatExt, FuturesAsyncWriteCompatExt};
+use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
 /// Main DOCX processor struct / 主 DOCX 处理器结构体
@@ -24,12 +56,136 @@ pub struct DOCX<'a> {
     // DPI (dots per inch) for image rendering / 图片渲染的 DPI（每英寸点数）
     dpi: f32,
 
+    // DPI overrides keyed by part name / 按部件名称索引的 DPI 覆盖
+    part_dpis: HashMap<String, f32>,
+
+    // Scale images down to the size limit / 将图片缩小到尺寸上限
+    image_autoscale: bool,
+
+    // Size limits of the longer side of landscape and portrait images in EMU / 横向和纵向图片较长边的尺寸上限（EMU）
+    image_max_emu: (u32, u32),
+
+    // Width and height limits of images in EMU, 0 for no limit / 图片的宽度和高度上限（EMU），0 表示不限制
+    image_max_box: Option<(u32, u32)>,
+
     // Custom cell value handler for placeholder replacement / 用于占位符替换的自定义单元格值处理器
-    cell_handler: Option<Box<dyn ValueExt + Send>>,
+    cell_handler: Arc<dyn ValueExt + Send>,
+
+    // Sources consulted when the cell handler does not find a placeholder / 单元格处理器未找到占位符时查询的来源
+    fallbacks: Arc<Vec<Fallback>>,
+
+    // Named transforms such as `[upper:key]` / `[upper:key]` 等命名变换
+    transforms: Arc<Transforms>,
 
     // Flag to skip w:t events during image processing / 在图片处理期间跳过 w:t 事件的标志
     skip_w_t_events: bool,
 
+    // Extra content type defaults (extension, content type) / 额外的内容类型默认值（扩展名，内容类型）
+    content_types: Vec<(String, String)>,
+
+    // Extra files injected into the package (path, content) / 注入包中的额外文件（路径，内容）
+    extra_files: Vec<(String, Bytes)>,
+
+    // Substitute placeholders found in element attributes / 替换元素属性中的占位符
+    replace_in_attributes: bool,
+
+    // Report of the last generation / 上次生成的报告
+    report: GenerationReport,
+
+    // Resolver for image references / 图片引用解析器
+    image_resolver: Option<ImageResolver>,
+
+    // Directory `defaultfile=` images are read from, disabled when unset / 读取 `defaultfile=` 图片的目录，未设置时禁用
+    image_default_dir: Option<PathBuf>,
+
+    // Post-processing of every embedded image / 每个嵌入图片的后处理
+    image_transform: Option<ImageTransform>,
+
+    // Remove metadata from embedded JPEG and PNG images / 移除嵌入 JPEG 和 PNG 图片中的元数据
+    strip_metadata: bool,
+
+    // Transform applied to each loop item / 应用于每个循环项的转换
+    item_transform: Option<ItemTransform>,
+
+    // Cell alignment of loop table columns, keyed by loop key / 循环表格列的单元格对齐方式，按循环键索引
+    column_alignments: HashMap<String, Vec<(usize, CellAlignment)>>,
+
+    // Template key to data key of loop table columns, keyed by loop key / 循环表格列的模板键到数据键映射，按循环键索引
+    column_maps: HashMap<String, HashMap<String, String>>,
+
+    // Display text of mapped values per key / 每个键的映射值显示文本
+    value_maps: HashMap<String, HashMap<String, String>>,
+
+    // Keep every generated loop row on one page / 使每个生成的循环行保持在同一页
+    cant_split_rows: bool,
+
+    // Repeat the header rows of loop tables on each page / 在每页重复循环表格的标题行
+    repeat_header_rows: bool,
+
+    // Fill color of every other generated loop row / 每隔一个生成循环行的填充颜色
+    zebra_shading: Option<String>,
+
+    // Continue past ill-formed XML in the document body / 在文档正文中遇到格式错误的 XML 时继续
+    lenient_xml: bool,
+
+    // Scan the template for constructs that keep placeholders from being filled / 扫描模板中导致占位符无法填充的结构
+    diagnostics: bool,
+
+    // Accept `{{field}}` and `[field]` in every context / 在任何上下文中都接受 `{{field}}` 和 `[field]`
+    unified_syntax: bool,
+
+    // Fail on loop items with inconsistent key sets / 循环项键集不一致时失败
+    strict_loop_keys: bool,
+
+    // Fail on placeholders without a value / 占位符没有值时失败
+    strict: bool,
+
+    // Drop loop rows whose cells all resolve to empty / 丢弃所有单元格都解析为空的循环行
+    skip_empty_rows: bool,
+
+    // Compare merge candidates trimmed and case-folded / 合并候选值去除空白并忽略大小写后比较
+    normalize_merge: bool,
+
+    // Maximum generated loop rows over all tables / 所有表格生成的最大循环行数
+    max_rows: Option<usize>,
+
+    // Maximum uncompressed bytes of the written parts / 写入部件的最大未压缩字节数
+    max_output_bytes: Option<usize>,
+
+    // Capacities preallocated while processing / 处理期间预分配的容量
+    capacity_hints: CapacityHints,
+
+    // Comment of the output zip archive / 输出 zip 归档的注释
+    zip_comment: Option<String>,
+
+    // Modification time of the output zip entries / 输出 zip 条目的修改时间
+    entry_modified: Option<ZipDateTime>,
+
+    // Byte-identical output for the same template and data / 相同模板和数据生成字节相同的输出
+    reproducible: bool,
+
+    // Write `[raw:key]` values as literal OOXML / 将 `[raw:key]` 的值作为字面 OOXML 写入
+    allow_raw_ooxml: bool,
+
+    // Reject raw OOXML values that are not well-formed / 拒绝格式不正确的原始 OOXML 值
+    validate_raw_ooxml: bool,
+
+    // Token aborting generation when cancelled / 取消时中止生成的令牌
+    cancellation: Option<CancellationToken>,
+
+    // Language of inserted runs and settings / 插入运行和设置的语言
+    language: Option<String>,
+
+    // Bookmark whose range is the only body content generated / 仅生成其范围内正文内容的书签
+    section: Option<String>,
+
+    // Substitute placeholders in chart parts / 替换图表部件中的占位符
+    replace_in_charts: bool,
+
+    // JPEG recompression quality, `None` keeps images as-is / JPEG 重新压缩质量，`None` 保持图片原样
+    #[cfg(feature = "image")]
+    jpeg_quality: Option<u8>,
+
     // Phantom data for lifetime parameter / 生命周期参数的幽灵数据
     _marker: PhantomData<&'a ()>,
 }
@@ -38,14 +194,68 @@ impl<'a> Default for DOCX<'a> {
     fn default() -> Self {
         Self {
             // Use default value handler / 使用默认值处理器
-            cell_handler: Some(Box::new(DefaultValueHandler)),
+            cell_handler: Arc::new(DefaultValueHandler),
+            fallbacks: Arc::default(),
+            transforms: Arc::new(builtin_transforms()),
 
             // Use default DPI constant / 使用默认 DPI 常量
             dpi: DEFAULT_DPI,
+            part_dpis: HashMap::new(),
+            image_autoscale: true,
+            image_max_emu: (MAX_EMU as u32, MAX_EMU as u32),
+            image_max_box: None,
 
             // Initially not skipping w:t events / 初始时不跳过 w:t 事件
             skip_w_t_events: false,
 
+            content_types: Vec::new(),
+
+            extra_files: Vec::new(),
+
+            // Attribute placeholders are only reported by default / 默认仅报告属性中的占位符
+            replace_in_attributes: false,
+
+            report: GenerationReport::default(),
+
+            image_resolver: None,
+            image_default_dir: None,
+            image_transform: None,
+            strip_metadata: false,
+            item_transform: None,
+            column_alignments: HashMap::new(),
+            column_maps: HashMap::new(),
+            value_maps: HashMap::new(),
+            cant_split_rows: false,
+            repeat_header_rows: false,
+            zebra_shading: None,
+            lenient_xml: false,
+            diagnostics: false,
+            unified_syntax: false,
+
+            // Inconsistent loop items are only reported by default / 默认仅报告不一致的循环项
+            strict_loop_keys: false,
+            strict: false,
+            skip_empty_rows: false,
+            normalize_merge: false,
+            max_rows: None,
+            max_output_bytes: None,
+            capacity_hints: CapacityHints::default(),
+            zip_comment: None,
+            entry_modified: None,
+            reproducible: false,
+            allow_raw_ooxml: false,
+            validate_raw_ooxml: false,
+            cancellation: None,
+
+            language: None,
+            section: None,
+
+            // Chart parts pass through untouched by default / 默认图表部件原样透传
+            replace_in_charts: false,
+
+            #[cfg(feature = "image")]
+            jpeg_quality: None,
+
             _marker: PhantomData,
         }
     }
@@ -57,149 +267,1556 @@ impl<'a> DOCX<'a> {
         self.dpi = dpi;
     }
 
+    /// Override the DPI of the images in one part, e.g. print-resolution logos in a header / 覆盖某个部件中图片的 DPI，例如页眉中打印分辨率的徽标
+    ///
+    /// Parts without an override use the DPI of [`DOCX::set_dpi`] / 没有覆盖的部件使用 [`DOCX::set_dpi`] 的 DPI
+    ///
+    /// # Arguments / 参数
+    /// * `part` - Part name inside the package (e.g. "word/header1.xml") / 包内的部件名称（例如 "word/header1.xml"）
+    /// * `dpi` - DPI of the images in that part / 该部件中图片的 DPI
+    pub fn set_part_dpi(&mut self, part: &str, dpi: f32) {
+        self.part_dpis.insert(part.to_string(), dpi);
+    }
+
+    /// Scale images whose longer side exceeds the size limit down to it / 将较长边超过尺寸上限的图片缩小到上限
+    ///
+    /// Enabled by default. When disabled, images are inserted at the full size computed from their pixels and DPI / 默认启用。禁用时图片按其像素和 DPI 计算出的完整尺寸插入
+    pub fn set_image_autoscale(&mut self, enabled: bool) {
+        self.image_autoscale = enabled;
+    }
+
+    /// Set separate size limits for landscape and portrait images / 为横向和纵向图片分别设置尺寸上限
+    ///
+    /// The longer side of an image wider than high is scaled down to `landscape`, of any other image to `portrait`. Both default to 1800000 EMU; 914400 EMU is one inch. Ignored once [`DOCX::set_max_image_size`] is set / 宽大于高的图片较长边缩小到 `landscape`，其他图片缩小到 `portrait`。两者默认均为 1800000 EMU；914400 EMU 为一英寸。设置 [`DOCX::set_max_image_size`] 后忽略
+    ///
+    /// # Arguments / 参数
+    ///  * `landscape` - Limit of landscape images in EMU / 横向图片的上限（EMU）
+    ///  * `portrait` - Limit of portrait and square images in EMU / 纵向和正方形图片的上限（EMU）
+    pub fn set_image_orientation_limits(&mut self, landscape: u32, portrait: u32) {
+        self.image_max_emu = (landscape, portrait);
+    }
+
+    /// Set the width and height images are scaled down to fit, e.g. the text area for full-page diagrams / 设置图片缩小后需适应的宽度和高度，例如整页图表使用正文区域
+    ///
+    /// Takes precedence over the longer side limits of [`DOCX::set_image_orientation_limits`] and keeps the aspect ratio. A value of 0 leaves that side unlimited, so `(0, 0)` never scales down / 优先于 [`DOCX::set_image_orientation_limits`] 的较长边上限并保持宽高比。值为 0 表示该边不受限制，因此 `(0, 0)` 从不缩小
+    ///
+    /// # Arguments / 参数
+    ///  * `width_emu` - Maximum width in EMU / 最大宽度（EMU）
+    ///  * `height_emu` - Maximum height in EMU / 最大高度（EMU）
+    pub fn set_max_image_size(&mut self, width_emu: u32, height_emu: u32) {
+        self.image_max_box = Some((width_emu, height_emu));
+    }
+
     /// Set custom cell value handler / 设置自定义单元格值处理器
     /// # Arguments / 参数
     ///  * `handler` - Custom cell value handle / 自定义单元格处理器
     ///
     /// see [`DefaultValueHandler`]
     pub fn set_cell_handler(&mut self, handler: Box<dyn ValueExt + Send>) {
-        self.cell_handler = Some(handler);
+        self.cell_handler = Arc::from(handler);
+    }
+
+    /// Append a handler consulted when the previous ones do not find a placeholder / 追加在前面的处理器未找到占位符时查询的处理器
+    ///
+    /// Handlers are asked through [`ValueExt::lookup`] and [`ValueExt::lookup_in_table`] in registration order after the cell handler; a placeholder no one finds is blank / 在单元格处理器之后按注册顺序通过 [`ValueExt::lookup`] 和 [`ValueExt::lookup_in_table`] 查询处理器；无人找到的占位符置空
+    ///
+    /// # Arguments / 参数
+    /// * `handler` - Fallback handler, given the same placeholder values / 后备处理器，使用相同的占位符值
+    pub fn add_fallback_handler(&mut self, handler: Box<dyn ValueExt + Send>) {
+        Arc::make_mut(&mut self.fallbacks).push(Fallback::Handler(Arc::from(handler)));
+    }
+
+    /// Append a map of default values consulted when the previous sources do not find a placeholder / 追加在前面的来源未找到占位符时查询的默认值映射
+    ///
+    /// Keys follow the placeholder map (e.g. "{{name}}"), inside loop rows they are the item fields (e.g. "qty") / 键与占位符映射一致（例如 "{{name}}"），在循环行中为项字段（例如 "qty"）
+    ///
+    /// # Arguments / 参数
+    /// * `values` - Default values / 默认值
+    pub fn add_fallback_values(&mut self, values: HashMap<String, Value>) {
+        Arc::make_mut(&mut self.fallbacks).push(Fallback::Values(values));
+    }
+
+    /// Register a named transform used as `[name:key]` in loop rows or `{{name:key}}` in text / 注册在循环行中以 `[name:key]`、在文本中以 `{{name:key}}` 使用的命名变换
+    ///
+    /// The transform receives the resolved value of `key`. `upper` and `lower` are registered by default; registering an existing name replaces it / 变换接收 `key` 的解析值。默认注册 `upper` 和 `lower`；注册已存在的名称会替换它
+    ///
+    /// # Arguments / 参数
+    /// * `name` - Transform name / 变换名称
+    /// * `transform` - Function producing the replacement text / 生成替换文本的函数
+    pub fn register_transform<F>(&mut self, name: &str, transform: F)
+    where
+        F: Fn(&str) -> String + Send + Sync + 'static,
+    {
+        Arc::make_mut(&mut self.transforms).insert(name.to_string(), Arc::new(transform));
+    }
+
+    /// Register a content type for a file extension / 为文件扩展名注册内容类型
+    ///
+    /// The entry is merged into `[Content_Types].xml` during generation unless the extension is already declared / 生成时合并到 `[Content_Types].xml` 中，除非该扩展名已声明
+    ///
+    /// # Arguments / 参数
+    ///  * `ext` - File extension, with or without leading dot / 文件扩展名，可带或不带前导点
+    ///  * `content_type` - MIME content type / MIME 内容类型
+    pub fn register_content_type(&mut self, ext: &str, content_type: &str) {
+        self.content_types.push((
+            ext.trim_start_matches('.').to_string(),
+            content_type.to_string(),
+        ));
+    }
+
+    /// Add an extra file to the generated package / 向生成的包中添加额外文件
+    ///
+    /// A template entry with the same path is replaced / 替换模板中相同路径的条目
+    ///
+    /// # Arguments / 参数
+    ///  * `path` - Path inside the package (e.g. "word/custom/data.bin") / 包内路径（例如 "word/custom/data.bin"）
+    ///  * `content` - File content / 文件内容
+    pub fn add_extra_file(&mut self, path: &str, content: impl Into<Bytes>) {
+        self.extra_files.push((path.to_string(), content.into()));
+    }
+
+    /// Enable placeholder substitution in element attributes / 启用元素属性中的占位符替换
+    ///
+    /// Attribute placeholders are always reported in [`GenerationReport`], this flag additionally replaces them / 属性中的占位符总会记录在 [`GenerationReport`] 中，此标志会额外替换它们
+    ///
+    /// Both `{{key}}` and a whole `[key]` attribute value are recognised / 同时识别 `{{key}}` 和整个属性值为 `[key]` 的形式
+    pub fn set_replace_in_attributes(&mut self, enabled: bool) {
+        self.replace_in_attributes = enabled;
+    }
+
+    /// Set a resolver loading images on demand / 设置按需加载图片的解析器
+    ///
+    /// For image placeholders (`{{@key}}`, `[@key]`) whose value is not base64, the value is passed to the resolver as a reference and the returned bytes are embedded. The resolver is only called for images actually rendered / 对于值不是 base64 的图片占位符（`{{@key}}`、`[@key]`），该值会作为引用传给解析器，并嵌入返回的字节。解析器仅对实际渲染的图片调用
+    ///
+    /// # Arguments / 参数
+    ///  * `resolver` - Maps a reference to encoded image bytes, `None` keeps the reference as text / 将引用映射为编码图片字节，返回 `None` 时保留引用文本
+    pub fn set_image_resolver<F>(&mut self, resolver: F)
+    where
+        F: Fn(&str) -> Option<Vec<u8>> + Send + Sync + 'static,
+    {
+        self.image_resolver = Some(Arc::new(resolver));
+    }
+
+    /// Allow `defaultfile=` image options to read files from a directory / 允许 `defaultfile=` 图片选项从目录读取文件
+    ///
+    /// Paths in the template are resolved against `dir`, and files outside it are rejected. Without a directory the option is skipped; skipped files are reported as [`GenerationWarning::ImageDefaultSkipped`] / 模板中的路径相对于 `dir` 解析，目录之外的文件会被拒绝。未设置目录时跳过该选项；跳过的文件记录为 [`GenerationWarning::ImageDefaultSkipped`]
+    ///
+    /// # Arguments / 参数
+    ///  * `dir` - Base directory of default image files / 默认图片文件的基础目录
+    pub fn set_image_default_dir(&mut self, dir: impl AsRef<Path>) {
+        self.image_default_dir = Some(dir.as_ref().to_path_buf());
+    }
+
+    /// Set a transform applied to the decoded bytes of every embedded image / 设置应用于每个嵌入图片解码字节的变换
+    ///
+    /// Runs before the image is measured and registered, so resizing, watermarking or stripping metadata is reflected in the document. The returned bytes must still be a PNG, JPEG or SVG image / 在测量和注册图片之前运行，因此缩放、加水印或去除元数据都会反映在文档中。返回的字节仍须为 PNG、JPEG 或 SVG 图片
+    ///
+    /// # Arguments / 参数
+    ///  * `transform` - Maps the decoded image bytes to the bytes to embed / 将解码的图片字节映射为要嵌入的字节
+    pub fn set_image_transform<F>(&mut self, transform: F)
+    where
+        F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static,
+    {
+        self.image_transform = Some(Arc::new(transform));
+    }
+
+    /// Remove metadata from embedded images / 移除嵌入图片中的元数据
+    ///
+    /// Drops EXIF, XMP, IPTC and comment segments from JPEGs and text, EXIF and time chunks from PNGs, for example the GPS position of uploaded photos. Pixels are not re-encoded. Off by default / 丢弃 JPEG 中的 EXIF、XMP、IPTC 和注释段，以及 PNG 中的文本、EXIF 和时间块，例如上传照片的 GPS 位置。不会重新编码像素。默认关闭
+    pub fn set_strip_metadata(&mut self, enabled: bool) {
+        self.strip_metadata = enabled;
+    }
+
+    /// Recompress embedded JPEG images at the given quality / 以指定质量重新压缩嵌入的 JPEG 图片
+    ///
+    /// Quality ranges from 1 to 100; an image is only replaced when the re-encoded bytes are smaller / 质量范围为 1 到 100；仅当重新编码的字节更小时才替换图片
+    #[cfg(feature = "image")]
+    pub fn set_jpeg_quality(&mut self, quality: u8) {
+        self.jpeg_quality = Some(quality);
+    }
+
+    /// Set a transform applied to each loop item before it is rendered / 设置在渲染前应用于每个循环项的转换
+    ///
+    /// The item is passed as a flattened JSON object (e.g. `{"name": .., "pets.name": ..}`) so derived fields can be added in Rust instead of the template. `[skipif:...]` conditions see the transformed item / 循环项以展平的 JSON 对象形式传入（例如 `{"name": .., "pets.name": ..}`），以便在 Rust 中而非模板中添加派生字段。`[skipif:...]` 条件看到的是转换后的项
+    ///
+    /// # Arguments / 参数
+    ///  * `transform` - Mutates the item in place, an item left as a non-object is reported and not rendered / 就地修改循环项，变为非对象的项会被报告且不被渲染
+    pub fn set_item_transform<F>(&mut self, transform: F)
+    where
+        F: Fn(&mut Value) + Send + Sync + 'static,
+    {
+        self.item_transform = Some(Arc::new(transform));
+    }
+
+    /// Align a column of the data rows of a loop table / 对齐循环表格数据行中的一列
+    ///
+    /// Sets `w:jc` on the paragraphs of the cell, replacing any alignment from the template. A marker such as `[r:amount]` in the data row does the same from the template side / 设置单元格中段落的 `w:jc`，替换模板中的对齐方式。数据行中的 `[r:amount]` 等标记可在模板侧实现相同效果
+    ///
+    /// # Arguments / 参数
+    ///  * `table_key` - Loop key of the table (e.g. "{{#items}}") / 表格的循环键（例如 "{{#items}}"）
+    ///  * `column` - Zero-based cell index within each data row / 每个数据行内从零开始的单元格索引
+    ///  * `alignment` - Alignment to apply / 要应用的对齐方式
+    pub fn set_column_alignment(
+        &mut self,
+        table_key: &str,
+        column: usize,
+        alignment: CellAlignment,
+    ) {
+        let columns = self
+            .column_alignments
+            .entry(table_key.to_string())
+            .or_default();
+        columns.retain(|(index, _)| *index != column);
+        columns.push((column, alignment));
+    }
+
+    /// Map template keys of a loop table to differently named data keys / 将循环表格的模板键映射到名称不同的数据键
+    ///
+    /// Each generated row resolves a template key such as `[col1]` from the mapped field of the flattened item (e.g. `product.name`), so templates need not follow the shape of the data / 每个生成行从展平项的映射字段（例如 `product.name`）解析 `[col1]` 等模板键，使模板无需遵循数据结构
+    ///
+    /// # Arguments / 参数
+    ///  * `table_key` - Loop key of the table (e.g. "{{#items}}") / 表格的循环键（例如 "{{#items}}"）
+    ///  * `columns` - Template key to data key / 模板键到数据键的映射
+    pub fn set_column_map(&mut self, table_key: &str, columns: HashMap<String, String>) {
+        self.column_maps.insert(table_key.to_string(), columns);
+    }
+
+    /// Translate the values of a key through a lookup table, e.g. `"Cat"` to `"Katze"` / 通过查找表转换某个键的值，例如将 `"Cat"` 转换为 `"Katze"`
+    ///
+    /// Applies to the `{{key}}` placeholder and to the `[key]` field of loop items. Booleans and numbers are matched by their JSON text (e.g. `"true"`); unmatched values are rendered as usual / 应用于 `{{key}}` 占位符和循环项的 `[key]` 字段。布尔值和数字按其 JSON 文本（例如 `"true"`）匹配；未匹配的值照常渲染
+    ///
+    /// # Arguments / 参数
+    ///  * `key` - Placeholder or field name (e.g. "type") / 占位符或字段名称（例如 "type"）
+    ///  * `values` - Original value to display text / 原始值到显示文本的映射
+    pub fn set_value_map(&mut self, key: &str, values: HashMap<String, String>) {
+        self.value_maps.insert(key.to_string(), values);
+    }
+
+    /// Prevent generated loop rows from breaking across pages / 防止生成的循环行跨页断开
+    ///
+    /// Adds `w:cantSplit` to the row properties of every generated row, so each row is moved to the next page as a whole instead of being split / 为每个生成行的行属性添加 `w:cantSplit`，使每行整体移到下一页而不是被拆分
+    pub fn set_cant_split_rows(&mut self, enabled: bool) {
+        self.cant_split_rows = enabled;
+    }
+
+    /// Repeat the header rows of loop tables at the top of each page / 在每页顶部重复循环表格的标题行
+    ///
+    /// Adds `w:tblHeader` to the rows above the data row of every loop table; it only shows when the table spans several pages / 为每个循环表格数据行之上的行添加 `w:tblHeader`；仅在表格跨多页时可见
+    pub fn set_repeat_header_rows(&mut self, enabled: bool) {
+        self.repeat_header_rows = enabled;
+    }
+
+    /// Shade every other generated loop row / 为每隔一个生成的循环行添加底纹
+    ///
+    /// The cells of the second, fourth, ... generated row get a `w:shd` fill of `color`; a vertically merged cell keeps the shading of the row it starts on. `None` or a color that is not `RRGGBB` disables shading / 第二、第四……个生成行的单元格获得填充为 `color` 的 `w:shd`；垂直合并的单元格保持其起始行的底纹。`None` 或不是 `RRGGBB` 的颜色会禁用底纹
+    ///
+    /// # Arguments / 参数
+    /// * `color` - RGB hex color (e.g. "F2F2F2") / RGB 十六进制颜色（例如 "F2F2F2"）
+    pub fn set_zebra_shading(&mut self, color: Option<String>) {
+        self.zebra_shading = color
+            .filter(|color| parse_hex_color(color).is_some())
+            .map(|color| color.trim_start_matches('#').to_string());
+    }
+
+    /// Continue past ill-formed XML in the document body / 在文档正文中遇到格式错误的 XML 时继续
+    ///
+    /// End tags that close no open element are dropped and every recovered error is reported as [`GenerationWarning::MalformedXml`](crate::GenerationWarning::MalformedXml). Syntax errors such as unclosed tags and errors inside tables still fail / 未关闭任何已打开元素的结束标签被丢弃，每个恢复的错误都会记录为 [`GenerationWarning::MalformedXml`](crate::GenerationWarning::MalformedXml)。未闭合标签等语法错误以及表格内的错误仍会失败
+    pub fn set_lenient_xml(&mut self, enabled: bool) {
+        self.lenient_xml = enabled;
+    }
+
+    /// Report template constructs that keep placeholders from being filled / 报告导致占位符无法填充的模板结构
+    ///
+    /// Document.xml is scanned once more before it is processed, and nested tables and placeholders split across runs are reported as [`GenerationWarning::NestedTable`](crate::GenerationWarning::NestedTable) and [`GenerationWarning::SplitPlaceholder`](crate::GenerationWarning::SplitPlaceholder). Off by default / 处理前会再扫描一次 document.xml，嵌套表格和拆分到多个运行中的占位符分别记录为 [`GenerationWarning::NestedTable`](crate::GenerationWarning::NestedTable) 和 [`GenerationWarning::SplitPlaceholder`](crate::GenerationWarning::SplitPlaceholder)。默认关闭
+    pub fn set_diagnostics(&mut self, enabled: bool) {
+        self.diagnostics = enabled;
+    }
+
+    /// Make `{{field}}` and `[field]` interchangeable / 使 `{{field}}` 与 `[field]` 可互换
+    ///
+    /// Before replacement, a text that is a single `[field]` is read as `{{field}}` outside loop tables, and `{{field}}` is read as `[field]` in the rows below a loop marker. Literal bracketed text such as `[1]` is then treated as a placeholder / 替换前，循环表格之外单个 `[field]` 文本按 `{{field}}` 读取，循环标记下方的行中 `{{field}}` 按 `[field]` 读取。此时 `[1]` 等带方括号的普通文本也会被视为占位符
+    pub fn set_unified_syntax(&mut self, enabled: bool) {
+        self.unified_syntax = enabled;
+    }
+
+    /// Fail when loop items have inconsistent key sets / 循环项键集不一致时失败
+    ///
+    /// Discrepancies are always reported in [`GenerationReport`]; with this flag the first one aborts generation with [`DocxError::Validation`] / 差异总会记录在 [`GenerationReport`] 中；启用此标志时第一处差异会以 [`DocxError::Validation`] 中止生成
+    pub fn set_strict_loop_keys(&mut self, strict: bool) {
+        self.strict_loop_keys = strict;
+    }
+
+    /// Fail when placeholders have no value / 占位符没有值时失败
+    ///
+    /// Every `{{key}}` placeholder, `MERGEFIELD` and loop row `[field]` found neither in the data nor in a fallback is collected in [`GenerationReport::missing_placeholders`]; after the parts are processed generation fails with [`DocxError::MissingPlaceholders`] listing all of them, before the zip is finished. [`DOCX::generate`] then leaves no output file, while writer-based methods have received an incomplete package to discard. Off by default, missing values are then left blank / 数据和后备来源中都找不到的每个 `{{key}}` 占位符、`MERGEFIELD` 和循环行 `[field]` 都会收集到 [`GenerationReport::missing_placeholders`] 中；处理各部件后、完成 zip 之前，生成以列出全部占位符的 [`DocxError::MissingPlaceholders`] 失败。此时 [`DOCX::generate`] 不会留下输出文件，而基于写入器的方法已收到应丢弃的不完整包。默认关闭，此时缺失的值留空
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Drop generated loop rows whose cells all resolve to empty / 丢弃所有单元格都解析为空的生成循环行
+    ///
+    /// Useful for trailing items that flatten to null-filled records. Off by default so intentionally blank rows are kept / 适用于展平后全为空值记录的末尾项。默认关闭，以保留有意留空的行
+    pub fn set_skip_empty_rows(&mut self, enabled: bool) {
+        self.skip_empty_rows = enabled;
+    }
+
+    /// Merge loop cells whose values differ only in surrounding whitespace or case / 合并仅在首尾空白或大小写上不同的循环单元格
+    ///
+    /// Only the merge comparison is normalized, each cell keeps its own text. Off by default / 仅规范化合并比较，每个单元格保留各自的文本。默认关闭
+    pub fn set_merge_normalization(&mut self, enabled: bool) {
+        self.normalize_merge = enabled;
+    }
+
+    /// Abort generation when loop tables would produce more than `max` rows in total / 当循环表格总共将生成超过 `max` 行时中止生成
+    ///
+    /// Guards against runaway data such as an accidentally huge array; generation fails with [`DocxError::LimitExceeded`] before the rows are written / 防止失控的数据，例如意外的超大数组；生成在写入行之前以 [`DocxError::LimitExceeded`] 失败
+    ///
+    /// Items removed by loop filters, `[skipif:...]` or the item transform do not count / 被循环过滤条件、`[skipif:...]` 或循环项转换移除的项不计入
+    pub fn set_max_rows(&mut self, max: usize) {
+        self.max_rows = Some(max);
+    }
+
+    /// Abort generation when the parts written exceed `max` uncompressed bytes / 当写入的部件超过 `max` 个未压缩字节时中止生成
+    ///
+    /// Checked while writing, so an oversized document fails with [`DocxError::LimitExceeded`] before it is complete / 在写入时检查，因此过大的文档会在完成前以 [`DocxError::LimitExceeded`] 失败
+    pub fn set_max_output_bytes(&mut self, max: usize) {
+        self.max_output_bytes = Some(max);
+    }
+
+    /// Set the capacities preallocated while processing, e.g. lower ones for memory-constrained environments / 设置处理期间预分配的容量，例如在内存受限的环境中调低
+    ///
+    /// Defaults to [`CapacityHints::default`], the generated document is the same whatever the hints / 默认为 [`CapacityHints::default`]，无论提示如何，生成的文档都相同
+    pub fn set_capacity_hints(&mut self, hints: CapacityHints) {
+        self.capacity_hints = hints;
+    }
+
+    /// Set the comment of the output zip archive, e.g. a generation ID for traceability / 设置输出 zip 归档的注释，例如用于追溯的生成 ID
+    ///
+    /// Ignored when generating into a directory. `None` writes no comment / 生成到目录时忽略。`None` 不写入注释
+    pub fn set_zip_comment(&mut self, comment: Option<&str>) {
+        self.zip_comment = comment.map(str::to_string);
+    }
+
+    /// Set the last-modified time of every output zip entry / 设置每个输出 zip 条目的最后修改时间
+    ///
+    /// A fixed time keeps the package identical across runs. `None` keeps the zero timestamp written by default / 固定时间可使包在多次运行间保持一致。`None` 保留默认写入的零时间戳
+    ///
+    /// # Arguments / 参数
+    /// * `modified` - Time built with [`ZipDateTimeBuilder`](crate::ZipDateTimeBuilder) / 使用 [`ZipDateTimeBuilder`](crate::ZipDateTimeBuilder) 构建的时间
+    pub fn set_entry_modified(&mut self, modified: Option<ZipDateTime>) {
+        self.entry_modified = modified;
+    }
+
+    /// Make the same template and data always yield a byte-identical package, e.g. for caching or golden tests / 使相同的模板和数据始终生成字节相同的包，例如用于缓存或黄金测试
+    ///
+    /// Images are named by their order, media entries are written sorted by name, and entries without a time set by [`DOCX::set_entry_modified`] are stamped 1980-01-01 00:00:00. Off by default / 图片按顺序命名，媒体条目按名称排序写入，未通过 [`DOCX::set_entry_modified`] 设置时间的条目标记为 1980-01-01 00:00:00。默认关闭
+    pub fn set_reproducible(&mut self, enabled: bool) {
+        self.reproducible = enabled;
+    }
+
+    /// Allow `[raw:key]` markers, writing their string value as literal OOXML in place of the run / 允许 `[raw:key]` 标记，将其字符串值作为字面 OOXML 写入以替换运行
+    ///
+    /// The value is trusted and written unescaped, so it must be well-formed run-level markup such as `<w:r>...</w:r>`; never enable it for untrusted data. Off by default, the markers are then treated as ordinary placeholders / 值被信任并不经转义写入，因此必须是格式正确的运行级标记，例如 `<w:r>...</w:r>`；切勿对不受信任的数据启用。默认关闭，此时标记按普通占位符处理
+    pub fn allow_raw_ooxml(&mut self, enabled: bool) {
+        self.allow_raw_ooxml = enabled;
+    }
+
+    /// Parse every `[raw:key]` value before writing it, failing with [`DocxError::Xml`] when it is not well-formed / 写入前解析每个 `[raw:key]` 值，格式不正确时以 [`DocxError::Xml`] 失败
+    ///
+    /// Guards against raw snippets corrupting the document; only balanced, properly nested elements are checked, not their schema. Off by default / 防止原始片段损坏文档；仅检查元素是否平衡且正确嵌套，不检查其架构。默认关闭
+    pub fn set_raw_ooxml_validation(&mut self, enabled: bool) {
+        self.validate_raw_ooxml = enabled;
+    }
+
+    /// Abort generation with [`DocxError::Cancelled`] once `token` is cancelled, e.g. when the client of a service disconnects / 一旦 `token` 被取消即以 [`DocxError::Cancelled`] 中止生成，例如服务的客户端断开连接时
+    ///
+    /// Checked before each zip entry, every few loop rows and every few document events; the temporary document file is removed on cancellation. `None` never cancels / 在每个 zip 条目之前、每隔若干循环行和每隔若干文档事件检查；取消时会删除临时文档文件。`None` 从不取消
+    pub fn set_cancellation_token(&mut self, token: Option<CancellationToken>) {
+        self.cancellation = token;
+    }
+
+    /// Generate only the body content spanned by a bookmark, e.g. for previews / 仅生成书签跨越的正文内容，例如用于预览
+    ///
+    /// Paragraphs and tables from the one holding the bookmark start to the one holding its end are kept, with the section properties of the document. An unknown bookmark produces an empty body / 保留从包含书签起点到包含其终点的段落和表格，以及文档的节属性。未知书签产生空正文
+    ///
+    /// The whole rendered document.xml is held in memory before the range is cut out, instead of being streamed to the output / 在截取范围之前，整个渲染后的 document.xml 保存在内存中，而不是流式写入输出
+    ///
+    /// # Arguments / 参数
+    ///  * `bookmark` - Bookmark name / 书签名称
+    pub fn set_section(&mut self, bookmark: &str) {
+        self.section = Some(bookmark.to_string());
+    }
+
+    /// Generate the whole document again after [`set_section`](Self::set_section) / 在 [`set_section`](Self::set_section) 之后重新生成整个文档
+    pub fn clear_section(&mut self) {
+        self.section = None;
+    }
+
+    /// Set the language of the generated document / 设置生成文档的语言
+    ///
+    /// Applied as `w:lang` to inserted runs (images, page breaks, links) and to `w:themeFontLang` in word/settings.xml when present / 作为 `w:lang` 应用于插入的运行（图片、分页符、链接），并在存在时应用于 word/settings.xml 中的 `w:themeFontLang`
+    ///
+    /// # Arguments / 参数
+    ///  * `language` - Language tag (e.g. "en-US") / 语言标签（例如 "en-US"）
+    pub fn set_default_language(&mut self, language: &str) {
+        self.language = Some(language.to_string());
+    }
+
+    /// Enable placeholder substitution in chart parts / 启用图表部件中的占位符替换
+    ///
+    /// Regular placeholders (`{{key}}`) in the text of `word/charts/*.xml` (titles, category labels, cached values) are replaced / 替换 `word/charts/*.xml` 文本（标题、类别标签、缓存值）中的常规占位符（`{{key}}`）
+    pub fn set_replace_in_charts(&mut self, enabled: bool) {
+        self.replace_in_charts = enabled;
+    }
+
+    /// Get the report of the last generation / 获取上次生成的报告
+    pub fn report(&self) -> &GenerationReport {
+        &self.report
+    }
+
+    /// List the media parts of a template / 列出模板中的媒体部件
+    ///
+    /// The template is only read, nothing is rendered / 仅读取模板，不进行渲染
+    ///
+    /// # Arguments / 参数
+    /// * `input_path` - Path to the DOCX file / DOCX 文件路径
+    ///
+    /// # Returns / 返回
+    /// * `Result<Vec<(String, usize)>, DocxError>` - Part names (e.g. "word/media/image1.png") and uncompressed sizes / 部件名称（例如 "word/media/image1.png"）和未压缩大小
+    pub async fn list_media(input_path: &str) -> Result<Vec<(String, usize)>, DocxError> {
+        let zip = Self::open_template(input_path).await?;
+        Ok(zip
+            .file()
+            .entries()
+            .iter()
+            .filter_map(|entry| {
+                let name = entry.filename().as_str().ok()?;
+                (name.starts_with(MEDIA_PATH_PREFIX) && !entry.dir().unwrap_or(false))
+                    .then(|| (name.to_string(), entry.uncompressed_size() as usize))
+            })
+            .collect())
+    }
+
+    /// Read a media part of a template / 读取模板中的媒体部件
+    ///
+    /// # Arguments / 参数
+    /// * `input_path` - Path to the DOCX file / DOCX 文件路径
+    /// * `name` - Part name as returned by [`DOCX::list_media`] / [`DOCX::list_media`] 返回的部件名称
+    ///
+    /// # Returns / 返回
+    /// * `Result<Bytes, DocxError>` - Media bytes, `DocxError::MissingPart` if there is no such media part / 媒体字节，不存在该媒体部件时为 `DocxError::MissingPart`
+    pub async fn read_media(input_path: &str, name: &str) -> Result<Bytes, DocxError> {
+        let mut zip = Self::open_template(input_path).await?;
+        let index = zip
+            .file()
+            .entries()
+            .iter()
+            .position(|entry| {
+                name.starts_with(MEDIA_PATH_PREFIX)
+                    && entry.filename().as_str().is_ok_and(|n| n == name)
+            })
+            .ok_or_else(|| DocxError::MissingPart(name.to_string()))?;
+
+        let mut content = Vec::new();
+        zip.reader_with_entry(index)
+            .await
+            .map_err(DocxError::from_package_error)?
+            .compat()
+            .read_to_end(&mut content)
+            .await?;
+        Ok(Bytes::from(content))
+    }
+
+    /// Detect the tables of a template with their loop keys and field placeholders / 检测模板中的表格及其循环键和字段占位符
+    ///
+    /// The template is only read, nothing is rendered. Useful to check table bindings before generating / 仅读取模板，不进行渲染。可用于在生成前检查表格绑定
+    ///
+    /// # Arguments / 参数
+    /// * `input_path` - Path to the DOCX file / DOCX 文件路径
+    ///
+    /// # Returns / 返回
+    /// * `Result<Vec<TableInfo>, DocxError>` - Tables of word/document.xml in document order / word/document.xml 中按文档顺序排列的表格
+    pub async fn inspect_tables(input_path: &str) -> Result<Vec<TableInfo>, DocxError> {
+        let content = Self::read_document_xml(input_path).await?;
+        Ok(DocxProcessor::inspect_tables(content.as_slice()).await?)
+    }
+
+    /// List the text replacements generating would make, without writing anything / 列出生成时将进行的文本替换，不写入任何内容
+    ///
+    /// word/document.xml is processed with the current settings and its output discarded. Each modified `w:t` element is reported once in document order / 使用当前设置处理 word/document.xml 并丢弃其输出。每个被修改的 `w:t` 元素按文档顺序报告一次
+    ///
+    /// # Arguments / 参数
+    /// * `input_path` - Path to the DOCX file / DOCX 文件路径
+    /// * `placeholders` - HashMap of placeholder values / 占位符值的 HashMap
+    ///
+    /// # Returns / 返回
+    /// * `Result<Vec<Change>, DocxError>` - Original and replaced text of each modified element / 每个被修改元素的原始文本和替换文本
+    pub async fn preview_changes(
+        &self,
+        input_path: &str,
+        placeholders: &HashMap<String, Value>,
+    ) -> Result<Vec<Change>, DocxError> {
+        let content = Self::read_document_xml(input_path).await?;
+        let placeholders = self.map_placeholders(placeholders);
+
+        // The report of the last generation is left untouched / 不影响上次生成的报告
+        let mut processor = self.processor(
+            GenerationReport::default(),
+            &ImageMap::default(),
+            BOOKMARK_ID_BASE,
+        );
+        processor.changes = Some(Vec::new());
+        processor
+            .process_xml_events(
+                &mut sink(),
+                &mut content.as_slice(),
+                placeholders.as_ref(),
+                &mut RelationshipManager::new(self.capacity_hints.images),
+                &mut ImageManager::new(
+                    self.part_dpi(DOCUMENT_XML_PATH),
+                    self.capacity_hints.images,
+                ),
+            )
+            .await?;
+        Ok(processor.changes.unwrap_or_default())
+    }
+
+    /// Read word/document.xml of a template / 读取模板的 word/document.xml
+    async fn read_document_xml(input_path: &str) -> Result<Vec<u8>, DocxError> {
+        let mut zip = Self::open_template(input_path).await?;
+        let index = zip
+            .file()
+            .entries()
+            .iter()
+            .position(|entry| {
+                entry
+                    .filename()
+                    .as_str()
+                    .is_ok_and(|name| name == DOCUMENT_XML_PATH)
+            })
+            .ok_or_else(|| DocxError::MissingPart(DOCUMENT_XML_PATH.to_string()))?;
+
+        let mut content = Vec::new();
+        zip.reader_with_entry(index)
+            .await
+            .map_err(DocxError::from_package_error)?
+            .compat()
+            .read_to_end(&mut content)
+            .await?;
+        Ok(content)
+    }
+
+    /// DPI of the images in a part / 部件中图片的 DPI
+    fn part_dpi(&self, part: &str) -> f32 {
+        self.part_dpis.get(part).copied().unwrap_or(self.dpi)
+    }
+
+    /// Translate mapped values of the placeholders, borrowing them when no map is set / 转换占位符的映射值，未设置映射时直接借用
+    fn map_placeholders<'p>(
+        &self,
+        placeholders: &'p HashMap<String, Value>,
+    ) -> Cow<'p, HashMap<String, Value>> {
+        if self.value_maps.is_empty() {
+            return Cow::Borrowed(placeholders);
+        }
+        let mut values = placeholders.clone();
+        map_values(&mut values, &self.value_maps, true);
+        Cow::Owned(values)
+    }
+
+    /// Open a template for reading only / 以只读方式打开模板
+    async fn open_template(
+        input_path: &str,
+    ) -> Result<ZipFileReader<BufReader<AsyncFile>>, DocxError> {
+        let reader = BufReader::new(AsyncFile::open(input_path).await?);
+        ZipFileReader::with_tokio(reader)
+            .await
+            .map_err(DocxError::from_package_error)
     }
 
     /// Single-pass processing of the DOCX file / DOCX 文件的单次处理
     ///
     /// Reads from input, processes XML, handles images, and writes to output / 从输入读取，处理 XML，处理图片，并写入输出
     ///
+    /// Missing values are rendered as a clean copy: placeholders blank out, loop tables keep only their header rows with the `{{#key}}` marker stripped, and no image is embedded. An empty `placeholders` map therefore yields the template without any markers / 缺失的值会渲染为干净副本：占位符置空，循环表格仅保留去除 `{{#key}}` 标记的标题行，且不嵌入图片。因此空的 `placeholders` 映射会生成不含任何标记的模板
+    ///
     /// # Arguments / 参数
     /// * `input_path` - Path to input DOCX file / 输入 DOCX 文件路径
     /// * `output_path` - Path to output DOCX file / 输出 DOCX 文件路径
     /// * `placeholders` - HashMap of placeholder values / 占位符值的 HashMap
     ///
     /// # Returns / 返回
-    /// * `Result<(), ZipError>` - Success or zip error / 成功或 zip 错误
+    /// * `Result<(), DocxError>` - Success or error, `DocxError::InvalidPackage` if the input is not a valid zip. On error an existing output file is left untouched / 成功或错误，输入不是有效 zip 时为 `DocxError::InvalidPackage`。出错时已有的输出文件保持不变
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, placeholders)))]
     pub async fn generate(
         &mut self,
         input_path: &str,
         output_path: &str,
         placeholders: &HashMap<String, Value>,
-    ) -> Result<(), ZipError> {
+    ) -> Result<(), DocxError> {
+        self.generate_file(input_path, output_path, placeholders, &ImageMap::default())
+            .await
+    }
+
+    /// Generate a DOCX file, renaming it into place on success / 生成 DOCX 文件，成功时重命名到位
+    async fn generate_file(
+        &mut self,
+        input_path: &str,
+        output_path: &str,
+        placeholders: &HashMap<String, Value>,
+        images: &ImageMap,
+    ) -> Result<(), DocxError> {
+        // Open input DOCX file before the output is touched / 在改动输出之前打开输入 DOCX 文件
+        let reader = BufReader::new(AsyncFile::open(input_path).await?);
+
         // Ensure output directory exists / 确保输出目录存在
         if let Some(parent_dir) = Path::new(output_path).parent() {
             create_dir_all(parent_dir).await?;
         }
 
-        // Open input DOCX file as zip stream / 将输入 DOCX 文件作为 zip 流打开
-        let input_file = AsyncFile::open(input_path).await?;
-        let reader = BufReader::new(input_file);
-        let mut zip_stream = ZipFileReader::with_tokio(reader).await?;
+        // Write beside the output and rename on success, so a failed generation never leaves a partial package / 写入输出旁边的文件并在成功时重命名，因此失败的生成不会留下不完整的包
+        let partial_path = format!(
+            "{}.{}{}",
+            output_path,
+            Uuid::now_v7(),
+            PARTIAL_FILE_EXTENSION
+        );
 
         // Create output DOCX file writer with buffering / 创建带缓冲的输出 DOCX 文件写入器
-        let output_file = AsyncFile::create(output_path).await?;
-        // // Wrap in BufWriter to optimize zip metadata writes / 包装在 BufWriter 中以优化 zip 元数据写入
+        let output_file = AsyncFile::create(&partial_path).await?;
+        // Wrap in BufWriter to optimize zip metadata writes / 包装在 BufWriter 中以优化 zip 元数据写入
         let buffered_output = BufWriter::new(output_file);
-        let mut writer = ZipFileWriter::with_tokio(buffered_output);
+
+        let result = self
+            .generate_flushed(reader, buffered_output, placeholders, images)
+            .await;
+        match result {
+            Ok(()) => rename(&partial_path, output_path).await?,
+            Err(e) => {
+                // The error is the one to report, a leftover partial file is harmless / 应报告的是该错误，残留的部分文件无害
+                let _ = remove_file(&partial_path).await;
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Generate a DOCX written to any async writer, e.g. an HTTP response body / 生成写入任意异步写入器（例如 HTTP 响应体）的 DOCX
+    ///
+    /// The zip is written straight into `writer`, which is flushed once the package is complete; no output file or directory is created / zip 直接写入 `writer`，包完成后刷新该写入器；不会创建输出文件或目录
+    ///
+    /// # Arguments / 参数
+    /// * `input_path` - Path to input DOCX file / 输入 DOCX 文件路径
+    /// * `writer` - Writer receiving the output DOCX / 接收输出 DOCX 的写入器
+    /// * `placeholders` - HashMap of placeholder values / 占位符值的 HashMap
+    pub async fn generate_to_writer<W: AsyncWrite + Unpin>(
+        &mut self,
+        input_path: &str,
+        writer: W,
+        placeholders: &HashMap<String, Value>,
+    ) -> Result<(), DocxError> {
+        // Open input DOCX file / 打开输入 DOCX 文件
+        let reader = BufReader::new(AsyncFile::open(input_path).await?);
+        self.generate_flushed(reader, writer, placeholders, &ImageMap::default())
+            .await
+    }
+
+    /// Process a template read from `reader` into `writer` and flush it / 将从 `reader` 读取的模板处理到 `writer` 中并刷新
+    async fn generate_flushed<R, W>(
+        &mut self,
+        reader: R,
+        writer: W,
+        placeholders: &HashMap<String, Value>,
+        images: &ImageMap,
+    ) -> Result<(), DocxError>
+    where
+        R: AsyncBufRead + AsyncSeek + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        let mut writer = self
+            .generate_stream(reader, writer, placeholders, images, false)
+            .await?;
+
+        // Flush buffered bytes to the writer / 将缓冲的字节刷新到写入器
+        writer.flush().await?;
+        Ok(())
+    }
+
+    /// Generate a DOCX with images passed as raw bytes beside the placeholders / 生成 DOCX，图片以原始字节形式与占位符一起传入
+    ///
+    /// An image placeholder such as `{{@logo}}` or `[@logo]` embeds `images["logo"]` directly, without any base64 encoding, ahead of its value in `placeholders` / `{{@logo}}` 或 `[@logo]` 等图片占位符直接嵌入 `images["logo"]`，无需任何 base64 编码，且优先于其在 `placeholders` 中的值
+    ///
+    /// # Arguments / 参数
+    /// * `input_path` - Path to input DOCX file / 输入 DOCX 文件路径
+    /// * `output_path` - Path to output DOCX file / 输出 DOCX 文件路径
+    /// * `placeholders` - HashMap of placeholder values / 占位符值的 HashMap
+    /// * `images` - Encoded image bytes by placeholder key without braces or `@` / 按不带花括号和 `@` 的占位符键索引的编码图片字节
+    pub async fn generate_with_images(
+        &mut self,
+        input_path: &str,
+        output_path: &str,
+        placeholders: &HashMap<String, Value>,
+        images: HashMap<String, Bytes>,
+    ) -> Result<(), DocxError> {
+        self.generate_file(input_path, output_path, placeholders, &Arc::new(images))
+            .await
+    }
+
+    /// Generate a DOCX with placeholders taken from a serializable struct / 使用从可序列化结构体获取的占位符生成 DOCX
+    ///
+    /// `data` must serialize to an object. Array fields become loop data under `{{#field}}`, nested objects are joined with dots as `{{address.city}}` and every other field becomes `{{field}}` (so `{{@field}}` images work too) / `data` 必须序列化为对象。数组字段作为循环数据放在 `{{#field}}` 下，嵌套对象用点连接为 `{{address.city}}`，其他字段作为 `{{field}}`（因此 `{{@field}}` 图片同样适用）
+    ///
+    /// # Arguments / 参数
+    /// * `input_path` - Path to input DOCX file / 输入 DOCX 文件路径
+    /// * `output_path` - Path to output DOCX file / 输出 DOCX 文件路径
+    /// * `data` - Struct providing the placeholder values / 提供占位符值的结构体
+    ///
+    /// # Returns / 返回
+    /// * `Result<(), DocxError>` - Success or error, `DocxError::Serialization` if `data` is not an object / 成功或错误，`data` 不是对象时为 `DocxError::Serialization`
+    pub async fn generate_from<T: Serialize>(
+        &mut self,
+        input_path: &str,
+        output_path: &str,
+        data: &T,
+    ) -> Result<(), DocxError> {
+        let Value::Object(object) = serde_json::to_value(data)? else {
+            return Err(serde_json::Error::custom(ERR_DATA_NOT_OBJECT).into());
+        };
+        let mut placeholders = HashMap::with_capacity(object.len());
+        collect_placeholders("", object, &mut placeholders);
+        self.generate(input_path, output_path, &placeholders).await
+    }
+
+    /// Generate a DOCX with placeholders merged from several data sources / 使用从多个数据源合并的占位符生成 DOCX
+    ///
+    /// Every source is mapped like [`generate_from`](Self::generate_from) and merged in order. When two sources provide the same placeholder the last one wins and the overlap is reported as [`GenerationWarning::DuplicatePlaceholder`](crate::GenerationWarning::DuplicatePlaceholder) / 每个数据源都按 [`generate_from`](Self::generate_from) 的方式映射并依次合并。两个数据源提供相同占位符时以最后一个为准，并将重叠记录为 [`GenerationWarning::DuplicatePlaceholder`](crate::GenerationWarning::DuplicatePlaceholder)
+    ///
+    /// # Arguments / 参数
+    /// * `input_path` - Path to input DOCX file / 输入 DOCX 文件路径
+    /// * `output_path` - Path to output DOCX file / 输出 DOCX 文件路径
+    /// * `sources` - JSON objects providing the placeholder values, consumed so their values are moved rather than copied / 提供占位符值的 JSON 对象，按值传入以移动而非复制其中的值
+    ///
+    /// # Returns / 返回
+    /// * `Result<(), DocxError>` - Success or error, `DocxError::Serialization` if a source is not an object / 成功或错误，数据源不是对象时为 `DocxError::Serialization`
+    pub async fn generate_merged(
+        &mut self,
+        input_path: &str,
+        output_path: &str,
+        sources: Vec<Value>,
+    ) -> Result<(), DocxError> {
+        let mut placeholders = HashMap::new();
+        let mut origins = HashMap::new();
+        let mut duplicates = Vec::new();
+        for (index, source) in sources.into_iter().enumerate() {
+            let Value::Object(object) = source else {
+                return Err(serde_json::Error::custom(ERR_DATA_NOT_OBJECT).into());
+            };
+            let mut source_placeholders = HashMap::with_capacity(object.len());
+            collect_placeholders("", object, &mut source_placeholders);
+            // Sorted so overlaps are reported in a stable order / 排序以稳定的顺序报告重叠
+            let mut entries = source_placeholders.into_iter().collect::<Vec<_>>();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            for (key, value) in entries {
+                if let Some(overwritten) = origins.insert(key.clone(), index) {
+                    duplicates.push(GenerationWarning::DuplicatePlaceholder {
+                        key: key.clone(),
+                        overwritten,
+                        kept: index,
+                    });
+                }
+                placeholders.insert(key, value);
+            }
+        }
+
+        // Overlaps are reported even when generation fails / 即使生成失败也会报告重叠
+        self.report = GenerationReport::default();
+        let result = self.generate(input_path, output_path, &placeholders).await;
+        duplicates.append(&mut self.report.warnings);
+        self.report.warnings = duplicates;
+        result
+    }
+
+    /// Generate a DOCX from a template held in shared memory / 从共享内存中的模板生成 DOCX
+    ///
+    /// The template is read through a cursor over `input`, so the shared buffer is never copied / 通过 `input` 上的游标读取模板，共享缓冲区不会被复制
+    ///
+    /// # Arguments / 参数
+    /// * `input` - Template DOCX bytes / 模板 DOCX 字节
+    /// * `placeholders` - HashMap of placeholder values / 占位符值的 HashMap
+    ///
+    /// # Returns / 返回
+    /// * `Result<Vec<u8>, DocxError>` - Generated DOCX bytes or error / 生成的 DOCX 字节或错误
+    pub async fn generate_bytes(
+        &mut self,
+        input: Bytes,
+        placeholders: &HashMap<String, Value>,
+    ) -> Result<Vec<u8>, DocxError> {
+        self.generate_bytes_with_images(input, placeholders, HashMap::new())
+            .await
+    }
+
+    /// Generate a DOCX from a template held in shared memory, with images passed as raw bytes / 从共享内存中的模板生成 DOCX，图片以原始字节形式传入
+    ///
+    /// Images are embedded as by [`DOCX::generate_with_images`] / 图片的嵌入方式与 [`DOCX::generate_with_images`] 相同
+    ///
+    /// # Arguments / 参数
+    /// * `input` - Template DOCX bytes / 模板 DOCX 字节
+    /// * `placeholders` - HashMap of placeholder values / 占位符值的 HashMap
+    /// * `images` - Encoded image bytes by placeholder key without braces or `@` / 按不带花括号和 `@` 的占位符键索引的编码图片字节
+    ///
+    /// # Returns / 返回
+    /// * `Result<Vec<u8>, DocxError>` - Generated DOCX bytes or error / 生成的 DOCX 字节或错误
+    pub async fn generate_bytes_with_images(
+        &mut self,
+        input: Bytes,
+        placeholders: &HashMap<String, Value>,
+        images: HashMap<String, Bytes>,
+    ) -> Result<Vec<u8>, DocxError> {
+        let output = Vec::with_capacity(input.len());
+        let output = self
+            .generate_stream(
+                Cursor::new(input),
+                output,
+                placeholders,
+                &Arc::new(images),
+                false,
+            )
+            .await?;
+        Ok(output)
+    }
+
+    /// Generate a DOCX from template bytes entirely in memory / 完全在内存中从模板字节生成 DOCX
+    ///
+    /// document.xml is buffered in memory instead of a temporary file, so concurrent calls never touch the filesystem / document.xml 缓冲在内存中而不是临时文件中，因此并发调用不会访问文件系统
+    ///
+    /// # Arguments / 参数
+    /// * `input` - Template DOCX bytes / 模板 DOCX 字节
+    /// * `placeholders` - HashMap of placeholder values / 占位符值的 HashMap
+    ///
+    /// # Returns / 返回
+    /// * `Result<Vec<u8>, DocxError>` - Generated DOCX bytes or error / 生成的 DOCX 字节或错误
+    pub async fn generate_from_bytes(
+        &mut self,
+        input: &[u8],
+        placeholders: &HashMap<String, Value>,
+    ) -> Result<Vec<u8>, DocxError> {
+        let output = Vec::with_capacity(input.len());
+        self.generate_stream(
+            Cursor::new(input),
+            output,
+            placeholders,
+            &ImageMap::default(),
+            true,
+        )
+        .await
+    }
+
+    /// Generate a DOCX streamed as chunks to a channel, e.g. to upload it while it is still being generated / 生成以数据块流式发送到通道的 DOCX，例如在生成期间即开始上传
+    ///
+    /// Chunks are sent as the zip is written and generation waits whenever the channel is full. The sender is dropped once the package is complete, ending the stream; a closed receiver fails with [`DocxError::Io`] / 数据块在写入 zip 时发送，通道已满时生成会等待。包完成后发送方被丢弃，从而结束数据流；接收方关闭时以 [`DocxError::Io`] 失败
+    ///
+    /// # Arguments / 参数
+    /// * `input_path` - Path to input DOCX file / 输入 DOCX 文件路径
+    /// * `tx` - Sender receiving the output chunks / 接收输出数据块的发送方
+    /// * `placeholders` - HashMap of placeholder values / 占位符值的 HashMap
+    pub async fn generate_to_sender(
+        &mut self,
+        input_path: &str,
+        tx: Sender<Bytes>,
+        placeholders: &HashMap<String, Value>,
+    ) -> Result<(), DocxError> {
+        let reader = BufReader::new(AsyncFile::open(input_path).await?);
+        // Buffered so chunks are not as small as the zip headers / 使用缓冲以免数据块与 zip 头一样小
+        let output = BufWriter::new(ChannelWriter::new(tx));
+        let mut output = self
+            .generate_stream(reader, output, placeholders, &ImageMap::default(), false)
+            .await?;
+        output.flush().await?;
+        Ok(())
+    }
+
+    /// Generate the processed parts as files under a directory instead of a zip / 将处理后的部件作为文件生成到目录中而不是 zip
+    ///
+    /// Debugging aid: every part of the package, including `word/document.xml`, is written unzipped under `output_dir` so it can be inspected or diffed / 调试辅助：包中的每个部件（包括 `word/document.xml`）都以未压缩形式写入 `output_dir`，便于检查或比较
+    ///
+    /// # Arguments / 参数
+    /// * `input_path` - Path to input DOCX file / 输入 DOCX 文件路径
+    /// * `output_dir` - Directory receiving the parts / 接收部件的目录
+    /// * `placeholders` - HashMap of placeholder values / 占位符值的 HashMap
+    pub async fn generate_to_dir(
+        &mut self,
+        input_path: &str,
+        output_dir: &str,
+        placeholders: &HashMap<String, Value>,
+    ) -> Result<(), DocxError> {
+        let output_dir = Path::new(output_dir);
+        create_dir_all(output_dir).await?;
+
+        let reader = BufReader::new(AsyncFile::open(input_path).await?);
+        self.generate_parts::<_, Sink>(
+            reader,
+            PartSink::Directory(output_dir),
+            placeholders,
+            &ImageMap::default(),
+            false,
+        )
+        .await
+    }
+
+    /// Generate a Flat OPC document, the single-XML form of a package read by Word and Power Automate / 生成 Flat OPC 文档，即 Word 和 Power Automate 可读取的单一 XML 形式的包
+    ///
+    /// Parts are processed as by [`DOCX::generate`], then written into one `<pkg:package>` document instead of a zip / 部件的处理方式与 [`DOCX::generate`] 相同，随后写入单个 `<pkg:package>` 文档而不是 zip
+    ///
+    /// # Arguments / 参数
+    /// * `input_path` - Path to input DOCX file / 输入 DOCX 文件路径
+    /// * `output_path` - Path to output XML file / 输出 XML 文件路径
+    /// * `placeholders` - HashMap of placeholder values / 占位符值的 HashMap
+    pub async fn generate_flat_opc(
+        &mut self,
+        input_path: &str,
+        output_path: &str,
+        placeholders: &HashMap<String, Value>,
+    ) -> Result<(), DocxError> {
+        if let Some(parent_dir) = Path::new(output_path).parent() {
+            create_dir_all(parent_dir).await?;
+        }
+
+        let reader = BufReader::new(AsyncFile::open(input_path).await?);
+        let mut parts = Vec::new();
+        self.generate_parts::<_, Sink>(
+            reader,
+            PartSink::Memory(&mut parts),
+            placeholders,
+            &ImageMap::default(),
+            false,
+        )
+        .await?;
+        write(output_path, write_flat_opc(&parts)?).await?;
+        Ok(())
+    }
+
+    /// Process a template read from `reader` and write the result to `output` / 处理从 `reader` 读取的模板并将结果写入 `output`
+    ///
+    /// `in_memory` buffers document.xml in memory instead of a temporary file / `in_memory` 在内存中而不是临时文件中缓冲 document.xml
+    ///
+    /// # Returns / 返回
+    /// * `Result<W, DocxError>` - The output writer after the zip is closed / zip 关闭后的输出写入器
+    async fn generate_stream<R, W>(
+        &mut self,
+        reader: R,
+        output: W,
+        placeholders: &HashMap<String, Value>,
+        images: &ImageMap,
+        in_memory: bool,
+    ) -> Result<W, DocxError>
+    where
+        R: AsyncBufRead + AsyncSeek + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        let mut writer = ZipFileWriter::with_tokio(output);
+        if let Some(comment) = &self.zip_comment {
+            writer.comment(comment.clone());
+        }
+        let modified = self.entry_modified.or_else(|| {
+            self.reproducible.then(|| {
+                ZipDateTimeBuilder::new()
+                    .year(REPRODUCIBLE_ZIP_YEAR)
+                    .month(1)
+                    .day(1)
+                    .build()
+            })
+        });
+        let sink = PartSink::Zip(&mut writer, modified);
+        self.generate_parts(reader, sink, placeholders, images, in_memory)
+            .await?;
+
+        // Close output zip file / 关闭输出 zip 文件
+        let output = timed(&mut self.report.timings.zip_write, writer.close()).await?;
+        Ok(output.into_inner())
+    }
+
+    /// Process a template read from `reader` and write every part to `sink` / 处理从 `reader` 读取的模板并将每个部件写入 `sink`
+    ///
+    /// `in_memory` buffers document.xml in memory instead of a temporary file / `in_memory` 在内存中而不是临时文件中缓冲 document.xml
+    async fn generate_parts<R, W>(
+        &mut self,
+        reader: R,
+        mut sink: PartSink<'_, W>,
+        placeholders: &HashMap<String, Value>,
+        images: &ImageMap,
+        in_memory: bool,
+    ) -> Result<(), DocxError>
+    where
+        R: AsyncBufRead + AsyncSeek + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        // Reset report for this generation / 为本次生成重置报告
+        self.report = GenerationReport::default();
+        let read_started = Instant::now();
+
+        // Translate mapped values once for every part / 为所有部件一次性转换映射值
+        let placeholders = self.map_placeholders(placeholders);
+        let placeholders = placeholders.as_ref();
+        let mut zip_write = Duration::ZERO; // Writes interleaved with reading / 与读取交错的写入
+        let mut budget = OutputBudget::new(self.max_output_bytes);
+
+        // Open input DOCX as zip stream / 将输入 DOCX 作为 zip 流打开
+        let mut zip_stream = ZipFileReader::with_tokio(reader)
+            .await
+            .map_err(DocxError::from_package_error)?;
 
         // Initialize managers for relationships and images / 初始化关系和图片管理器
-        let mut rel_manager = RelationshipManager::new();
-        let mut img_manager = ImageManager::new(self.dpi);
+        let mut rel_manager = RelationshipManager::new(self.capacity_hints.images);
+        let mut img_manager = ImageManager::new(self.dpi, self.capacity_hints.images);
+        img_manager.set_autoscale(self.image_autoscale);
+        img_manager.set_transform(self.image_transform.clone());
+        img_manager.set_strip_metadata(self.strip_metadata);
+        img_manager.set_numbered_names(self.reproducible);
+        img_manager.set_max_emu(
+            f64::from(self.image_max_emu.0),
+            f64::from(self.image_max_emu.1),
+        );
+        img_manager.set_max_box(self.image_max_box.map(|(width, height)| {
+            let limit = |emu: u32| match emu {
+                0 => f64::INFINITY,
+                emu => f64::from(emu),
+            };
+            (limit(width), limit(height))
+        }));
+        #[cfg(feature = "image")]
+        img_manager.set_jpeg_quality(self.jpeg_quality);
+        let mut content_type_manager = ContentTypeManager::new();
+        for (ext, content_type) in &self.content_types {
+            content_type_manager.add_default(ext, content_type);
+        }
 
-        // Store path to temporary document.xml file / 存储临时 document.xml 文件的路径
-        let mut temp_doc_xml_path: Option<PathBuf> = None;
+        // Buffered document.xml / 缓冲的 document.xml
+        let mut document: Option<DocumentBuffer> = None;
+        // Headers and footers with their relationships keyed by part name / 页眉和页脚及其按部件名称索引的关系
+        let mut header_footer_parts: Vec<(String, Vec<u8>)> = Vec::new();
+        let mut part_rel_managers: HashMap<String, RelationshipManager> = HashMap::new();
 
         // Process all entries in the input zip / 处理输入 zip 中的所有条目
         let entries_len = zip_stream.file().entries().len();
         for index in 0..entries_len {
+            if self.is_cancelled() {
+                return Err(DocxError::Cancelled);
+            }
             let entry = &zip_stream.file().entries()[index];
             let filename_owned = entry.filename().as_str()?.to_string();
             let filename_str = filename_owned.as_str();
-            let entry_reader = zip_stream.reader_with_entry(index).await?;
+            #[cfg(feature = "tracing")]
+            tracing::trace!(entry = filename_str, "processing entry");
+            let entry_reader = zip_stream
+                .reader_with_entry(index)
+                .await
+                .map_err(DocxError::from_package_error)?;
             // Handle document relationships file / 处理文档关系文件
             if filename_str == RELS_PATH {
                 let mut content = Vec::with_capacity(DEFAULT_BUFFER_SIZE);
                 entry_reader.compat().read_to_end(&mut content).await?;
                 // Store relationships for later processing (Bytes for zero-copy) / 存储关系以供后续处理（Bytes 实现零拷贝）
                 rel_manager.set_initial_content(Bytes::from(content));
+            } else if filename_str == CONTENT_TYPES_PATH {
+                let mut content = Vec::with_capacity(DEFAULT_BUFFER_SIZE);
+                entry_reader.compat().read_to_end(&mut content).await?;
+                // Store content types for later merging / 存储内容类型以供后续合并
+                content_type_manager.set_initial_content(Bytes::from(content));
+            } else if self
+                .extra_files
+                .iter()
+                .any(|(path, _)| path == filename_str)
+            {
+                // Replaced by an extra file / 被额外文件替换
+                continue;
+            } else if filename_str == SETTINGS_PATH
+                && let Some(language) = &self.language
+            {
+                let mut content = String::with_capacity(DEFAULT_BUFFER_SIZE);
+                entry_reader.compat().read_to_string(&mut content).await?;
+                let content = patch_theme_font_lang(&content, language);
+
+                budget.charge(content.len())?;
+                timed(
+                    &mut zip_write,
+                    sink.write_part(filename_str, content.as_bytes(), Compression::Deflate),
+                )
+                .await?;
+            } else if self.replace_in_charts
+                && filename_str.starts_with(CHART_PATH_PREFIX)
+                && filename_str.ends_with(XML_PART_EXTENSION)
+            {
+                let mut content = Vec::with_capacity(DEFAULT_BUFFER_SIZE);
+                entry_reader.compat().read_to_end(&mut content).await?;
+                let content = replace_part_text(
+                    &content,
+                    self.cell_handler.as_ref(),
+                    &self.fallbacks,
+                    &self.transforms,
+                    placeholders,
+                )?;
+
+                budget.charge(content.len())?;
+                timed(
+                    &mut zip_write,
+                    sink.write_part(filename_str, &content, Compression::Deflate),
+                )
+                .await?;
+            } else if is_header_footer_part(filename_str) {
+                // Buffer to process after document.xml / 缓冲以便在 document.xml 之后处理
+                let mut content = Vec::with_capacity(DEFAULT_BUFFER_SIZE);
+                entry_reader.compat().read_to_end(&mut content).await?;
+                header_footer_parts.push((filename_owned, content));
+            } else if let Some(part) = rels_owner(filename_str)
+                && is_header_footer_part(&part)
+            {
+                let mut content = Vec::with_capacity(DEFAULT_BUFFER_SIZE);
+                entry_reader.compat().read_to_end(&mut content).await?;
+                let mut part_rel_manager = RelationshipManager::new(self.capacity_hints.images);
+                part_rel_manager.set_initial_content(Bytes::from(content));
+                part_rel_managers.insert(part, part_rel_manager);
             } else if filename_str == DOCUMENT_XML_PATH {
-                // Buffer to temp file to process later / 缓冲到临时文件以便后续处理
-                let uuid = Uuid::now_v7().to_string();
-                let tmp_path = temp_dir().join(format!(
-                    "{}{}{}",
-                    TEMP_FILE_PREFIX, uuid, TEMP_FILE_EXTENSION
-                ));
-                let mut tmp_file = AsyncFile::create(&tmp_path).await?;
-                tokio::io::copy(&mut entry_reader.compat(), &mut tmp_file).await?;
-                temp_doc_xml_path = Some(tmp_path);
+                // Buffer to a temp file or memory to process later / 缓冲到临时文件或内存以便后续处理
+                document =
+                    Some(DocumentBuffer::read_from(&mut entry_reader.compat(), in_memory).await?);
             } else {
                 // Write other files immediately (pass-through) / 立即写入其他文件（透传）
                 // Load into memory to ensure correct decompression / 加载到内存以确保正确解压
                 let mut content = Vec::with_capacity(DEFAULT_BUFFER_SIZE);
                 entry_reader.compat().read_to_end(&mut content).await?;
 
-                let options = ZipEntryBuilder::new(filename_owned.into(), Compression::Deflate);
-                writer.write_entry_whole(options, &content).await?;
+                budget.charge(content.len())?;
+                timed(
+                    &mut zip_write,
+                    sink.write_part(filename_str, &content, Compression::Deflate),
+                )
+                .await?;
             }
         }
 
-        // Now process document.xml if we found it / 如果找到了 document.xml，现在处理它
-        if let Some(tmp_path) = temp_doc_xml_path {
-            let options = ZipEntryBuilder::new(DOCUMENT_XML_PATH.into(), Compression::Deflate);
-            let entry_writer = writer.write_entry_stream(options).await?;
-
-            // Take ownership of cell handler / 获取单元格处理器的所有权
-            let cell_handler = self
-                .cell_handler
-                .take()
-                .unwrap_or(Box::from(DefaultValueHandler));
-
-            let mut processor = DocxProcessor {
-                cell_handler,
-                skip_w_t_events: self.skip_w_t_events,
-            };
+        self.report.timings.zip_read = read_started.elapsed().saturating_sub(zip_write);
 
-            // Open temp file asynchronously for reading / 异步打开临时文件进行读取
-            let file = AsyncFile::open(&tmp_path).await?;
-            let mut buf_reader = BufReader::new(file);
+        // Generated bookmark ids continue from one part to the next / 生成的书签 id 在各部件之间连续
+        let mut bookmark_id = BOOKMARK_ID_BASE;
 
-            // Process XML events directly / 直接处理 XML 事件
-            // Use compat_write() to convert futures AsyncWrite to tokio AsyncWrite if needed
-            let mut compat_writer = entry_writer.compat_write();
+        // Now process document.xml if we found it / 如果找到了 document.xml，现在处理它
+        // The temporary file is deleted when the buffer is dropped, on every path / 缓冲被丢弃时删除临时文件，任何路径均如此
+        if let Some(mut document) = document {
+            img_manager.set_dpi(self.part_dpi(DOCUMENT_XML_PATH));
+            self.expand_includes(&mut document, &mut zip_stream, &rel_manager)
+                .await?;
+            self.write_document(
+                &mut sink,
+                &document,
+                &mut budget,
+                (placeholders, images),
+                &mut rel_manager,
+                &mut img_manager,
+                &mut bookmark_id,
+            )
+            .await?;
+        }
 
-            processor
-                .process_xml_events(
-                    &mut compat_writer,
-                    &mut buf_reader,
-                    placeholders,
-                    &mut rel_manager,
-                    &mut img_manager,
-                )
-                .await
-                .map_err(|_| ZipError::FeatureNotSupported("XML processing failed"))?;
+        // Process headers and footers, numbering their drawings and bookmarks after the body / 处理页眉和页脚，其绘图和书签编号接在正文之后
+        let mut drawing_id = rel_manager.next_drawing_id();
+        for (part, content) in header_footer_parts {
+            let mut part_rel_manager = part_rel_managers
+                .remove(&part)
+                .unwrap_or_else(|| RelationshipManager::new(self.capacity_hints.images));
+            part_rel_manager.set_drawing_id_start(drawing_id);
+            img_manager.set_dpi(self.part_dpi(&part));
 
-            // Restore cell handler / 恢复单元格处理器
-            self.cell_handler = Some(processor.cell_handler);
+            let mut output = Vec::with_capacity(content.len());
+            self.process_part(
+                &content,
+                &mut output,
+                (placeholders, images),
+                &mut part_rel_manager,
+                &mut img_manager,
+                &mut bookmark_id,
+            )
+            .await?;
+            drawing_id = part_rel_manager.next_drawing_id();
 
-            // Get back entry_writer and close it
-            compat_writer.into_inner().close().await?;
+            budget.charge(output.len())?;
+            timed(
+                &mut zip_write,
+                sink.write_part(&part, &output, Compression::Deflate),
+            )
+            .await?;
+            part_rel_managers.insert(part, part_rel_manager);
+        }
 
-            // Cleanup temp file after successful processing / 成功处理后清理临时文件
-            remove_file(&tmp_path).await?;
+        // Write relationship files of headers and footers / 写入页眉和页脚的关系文件
+        let mut part_rel_managers = part_rel_managers.into_iter().collect::<Vec<_>>();
+        part_rel_managers.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        for (part, part_rel_manager) in part_rel_managers {
+            let path = part_rels_path(&part);
+            if self.extra_files.iter().any(|(extra, _)| *extra == path) {
+                continue;
+            }
+            if let Some(rels_content) = part_rel_manager.generate_final_rels_content() {
+                budget.charge(rels_content.len())?;
+                timed(
+                    &mut zip_write,
+                    sink.write_part(&path, &rels_content, Compression::Deflate),
+                )
+                .await?;
+            }
         }
 
         // Write updated relationship file / 写入更新后的关系文件
         if let Some(rels_content) = rel_manager.generate_final_rels_content() {
-            let options = ZipEntryBuilder::new(RELS_PATH.into(), Compression::Deflate);
-            writer.write_entry_whole(options, &rels_content).await?;
+            budget.charge(rels_content.len())?;
+            timed(
+                &mut zip_write,
+                sink.write_part(RELS_PATH, &rels_content, Compression::Deflate),
+            )
+            .await?;
         }
 
         // Write all new images to media folder / 将所有新图片写入媒体文件夹
-        for (filename, (bytes, _)) in img_manager.get_images() {
+        let mut images = img_manager.get_images().iter().collect::<Vec<_>>();
+        if self.reproducible {
+            images.sort_unstable_by_key(|(filename, _)| *filename);
+        }
+        for (filename, (bytes, _)) in images {
             let path = format!("{}{}", MEDIA_PATH_PREFIX, filename);
-            let options = ZipEntryBuilder::new(path.into(), Compression::Stored);
-            writer.write_entry_whole(options, bytes).await?;
+            budget.charge(bytes.len())?;
+            timed(
+                &mut zip_write,
+                sink.write_part(&path, bytes, Compression::Stored),
+            )
+            .await?;
+
+            // Declare the image extension / 声明图片扩展名
+            if let Some((_, ext)) = filename.rsplit_once('.')
+                && let Some(content_type) = ContentTypeManager::image_content_type(ext)
+            {
+                content_type_manager.add_default(ext, content_type);
+            }
         }
 
-        // Close output zip file / 关闭输出 zip 文件
-        writer.close().await?;
+        // Write extra files / 写入额外文件
+        for (path, content) in &self.extra_files {
+            budget.charge(content.len())?;
+            timed(
+                &mut zip_write,
+                sink.write_part(path, content, Compression::Deflate),
+            )
+            .await?;
+        }
+
+        // Write updated content types file / 写入更新后的内容类型文件
+        if let Some(types_content) = content_type_manager.generate_final_content() {
+            budget.charge(types_content.len())?;
+            timed(
+                &mut zip_write,
+                sink.write_part(CONTENT_TYPES_PATH, &types_content, Compression::Deflate),
+            )
+            .await?;
+        }
+
+        self.report.timings.zip_write += zip_write;
+
+        if self.strict && !self.report.missing_placeholders.is_empty() {
+            return Err(DocxError::MissingPlaceholders(
+                self.report.missing_placeholders.clone(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Build the document processor from the current settings / 根据当前设置构建文档处理器
+    fn processor(
+        &self,
+        report: GenerationReport,
+        images: &ImageMap,
+        bookmark_id: u32,
+    ) -> DocxProcessor {
+        // Handlers are shared, so the DOCX stays usable when processing fails / 处理器为共享的，因此处理失败后 DOCX 仍可使用
+        DocxProcessor {
+            cell_handler: Arc::clone(&self.cell_handler),
+            fallbacks: Arc::clone(&self.fallbacks),
+            transforms: Arc::clone(&self.transforms),
+            skip_w_t_events: self.skip_w_t_events,
+            replace_in_attributes: self.replace_in_attributes,
+            report,
+            image_resolver: self.image_resolver.clone(),
+            image_bytes: Arc::clone(images),
+            image_default_dir: self.image_default_dir.clone(),
+            item_transform: self.item_transform.clone(),
+            column_alignments: self.column_alignments.clone(),
+            column_maps: self.column_maps.clone(),
+            value_maps: self.value_maps.clone(),
+            cant_split_rows: self.cant_split_rows,
+            repeat_header_rows: self.repeat_header_rows,
+            zebra_shading: self.zebra_shading.clone(),
+            lenient_xml: self.lenient_xml,
+            unified_syntax: self.unified_syntax,
+            strict_loop_keys: self.strict_loop_keys,
+            strict: self.strict,
+            skip_empty_rows: self.skip_empty_rows,
+            normalize_merge: self.normalize_merge,
+            max_rows: self.max_rows,
+            capacity_hints: self.capacity_hints,
+            bookmark_id,
+            language: self.language.clone(),
+            allow_raw_ooxml: self.allow_raw_ooxml,
+            validate_raw_ooxml: self.validate_raw_ooxml,
+            cancellation: self.cancellation.clone(),
+            changes: None,
+            paragraph: ParagraphContext::default(),
+        }
+    }
+
+    /// Whether the cancellation token was triggered / 取消令牌是否已触发
+    fn is_cancelled(&self) -> bool {
+        self.cancellation
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+    }
+
+    /// Process the buffered document.xml into `sink` / 将缓冲的 document.xml 处理到 `sink` 中
+    #[allow(clippy::too_many_arguments)]
+    async fn write_document<W>(
+        &mut self,
+        sink: &mut PartSink<'_, W>,
+        document: &DocumentBuffer,
+        budget: &mut OutputBudget,
+        values: (&HashMap<String, Value>, &ImageMap),
+        rel_manager: &mut RelationshipManager,
+        img_manager: &mut ImageManager<'_>,
+        bookmark_id: &mut u32,
+    ) -> Result<(), DocxError>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        match sink {
+            PartSink::Zip(writer, modified) => {
+                let options = zip_entry(DOCUMENT_XML_PATH, Compression::Deflate, *modified);
+                let entry_writer = writer.write_entry_stream(options).await?;
+
+                // Use compat_write() to convert futures AsyncWrite to tokio AsyncWrite if needed
+                let mut compat_writer = entry_writer.compat_write();
+                let result = self
+                    .process_document(
+                        document,
+                        &mut LimitedWriter::new(&mut compat_writer, budget),
+                        values,
+                        rel_manager,
+                        img_manager,
+                        bookmark_id,
+                    )
+                    .await;
+                budget.check()?;
+                result?;
+
+                // Get back entry_writer and close it
+                compat_writer.into_inner().close().await?;
+            }
+            PartSink::Directory(dir) => {
+                let file = AsyncFile::create(part_path(dir, DOCUMENT_XML_PATH).await?).await?;
+                let mut output = BufWriter::new(file);
+                let result = self
+                    .process_document(
+                        document,
+                        &mut LimitedWriter::new(&mut output, budget),
+                        values,
+                        rel_manager,
+                        img_manager,
+                        bookmark_id,
+                    )
+                    .await;
+                budget.check()?;
+                result?;
+                output.flush().await?;
+            }
+            PartSink::Memory(parts) => {
+                let mut content = Vec::with_capacity(DEFAULT_BUFFER_SIZE);
+                let result = self
+                    .process_document(
+                        document,
+                        &mut LimitedWriter::new(&mut content, budget),
+                        values,
+                        rel_manager,
+                        img_manager,
+                        bookmark_id,
+                    )
+                    .await;
+                budget.check()?;
+                result?;
+                parts.push((DOCUMENT_XML_PATH.to_string(), content));
+            }
+        }
+        Ok(())
+    }
+
+    /// Splice the fragments named by `[include:path]` markers into the buffered document.xml / 将 `[include:path]` 标记指定的片段拼接到缓冲的 document.xml 中
+    ///
+    /// Fragments are read from the template package, including the ones named inside other fragments. Their placeholders are filled with the document. A fragment using a relationship id missing from the document relationships, such as an image of its own, is not spliced and reported / 片段从模板包中读取，包括其他片段中指定的片段。其占位符随文档一起填充。使用了文档关系中缺少的关系 id（例如其自身的图片）的片段不会被拼接并会被报告
+    async fn expand_includes<R>(
+        &mut self,
+        document: &mut DocumentBuffer,
+        zip_stream: &mut ZipFileReader<R>,
+        rel_manager: &RelationshipManager,
+    ) -> Result<(), DocxError>
+    where
+        R: AsyncBufRead + AsyncSeek + Unpin,
+    {
+        let content = document.content().await?;
+        let mut pending = include_paths(&content);
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        let mut fragments = HashMap::new();
+        let mut rejected = Vec::new();
+        while let Some(path) = pending.pop() {
+            if fragments.contains_key(&path) || rejected.contains(&path) {
+                continue;
+            }
+            let Some(index) = zip_stream
+                .file()
+                .entries()
+                .iter()
+                .position(|entry| entry.filename().as_str().is_ok_and(|name| name == path))
+            else {
+                continue;
+            };
+            let mut content = Vec::with_capacity(DEFAULT_BUFFER_SIZE);
+            zip_stream
+                .reader_with_entry(index)
+                .await
+                .map_err(DocxError::from_package_error)?
+                .compat()
+                .read_to_end(&mut content)
+                .await?;
+            // Relationships of the fragment part itself are not carried over / 片段部件自身的关系不会被带入
+            if let Some(id) = relationship_ids(&content)?
+                .into_iter()
+                .find(|id| !rel_manager.has_relationship(id))
+            {
+                self.report
+                    .warnings
+                    .push(GenerationWarning::IncludeRelationshipMissing {
+                        path: path.clone(),
+                        id,
+                    });
+                rejected.push(path);
+                continue;
+            }
+            pending.extend(include_paths(&content));
+            fragments.insert(path, content);
+        }
+
+        let (spliced, unresolved) = splice_includes(&content, &fragments)?;
+        drop(content);
+        self.report.warnings.extend(
+            unresolved
+                .into_iter()
+                .filter(|path| !rejected.contains(path))
+                .map(|path| GenerationWarning::UnresolvedInclude { path }),
+        );
+        document.replace(spliced).await?;
+        Ok(())
+    }
+
+    /// Process a buffered header or footer part into `output` / 将缓冲的页眉或页脚部件处理到 `output` 中
+    ///
+    /// `bookmark_id` is the first id for generated bookmarks and is advanced past the ones used / `bookmark_id` 是生成书签的起始 id，处理后越过已使用的 id
+    async fn process_part(
+        &mut self,
+        content: &[u8],
+        output: &mut Vec<u8>,
+        (placeholders, images): (&HashMap<String, Value>, &ImageMap),
+        rel_manager: &mut RelationshipManager,
+        img_manager: &mut ImageManager<'_>,
+        bookmark_id: &mut u32,
+    ) -> Result<(), DocxError> {
+        let report = take(&mut self.report);
+        let mut processor = self.processor(report, images, *bookmark_id);
+        processor
+            .process_xml_events(
+                output,
+                &mut &content[..],
+                placeholders,
+                rel_manager,
+                img_manager,
+            )
+            .await?;
+
+        // Keep the report / 保留报告
+        self.report = processor.report;
+        *bookmark_id = processor.bookmark_id;
+        Ok(())
+    }
+
+    /// Process the buffered document.xml into `output` / 将缓冲的 document.xml 处理到 `output` 中
+    ///
+    /// `bookmark_id` is the first id for generated bookmarks and is advanced past the ones used / `bookmark_id` 是生成书签的起始 id，处理后越过已使用的 id
+    async fn process_document<W>(
+        &mut self,
+        document: &DocumentBuffer,
+        output: &mut W,
+        (placeholders, images): (&HashMap<String, Value>, &ImageMap),
+        rel_manager: &mut RelationshipManager,
+        img_manager: &mut ImageManager<'_>,
+        bookmark_id: &mut u32,
+    ) -> Result<(), DocxError>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let report = take(&mut self.report);
+        let mut processor = self.processor(report, images, *bookmark_id);
+
+        let started = Instant::now();
+
+        // Report constructs that keep placeholders from being filled, parse errors are left to the processing below / 报告导致占位符无法填充的结构，解析错误留给下面的处理
+        if self.diagnostics
+            && let Ok(warnings) = scan_template(document.reader().await?).await
+        {
+            processor.report.warnings.extend(warnings);
+        }
+
+        // Open the buffered document for reading / 打开缓冲的文档进行读取
+        let mut buf_reader = document.reader().await?;
+
+        match &self.section {
+            // The range is only known once the document is rendered / 范围仅在文档渲染后才可知
+            Some(bookmark) => {
+                let mut rendered = Vec::with_capacity(DEFAULT_BUFFER_SIZE);
+                processor
+                    .process_xml_events(
+                        &mut rendered,
+                        &mut buf_reader,
+                        placeholders,
+                        rel_manager,
+                        img_manager,
+                    )
+                    .await?;
+                output
+                    .write_all(&extract_section(&rendered, bookmark)?)
+                    .await?;
+            }
+            // Process XML events directly / 直接处理 XML 事件
+            None => {
+                processor
+                    .process_xml_events(
+                        output,
+                        &mut buf_reader,
+                        placeholders,
+                        rel_manager,
+                        img_manager,
+                    )
+                    .await?;
+            }
+        }
+
+        // Keep the report / 保留报告
+        self.report = processor.report;
+        *bookmark_id = processor.bookmark_id;
+        self.report.timings.document = started.elapsed().saturating_sub(self.report.timings.images);
         Ok(())
     }
 }
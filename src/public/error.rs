@@ -1,15 +1,78 @@
+use crate::public::report::GenerationWarning;
 use async_zip::error::ZipError;
+use std::io::ErrorKind;
 
 /// Error type for DOCX operations / DOCX 操作的错误类型
 ///
 /// Wraps errors from XML parsing and ZIP file operations / 包装来自 XML 解析和 ZIP 文件操作的错误
+///
+/// New variants may be added, so matches need a wildcard arm / 可能会添加新的变体，因此匹配需要通配分支
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum DocxError {
     /// XML parsing error / XML 解析错误
     Xml(quick_xml::Error),
 
     /// ZIP file operation error / ZIP 文件操作错误
     Zip(ZipError),
+
+    /// Input is not a zip archive or is truncated / 输入不是 zip 压缩包或已被截断
+    InvalidPackage(ZipError),
+
+    /// Requested part is not in the package, with its path (e.g. "word/media/image9.png") / 请求的部件不在包中，附带其路径（例如 "word/media/image9.png"）
+    MissingPart(String),
+
+    /// File system error / 文件系统错误
+    Io(std::io::Error),
+
+    /// Warning promoted to an error by a strict option / 被严格选项提升为错误的警告
+    Validation(GenerationWarning),
+
+    /// Placeholder data could not be serialized to JSON / 占位符数据无法序列化为 JSON
+    Serialization(serde_json::Error),
+
+    /// A configured size limit was exceeded / 超出了配置的大小限制
+    LimitExceeded(Limit),
+
+    /// Generation was aborted by its cancellation token / 生成被其取消令牌中止
+    Cancelled,
+
+    /// Placeholders without a value in strict mode, in document order / 严格模式下没有值的占位符，按文档顺序排列
+    MissingPlaceholders(Vec<String>),
+}
+
+/// Size limit that aborted generation / 中止生成的大小限制
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Limit {
+    /// Generated loop rows, summed over all tables / 生成的循环行数，对所有表格求和
+    Rows {
+        /// Configured maximum / 配置的最大值
+        max: usize,
+        /// Rows the data would have produced, counting stops at the first row over the limit when flattening can be cut short / 数据将生成的行数，可提前结束展平时计数在超出限制的第一行处停止
+        requested: usize,
+    },
+    /// Uncompressed bytes of the written parts / 已写入部件的未压缩字节数
+    OutputBytes {
+        /// Configured maximum / 配置的最大值
+        max: usize,
+    },
+}
+
+impl DocxError {
+    /// Classify an error raised while reading the input package / 对读取输入包时产生的错误进行分类
+    ///
+    /// Structural errors become `InvalidPackage`, everything else stays `Zip` / 结构性错误归为 `InvalidPackage`，其他错误保持为 `Zip`
+    pub(crate) fn from_package_error(value: ZipError) -> Self {
+        match value {
+            ZipError::UnableToLocateEOCDR
+            | ZipError::UnexpectedHeaderError(..)
+            | ZipError::CRC32CheckError => DocxError::InvalidPackage(value),
+            ZipError::UpstreamReadError(ref e) if e.kind() == ErrorKind::UnexpectedEof => {
+                DocxError::InvalidPackage(value)
+            }
+            _ => DocxError::Zip(value),
+        }
+    }
 }
 
 // Automatic conversion from ZipError / 从 ZipError 自动转换
@@ -25,3 +88,24 @@ impl From<quick_xml::Error> for DocxError {
         DocxError::Xml(value)
     }
 }
+
+// Automatic conversion from IO Error / 从 IO 错误自动转换
+impl From<std::io::Error> for DocxError {
+    fn from(value: std::io::Error) -> Self {
+        DocxError::Io(value)
+    }
+}
+
+// Automatic conversion from JSON serialization Error / 从 JSON 序列化错误自动转换
+impl From<serde_json::Error> for DocxError {
+    fn from(value: serde_json::Error) -> Self {
+        DocxError::Serialization(value)
+    }
+}
+
+// Automatic conversion from XML encoding Error / 从 XML 编码错误自动转换
+impl From<quick_xml::encoding::EncodingError> for DocxError {
+    fn from(value: quick_xml::encoding::EncodingError) -> Self {
+        DocxError::Xml(value.into())
+    }
+}
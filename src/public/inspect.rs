@@ -0,0 +1,17 @@
+/// Structure of a table detected in a template / 在模板中检测到的表格结构
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableInfo {
+    /// Loop key of the table (e.g. "{{#users}}"), `None` for a static table / 表格的循环键（例如 "{{#users}}"），静态表格为 `None`
+    pub loop_key: Option<String>,
+    /// Field placeholders of the data rows in document order (e.g. "name" for `[name]`) / 数据行中按文档顺序排列的字段占位符（例如 `[name]` 对应 "name"）
+    pub column_keys: Vec<String>,
+}
+
+/// Text replacement that generating would make / 生成时将进行的文本替换
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    /// Text of the `w:t` element in the template / 模板中 `w:t` 元素的文本
+    pub original: String,
+    /// Text written in its place, empty when run-level content such as an image replaces it / 写入的替代文本，被图片等运行级内容替换时为空
+    pub replaced: String,
+}
@@ -1,3 +1,8 @@
+pub mod alignment;
+pub mod capacity;
+pub mod compiled;
 pub mod docx;
 pub mod error;
+pub mod inspect;
+pub mod report;
 pub mod value_extern;
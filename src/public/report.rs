@@ -0,0 +1,135 @@
+use std::time::Duration;
+
+/// Warning collected while generating a document / 生成文档时收集的警告
+#[derive(Debug, Clone, PartialEq)]
+pub enum GenerationWarning {
+    /// Placeholder found in an element attribute instead of text content / 在元素属性而非文本内容中发现占位符
+    PlaceholderInAttribute {
+        /// Element name (e.g. "w:pStyle") / 元素名称（例如 "w:pStyle"）
+        element: String,
+        /// Attribute name (e.g. "w:val") / 属性名称（例如 "w:val"）
+        attribute: String,
+        /// Unescaped attribute value / 反转义后的属性值
+        value: String,
+    },
+    /// Flattened loop item whose keys differ from the first item / 键与第一项不同的展平循环项
+    InconsistentLoopKeys {
+        /// Loop key of the table (e.g. "{{#users}}") / 表格的循环键（例如 "{{#users}}"）
+        table: String,
+        /// Index of the flattened item / 展平项的索引
+        item: usize,
+        /// Keys of the first item missing from this item / 此项缺少的第一项中的键
+        missing: Vec<String>,
+        /// Keys of this item absent from the first item / 第一项中不存在的此项的键
+        extra: Vec<String>,
+    },
+    /// Loop item the item transform turned into a non-object value, the item is not rendered / 被循环项转换变为非对象值的循环项，该项不会被渲染
+    ItemTransformNotObject {
+        /// Loop key of the table (e.g. "{{#users}}") / 表格的循环键（例如 "{{#users}}"）
+        table: String,
+        /// Index of the flattened item / 展平项的索引
+        item: usize,
+    },
+    /// Placeholder provided by several merged data sources, the last one wins / 由多个合并数据源提供的占位符，以最后一个为准
+    DuplicatePlaceholder {
+        /// Placeholder key (e.g. "{{name}}") / 占位符键（例如 "{{name}}"）
+        key: String,
+        /// Index of the source whose value was overwritten / 值被覆盖的数据源索引
+        overwritten: usize,
+        /// Index of the source whose value is kept / 保留其值的数据源索引
+        kept: usize,
+    },
+    /// Malformed XML skipped in lenient mode / 宽松模式下跳过的格式错误的 XML
+    MalformedXml {
+        /// Byte position of the error in the part / 错误在部件中的字节位置
+        position: u64,
+        /// Parser error message / 解析器错误消息
+        message: String,
+    },
+    /// Table inside a table cell, which table processing does not support: its placeholders may stay unfilled or generation may fail / 表格单元格内的表格，表格处理不支持此结构：其占位符可能不会被填充，或生成可能失败
+    NestedTable {
+        /// Byte position of the nested table in document.xml / 嵌套表格在 document.xml 中的字节位置
+        position: u64,
+    },
+    /// Placeholder whose text is split across several runs, usually by formatting or spell checking / 文本被拆分到多个运行中的占位符，通常由格式或拼写检查造成
+    SplitPlaceholder {
+        /// Joined placeholder text (e.g. "{{name}}") / 拼接后的占位符文本（例如 "{{name}}"）
+        placeholder: String,
+        /// Byte position of the paragraph end in document.xml / 段落结束在 document.xml 中的字节位置
+        position: u64,
+    },
+    /// `[include:path]` marker left in place, its fragment is missing from the package or nested too deeply / 保留原样的 `[include:path]` 标记，其片段不在包中或嵌套过深
+    UnresolvedInclude {
+        /// Package path of the fragment (e.g. "word/fragments/cover.xml") / 片段的包路径（例如 "word/fragments/cover.xml"）
+        path: String,
+    },
+    /// `[include:path]` marker left in place, its fragment uses a relationship the document does not define / 保留原样的 `[include:path]` 标记，其片段使用了文档未定义的关系
+    IncludeRelationshipMissing {
+        /// Package path of the fragment (e.g. "word/fragments/cover.xml") / 片段的包路径（例如 "word/fragments/cover.xml"）
+        path: String,
+        /// Relationship id missing from document.xml.rels (e.g. "rId9") / document.xml.rels 中缺少的关系 id（例如 "rId9"）
+        id: String,
+    },
+    /// SVG image embedded with a transparent PNG fallback, since it could not be rasterized without the `svg` feature or a declared size; viewers without SVG support show it blank / 以透明 PNG 回退图片嵌入的 SVG 图片，因为没有 `svg` 特性或声明的尺寸无法栅格化；不支持 SVG 的查看器将其显示为空白
+    BlankSvgFallback {
+        /// Name of the picture in the document (e.g. "Picture 3") / 图片在文档中的名称（例如 "Picture 3"）
+        name: String,
+    },
+    /// `defaultfile=` image not embedded / 未嵌入的 `defaultfile=` 图片
+    ImageDefaultSkipped {
+        /// Path named in the template / 模板中指定的路径
+        path: String,
+        /// Reason the file was skipped / 跳过该文件的原因
+        message: String,
+    },
+}
+
+/// Vertical merges applied to one column of a loop table / 循环表格中一列应用的垂直合并
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnMerge {
+    /// Zero-based column index / 从零开始的列索引
+    pub column: usize,
+    /// Number of rows spanned by each merged run, in order / 每个合并区域跨越的行数，按顺序排列
+    pub spans: Vec<usize>,
+}
+
+/// Merge summary of a loop table / 循环表格的合并摘要
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableMergeSummary {
+    /// Loop key of the table (e.g. "{{#users}}") / 表格的循环键（例如 "{{#users}}"）
+    pub key: String,
+    /// Number of loop rows generated, after flattening and skipping / 生成的循环行数（展平和跳过之后）
+    pub rows: usize,
+    /// Columns where vertical merging was applied / 应用了垂直合并的列
+    pub columns: Vec<ColumnMerge>,
+}
+
+/// Time spent in each stage of a generation / 生成各阶段所花费的时间
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[non_exhaustive]
+pub struct StageTimings {
+    /// Reading and decompressing the template entries / 读取并解压模板条目
+    pub zip_read: Duration,
+    /// Processing document.xml, excluding image embedding / 处理 document.xml，不含图片嵌入
+    pub document: Duration,
+    /// Decoding and registering embedded images / 解码并注册嵌入的图片
+    pub images: Duration,
+    /// Writing and compressing the output parts / 写入并压缩输出部件
+    pub zip_write: Duration,
+}
+
+/// Report of the last generation / 上次生成的报告
+///
+/// Reset at the start of every generation / 每次生成开始时重置
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct GenerationReport {
+    /// Warnings collected during generation / 生成期间收集的警告
+    pub warnings: Vec<GenerationWarning>,
+    /// Merge summary of every rendered loop table, in document order / 每个已渲染循环表格的合并摘要，按文档顺序排列
+    pub tables: Vec<TableMergeSummary>,
+    /// Time spent in each stage / 各阶段所花费的时间
+    pub timings: StageTimings,
+    /// Placeholders without a value, collected in strict mode in document order / 没有值的占位符，在严格模式下按文档顺序收集
+    pub missing_placeholders: Vec<String>,
+}
@@ -1,7 +1,10 @@
 use serde_json::Value;
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 /// Value extension trait for placeholder replacement / 占位符替换的值扩展 trait
+///
+/// Results borrow the key when nothing is substituted, so static text is written without allocation / 未发生替换时结果借用键，因此静态文本写入时无需分配
 pub trait ValueExt: Send + Sync {
     /// Replace placeholders in cyclic table cells / 替换循环表格单元格中的占位符
     ///
@@ -9,17 +12,49 @@ pub trait ValueExt: Send + Sync {
     /// * `index` - Row index for context / 用于上下文的行索引
     /// * `key` - Placeholder key / 占位符键
     /// * `placeholders` - Value map / 值映射
-    fn replace_in_table(
+    fn replace_in_table<'k>(
         &self,
         index: usize,
-        key: &str,
+        key: &'k str,
         placeholders: &HashMap<String, Value>,
-    ) -> String;
+    ) -> Cow<'k, str>;
 
     /// Replace placeholders in regular text / 替换常规文本中的占位符
     ///
     /// # Arguments / 参数
     /// * `key` - Placeholder key / 占位符键
     /// * `placeholders` - Value map / 值映射
-    fn replace(&self, key: &str, placeholders: &HashMap<String, Value>) -> String;
+    fn replace<'k>(&self, key: &'k str, placeholders: &HashMap<String, Value>) -> Cow<'k, str>;
+
+    /// Look up a placeholder in cyclic table cells, `None` when the key is not found / 在循环表格单元格中查找占位符，未找到键时为 `None`
+    ///
+    /// Used to consult fallback handlers. The default treats every key as found / 用于查询后备处理器。默认实现视所有键为已找到
+    ///
+    /// # Arguments / 参数
+    /// * `index` - Row index for context / 用于上下文的行索引
+    /// * `key` - Placeholder key / 占位符键
+    /// * `placeholders` - Value map / 值映射
+    fn lookup_in_table<'k>(
+        &self,
+        index: usize,
+        key: &'k str,
+        placeholders: &HashMap<String, Value>,
+    ) -> Option<Cow<'k, str>> {
+        Some(self.replace_in_table(index, key, placeholders))
+    }
+
+    /// Look up a placeholder in regular text, `None` when the key is not found / 在常规文本中查找占位符，未找到键时为 `None`
+    ///
+    /// Used to consult fallback handlers. The default treats every key as found / 用于查询后备处理器。默认实现视所有键为已找到
+    ///
+    /// # Arguments / 参数
+    /// * `key` - Placeholder key / 占位符键
+    /// * `placeholders` - Value map / 值映射
+    fn lookup<'k>(
+        &self,
+        key: &'k str,
+        placeholders: &HashMap<String, Value>,
+    ) -> Option<Cow<'k, str>> {
+        Some(self.replace(key, placeholders))
+    }
 }
@@ -0,0 +1,92 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_accounting_negative_colored() -> Result<(), DocxError> {
+    let input = "output/tests/accounting_format_template.docx";
+    let output = "output/tests/accounting_format.docx";
+    let body = table(
+        2,
+        &[
+            table_row(&["{{#lines}}Item", "Amount"]),
+            table_row(&["[item]", "[acct:amount:C00000]"]),
+        ],
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#lines}}".to_string(),
+        json!([
+            { "item": "Revenue", "amount": 1234.5 },
+            { "item": "Costs", "amount": -1234 },
+            { "item": "Other", "amount": 0 },
+        ]),
+    );
+    DOCX::default().generate(input, output, &data).await?;
+
+    let document = read_entry(output, "word/document.xml").await?;
+    assert!(document.contains(
+        r#"<w:r><w:rPr><w:color w:val="C00000"/></w:rPr><w:t xml:space="preserve">(1,234.00)</w:t></w:r>"#
+    ));
+    assert!(document.contains(">1,234.50<"));
+    assert!(document.contains(">0.00<"));
+    // Only the negative value is colored / 仅负值带颜色
+    assert_eq!(document.matches("w:color").count(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_accounting_paragraph_uncolored() -> Result<(), DocxError> {
+    let input = "output/tests/accounting_format_paragraph_template.docx";
+    let output = "output/tests/accounting_format_paragraph.docx";
+    write_template(input, &paragraph("{{acct:total}}")).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{total}}".to_string(), json!(-98765.4));
+    DOCX::default().generate(input, output, &data).await?;
+
+    let document = read_entry(output, "word/document.xml").await?;
+    assert!(document.contains(">(98,765.40)<"));
+    assert!(!document.contains("w:color"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_accounting_color_keeps_run_properties() -> Result<(), DocxError> {
+    let input = "output/tests/accounting_format_rpr_template.docx";
+    let output = "output/tests/accounting_format_rpr.docx";
+    let cell = concat!(
+        r#"<w:tc><w:p><w:r><w:rPr><w:b/><w:color w:val="000000"/><w:sz w:val="20"/></w:rPr>"#,
+        r#"<w:t>[acct:amount:C00000]</w:t></w:r></w:p></w:tc>"#
+    );
+    let body = table(
+        1,
+        &[
+            table_row(&["{{#lines}}Amount"]),
+            format!("<w:tr>{}</w:tr>", cell),
+        ],
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#lines}}".to_string(),
+        json!([{ "amount": -5 }, { "amount": "(R&amp;D)" }]),
+    );
+    DOCX::default().generate(input, output, &data).await?;
+
+    let document = read_entry(output, "word/document.xml").await?;
+    // The color replaces the template color, other formatting is kept / 颜色替换模板颜色，其他格式保留
+    assert!(document.contains(concat!(
+        r#"<w:r><w:rPr><w:b/><w:color w:val="C00000"/><w:sz w:val="20"/></w:rPr>"#,
+        r#"<w:t xml:space="preserve">(5.00)</w:t></w:r>"#
+    )));
+    // Values are written as escaped text like every other placeholder / 值与其他占位符一样作为已转义文本写入
+    assert!(document.contains(">(R&amp;D)<"));
+    assert!(!document.contains("&amp;amp;"));
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Partial files left beside `output` / `output` 旁残留的部分文件
+fn partial_files(output: &str) -> usize {
+    let output = Path::new(output);
+    let name = output.file_name().unwrap().to_string_lossy().into_owned();
+    std::fs::read_dir(output.parent().unwrap())
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| {
+            let file = entry.file_name().to_string_lossy().into_owned();
+            file.starts_with(&name) && file != name
+        })
+        .count()
+}
+
+#[tokio::test]
+async fn test_invalid_package_keeps_existing_output() -> Result<(), DocxError> {
+    let input = "output/tests/atomic_output_invalid.docx";
+    let output = "output/tests/atomic_output_invalid_result.docx";
+    tokio::fs::create_dir_all("output/tests").await?;
+    tokio::fs::write(input, b"not a zip").await?;
+    tokio::fs::write(output, b"previous output").await?;
+
+    let mut docx = DOCX::default();
+    let result = docx.generate(input, output, &HashMap::new()).await;
+    assert!(matches!(result, Err(DocxError::InvalidPackage(_))));
+    assert_eq!(tokio::fs::read(output).await?, b"previous output");
+    assert_eq!(partial_files(output), 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_strict_failure_writes_no_output() -> Result<(), DocxError> {
+    let input = "output/tests/atomic_output_strict_template.docx";
+    let output = "output/tests/atomic_output_strict.docx";
+    write_template(input, &paragraph("{{name}}")).await?;
+    let _ = tokio::fs::remove_file(output).await;
+
+    let mut docx = DOCX::default();
+    docx.set_strict(true);
+    let result = docx.generate(input, output, &HashMap::new()).await;
+    assert!(matches!(result, Err(DocxError::MissingPlaceholders(_))));
+    assert!(!Path::new(output).exists());
+    assert_eq!(partial_files(output), 0);
+
+    // A successful generation still lands at the output path / 成功的生成仍然写入输出路径
+    let mut data = HashMap::new();
+    data.insert("{{name}}".to_string(), json!("Alice"));
+    docx.generate(input, output, &data).await?;
+    assert!(Path::new(output).exists());
+    assert_eq!(partial_files(output), 0);
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use crate::public::error::DocxError;
+use crate::tests::common::{read_entry, write_template};
+use crate::{DOCX, GenerationWarning};
+use serde_json::Value;
+use std::collections::HashMap;
+
+const BODY: &str =
+    r#"<w:p><w:pPr><w:pStyle w:val="{{style}}"/></w:pPr><w:r><w:t>{{title}}</w:t></w:r></w:p>"#;
+
+fn data() -> HashMap<String, Value> {
+    let mut data = HashMap::new();
+    data.insert(
+        "{{style}}".to_string(),
+        Value::String("Heading&1".to_string()),
+    );
+    data.insert("{{title}}".to_string(), Value::String("Title".to_string()));
+    data
+}
+
+#[tokio::test]
+async fn test_placeholder_in_attribute_reported() -> Result<(), DocxError> {
+    let input = "output/tests/attribute_reported_template.docx";
+    let output = "output/tests/attribute_reported.docx";
+    write_template(input, BODY).await?;
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data()).await?;
+
+    assert_eq!(
+        docx.report().warnings,
+        vec![GenerationWarning::PlaceholderInAttribute {
+            element: "w:pStyle".to_string(),
+            attribute: "w:val".to_string(),
+            value: "{{style}}".to_string(),
+        }]
+    );
+    // Attribute left untouched by default / 默认情况下属性保持不变
+    let xml = read_entry(output, "word/document.xml").await?;
+    assert!(xml.contains(r#"<w:pStyle w:val="{{style}}"/>"#));
+    assert!(xml.contains("<w:t>Title</w:t>"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_placeholder_in_attribute_replaced() -> Result<(), DocxError> {
+    let input = "output/tests/attribute_replaced_template.docx";
+    let output = "output/tests/attribute_replaced.docx";
+    write_template(input, BODY).await?;
+
+    let mut docx = DOCX::default();
+    docx.set_replace_in_attributes(true);
+    docx.generate(input, output, &data()).await?;
+
+    assert_eq!(docx.report().warnings.len(), 1);
+    let xml = read_entry(output, "word/document.xml").await?;
+    assert!(xml.contains(r#"<w:pStyle w:val="Heading&amp;1"/>"#));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bracket_placeholder_in_attribute() -> Result<(), DocxError> {
+    let input = "output/tests/attribute_bracket_template.docx";
+    let output = "output/tests/attribute_bracket.docx";
+    write_template(
+        input,
+        r#"<w:p><w:pPr><w:pStyle w:val="[style]"/></w:pPr><w:r><w:t>{{title}}</w:t></w:r></w:p>"#,
+    )
+    .await?;
+
+    let mut docx = DOCX::default();
+    docx.set_replace_in_attributes(true);
+    docx.generate(input, output, &data()).await?;
+
+    assert_eq!(
+        docx.report().warnings,
+        vec![GenerationWarning::PlaceholderInAttribute {
+            element: "w:pStyle".to_string(),
+            attribute: "w:val".to_string(),
+            value: "[style]".to_string(),
+        }]
+    );
+    let xml = read_entry(output, "word/document.xml").await?;
+    assert!(xml.contains(r#"<w:pStyle w:val="Heading&amp;1"/>"#));
+    Ok(())
+}
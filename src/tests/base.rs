@@ -35,6 +35,7 @@ enum PetType {
 }
 
 #[tokio::test]
+#[allow(clippy::vec_init_then_push)]
 async fn test_base() -> Result<(), DocxError> {
     let mut thumbnail = String::new();
     let mut logo = String::new();
@@ -0,0 +1,32 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_zip_entry, write_template};
+use bytes::Bytes;
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_base64_with_crlf_line_breaks() -> Result<(), DocxError> {
+    let input = "output/tests/base64_line_breaks_template.docx";
+    write_template(input, &paragraph("{{@logo}}")).await?;
+    let template = Bytes::from(tokio::fs::read(input).await?);
+
+    // Wrap at 76 characters with CRLF, as MIME encoders and Windows files do / 按 76 个字符以 CRLF 换行，与 MIME 编码器和 Windows 文件一致
+    let logo = tokio::fs::read_to_string("template/logo_base64.txt").await?;
+    let wrapped = logo
+        .as_bytes()
+        .chunks(76)
+        .map(|line| String::from_utf8_lossy(line).into_owned())
+        .collect::<Vec<_>>()
+        .join("\r\n")
+        + "\r\n";
+
+    let mut data = HashMap::new();
+    data.insert("{{logo}}".to_string(), Value::String(wrapped));
+
+    let mut docx = DOCX::default();
+    let output = docx.generate_bytes(template, &data).await?;
+    let document = read_zip_entry(&output, "word/document.xml").await?;
+    assert!(String::from_utf8_lossy(&document).contains("<w:drawing>"));
+    Ok(())
+}
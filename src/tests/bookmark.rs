@@ -0,0 +1,70 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, write_template, write_template_with_parts};
+use regex::Regex;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_bookmark_markers() -> Result<(), DocxError> {
+    let input = "output/tests/bookmark_template.docx";
+    let output = "output/tests/bookmark.docx";
+    let body = [
+        paragraph("[ilink:details|Go to details]"),
+        paragraph("[bookmark:details]"),
+        paragraph("[bookmark:appendix]"),
+    ]
+    .concat();
+    write_template(input, &body).await?;
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &HashMap::new()).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    assert!(!xml.contains("[bookmark:"));
+    let pair = Regex::new(
+        r#"<w:bookmarkStart w:id="(\d+)" w:name="(\w+)"/><w:bookmarkEnd w:id="(\d+)"/>"#,
+    )
+    .unwrap();
+    let bookmarks = pair
+        .captures_iter(&xml)
+        .map(|c| (c[1].to_string(), c[2].to_string(), c[3].to_string()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(bookmarks.len(), 2);
+    assert_eq!(bookmarks[0].1, "details");
+    assert_eq!(bookmarks[1].1, "appendix");
+    // Start and end share an id, ids are unique / 开始和结束共享 id，id 唯一
+    assert!(bookmarks.iter().all(|(start, _, end)| start == end));
+    assert_ne!(bookmarks[0].0, bookmarks[1].0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bookmark_ids_across_parts() -> Result<(), DocxError> {
+    let input = "output/tests/bookmark_parts_template.docx";
+    let output = "output/tests/bookmark_parts.docx";
+    let header = format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><w:hdr xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">{}</w:hdr>"#,
+        paragraph("[bookmark:top]")
+    );
+    write_template_with_parts(
+        input,
+        &paragraph("[bookmark:body]"),
+        &[("word/header1.xml", header.as_bytes())],
+    )
+    .await?;
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &HashMap::new()).await?;
+
+    let id = Regex::new(r#"<w:bookmarkStart w:id="(\d+)""#).unwrap();
+    let mut ids = Vec::new();
+    for part in ["word/document.xml", "word/header1.xml"] {
+        let xml = read_entry(output, part).await?;
+        ids.extend(id.captures_iter(&xml).map(|c| c[1].to_string()));
+    }
+    // Headers continue the ids of the body / 页眉延续正文的 id
+    assert_eq!(ids.len(), 2);
+    assert_ne!(ids[0], ids[1]);
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use crate::core::default_handler::DefaultValueHandler;
+use crate::public::value_extern::ValueExt;
+use serde_json::json;
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+#[test]
+fn test_static_text_is_borrowed() {
+    let text = "Lorem ipsum dolor sit amet. ".repeat(10_000);
+    let mut placeholders = HashMap::new();
+    placeholders.insert("{{name}}".to_string(), json!("Alice"));
+    let handler = DefaultValueHandler;
+
+    let replaced = handler.replace(&text, &placeholders);
+    assert!(matches!(replaced, Cow::Borrowed(_)));
+    assert_eq!(replaced, text);
+
+    let replaced = handler.replace("{{name}}", &placeholders);
+    assert!(matches!(replaced, Cow::Owned(_)));
+    assert_eq!(replaced, "Alice");
+}
+
+#[test]
+fn test_plain_loop_key_is_resolved() {
+    let mut item = HashMap::new();
+    item.insert("product".to_string(), json!("Pen"));
+    let handler = DefaultValueHandler;
+
+    assert_eq!(handler.replace_in_table(0, "product", &item), "Pen");
+    assert_eq!(handler.replace_in_table(0, "[product]", &item), "Pen");
+}
@@ -0,0 +1,33 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{read_entry, table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_key_with_inner_brackets() -> Result<(), DocxError> {
+    let input = "output/tests/bracket_key_template.docx";
+    let output = "output/tests/bracket_key.docx";
+    let body = table(
+        2,
+        &[
+            table_row(&["{{#items}}name", "size"]),
+            table_row(&["[name]", "[size[cm]]"]),
+        ],
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#items}}".to_string(),
+        json!([{"name": "Desk", "size[cm]": "120", "sizecm": "wrong"}]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+    assert!(xml.contains("<w:t>Desk</w:t>"));
+    assert!(xml.contains("<w:t>120</w:t>"));
+    assert!(!xml.contains("wrong"));
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, table, table_row, write_template};
+use crate::{CancellationToken, DOCX};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::env::temp_dir;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// Buffered document.xml files holding `marker` / 包含 `marker` 的缓冲 document.xml 文件
+fn buffered_documents(marker: &str) -> Vec<PathBuf> {
+    std::fs::read_dir(temp_dir())
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with("docx_"))
+                && std::fs::read_to_string(path).is_ok_and(|content| content.contains(marker))
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_cancelled_before_generation() -> Result<(), DocxError> {
+    let input = "output/tests/cancellation_before_template.docx";
+    let output = "output/tests/cancellation_before.docx";
+    write_template(input, &paragraph("{{name}}")).await?;
+
+    let token = CancellationToken::new();
+    token.cancel();
+    let mut docx = DOCX::default();
+    docx.set_cancellation_token(Some(token));
+
+    let mut data = HashMap::new();
+    data.insert("{{name}}".to_string(), json!("Alice"));
+    let result = docx.generate(input, output, &data).await;
+    assert!(matches!(result, Err(DocxError::Cancelled)));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cancelled_mid_generation_cleans_up() -> Result<(), DocxError> {
+    let input = "output/tests/cancellation_mid_template.docx";
+    let output = "output/tests/cancellation_mid.docx";
+    let marker = "cancellation_mid_marker";
+    let body = format!(
+        "{}{}",
+        paragraph(marker),
+        table(1, &[table_row(&["{{#items}}Name"]), table_row(&["[name]"])])
+    );
+    write_template(input, &body).await?;
+
+    // Cancel from inside the loop, remembering the buffered document / 在循环内部取消，并记住缓冲的文档
+    let token = CancellationToken::new();
+    let buffered = Arc::new(Mutex::new(Vec::new()));
+    let mut docx = DOCX::default();
+    docx.set_cancellation_token(Some(token.clone()));
+    let seen = buffered.clone();
+    docx.set_item_transform(move |_| {
+        if !token.is_cancelled() {
+            *seen.lock().unwrap() = buffered_documents(marker);
+            token.cancel();
+        }
+    });
+
+    let items = (0..1000)
+        .map(|index| json!({ "name": format!("Item {}", index) }))
+        .collect::<Vec<_>>();
+    let mut data = HashMap::new();
+    data.insert("{{#items}}".to_string(), Value::Array(items));
+    let result = docx.generate(input, output, &data).await;
+    assert!(matches!(result, Err(DocxError::Cancelled)));
+
+    let buffered = buffered.lock().unwrap();
+    assert_eq!(buffered.len(), 1);
+    assert!(!buffered[0].exists());
+    Ok(())
+}
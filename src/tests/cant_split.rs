@@ -0,0 +1,61 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{read_entry, table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+/// Render a loop table whose data row is `data_row` / 渲染数据行为 `data_row` 的循环表格
+async fn render(name: &str, data_row: String, enabled: bool) -> Result<String, DocxError> {
+    let input = format!("output/tests/cant_split_{}_template.docx", name);
+    let output = format!("output/tests/cant_split_{}.docx", name);
+    let body = table(1, &[table_row(&["{{#lines}}product"]), data_row]);
+    write_template(&input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#lines}}".to_string(),
+        json!([{"product": "Pen"}, {"product": "Desk"}]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.set_cant_split_rows(enabled);
+    docx.generate(&input, &output, &data).await?;
+    read_entry(&output, "word/document.xml").await
+}
+
+#[tokio::test]
+async fn test_generated_rows_cant_split() -> Result<(), DocxError> {
+    let xml = render("plain", table_row(&["[product]"]), true).await?;
+    let rows = xml.split("<w:tr>").skip(1).collect::<Vec<_>>();
+    assert_eq!(rows.len(), 3);
+    // Only the generated rows carry the property / 仅生成的行带有该属性
+    assert!(!rows[0].contains("w:cantSplit"));
+    for row in &rows[1..] {
+        assert!(row.starts_with("<w:trPr><w:cantSplit/></w:trPr><w:tc>"));
+    }
+
+    let xml = render("disabled", table_row(&["[product]"]), false).await?;
+    assert!(!xml.contains("w:cantSplit"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cant_split_extends_row_properties() -> Result<(), DocxError> {
+    let row = table_row(&["[product]"]).replacen(
+        "<w:tr>",
+        r#"<w:tr><w:trPr><w:trHeight w:val="400"/></w:trPr>"#,
+        1,
+    );
+    let xml = render("existing", row, true).await?;
+    assert_eq!(
+        xml.matches(r#"<w:trPr><w:trHeight w:val="400"/><w:cantSplit/></w:trPr>"#)
+            .count(),
+        2
+    );
+
+    let row =
+        table_row(&["[product]"]).replacen("<w:tr>", "<w:tr><w:trPr><w:cantSplit/></w:trPr>", 1);
+    let xml = render("present", row, true).await?;
+    assert_eq!(xml.matches("<w:cantSplit/>").count(), 2);
+    Ok(())
+}
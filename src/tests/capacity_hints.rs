@@ -0,0 +1,56 @@
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, table, table_row, write_template};
+use crate::{CapacityHints, DOCX};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_small_capacity_hints() -> Result<(), DocxError> {
+    let input = "output/tests/capacity_hints_template.docx";
+    let body = format!(
+        "{}{}",
+        paragraph("{{title}}"),
+        table(
+            2,
+            &[
+                table_row(&["{{#items}}Name", "Qty"]),
+                table_row(&["[name]", "[qty]"]),
+            ],
+        ),
+    );
+    write_template(input, &body).await?;
+
+    let items = (0..20)
+        .map(|i| json!({"name": format!("item{}", i), "qty": i}))
+        .collect::<Vec<_>>();
+    let data = HashMap::from([
+        ("{{title}}".to_string(), json!("Stock")),
+        ("{{#items}}".to_string(), Value::Array(items)),
+    ]);
+
+    let default_output = "output/tests/capacity_hints_default.docx";
+    DOCX::default()
+        .generate(input, default_output, &data)
+        .await?;
+
+    let small_output = "output/tests/capacity_hints_small.docx";
+    let mut docx = DOCX::default();
+    docx.set_capacity_hints(CapacityHints {
+        header_rows: 0,
+        data_rows: 0,
+        other_events: 0,
+        row_events: 1,
+        columns: 1,
+        images: 0,
+    });
+    docx.generate(input, small_output, &data).await?;
+
+    let document = read_entry(small_output, "word/document.xml").await?;
+    assert!(document.contains("Stock"));
+    assert!(document.contains("item19"));
+    assert_eq!(
+        document,
+        read_entry(default_output, "word/document.xml").await?
+    );
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use crate::public::error::DocxError;
+use crate::tests::common::{read_entry, table, table_row, write_template};
+use crate::{CellAlignment, DOCX};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_cell_alignment() -> Result<(), DocxError> {
+    let input = "output/tests/cell_alignment_template.docx";
+    let output = "output/tests/cell_alignment.docx";
+    // The third cell already has a left alignment and run properties / 第三个单元格已有左对齐和运行属性
+    let note = r#"<w:tc><w:p><w:pPr><w:spacing w:after="0"/><w:jc w:val="left"/><w:rPr><w:b/></w:rPr></w:pPr><w:r><w:t>[note]</w:t></w:r></w:p></w:tc>"#;
+    let data_row =
+        table_row(&["[name]", "[r:amount]"]).replace("</w:tr>", &format!("{}</w:tr>", note));
+    let body = table(
+        3,
+        &[table_row(&["{{#lines}}name", "amount", "note"]), data_row],
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#lines}}".to_string(),
+        json!([
+            {"name": "Pen", "amount": 3, "note": "a"},
+            {"name": "Desk", "amount": 120, "note": "b"}
+        ]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.set_column_alignment("{{#lines}}", 2, CellAlignment::Center);
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    let rows = xml.split("<w:tr>").skip(1).collect::<Vec<_>>();
+    assert!(!rows[0].contains("w:jc"));
+    for row in &rows[1..] {
+        let cells = row.split("<w:tc>").skip(1).collect::<Vec<_>>();
+        assert!(!cells[0].contains("w:jc"));
+        assert!(cells[1].contains(r#"<w:p><w:pPr><w:jc w:val="right"/></w:pPr><w:r><w:t>"#));
+        // Replaced in place, before w:rPr / 原位替换，位于 w:rPr 之前
+        assert!(cells[2].contains(
+            r#"<w:pPr><w:spacing w:after="0"/><w:jc w:val="center"/><w:rPr><w:b/></w:rPr></w:pPr>"#
+        ));
+    }
+    assert!(xml.contains("<w:t>3.00</w:t>"));
+    assert!(!xml.contains("r:amount"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_alignment_marker_outside_loop() -> Result<(), DocxError> {
+    let input = "output/tests/cell_alignment_static_template.docx";
+    write_template(input, &table(1, &[table_row(&["[r:ref]"])])).await?;
+
+    // Outside loop data rows the marker is an ordinary field / 在循环数据行之外标记是普通字段
+    let tables = DOCX::inspect_tables(input).await?;
+    assert_eq!(tables[0].loop_key, None);
+    assert_eq!(tables[0].column_keys, vec!["r:ref".to_string()]);
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_zip_entry, write_template};
+use bytes::Bytes;
+use serde_json::json;
+use std::collections::HashMap;
+use tokio::sync::mpsc::channel;
+
+#[tokio::test]
+async fn test_generate_to_sender() -> Result<(), DocxError> {
+    let input = "output/tests/channel_output_template.docx";
+    let body = format!("{}{}", paragraph("{{name}}"), paragraph("{{@logo}}"));
+    write_template(input, &body).await?;
+
+    // A single slot forces generation to wait for the receiver / 单个位置迫使生成等待接收方
+    let (tx, mut rx) = channel::<Bytes>(1);
+    let receiver = tokio::spawn(async move {
+        let mut chunks = Vec::new();
+        while let Some(chunk) = rx.recv().await {
+            chunks.push(chunk);
+        }
+        chunks
+    });
+
+    let mut data = HashMap::new();
+    data.insert("{{name}}".to_string(), json!("Alice"));
+    let logo = tokio::fs::read_to_string("template/logo_base64.txt").await?;
+    data.insert("{{logo}}".to_string(), json!(logo.trim()));
+    DOCX::default().generate_to_sender(input, tx, &data).await?;
+
+    let chunks = receiver.await.unwrap();
+    // The embedded logo spans several chunks / 嵌入的标志跨越多个数据块
+    assert!(chunks.len() > 1);
+    let package = chunks.concat();
+    let document = read_zip_entry(&package, "word/document.xml").await?;
+    assert!(String::from_utf8_lossy(&document).contains("Alice"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_generate_to_closed_sender() -> Result<(), DocxError> {
+    let input = "output/tests/channel_output_closed_template.docx";
+    write_template(input, &paragraph("{{name}}")).await?;
+
+    let (tx, rx) = channel::<Bytes>(1);
+    drop(rx);
+    let result = DOCX::default()
+        .generate_to_sender(input, tx, &HashMap::new())
+        .await;
+    assert!(matches!(
+        result,
+        Err(DocxError::Io(_)) | Err(DocxError::Zip(_))
+    ));
+    Ok(())
+}
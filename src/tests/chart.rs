@@ -0,0 +1,43 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, write_template_with_parts};
+use serde_json::json;
+use std::collections::HashMap;
+
+const CHART: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><c:chartSpace xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><c:chart><c:title><c:tx><c:rich><a:bodyPr/><a:p><a:r><a:t>{{chart_title}}</a:t></a:r></a:p></c:rich></c:tx></c:title><c:plotArea><c:barChart><c:ser><c:cat><c:strRef><c:strCache><c:ptCount c:val="1"/><c:pt idx="0"><c:v>{{category}}</c:v></c:pt></c:strCache></c:strRef></c:cat></c:ser></c:barChart></c:plotArea></c:chart></c:chartSpace>"#;
+
+async fn render(name: &str, enabled: bool) -> Result<String, DocxError> {
+    let input = format!("output/tests/{}_template.docx", name);
+    let output = format!("output/tests/{}.docx", name);
+    write_template_with_parts(
+        &input,
+        &paragraph("{{chart_title}}"),
+        &[("word/charts/chart1.xml", CHART.as_bytes())],
+    )
+    .await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{chart_title}}".to_string(), json!("Quarterly revenue"));
+    data.insert("{{category}}".to_string(), json!("Q1"));
+
+    let mut docx = DOCX::default();
+    docx.set_replace_in_charts(enabled);
+    docx.generate(&input, &output, &data).await?;
+    read_entry(&output, "word/charts/chart1.xml").await
+}
+
+#[tokio::test]
+async fn test_chart_placeholders_replaced() -> Result<(), DocxError> {
+    let chart = render("chart_replaced", true).await?;
+    assert!(chart.contains("<a:t>Quarterly revenue</a:t>"));
+    assert!(chart.contains("<c:v>Q1</c:v>"));
+    assert!(chart.contains(r#"<c:ptCount c:val="1"/>"#));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_chart_untouched_by_default() -> Result<(), DocxError> {
+    let chart = render("chart_untouched", false).await?;
+    assert_eq!(chart, CHART);
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{read_entry, table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_column_map() -> Result<(), DocxError> {
+    let input = "output/tests/column_map_template.docx";
+    let output = "output/tests/column_map.docx";
+    let body = table(
+        2,
+        &[
+            table_row(&["{{#items}}col1", "col2"]),
+            table_row(&["[col1]", "[col2]"]),
+        ],
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#items}}".to_string(),
+        json!([
+            {"product": {"name": "Pen"}, "vendor": "Acme"},
+            {"product": {"name": "Desk"}, "vendor": "Globex"}
+        ]),
+    );
+
+    let mut columns = HashMap::new();
+    columns.insert("col1".to_string(), "product.name".to_string());
+    columns.insert("[col2]".to_string(), "vendor".to_string());
+    let mut docx = DOCX::default();
+    docx.set_column_map("{{#items}}", columns);
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    let pen = xml.find("<w:t>Pen</w:t>").expect("mapped nested field");
+    let desk = xml.find("<w:t>Desk</w:t>").expect("mapped nested field");
+    assert!(pen < desk);
+    assert!(xml.contains("<w:t>Acme</w:t>"));
+    assert!(xml.contains("<w:t>Globex</w:t>"));
+    assert!(!xml.contains("[col1]"));
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use crate::public::error::DocxError;
+use async_zip::tokio::read::seek::ZipFileReader;
+use async_zip::tokio::write::ZipFileWriter;
+use async_zip::{Compression, ZipEntryBuilder};
+use std::io::Cursor;
+use std::path::Path;
+use tokio::fs::{File as AsyncFile, create_dir_all};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio_util::compat::FuturesAsyncReadCompatExt;
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/></Types>"#;
+
+const PACKAGE_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/></Relationships>"#;
+
+const DOCUMENT_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/></Relationships>"#;
+
+const DOCUMENT_HEAD: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><w:document xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>"#;
+
+const DOCUMENT_TAIL: &str =
+    r#"<w:sectPr><w:pgSz w:w="11906" w:h="16838"/></w:sectPr></w:body></w:document>"#;
+
+/// Build a paragraph containing a single text run / 构建包含单个文本运行的段落
+pub(crate) fn paragraph(text: &str) -> String {
+    format!("<w:p><w:r><w:t>{}</w:t></w:r></w:p>", text)
+}
+
+/// Build a table row from cell texts / 根据单元格文本构建表格行
+pub(crate) fn table_row(cells: &[&str]) -> String {
+    let mut row = String::from("<w:tr>");
+    for cell in cells {
+        row.push_str("<w:tc><w:tcPr><w:tcW w:w=\"1000\" w:type=\"dxa\"/></w:tcPr>");
+        row.push_str(&paragraph(cell));
+        row.push_str("</w:tc>");
+    }
+    row.push_str("</w:tr>");
+    row
+}
+
+/// Build a table with a grid of `columns` columns / 构建包含 `columns` 列网格的表格
+pub(crate) fn table(columns: usize, rows: &[String]) -> String {
+    let mut tbl =
+        String::from("<w:tbl><w:tblPr><w:tblW w:w=\"0\" w:type=\"auto\"/></w:tblPr><w:tblGrid>");
+    for _ in 0..columns {
+        tbl.push_str("<w:gridCol w:w=\"1000\"/>");
+    }
+    tbl.push_str("</w:tblGrid>");
+    for row in rows {
+        tbl.push_str(row);
+    }
+    tbl.push_str("</w:tbl>");
+    tbl
+}
+
+/// Write a minimal DOCX template whose body is `body` / 写入正文为 `body` 的最小 DOCX 模板
+pub(crate) async fn write_template(path: &str, body: &str) -> Result<(), DocxError> {
+    write_template_with_parts(path, body, &[]).await
+}
+
+/// Write a minimal DOCX template with additional parts / 写入带有附加部件的最小 DOCX 模板
+pub(crate) async fn write_template_with_parts(
+    path: &str,
+    body: &str,
+    parts: &[(&str, &[u8])],
+) -> Result<(), DocxError> {
+    let document = format!("{}{}{}", DOCUMENT_HEAD, body, DOCUMENT_TAIL);
+    let mut entries = vec![
+        ("[Content_Types].xml", CONTENT_TYPES.as_bytes()),
+        ("_rels/.rels", PACKAGE_RELS.as_bytes()),
+        ("word/_rels/document.xml.rels", DOCUMENT_RELS.as_bytes()),
+        ("word/document.xml", document.as_bytes()),
+    ];
+    entries.extend_from_slice(parts);
+
+    if let Some(parent_dir) = Path::new(path).parent() {
+        create_dir_all(parent_dir)
+            .await
+            .map_err(|e| DocxError::Xml(e.into()))?;
+    }
+    let file = AsyncFile::create(path)
+        .await
+        .map_err(|e| DocxError::Xml(e.into()))?;
+    let mut writer = ZipFileWriter::with_tokio(file);
+    for (name, content) in entries {
+        let options = ZipEntryBuilder::new(name.to_string().into(), Compression::Deflate);
+        writer.write_entry_whole(options, content).await?;
+    }
+    let mut file = writer.close().await?.into_inner();
+    file.flush().await.map_err(|e| DocxError::Xml(e.into()))?;
+    Ok(())
+}
+
+/// Read a single entry of a zip package as text / 以文本形式读取 zip 包中的单个条目
+pub(crate) async fn read_entry(path: &str, name: &str) -> Result<String, DocxError> {
+    let package = tokio::fs::read(path)
+        .await
+        .map_err(|e| DocxError::Xml(e.into()))?;
+    let bytes = read_zip_entry(&package, name).await?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Read a single entry of an in-memory zip package / 读取内存中 zip 包的单个条目
+pub(crate) async fn read_zip_entry(package: &[u8], name: &str) -> Result<Vec<u8>, DocxError> {
+    let mut zip = ZipFileReader::with_tokio(Cursor::new(package)).await?;
+    let index = zip
+        .file()
+        .entries()
+        .iter()
+        .position(|e| e.filename().as_str().map(|n| n == name).unwrap_or(false))
+        .ok_or(DocxError::Zip(
+            async_zip::error::ZipError::EntryIndexOutOfBounds,
+        ))?;
+    let mut content = Vec::new();
+    zip.reader_with_entry(index)
+        .await?
+        .compat()
+        .read_to_end(&mut content)
+        .await
+        .map_err(|e| DocxError::Xml(e.into()))?;
+    Ok(content)
+}
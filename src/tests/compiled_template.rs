@@ -0,0 +1,46 @@
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_zip_entry, write_template};
+use crate::{CompiledTemplate, DOCX};
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[tokio::test]
+async fn test_concurrent_renders_share_one_template() -> Result<(), DocxError> {
+    assert_send_sync::<CompiledTemplate>();
+
+    let input = "output/tests/compiled_template.docx";
+    write_template(input, &paragraph("{{name}}")).await?;
+    let template = Arc::new(
+        CompiledTemplate::compile(input)
+            .await?
+            .with_options(|docx: &mut DOCX| docx.set_cant_split_rows(true)),
+    );
+
+    let tasks = (0..8)
+        .map(|index| {
+            let template = Arc::clone(&template);
+            tokio::spawn(async move {
+                let mut data = HashMap::new();
+                data.insert("{{name}}".to_string(), json!(format!("User {}", index)));
+                template.render(&data).await
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for (index, task) in tasks.into_iter().enumerate() {
+        let package = task.await.expect("render task panicked")?;
+        let document = read_zip_entry(&package, "word/document.xml").await?;
+        let document = String::from_utf8_lossy(&document);
+        assert!(document.contains(&format!("<w:t>User {}</w:t>", index)));
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_compile_rejects_invalid_package() {
+    let result = CompiledTemplate::from_bytes(bytes::Bytes::from_static(b"not a zip")).await;
+    assert!(matches!(result, Err(DocxError::InvalidPackage(_))));
+}
@@ -0,0 +1,57 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{read_entry, table, table_row, write_template};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+async fn render_discount_table(name: &str, has_discount: bool) -> Result<String, DocxError> {
+    let input = format!("output/tests/{}_template.docx", name);
+    let output = format!("output/tests/{}.docx", name);
+    let body = table(
+        3,
+        &[
+            table_row(&["{{#items}}product", "price", "discount{{col?hasDiscount}}"]),
+            table_row(&["[name]", "[price]", "[discount]"]),
+        ],
+    );
+    write_template(&input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#items}}".to_string(),
+        json!([
+            {"name": "Apple", "price": 1.5, "discount": "10%"},
+            {"name": "Pear", "price": 2, "discount": "5%"}
+        ]),
+    );
+    data.insert("{{hasDiscount}}".to_string(), Value::Bool(has_discount));
+
+    let mut docx = DOCX::default();
+    docx.generate(&input, &output, &data).await?;
+    read_entry(&output, "word/document.xml").await
+}
+
+#[tokio::test]
+async fn test_conditional_column_hidden() -> Result<(), DocxError> {
+    let xml = render_discount_table("conditional_column_hidden", false).await?;
+
+    assert_eq!(xml.matches("<w:gridCol").count(), 2);
+    assert_eq!(xml.matches("<w:tc>").count(), 6);
+    assert!(!xml.contains("discount"));
+    assert!(!xml.contains("10%"));
+    assert!(!xml.contains("{{col?"));
+    assert!(xml.contains("Apple"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_conditional_column_shown() -> Result<(), DocxError> {
+    let xml = render_discount_table("conditional_column_shown", true).await?;
+
+    assert_eq!(xml.matches("<w:gridCol").count(), 3);
+    assert_eq!(xml.matches("<w:tc>").count(), 9);
+    assert!(xml.contains(">discount<"));
+    assert!(xml.contains("10%"));
+    assert!(!xml.contains("{{col?"));
+    Ok(())
+}
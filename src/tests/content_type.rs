@@ -0,0 +1,28 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, write_template};
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_register_content_type() -> Result<(), DocxError> {
+    let input = "output/tests/content_type_template.docx";
+    let output = "output/tests/content_type.docx";
+    write_template(input, &paragraph("static text")).await?;
+
+    let mut docx = DOCX::default();
+    docx.register_content_type(".dat", "application/x-custom-data");
+    docx.register_content_type("xml", "text/xml");
+    docx.add_extra_file("word/custom/data.dat", b"payload".to_vec());
+    docx.generate(input, output, &HashMap::new()).await?;
+
+    let types = read_entry(output, "[Content_Types].xml").await?;
+    assert!(
+        types.contains(r#"<Default Extension="dat" ContentType="application/x-custom-data"/>"#)
+    );
+    // Already declared extensions are left untouched / 已声明的扩展名保持不变
+    assert_eq!(types.matches(r#"Extension="xml""#).count(), 1);
+    assert!(!types.contains("text/xml"));
+
+    assert_eq!(read_entry(output, "word/custom/data.dat").await?, "payload");
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use crate::ValueExt;
+use crate::core::default_handler::DefaultValueHandler;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+fn row(key: &str, value: Value) -> HashMap<String, Value> {
+    HashMap::from([(key.to_string(), value)])
+}
+
+#[test]
+fn test_clamp_modifier() {
+    let handler = DefaultValueHandler;
+    let clamp = |v: Value| handler.replace_in_table(0, "[clamp:score:0:100]", &row("score", v));
+
+    assert_eq!(clamp(json!(-5)), "0.00");
+    assert_eq!(clamp(json!(42.5)), "42.50");
+    assert_eq!(clamp(json!(150)), "100.00");
+    // Non-numeric values pass through / 非数值原样输出
+    assert_eq!(clamp(json!("n/a")), "n/a");
+    // Regular text placeholders keep their braces / 常规文本占位符保留花括号
+    assert_eq!(
+        handler.replace("{{clamp:score:0:100}}", &row("{{score}}", json!(101))),
+        "100.00"
+    );
+}
+
+#[test]
+fn test_ordinal_modifier() {
+    let handler = DefaultValueHandler;
+    let ord = |v: Value| handler.replace_in_table(0, "[ord:rank]", &row("rank", v));
+
+    let expected = [
+        (1, "1st"),
+        (2, "2nd"),
+        (3, "3rd"),
+        (11, "11th"),
+        (12, "12th"),
+        (13, "13th"),
+        (21, "21st"),
+        (101, "101st"),
+    ];
+    for (n, text) in expected {
+        assert_eq!(ord(json!(n)), text);
+    }
+    // Non-integer values pass through / 非整数值原样输出
+    assert_eq!(ord(json!(1.5)), "1.50");
+    assert_eq!(ord(json!("first")), "first");
+}
+
+#[test]
+fn test_truncate_modifier() {
+    let handler = DefaultValueHandler;
+    let trunc = |v: Value| handler.replace_in_table(0, "[trunc:remark:5]", &row("remark", v));
+
+    assert_eq!(trunc(json!("Hello, world")), "Hello…");
+    // Multi-byte characters are never split / 多字节字符不会被拆分
+    assert_eq!(trunc(json!("日本語のテキスト")), "日本語のテ…");
+    // No ellipsis when under the limit / 未超出限制时不追加省略号
+    assert_eq!(trunc(json!("Hi")), "Hi");
+    assert_eq!(trunc(json!("exact")), "exact");
+}
+
+#[test]
+fn test_accounting_modifier() {
+    let handler = DefaultValueHandler;
+    let acct = |v: Value| handler.replace_in_table(0, "[acct:amount]", &row("amount", v));
+
+    assert_eq!(acct(json!(1234567.891)), "1,234,567.89");
+    assert_eq!(acct(json!(-1234)), "(1,234.00)");
+    assert_eq!(acct(json!(-12.5)), "(12.50)");
+    assert_eq!(acct(json!(0)), "0.00");
+    // Rounding to zero is not negative / 舍入为零不是负数
+    assert_eq!(acct(json!(-0.001)), "0.00");
+    assert_eq!(acct(json!(999)), "999.00");
+    // Non-numeric values pass through / 非数值原样输出
+    assert_eq!(acct(json!("n/a")), "n/a");
+    assert_eq!(
+        handler.replace("{{acct:amount}}", &row("{{amount}}", json!(-1000))),
+        "(1,000.00)"
+    );
+}
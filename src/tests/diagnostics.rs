@@ -0,0 +1,51 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::public::report::GenerationWarning;
+use crate::tests::common::{paragraph, table, table_row, write_template};
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_nested_table_warning() -> Result<(), DocxError> {
+    let input = "output/tests/diagnostics_template.docx";
+    let output = "output/tests/diagnostics.docx";
+    let inner = table(1, &[table_row(&["{{inner}}"])]);
+    let outer = table(1, &[table_row(&["{{outer}}"])]).replacen(
+        "</w:tc>",
+        &format!("{}<w:p/></w:tc>", inner),
+        1,
+    );
+    let split =
+        r#"<w:p><w:r><w:t>{{na</w:t></w:r><w:r><w:rPr><w:b/></w:rPr><w:t>me}}</w:t></w:r></w:p>"#;
+    let body = [paragraph("{{title}}"), outer, split.to_string()].concat();
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{title}}".to_string(), Value::from("Report"));
+
+    // Nothing is scanned unless asked for / 未请求时不进行扫描
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    assert!(docx.report().warnings.is_empty());
+
+    docx.set_diagnostics(true);
+    docx.generate(input, output, &data).await?;
+    let warnings = &docx.report().warnings;
+    assert_eq!(
+        warnings
+            .iter()
+            .filter(|w| matches!(w, GenerationWarning::NestedTable { .. }))
+            .count(),
+        1
+    );
+    assert!(warnings.iter().any(|w| matches!(
+        w,
+        GenerationWarning::SplitPlaceholder { placeholder, .. } if placeholder == "{{name}}"
+    )));
+    // A placeholder inside one run is not reported / 位于单个运行内的占位符不会被报告
+    assert!(!warnings.iter().any(|w| matches!(
+        w,
+        GenerationWarning::SplitPlaceholder { placeholder, .. } if placeholder == "{{title}}"
+    )));
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_tables_sharing_loop_key() -> Result<(), DocxError> {
+    let input = "output/tests/duplicate_loop_key_template.docx";
+    let output = "output/tests/duplicate_loop_key.docx";
+    let users = table(
+        2,
+        &[
+            table_row(&["{{#users}}name", "age"]),
+            table_row(&["[name]", "[age]"]),
+        ],
+    );
+    let body = format!("{}{}{}", users, paragraph("{{title}}"), users);
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{title}}".to_string(), json!("Between"));
+    data.insert(
+        "{{#users}}".to_string(),
+        json!([
+            {"name": "Alice", "age": "30"},
+            {"name": "Bob", "age": "41"}
+        ]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    let tables = xml
+        .split("<w:tbl>")
+        .skip(1)
+        .map(|table| &table[..table.find("</w:tbl>").unwrap()])
+        .collect::<Vec<_>>();
+    assert_eq!(tables.len(), 2);
+    assert_eq!(tables[0], tables[1]);
+    assert_eq!(tables[0].matches("<w:tr>").count(), 3);
+    assert!(tables[0].contains("<w:t>Alice</w:t>"));
+    assert!(tables[0].contains("<w:t>Bob</w:t>"));
+    assert!(xml.contains("<w:t>Between</w:t>"));
+
+    // Both tables are reported / 两个表格都会被报告
+    let report = docx.report();
+    assert_eq!(report.tables.len(), 2);
+    assert!(
+        report
+            .tables
+            .iter()
+            .all(|table| table.key == "{{#users}}" && table.rows == 2)
+    );
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, write_template};
+use crate::{DOCX, GenerationWarning};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_colliding_sources_are_reported() -> Result<(), DocxError> {
+    let input = "output/tests/duplicate_placeholder_template.docx";
+    let output = "output/tests/duplicate_placeholder.docx";
+    let body = format!(
+        "{}{}{}",
+        paragraph("{{name}}"),
+        paragraph("{{address.city}}"),
+        paragraph("{{title}}")
+    );
+    write_template(input, &body).await?;
+
+    let sources = vec![
+        json!({"name": "Alice", "address": {"city": "Paris"}}),
+        json!({"title": "Report"}),
+        json!({"name": "Bob", "address": {"city": "Berlin"}}),
+    ];
+
+    let mut docx = DOCX::default();
+    docx.generate_merged(input, output, sources).await?;
+
+    let document = read_entry(output, "word/document.xml").await?;
+    assert!(document.contains("<w:t>Bob</w:t>"));
+    assert!(document.contains("<w:t>Berlin</w:t>"));
+    assert!(document.contains("<w:t>Report</w:t>"));
+
+    assert_eq!(
+        docx.report().warnings,
+        vec![
+            GenerationWarning::DuplicatePlaceholder {
+                key: "{{address.city}}".to_string(),
+                overwritten: 0,
+                kept: 2,
+            },
+            GenerationWarning::DuplicatePlaceholder {
+                key: "{{name}}".to_string(),
+                overwritten: 0,
+                kept: 2,
+            },
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_non_object_source_is_rejected() -> Result<(), DocxError> {
+    let input = "output/tests/duplicate_placeholder_invalid_template.docx";
+    let output = "output/tests/duplicate_placeholder_invalid.docx";
+    write_template(input, &paragraph("{{name}}")).await?;
+
+    let result = DOCX::default()
+        .generate_merged(input, output, vec![json!({"name": "Alice"}), json!([1, 2])])
+        .await;
+    assert!(matches!(result, Err(DocxError::Serialization(_))));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_collisions_reported_when_generation_fails() {
+    let sources = vec![json!({"name": "Alice"}), json!({"name": "Bob"})];
+
+    let mut docx = DOCX::default();
+    let result = docx
+        .generate_merged(
+            "output/tests/duplicate_placeholder_missing.docx",
+            "output/tests/duplicate_placeholder_missing_out.docx",
+            sources,
+        )
+        .await;
+    assert!(result.is_err());
+    assert_eq!(
+        docx.report().warnings,
+        vec![GenerationWarning::DuplicatePlaceholder {
+            key: "{{name}}".to_string(),
+            overwritten: 0,
+            kept: 1,
+        }]
+    );
+}
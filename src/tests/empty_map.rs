@@ -0,0 +1,63 @@
+use crate::DOCX;
+use crate::core::docx_processor::REGEX;
+use crate::public::error::DocxError;
+use crate::tests::common::read_zip_entry;
+use async_zip::tokio::read::seek::ZipFileReader;
+use bytes::Bytes;
+use quick_xml::Reader;
+use quick_xml::events::Event;
+use std::collections::HashMap;
+use std::io::Cursor;
+
+#[tokio::test]
+async fn test_empty_placeholders_produce_clean_copy() -> Result<(), DocxError> {
+    let template = Bytes::from(tokio::fs::read("template/test.docx").await?);
+
+    let mut docx = DOCX::default();
+    let output = docx
+        .generate_bytes(template.clone(), &HashMap::new())
+        .await?;
+    let document = read_zip_entry(&output, "word/document.xml").await?;
+
+    // Output must stay well-formed XML / 输出必须保持为格式良好的 XML
+    let mut reader = Reader::from_reader(document.as_slice());
+    let mut buf = Vec::new();
+    let mut texts = String::new();
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Text(text) => texts.push_str(&text.decode()?),
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    // Regular placeholders blank out, loop markers and template rows are stripped / 常规占位符置空，循环标记和模板行被移除
+    assert!(!texts.contains("{{"));
+    assert!(!REGEX.is_match(&texts));
+    assert!(texts.contains("Recorder:"));
+    assert!(texts.contains("thumbnail"));
+
+    // No image is embedded / 不嵌入任何图片
+    let original = read_zip_entry(&template, "word/document.xml").await?;
+    let count = |xml: &[u8]| String::from_utf8_lossy(xml).matches("<w:drawing>").count();
+    assert_eq!(count(&document), count(&original));
+    assert_eq!(
+        media_entries(&output).await?,
+        media_entries(&template).await?
+    );
+    Ok(())
+}
+
+async fn media_entries(package: &[u8]) -> Result<Vec<String>, DocxError> {
+    let zip = ZipFileReader::with_tokio(Cursor::new(package)).await?;
+    let mut names = zip
+        .file()
+        .entries()
+        .iter()
+        .filter_map(|e| e.filename().as_str().ok().map(str::to_string))
+        .filter(|name| name.starts_with("word/media/"))
+        .collect::<Vec<_>>();
+    names.sort();
+    Ok(names)
+}
@@ -0,0 +1,45 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{read_entry, table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_skip_empty_rows() -> Result<(), DocxError> {
+    let input = "output/tests/empty_rows_template.docx";
+    let output = "output/tests/empty_rows.docx";
+    let body = table(
+        2,
+        &[
+            table_row(&["{{#items}}name", "note"]),
+            table_row(&["[name]", "[note]"]),
+        ],
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#items}}".to_string(),
+        json!([
+            {"name": "Pen", "note": "blue"},
+            {"name": "Desk", "note": ""},
+            {"name": null, "note": null}
+        ]),
+    );
+
+    // Kept by default / 默认保留
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+    assert_eq!(xml.matches("<w:tr>").count(), 4);
+
+    docx.set_skip_empty_rows(true);
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+    assert_eq!(xml.matches("<w:tr>").count(), 3);
+    assert!(xml.contains("<w:t>Pen</w:t>"));
+    // A row with a single value is kept / 只有一个值的行会保留
+    assert!(xml.contains("<w:t>Desk</w:t>"));
+    assert_eq!(docx.report().tables[0].rows, 2);
+    Ok(())
+}
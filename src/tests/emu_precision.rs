@@ -0,0 +1,54 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, write_template};
+use base64::Engine;
+use base64::engine::general_purpose;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Build a PNG header declaring the given pixel size / 构建声明给定像素尺寸的 PNG 头
+fn png_header(width: u32, height: u32) -> String {
+    let mut bytes = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+    bytes.extend_from_slice(&13u32.to_be_bytes());
+    bytes.extend_from_slice(b"IHDR");
+    bytes.extend_from_slice(&width.to_be_bytes());
+    bytes.extend_from_slice(&height.to_be_bytes());
+    bytes.extend_from_slice(&[8, 6, 0, 0, 0]);
+    general_purpose::STANDARD.encode(bytes)
+}
+
+/// Render one image and return its (cx, cy) extent / 渲染一张图片并返回其 (cx, cy) 范围
+async fn extent(name: &str, dpi: f32, width: u32, height: u32) -> Result<String, DocxError> {
+    let input = format!("output/tests/emu_precision_{}_template.docx", name);
+    let output = format!("output/tests/emu_precision_{}.docx", name);
+    write_template(&input, &paragraph("{{@img}}")).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{img}}".to_string(),
+        Value::String(png_header(width, height)),
+    );
+    let mut docx = DOCX::default();
+    docx.set_dpi(dpi);
+    docx.generate(&input, &output, &data).await?;
+
+    let document = read_entry(&output, "word/document.xml").await?;
+    let start = document.find("<wp:extent ").unwrap();
+    let end = start + document[start..].find("/>").unwrap();
+    Ok(document[start..end].to_string())
+}
+
+#[tokio::test]
+async fn test_emu_exact_for_pixel_and_dpi() -> Result<(), DocxError> {
+    // 123px at 220 DPI = 511232.73 EMU, 45px = 187036.36 EMU
+    assert_eq!(
+        extent("220", 220.0, 123, 45).await?,
+        r#"<wp:extent cx="511233" cy="187036""#
+    );
+    // 1920x1080 at 96 DPI is scaled to the 1800000 EMU limit / 96 DPI 下的 1920x1080 缩放到 1800000 EMU 上限
+    assert_eq!(
+        extent("1920", 96.0, 1920, 1080).await?,
+        r#"<wp:extent cx="1800000" cy="1012500""#
+    );
+    Ok(())
+}
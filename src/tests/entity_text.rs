@@ -0,0 +1,74 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_entities_round_trip() -> Result<(), DocxError> {
+    let input = "output/tests/entity_text_template.docx";
+    let output = "output/tests/entity_text.docx";
+    let body = format!(
+        "{}{}{}{}{}",
+        paragraph("It&#8217;s {{name}}"),
+        r#"<w:p><w:r><w:t>&#8217;</w:t></w:r><w:r><w:t>{{name}}</w:t></w:r><w:r><w:t xml:space="preserve">&amp; &lt;co&gt; &quot;x&quot; &#x2019;</w:t></w:r></w:p>"#,
+        paragraph("{{name}}&#8217;s"),
+        table(
+            1,
+            &[
+                table_row(&["{{#lines}}Tom &amp; Jerry&#8217;s"]),
+                table_row(&["[product]&#8217;"]),
+            ],
+        ),
+        table(
+            1,
+            &[
+                table_row(&["{{#missing}}A &amp; B&#8217;s"]),
+                table_row(&["[name]"]),
+            ],
+        )
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{name}}".to_string(), json!("Alice"));
+    data.insert("{{#lines}}".to_string(), json!([{"product": "Pen"}]));
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+
+    let xml = read_entry(output, "word/document.xml").await?;
+    for expected in [
+        "<w:t>It&#8217;s {{name}}</w:t>",
+        "<w:t>&#8217;</w:t></w:r><w:r><w:t>Alice</w:t>",
+        r#"<w:t xml:space="preserve">&amp; &lt;co&gt; &quot;x&quot; &#x2019;</w:t>"#,
+        "<w:t>Alice&#8217;s</w:t>",
+        "<w:t>Tom &amp; Jerry&#8217;s</w:t>",
+        "<w:t>Pen&#8217;</w:t>",
+        "<w:t>A &amp; B&#8217;s</w:t>",
+    ] {
+        assert!(xml.contains(expected), "{}", expected);
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_entity_after_marker_kept() -> Result<(), DocxError> {
+    let input = "output/tests/entity_text_break_template.docx";
+    let output = "output/tests/entity_text_break.docx";
+    let body = format!(
+        "{}{}",
+        paragraph("[pagebreak]"),
+        paragraph("[pagebreak]&#8217;s")
+    );
+    write_template(input, &body).await?;
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &HashMap::new()).await?;
+
+    // Only a text holding nothing but the marker is a page break / 仅有标记的文本才是分页符
+    let xml = read_entry(output, "word/document.xml").await?;
+    assert_eq!(xml.matches(r#"<w:br w:type="page"/>"#).count(), 1);
+    assert!(xml.contains("<w:t>[pagebreak]&#8217;s</w:t>"));
+    Ok(())
+}
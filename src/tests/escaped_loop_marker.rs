@@ -0,0 +1,51 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_escaped_loop_marker() -> Result<(), DocxError> {
+    let input = "output/tests/escaped_loop_marker_template.docx";
+    let output = "output/tests/escaped_loop_marker.docx";
+    let body = format!(
+        "{}{}{}",
+        paragraph(r"Start a loop with {{\#items}}"),
+        // Documentation table about the syntax / 关于语法的文档表格
+        table(
+            2,
+            &[
+                table_row(&[r"{{\#notaloop}}", "Marks a loop table"]),
+                table_row(&["Syntax", "Meaning"]),
+            ],
+        ),
+        table(
+            1,
+            &[
+                table_row(&[r"{{#items}}Write {{\#items}} literally"]),
+                table_row(&["[name]"]),
+            ],
+        ),
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{#notaloop}}".to_string(), json!([{"name": "x"}]));
+    data.insert("{{#items}}".to_string(), json!([{"name": "Pen"}]));
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    assert!(xml.contains("<w:t>Start a loop with {{#items}}</w:t>"));
+    assert!(xml.contains("<w:t>{{#notaloop}}</w:t>"));
+    assert!(xml.contains("<w:t>Marks a loop table</w:t>"));
+    assert!(xml.contains("<w:t>Write {{#items}} literally</w:t>"));
+    assert!(xml.contains("<w:t>Pen</w:t>"));
+    assert!(!xml.contains(r"\#"));
+    // Only the unescaped marker is a loop / 只有未转义的标记才是循环
+    let report = docx.report();
+    assert_eq!(report.tables.len(), 1);
+    assert_eq!(report.tables[0].key, "{{#items}}");
+    Ok(())
+}
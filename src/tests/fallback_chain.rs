@@ -0,0 +1,79 @@
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, table, table_row, write_template};
+use crate::{DOCX, ValueExt};
+use serde_json::{Value, json};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+/// Handler finding every placeholder as a fixed text / 将每个占位符都解析为固定文本的处理器
+struct Placeholder;
+
+impl ValueExt for Placeholder {
+    fn replace_in_table<'k>(
+        &self,
+        _: usize,
+        _: &'k str,
+        _: &HashMap<String, Value>,
+    ) -> Cow<'k, str> {
+        Cow::Borrowed("n/a")
+    }
+
+    fn replace<'k>(&self, _: &'k str, _: &HashMap<String, Value>) -> Cow<'k, str> {
+        Cow::Borrowed("n/a")
+    }
+}
+
+#[tokio::test]
+async fn test_missing_key_resolves_from_fallback() -> Result<(), DocxError> {
+    let input = "output/tests/fallback_chain_template.docx";
+    let output = "output/tests/fallback_chain.docx";
+    let body = format!(
+        "{}{}{}{}{}",
+        paragraph("{{name}}"),
+        paragraph("{{city}}"),
+        paragraph("{{note}}"),
+        paragraph("{{phone}}"),
+        table(
+            2,
+            &[
+                table_row(&["{{#lines}}product", "unit"]),
+                table_row(&["[product]", "[unit]"]),
+            ],
+        )
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{name}}".to_string(), json!("Alice"));
+    // Found empty stays empty / 找到的空值保持为空
+    data.insert("{{note}}".to_string(), json!(""));
+    data.insert(
+        "{{#lines}}".to_string(),
+        json!([{"product": "Pen", "unit": "box"}, {"product": "Desk"}]),
+    );
+
+    let mut defaults = HashMap::new();
+    defaults.insert("{{name}}".to_string(), json!("Guest"));
+    defaults.insert("{{city}}".to_string(), json!("Paris"));
+    defaults.insert("{{note}}".to_string(), json!("none"));
+    defaults.insert("unit".to_string(), json!("piece"));
+
+    let mut docx = DOCX::default();
+    docx.add_fallback_values(defaults);
+    docx.add_fallback_handler(Box::new(Placeholder));
+    docx.generate(input, output, &data).await?;
+
+    let document = read_entry(output, "word/document.xml").await?;
+    let texts = document
+        .split("<w:t>")
+        .skip(1)
+        .filter_map(|part| part.split_once("</w:t>").map(|(text, _)| text))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        texts,
+        vec![
+            "Alice", "Paris", "", "n/a", "product", "unit", "Pen", "box", "Desk", "piece"
+        ]
+    );
+    Ok(())
+}
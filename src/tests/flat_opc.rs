@@ -0,0 +1,48 @@
+use crate::DOCX;
+use crate::core::utils::check_well_formed;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, write_template};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_generate_flat_opc() -> Result<(), DocxError> {
+    let input = "output/tests/flat_opc_template.docx";
+    let output = "output/tests/flat_opc.xml";
+    write_template(
+        input,
+        &format!("{}{}", paragraph("{{name}}"), paragraph("{{@logo}}")),
+    )
+    .await?;
+
+    let logo = tokio::fs::read_to_string("template/logo_base64.txt").await?;
+    let data = HashMap::from([
+        ("{{name}}".to_string(), json!("Alice")),
+        ("{{logo}}".to_string(), Value::String(logo)),
+    ]);
+    DOCX::default()
+        .generate_flat_opc(input, output, &data)
+        .await?;
+
+    let xml = tokio::fs::read_to_string(output).await?;
+    check_well_formed(&xml)?;
+    assert!(xml.starts_with("<?xml"));
+    assert!(xml.contains(r#"<?mso-application progid="Word.Document"?>"#));
+    assert_eq!(xml.matches("<pkg:package ").count(), 1);
+
+    // The processed document part is embedded as XML / 处理后的文档部件以 XML 形式嵌入
+    let start = xml
+        .find(r#"<pkg:part pkg:name="/word/document.xml" pkg:contentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"><pkg:xmlData><w:document"#)
+        .expect("document part");
+    let end = start + xml[start..].find("</pkg:part>").unwrap();
+    assert!(xml[start..end].contains("Alice"));
+    assert!(!xml[start..end].contains("{{name}}"));
+
+    // Media are embedded as base64 and content types are not a part / 媒体以 base64 嵌入，内容类型不作为部件
+    assert!(
+        xml.contains(r#"pkg:contentType="image/jpeg" pkg:compression="store"><pkg:binaryData>"#)
+    );
+    assert!(xml.contains(r#"pkg:name="/word/_rels/document.xml.rels""#));
+    assert!(!xml.contains("[Content_Types]"));
+    Ok(())
+}
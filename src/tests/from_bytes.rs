@@ -0,0 +1,74 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_zip_entry, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+use std::env::temp_dir;
+
+/// Whether a buffered document.xml holding `marker` exists / 是否存在包含 `marker` 的缓冲 document.xml
+fn buffered_document_exists(marker: &str) -> bool {
+    std::fs::read_dir(temp_dir())
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .any(|path| {
+            path.file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with("docx_"))
+                && std::fs::read_to_string(&path).is_ok_and(|content| content.contains(marker))
+        })
+}
+
+#[tokio::test]
+async fn test_generate_from_bytes_round_trip() -> Result<(), DocxError> {
+    let input = "output/tests/from_bytes_template.docx";
+    write_template(input, &paragraph("{{name}}")).await?;
+    let template = tokio::fs::read(input).await?;
+
+    let mut docx = DOCX::default();
+    let mut data = HashMap::new();
+    data.insert("{{name}}".to_string(), json!("Alice"));
+    let output = docx.generate_from_bytes(&template, &data).await?;
+
+    let document =
+        String::from_utf8_lossy(&read_zip_entry(&output, "word/document.xml").await?).into_owned();
+    assert!(document.contains("Alice"));
+    assert!(!document.contains("{{name}}"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_generate_from_bytes_concurrently() -> Result<(), DocxError> {
+    let input = "output/tests/from_bytes_concurrent_template.docx";
+    let marker = "from_bytes_concurrent_marker";
+    write_template(
+        input,
+        &format!("{}{}", paragraph(marker), paragraph("{{name}}")),
+    )
+    .await?;
+    let template = tokio::fs::read(input).await?;
+
+    let tasks: Vec<_> = (0..8)
+        .map(|index| {
+            let template = template.clone();
+            tokio::spawn(async move {
+                let mut docx = DOCX::default();
+                let mut data = HashMap::new();
+                data.insert("{{name}}".to_string(), json!(format!("Name {}", index)));
+                docx.generate_from_bytes(&template, &data).await
+            })
+        })
+        .collect();
+
+    for (index, task) in tasks.into_iter().enumerate() {
+        let output = task.await.expect("generation task panicked")?;
+        let document =
+            String::from_utf8_lossy(&read_zip_entry(&output, "word/document.xml").await?)
+                .into_owned();
+        assert!(document.contains(&format!("Name {}", index)));
+    }
+
+    // document.xml never went through a temporary file / document.xml 从未经过临时文件
+    assert!(!buffered_document_exists(marker));
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, table, table_row, write_template};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Invoice {
+    title: String,
+    customer: Customer,
+    lines: Vec<Line>,
+}
+
+#[derive(Serialize)]
+struct Customer {
+    name: String,
+}
+
+#[derive(Serialize)]
+struct Line {
+    product: String,
+    qty: u32,
+}
+
+#[tokio::test]
+async fn test_generate_from_struct() -> Result<(), DocxError> {
+    let input = "output/tests/generate_from_template.docx";
+    let output = "output/tests/generate_from.docx";
+    let body = format!(
+        "{}{}{}",
+        paragraph("{{title}}"),
+        paragraph("{{customer.name}}"),
+        table(
+            2,
+            &[
+                table_row(&["{{#lines}}Product", "Qty"]),
+                table_row(&["[product]", "[qty]"]),
+            ],
+        )
+    );
+    write_template(input, &body).await?;
+
+    let invoice = Invoice {
+        title: "Invoice 42".to_string(),
+        customer: Customer {
+            name: "Alice".to_string(),
+        },
+        lines: vec![
+            Line {
+                product: "Pen".to_string(),
+                qty: 2,
+            },
+            Line {
+                product: "Desk".to_string(),
+                qty: 1,
+            },
+        ],
+    };
+
+    let mut docx = DOCX::default();
+    docx.generate_from(input, output, &invoice).await?;
+
+    let xml = read_entry(output, "word/document.xml").await?;
+    for text in ["Invoice 42", "Alice", "Product", "Pen", "Desk"] {
+        assert!(xml.contains(&format!("<w:t>{}</w:t>", text)), "{}", text);
+    }
+    assert!(!xml.contains("{{"));
+    assert!(!xml.contains("[product]"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_generate_from_rejects_non_object() -> Result<(), DocxError> {
+    let input = "output/tests/generate_from_scalar_template.docx";
+    let output = "output/tests/generate_from_scalar.docx";
+    write_template(input, &paragraph("{{title}}")).await?;
+
+    let mut docx = DOCX::default();
+    let result = docx.generate_from(input, output, &vec![1, 2]).await;
+    assert!(matches!(result, Err(DocxError::Serialization(_))));
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use crate::DOCX;
+use crate::core::utils::get_image_dimensions;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, write_template};
+use base64::Engine;
+use base64::engine::general_purpose;
+use serde_json::json;
+use std::collections::HashMap;
+
+/// Minimal GIF89a header with an empty global color table / 带空全局颜色表的最小 GIF89a 头部
+fn gif(width: u16, height: u16) -> Vec<u8> {
+    let mut bytes = b"GIF89a".to_vec();
+    bytes.extend_from_slice(&width.to_le_bytes());
+    bytes.extend_from_slice(&height.to_le_bytes());
+    bytes.extend_from_slice(&[0, 0, 0]);
+    bytes.extend_from_slice(&[0x2C, 0, 0, 0, 0, 1, 0, 1, 0, 0]);
+    bytes.extend_from_slice(&[0x02, 0x02, 0x44, 0x01, 0x00, 0x3B]);
+    bytes
+}
+
+/// Minimal BMP file and BITMAPINFOHEADER without pixel data / 不含像素数据的最小 BMP 文件头和 BITMAPINFOHEADER
+fn bmp(width: i32, height: i32) -> Vec<u8> {
+    let mut bytes = b"BM".to_vec();
+    bytes.extend_from_slice(&54u32.to_le_bytes());
+    bytes.extend_from_slice(&[0; 4]);
+    bytes.extend_from_slice(&54u32.to_le_bytes());
+    bytes.extend_from_slice(&40u32.to_le_bytes());
+    bytes.extend_from_slice(&width.to_le_bytes());
+    bytes.extend_from_slice(&height.to_le_bytes());
+    bytes.extend_from_slice(&1u16.to_le_bytes());
+    bytes.extend_from_slice(&24u16.to_le_bytes());
+    bytes.extend_from_slice(&[0; 24]);
+    bytes
+}
+
+#[test]
+fn test_gif_dimensions() {
+    assert_eq!(get_image_dimensions(&gif(320, 200)), Ok((320, 200)));
+}
+
+#[test]
+fn test_bmp_dimensions() {
+    assert_eq!(get_image_dimensions(&bmp(640, 480)), Ok((640, 480)));
+}
+
+#[test]
+fn test_bmp_top_down_dimensions() {
+    assert_eq!(get_image_dimensions(&bmp(64, -32)), Ok((64, 32)));
+}
+
+#[tokio::test]
+async fn test_gif_and_bmp_media_types() -> Result<(), DocxError> {
+    let input = "output/tests/gif_bmp_images_template.docx";
+    let output = "output/tests/gif_bmp_images.docx";
+    let body = format!("{}{}", paragraph("{{@gif}}"), paragraph("{{@bmp}}"));
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{gif}}".to_string(),
+        json!(general_purpose::STANDARD.encode(gif(16, 8))),
+    );
+    data.insert(
+        "{{bmp}}".to_string(),
+        json!(general_purpose::STANDARD.encode(bmp(16, 8))),
+    );
+    DOCX::default().generate(input, output, &data).await?;
+
+    let mut extensions = DOCX::list_media(output)
+        .await?
+        .into_iter()
+        .filter_map(|(name, _)| name.rsplit_once('.').map(|(_, ext)| ext.to_string()))
+        .collect::<Vec<_>>();
+    extensions.sort();
+    assert_eq!(extensions, ["bmp", "gif"]);
+
+    let content_types = read_entry(output, "[Content_Types].xml").await?;
+    assert!(content_types.contains("image/gif"));
+    assert!(content_types.contains("image/bmp"));
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, write_template_with_parts};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Build a header or footer part around `body` / 围绕 `body` 构建页眉或页脚部件
+fn part(root: &str, body: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><w:{root} xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">{body}</w:{root}>"#
+    )
+}
+
+/// Collect the drawing ids of a part / 收集部件的绘图 ID
+fn drawing_ids(xml: &str) -> Vec<String> {
+    xml.split(r#"<wp:docPr id=""#)
+        .skip(1)
+        .filter_map(|rest| rest.split('"').next().map(str::to_string))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_header_images() -> Result<(), DocxError> {
+    let input = "output/tests/header_images_template.docx";
+    let output = "output/tests/header_images.docx";
+    let header = part("hdr", &paragraph("{{@report_logo}}"));
+    let header_rels = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink" Target="https://example.com" TargetMode="External"/></Relationships>"#;
+    let footer = part(
+        "ftr",
+        &format!(
+            "{}{}",
+            paragraph("{{title}}"),
+            paragraph("{{@report_logo}}")
+        ),
+    );
+    write_template_with_parts(
+        input,
+        &paragraph("{{@report_logo}}"),
+        &[
+            ("word/header1.xml", header.as_bytes()),
+            ("word/_rels/header1.xml.rels", header_rels.as_bytes()),
+            ("word/footer1.xml", footer.as_bytes()),
+        ],
+    )
+    .await?;
+
+    let logo = tokio::fs::read_to_string("template/logo_base64.txt").await?;
+    let mut data = HashMap::new();
+    data.insert("{{report_logo}}".to_string(), Value::String(logo));
+    data.insert("{{title}}".to_string(), Value::from("Quarterly"));
+    DOCX::default().generate(input, output, &data).await?;
+
+    // The header keeps its relationships and gets the next rId / 页眉保留其关系并获得下一个 rId
+    let header = read_entry(output, "word/header1.xml").await?;
+    assert!(header.contains("<w:drawing>"));
+    assert!(header.contains(r#"r:embed="rId2""#));
+    let header_rels = read_entry(output, "word/_rels/header1.xml.rels").await?;
+    assert!(header_rels.contains("https://example.com"));
+    assert!(header_rels.contains(r#"<Relationship Id="rId2""#));
+
+    // A footer without relationships gets a new file / 没有关系的页脚获得新的关系文件
+    let footer = read_entry(output, "word/footer1.xml").await?;
+    assert!(footer.contains("Quarterly"));
+    assert!(footer.contains(r#"r:embed="rId1""#));
+    let footer_rels = read_entry(output, "word/_rels/footer1.xml.rels").await?;
+    assert!(footer_rels.contains(r#"<Relationship Id="rId1""#));
+    assert!(footer_rels.contains("media/"));
+
+    // Drawing ids stay unique across the parts / 绘图 ID 在各部件间保持唯一
+    let document = read_entry(output, "word/document.xml").await?;
+    let mut ids = [
+        drawing_ids(&document),
+        drawing_ids(&header),
+        drawing_ids(&footer),
+    ]
+    .concat();
+    assert_eq!(ids.len(), 3);
+    ids.sort();
+    ids.dedup();
+    assert_eq!(ids.len(), 3);
+
+    assert_eq!(DOCX::list_media(output).await?.len(), 3);
+    Ok(())
+}
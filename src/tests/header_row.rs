@@ -0,0 +1,42 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{read_entry, table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_repeat_header_rows() -> Result<(), DocxError> {
+    let input = "output/tests/header_row_template.docx";
+    let output = "output/tests/header_row.docx";
+    let body = format!(
+        "{}{}",
+        table(
+            2,
+            &[
+                table_row(&["{{#lines}}product", "qty"]),
+                table_row(&["[product]", "[qty]"]),
+                table_row(&["Total", "3"]),
+            ],
+        ),
+        table(1, &[table_row(&["static"])])
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#lines}}".to_string(),
+        json!([{"product": "Pen", "qty": 2}, {"product": "Desk", "qty": 1}]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.set_repeat_header_rows(true);
+    docx.generate(input, output, &data).await?;
+
+    let xml = read_entry(output, "word/document.xml").await?;
+    let rows = xml.split("<w:tr>").skip(1).collect::<Vec<_>>();
+    assert_eq!(rows.len(), 5);
+    // Only the loop table header above the data row repeats / 仅数据行之上的循环表格标题重复
+    assert!(rows[0].starts_with("<w:trPr><w:tblHeader/></w:trPr><w:tc>"));
+    assert!(rows[1..].iter().all(|row| !row.contains("w:tblHeader")));
+    Ok(())
+}
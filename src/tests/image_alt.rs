@@ -0,0 +1,44 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, table, table_row, write_template};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_image_alt_text() -> Result<(), DocxError> {
+    let input = "output/tests/image_alt_template.docx";
+    let output = "output/tests/image_alt.docx";
+    let body = [
+        paragraph("{{@logo|alt=Company \"logo\"}}"),
+        table(
+            1,
+            &[
+                table_row(&["{{#items}}photo"]),
+                table_row(&["[@photo|alt=Customer portrait]"]),
+            ],
+        ),
+    ]
+    .concat();
+    write_template(input, &body).await?;
+
+    let logo = tokio::fs::read_to_string("template/logo_base64.txt").await?;
+    let mut data = HashMap::new();
+    data.insert("{{logo}}".to_string(), Value::String(logo.clone()));
+    data.insert("{{#items}}".to_string(), json!([{ "photo": logo }]));
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let document = read_entry(output, "word/document.xml").await?;
+
+    assert_eq!(document.matches("<w:drawing>").count(), 2);
+    for alt in [
+        r#"descr="Company &quot;logo&quot;""#,
+        r#"descr="Customer portrait""#,
+    ] {
+        // Both wp:docPr and pic:cNvPr carry the description / wp:docPr 和 pic:cNvPr 都带有描述
+        assert_eq!(document.matches(alt).count(), 2);
+    }
+    assert!(!document.contains("Generated Image"));
+    assert!(!document.contains("alt="));
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, write_template};
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_image_anchor() -> Result<(), DocxError> {
+    let input = "output/tests/image_anchor_template.docx";
+    let output = "output/tests/image_anchor.docx";
+    let body = [
+        paragraph("{{@bg|anchor=behind|x=36pt|y=-12700}}"),
+        paragraph("{{@bg|anchor=square}}"),
+        paragraph("{{@bg|anchor=tight}}"),
+        paragraph("{{@bg}}"),
+    ]
+    .concat();
+    write_template(input, &body).await?;
+
+    let logo = tokio::fs::read_to_string("template/logo_base64.txt").await?;
+    let mut data = HashMap::new();
+    data.insert("{{bg}}".to_string(), Value::String(logo));
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let document = read_entry(output, "word/document.xml").await?;
+
+    let drawings = document.split("<w:drawing>").skip(1).collect::<Vec<_>>();
+    assert_eq!(drawings.len(), 4);
+
+    // Behind text: anchored, no wrap, offsets in EMU / 衬于文字下方：浮动、不环绕、偏移以 EMU 表示
+    assert!(drawings[0].starts_with("<wp:anchor "));
+    assert!(drawings[0].contains(r#"behindDoc="1""#));
+    assert!(drawings[0].contains(
+        r#"<wp:positionH relativeFrom="column"><wp:posOffset>457200</wp:posOffset></wp:positionH>"#
+    ));
+    assert!(drawings[0].contains(
+        r#"<wp:positionV relativeFrom="paragraph"><wp:posOffset>-12700</wp:posOffset></wp:positionV>"#
+    ));
+    assert!(drawings[0].contains("<wp:wrapNone/>"));
+    assert!(drawings[0].contains("</wp:anchor></w:drawing>"));
+
+    assert!(drawings[1].contains(r#"behindDoc="0""#));
+    assert!(drawings[1].contains(r#"<wp:wrapSquare wrapText="bothSides"/>"#));
+    assert!(drawings[2].contains("<wp:wrapTight wrapText=\"bothSides\"><wp:wrapPolygon"));
+
+    // Without the option the image stays inline / 不带选项时图片保持内联
+    assert!(drawings[3].starts_with("<wp:inline "));
+    assert!(!drawings[3].contains("wp:anchor"));
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, write_template};
+use base64::Engine;
+use base64::engine::general_purpose;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Build a PNG header declaring the given pixel size / 构建声明给定像素尺寸的 PNG 头
+fn png_header(width: u32, height: u32) -> String {
+    let mut bytes = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+    bytes.extend_from_slice(&13u32.to_be_bytes());
+    bytes.extend_from_slice(b"IHDR");
+    bytes.extend_from_slice(&width.to_be_bytes());
+    bytes.extend_from_slice(&height.to_be_bytes());
+    bytes.extend_from_slice(&[8, 6, 0, 0, 0]);
+    general_purpose::STANDARD.encode(bytes)
+}
+
+#[tokio::test]
+async fn test_image_autoscale_disabled() -> Result<(), DocxError> {
+    let input = "output/tests/image_autoscale_template.docx";
+    let output = "output/tests/image_autoscale.docx";
+    write_template(input, &paragraph("{{@img}}")).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{img}}".to_string(), Value::String(png_header(1920, 1080)));
+
+    // Clamped to the 1800000 EMU limit by default / 默认限制在 1800000 EMU 上限
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let document = read_entry(output, "word/document.xml").await?;
+    assert!(document.contains(r#"<wp:extent cx="1800000" cy="1012500""#));
+
+    // 1920x1080 at 96 DPI is 18288000x10287000 EMU / 96 DPI 下的 1920x1080 为 18288000x10287000 EMU
+    docx.set_image_autoscale(false);
+    docx.generate(input, output, &data).await?;
+    let document = read_entry(output, "word/document.xml").await?;
+    assert!(document.contains(r#"<wp:extent cx="18288000" cy="10287000""#));
+    assert!(document.contains(r#"<a:ext cx="18288000" cy="10287000""#));
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_zip_entry, write_template};
+use async_zip::tokio::read::seek::ZipFileReader;
+use base64::Engine;
+use base64::engine::general_purpose;
+use bytes::Bytes;
+use image::{ImageFormat, Rgba, RgbaImage};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::io::Cursor;
+
+/// Render a fully transparent 4x4 PNG and decode the embedded image / 渲染完全透明的 4x4 PNG 并解码嵌入的图片
+async fn embedded_image(name: &str, placeholder: &str) -> Result<image::DynamicImage, DocxError> {
+    let input = format!("output/tests/image_background_{}_template.docx", name);
+    write_template(&input, &paragraph(placeholder)).await?;
+    let template = Bytes::from(tokio::fs::read(&input).await?);
+
+    let mut png = Cursor::new(Vec::new());
+    RgbaImage::from_pixel(4, 4, Rgba([0, 0, 0, 0]))
+        .write_to(&mut png, ImageFormat::Png)
+        .unwrap();
+    let mut data = HashMap::new();
+    data.insert(
+        "{{logo}}".to_string(),
+        Value::String(general_purpose::STANDARD.encode(png.into_inner())),
+    );
+
+    let mut docx = DOCX::default();
+    let output = docx.generate_bytes(template, &data).await?;
+
+    let zip = ZipFileReader::with_tokio(Cursor::new(output.as_slice())).await?;
+    let name = zip
+        .file()
+        .entries()
+        .iter()
+        .filter_map(|e| e.filename().as_str().ok().map(str::to_string))
+        .find(|name| name.starts_with("word/media/"))
+        .unwrap();
+    let bytes = read_zip_entry(&output, &name).await?;
+    Ok(image::load_from_memory_with_format(&bytes, ImageFormat::Png).unwrap())
+}
+
+#[tokio::test]
+async fn test_background_flattens_transparency() -> Result<(), DocxError> {
+    let image = embedded_image("white", "{{@logo|bg=FFFFFF}}").await?;
+    assert!(!image.color().has_alpha());
+    assert!(image.to_rgb8().pixels().all(|p| p.0 == [255, 255, 255]));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transparency_kept_without_background() -> Result<(), DocxError> {
+    let image = embedded_image("none", "{{@logo}}").await?;
+    assert!(image.color().has_alpha());
+    assert_eq!(image.to_rgba8().get_pixel(0, 0).0[3], 0);
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, write_template};
+use regex::Regex;
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_image_border() -> Result<(), DocxError> {
+    let input = "output/tests/image_border_template.docx";
+    let output = "output/tests/image_border.docx";
+    let body = [
+        paragraph("{{@sig|border=1pt:ff0000}}"),
+        paragraph("{{@sig|border=25400}}"),
+        paragraph("{{@sig|border=thick}}"),
+        paragraph("{{@sig}}"),
+    ]
+    .concat();
+    write_template(input, &body).await?;
+
+    let logo = tokio::fs::read_to_string("template/logo_base64.txt").await?;
+    let mut data = HashMap::new();
+    data.insert("{{sig}}".to_string(), Value::String(logo));
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let document = read_entry(output, "word/document.xml").await?;
+
+    // The line follows the geometry inside the shape properties / 线条位于形状属性中的几何之后
+    let shapes = Regex::new(r"<pic:spPr>.*?</pic:spPr>").unwrap();
+    let borders = shapes
+        .find_iter(&document)
+        .map(|m| {
+            m.as_str()
+                .split_once("</a:prstGeom>")
+                .map(|(_, line)| line.trim_end_matches("</pic:spPr>").to_string())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        borders,
+        vec![
+            Some(
+                r#"<a:ln w="12700"><a:solidFill><a:srgbClr val="FF0000"/></a:solidFill></a:ln>"#
+                    .to_string()
+            ),
+            Some(
+                r#"<a:ln w="25400"><a:solidFill><a:srgbClr val="000000"/></a:solidFill></a:ln>"#
+                    .to_string()
+            ),
+            Some(String::new()),
+            Some(String::new()),
+        ]
+    );
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{
+    paragraph, read_entry, read_zip_entry, table, table_row, write_template,
+};
+use base64::Engine;
+use base64::engine::general_purpose;
+use bytes::Bytes;
+use serde_json::json;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_generate_with_images() -> Result<(), DocxError> {
+    let input = "output/tests/image_bytes_template.docx";
+    let output = "output/tests/image_bytes.docx";
+    let body = format!(
+        "{}{}{}",
+        paragraph("{{title}}"),
+        paragraph("{{@logo|alt=Company}}"),
+        table(
+            2,
+            &[
+                table_row(&["{{#people}}Name", "Badge"]),
+                table_row(&["[name]", "[@badge]"]),
+            ],
+        )
+    );
+    write_template(input, &body).await?;
+
+    let data = tokio::fs::read_to_string("template/logo_base64.txt").await?;
+    let logo = Bytes::from(
+        general_purpose::STANDARD
+            .decode(data.split_whitespace().collect::<String>())
+            .unwrap(),
+    );
+    let mut placeholders = HashMap::new();
+    placeholders.insert("{{title}}".to_string(), json!("Staff"));
+    placeholders.insert("{{#people}}".to_string(), json!([{ "name": "Alice" }]));
+    let images = HashMap::from([
+        ("logo".to_string(), logo.clone()),
+        ("badge".to_string(), logo.clone()),
+    ]);
+
+    let mut docx = DOCX::default();
+    docx.generate_with_images(input, output, &placeholders, images)
+        .await?;
+
+    let media = DOCX::list_media(output).await?;
+    assert_eq!(media.len(), 2);
+    for (name, _) in &media {
+        assert_eq!(
+            DOCX::read_media(output, name).await?.as_ref(),
+            logo.as_ref()
+        );
+    }
+    let document = read_entry(output, "word/document.xml").await?;
+    assert!(document.contains("Staff"));
+    assert!(document.contains(r#"descr="Company""#));
+
+    // The images are not kept for later generations / 图片不会保留到之后的生成
+    docx.generate(input, output, &placeholders).await?;
+    assert!(DOCX::list_media(output).await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_generate_bytes_with_images() -> Result<(), DocxError> {
+    let input = "output/tests/image_bytes_memory_template.docx";
+    write_template(input, &paragraph("{{@logo}}")).await?;
+    let template = Bytes::from(tokio::fs::read(input).await?);
+
+    let data = tokio::fs::read_to_string("template/logo_base64.txt").await?;
+    let logo = Bytes::from(
+        general_purpose::STANDARD
+            .decode(data.split_whitespace().collect::<String>())
+            .unwrap(),
+    );
+    let images = HashMap::from([("logo".to_string(), logo)]);
+
+    let mut docx = DOCX::default();
+    let output = docx
+        .generate_bytes_with_images(template.clone(), &HashMap::new(), images)
+        .await?;
+    let document =
+        String::from_utf8_lossy(&read_zip_entry(&output, "word/document.xml").await?).into_owned();
+    assert!(document.contains("<w:drawing>"));
+
+    // Nothing is kept on the instance / 实例上不保留任何内容
+    let output = docx.generate_bytes(template, &HashMap::new()).await?;
+    let document =
+        String::from_utf8_lossy(&read_zip_entry(&output, "word/document.xml").await?).into_owned();
+    assert!(!document.contains("<w:drawing>"));
+    Ok(())
+}
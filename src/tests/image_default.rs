@@ -0,0 +1,134 @@
+use crate::DOCX;
+use crate::core::constant::{
+    ERR_IMAGE_DEFAULT_DISABLED, ERR_IMAGE_DEFAULT_OUTSIDE, ERR_UNKNOWN_FORMAT,
+};
+use crate::public::error::DocxError;
+use crate::public::report::GenerationWarning;
+use crate::tests::common::{paragraph, table, table_row, write_template};
+use base64::Engine;
+use base64::engine::general_purpose;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+async fn decode(path: &str) -> Result<(String, Vec<u8>), DocxError> {
+    let data = tokio::fs::read_to_string(path).await?;
+    let data = data.split_whitespace().collect::<String>();
+    let bytes = general_purpose::STANDARD.decode(&data).unwrap();
+    Ok((data, bytes))
+}
+
+#[tokio::test]
+async fn test_image_default_file() -> Result<(), DocxError> {
+    let input = "output/tests/image_default_file_template.docx";
+    let output = "output/tests/image_default_file.docx";
+    let default_path = "output/tests/image_default_file.png";
+    let (_, placeholder) = decode("template/thumbnail_base64.txt").await?;
+    tokio::fs::write(default_path, &placeholder).await?;
+    let (logo, photo) = decode("template/logo_base64.txt").await?;
+
+    let body = table(
+        2,
+        &[
+            table_row(&["{{#people}}Name", "Photo"]),
+            table_row(&["[name]", "[@photo|defaultfile=image_default_file.png]"]),
+        ],
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#people}}".to_string(),
+        json!([{ "name": "Alice", "photo": "" }, { "name": "Bob", "photo": logo }]),
+    );
+    let mut docx = DOCX::default();
+    docx.set_image_default_dir("output/tests");
+    docx.generate(input, output, &data).await?;
+
+    let mut embedded = Vec::new();
+    for (name, _) in DOCX::list_media(output).await? {
+        embedded.push(DOCX::read_media(output, &name).await?.to_vec());
+    }
+    assert_eq!(embedded.len(), 2);
+    assert!(embedded.contains(&placeholder));
+    assert!(embedded.contains(&photo));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_image_default_file_rejected() -> Result<(), DocxError> {
+    let input = "output/tests/image_default_rejected_template.docx";
+    let output = "output/tests/image_default_rejected.docx";
+    write_template(
+        input,
+        &format!(
+            "{}{}{}",
+            paragraph("{{@logo|defaultfile=../../Cargo.toml}}"),
+            paragraph("{{@photo|defaultfile=image_default_file.png}}"),
+            paragraph("{{@note|defaultfile=image_default_note.txt}}")
+        ),
+    )
+    .await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{logo}}".to_string(), Value::String(String::new()));
+    data.insert("{{photo}}".to_string(), Value::String(String::new()));
+    data.insert("{{note}}".to_string(), Value::String(String::new()));
+    tokio::fs::write(
+        "output/tests/image_default_note.txt",
+        "Plain text notes, not an image file",
+    )
+    .await?;
+
+    // Without a directory no file is read / 未设置目录时不读取任何文件
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    assert!(DOCX::list_media(output).await?.is_empty());
+    assert!(
+        docx.report()
+            .warnings
+            .contains(&GenerationWarning::ImageDefaultSkipped {
+                path: "image_default_file.png".to_string(),
+                message: ERR_IMAGE_DEFAULT_DISABLED.to_string(),
+            })
+    );
+
+    // Paths leaving the directory are rejected / 超出目录的路径被拒绝
+    docx.set_image_default_dir("output/tests");
+    docx.generate(input, output, &data).await?;
+    let warnings = &docx.report().warnings;
+    assert!(warnings.contains(&GenerationWarning::ImageDefaultSkipped {
+        path: "../../Cargo.toml".to_string(),
+        message: ERR_IMAGE_DEFAULT_OUTSIDE.to_string(),
+    }));
+
+    // Files that are not images are not embedded / 不是图片的文件不会被嵌入
+    assert!(warnings.contains(&GenerationWarning::ImageDefaultSkipped {
+        path: "image_default_note.txt".to_string(),
+        message: ERR_UNKNOWN_FORMAT.to_string(),
+    }));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_image_default_base64() -> Result<(), DocxError> {
+    let input = "output/tests/image_default_base64_template.docx";
+    let output = "output/tests/image_default_base64.docx";
+    let (thumbnail, placeholder) = decode("template/thumbnail_base64.txt").await?;
+    write_template(
+        input,
+        &paragraph(&format!("{{{{@photo|default={}}}}}", thumbnail)),
+    )
+    .await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{photo}}".to_string(), Value::String(String::new()));
+    DOCX::default().generate(input, output, &data).await?;
+
+    let media = DOCX::list_media(output).await?;
+    assert_eq!(media.len(), 1);
+    assert_eq!(
+        DOCX::read_media(output, &media[0].0).await?.as_ref(),
+        placeholder.as_slice()
+    );
+    Ok(())
+}
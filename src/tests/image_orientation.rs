@@ -0,0 +1,58 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, write_template};
+use base64::Engine;
+use base64::engine::general_purpose;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Build a PNG header declaring the given pixel size / 构建声明给定像素尺寸的 PNG 头
+fn png_header(width: u32, height: u32) -> String {
+    let mut bytes = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+    bytes.extend_from_slice(&13u32.to_be_bytes());
+    bytes.extend_from_slice(b"IHDR");
+    bytes.extend_from_slice(&width.to_be_bytes());
+    bytes.extend_from_slice(&height.to_be_bytes());
+    bytes.extend_from_slice(&[8, 6, 0, 0, 0]);
+    general_purpose::STANDARD.encode(bytes)
+}
+
+#[tokio::test]
+async fn test_image_max_size_by_orientation() -> Result<(), DocxError> {
+    let input = "output/tests/image_orientation_template.docx";
+    let output = "output/tests/image_orientation.docx";
+    write_template(
+        input,
+        &format!(
+            "{}{}",
+            paragraph("{{@landscape}}"),
+            paragraph("{{@portrait}}")
+        ),
+    )
+    .await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{landscape}}".to_string(),
+        Value::String(png_header(1200, 800)),
+    );
+    data.insert(
+        "{{portrait}}".to_string(),
+        Value::String(png_header(800, 1200)),
+    );
+
+    let mut docx = DOCX::default();
+    docx.set_image_orientation_limits(1_600_000, 1_200_000);
+    docx.generate(input, output, &data).await?;
+    let document = read_entry(output, "word/document.xml").await?;
+
+    let extents = document
+        .match_indices("<wp:extent ")
+        .map(|(start, _)| &document[start..start + document[start..].find("/>").unwrap()])
+        .collect::<Vec<_>>();
+    // Landscape: width on the 1600000 limit / 横向：宽度位于 1600000 上限
+    assert_eq!(extents[0], r#"<wp:extent cx="1600000" cy="1066667""#);
+    // Portrait: height on the 1200000 limit / 纵向：高度位于 1200000 上限
+    assert_eq!(extents[1], r#"<wp:extent cx="800000" cy="1200000""#);
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_zip_entry, table, table_row, write_template};
+use base64::Engine;
+use base64::engine::general_purpose;
+use bytes::Bytes;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+#[tokio::test]
+async fn test_image_resolved_by_callback() -> Result<(), DocxError> {
+    let input = "output/tests/image_resolver_template.docx";
+    let body = [
+        paragraph("{{@logo}}"),
+        table(
+            2,
+            &[
+                table_row(&["{{#items}}name", "photo"]),
+                table_row(&["[name]", "[@photo]"]),
+            ],
+        ),
+    ]
+    .concat();
+    write_template(input, &body).await?;
+    let template = Bytes::from(tokio::fs::read(input).await?);
+
+    let logo = tokio::fs::read_to_string("template/logo_base64.txt").await?;
+    let logo = general_purpose::STANDARD.decode(logo.trim()).unwrap();
+
+    // Record every reference the resolver is asked for / 记录解析器被请求的每个引用
+    let requested = Arc::new(Mutex::new(Vec::new()));
+    let seen = requested.clone();
+    let mut docx = DOCX::default();
+    docx.set_image_resolver(move |reference| {
+        seen.lock().unwrap().push(reference.to_string());
+        reference.starts_with("img-").then(|| logo.clone())
+    });
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{logo}}".to_string(),
+        Value::String("img-logo".to_string()),
+    );
+    data.insert(
+        "{{#items}}".to_string(),
+        json!([{"name": "A", "photo": "img-a"}, {"name": "B", "photo": "missing"}]),
+    );
+    let output = docx.generate_bytes(template, &data).await?;
+    let document = read_zip_entry(&output, "word/document.xml").await?;
+    let document = String::from_utf8_lossy(&document);
+
+    assert_eq!(document.matches("<w:drawing>").count(), 2);
+    assert!(document.contains(">missing<"));
+    assert_eq!(
+        *requested.lock().unwrap(),
+        vec!["img-logo", "img-a", "missing"]
+    );
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, write_template};
+use regex::Regex;
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_image_rotation() -> Result<(), DocxError> {
+    let input = "output/tests/image_rotate_template.docx";
+    let output = "output/tests/image_rotate.docx";
+    let body = [
+        paragraph("{{@sig|rotate=90}}"),
+        paragraph("{{@sig|rotate=-180}}"),
+        paragraph("{{@sig}}"),
+    ]
+    .concat();
+    write_template(input, &body).await?;
+
+    let logo = tokio::fs::read_to_string("template/logo_base64.txt").await?;
+    let mut data = HashMap::new();
+    data.insert("{{sig}}".to_string(), Value::String(logo));
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let document = read_entry(output, "word/document.xml").await?;
+
+    let xfrm = Regex::new(r"<a:xfrm(?: rot=.(\d+).)?>").unwrap();
+    let rotations = xfrm
+        .captures_iter(&document)
+        .map(|c| c.get(1).map(|m| m.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(rotations, vec![Some("5400000"), Some("10800000"), None]);
+
+    // A quarter turn swaps the inline extent but keeps the picture size / 四分之一圈旋转交换内联范围但保持图片尺寸
+    let extent = Regex::new(r#"<wp:extent cx="(\d+)" cy="(\d+)"/>"#).unwrap();
+    let size = Regex::new(r#"<a:ext cx="(\d+)" cy="(\d+)"/>"#).unwrap();
+    let extents = extent.captures_iter(&document).collect::<Vec<_>>();
+    let sizes = size.captures_iter(&document).collect::<Vec<_>>();
+    assert_eq!(extents[0][1], sizes[0][2]);
+    assert_eq!(extents[0][2], sizes[0][1]);
+    assert_eq!(extents[1][1], sizes[1][1]);
+    assert_eq!(extents[2][1], sizes[2][1]);
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, table, table_row, write_template};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+async fn logo() -> Result<Value, DocxError> {
+    let data = tokio::fs::read_to_string("template/logo_base64.txt").await?;
+    Ok(json!(data.split_whitespace().collect::<String>()))
+}
+
+/// `cx` and `cy` of the first `wp:extent` / 第一个 `wp:extent` 的 `cx` 和 `cy`
+fn extent(document: &str) -> (u64, u64) {
+    let start = document.find("<wp:extent ").unwrap();
+    let element = &document[start..start + document[start..].find("/>").unwrap()];
+    let attribute = |name: &str| -> u64 {
+        let value = &element[element.find(name).unwrap() + name.len()..];
+        value[..value.find('"').unwrap()].parse().unwrap()
+    };
+    (attribute("cx=\""), attribute("cy=\""))
+}
+
+#[tokio::test]
+async fn test_image_size_both_sides() -> Result<(), DocxError> {
+    let input = "output/tests/image_size_both_template.docx";
+    let output = "output/tests/image_size_both.docx";
+    write_template(input, &paragraph("{{@logo:200x150}}")).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{logo}}".to_string(), logo().await?);
+    DOCX::default().generate(input, output, &data).await?;
+
+    // 200pt x 150pt at 12700 EMU per point / 每磅 12700 EMU 下的 200pt x 150pt
+    let document = read_entry(output, "word/document.xml").await?;
+    assert_eq!(extent(&document), (2_540_000, 1_905_000));
+    assert!(document.contains(r#"<a:ext cx="2540000" cy="1905000"/>"#));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_image_size_keeps_aspect_ratio() -> Result<(), DocxError> {
+    let input = "output/tests/image_size_aspect_template.docx";
+    let computed_output = "output/tests/image_size_aspect_computed.docx";
+    let output = "output/tests/image_size_aspect.docx";
+    let mut data = HashMap::new();
+    data.insert("{{logo}}".to_string(), logo().await?);
+
+    write_template(input, &paragraph("{{@logo}}")).await?;
+    DOCX::default()
+        .generate(input, computed_output, &data)
+        .await?;
+    let (width, height) = extent(&read_entry(computed_output, "word/document.xml").await?);
+
+    write_template(input, &paragraph("{{@logo:100x}}")).await?;
+    DOCX::default().generate(input, output, &data).await?;
+    let (sized_width, sized_height) = extent(&read_entry(output, "word/document.xml").await?);
+    assert_eq!(sized_width, 1_270_000);
+    assert_eq!(
+        sized_height,
+        (height as f64 * 1_270_000.0 / width as f64).round() as u64
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_image_size_in_table_with_options() -> Result<(), DocxError> {
+    let input = "output/tests/image_size_table_template.docx";
+    let output = "output/tests/image_size_table.docx";
+    let body = table(
+        1,
+        &[
+            table_row(&["{{#people}}Photo"]),
+            table_row(&["[@photo:x72|alt=Portrait]"]),
+        ],
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#people}}".to_string(),
+        json!([{ "photo": logo().await? }]),
+    );
+    DOCX::default().generate(input, output, &data).await?;
+
+    let document = read_entry(output, "word/document.xml").await?;
+    assert_eq!(extent(&document).1, 914_400);
+    assert!(document.contains(r#"descr="Portrait""#));
+    Ok(())
+}
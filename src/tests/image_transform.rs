@@ -0,0 +1,43 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, write_template};
+use base64::Engine;
+use base64::engine::general_purpose;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Marker appended by the transform, PNG decoders ignore trailing bytes / 变换追加的标记，PNG 解码器忽略尾随字节
+const WATERMARK: &[u8] = b"watermark";
+
+#[tokio::test]
+async fn test_image_transform() -> Result<(), DocxError> {
+    let input = "output/tests/image_transform_template.docx";
+    let output = "output/tests/image_transform.docx";
+    write_template(input, &paragraph("{{@logo}}")).await?;
+
+    let logo = tokio::fs::read_to_string("template/logo_base64.txt").await?;
+    let original = general_purpose::STANDARD
+        .decode(logo.split_whitespace().collect::<String>())
+        .unwrap();
+    let mut data = HashMap::new();
+    data.insert("{{logo}}".to_string(), Value::String(logo));
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    let mut docx = DOCX::default();
+    docx.set_image_transform(move |bytes| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        [bytes, WATERMARK].concat()
+    });
+    docx.generate(input, output, &data).await?;
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    let media = DOCX::list_media(output).await?;
+    assert_eq!(media.len(), 1);
+    let embedded = DOCX::read_media(output, &media[0].0).await?;
+    assert_ne!(embedded.as_ref(), original.as_slice());
+    assert_eq!(embedded.as_ref(), [original.as_slice(), WATERMARK].concat());
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::read_zip_entry;
+use bytes::Bytes;
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_generate_bytes_from_cached_template() -> Result<(), DocxError> {
+    // Template cached once as shared bytes / 模板作为共享字节缓存一次
+    let template = Bytes::from(
+        tokio::fs::read("template/test.docx")
+            .await
+            .map_err(|e| DocxError::Xml(e.into()))?,
+    );
+
+    let mut docx = DOCX::default();
+    for subtitle in ["First render", "Second render"] {
+        let mut data = HashMap::new();
+        data.insert(
+            "{{report_subtitle}}".to_string(),
+            Value::String(subtitle.to_string()),
+        );
+
+        let output = docx.generate_bytes(template.clone(), &data).await?;
+        let document = read_zip_entry(&output, "word/document.xml").await?;
+        assert!(String::from_utf8_lossy(&document).contains(subtitle));
+    }
+    Ok(())
+}
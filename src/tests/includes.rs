@@ -0,0 +1,135 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::public::report::GenerationWarning;
+use crate::tests::common::{paragraph, read_entry, write_template_with_parts};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Build a fragment document around `body` / 围绕 `body` 构建片段文档
+fn fragment(body: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>{}<w:sectPr><w:pgSz w:w="12240" w:h="15840"/></w:sectPr></w:body></w:document>"#,
+        body
+    )
+}
+
+#[tokio::test]
+async fn test_includes() -> Result<(), DocxError> {
+    let input = "output/tests/includes_template.docx";
+    let output = "output/tests/includes.docx";
+    let disclaimer = fragment(&format!(
+        "{}{}",
+        paragraph("{{client}}"),
+        paragraph("[include:word/fragments/note.xml]")
+    ));
+    let note = fragment(&paragraph("Figures are unaudited"));
+    let recursive = fragment(&paragraph("[include:word/fragments/recursive.xml]"));
+    let body = [
+        paragraph("{{title}}"),
+        paragraph("[include:word/fragments/disclaimer.xml]"),
+        paragraph("[include:word/fragments/missing.xml]"),
+        paragraph("[include:word/fragments/recursive.xml]"),
+        paragraph("End"),
+    ]
+    .concat();
+    write_template_with_parts(
+        input,
+        &body,
+        &[
+            ("word/fragments/disclaimer.xml", disclaimer.as_bytes()),
+            ("word/fragments/note.xml", note.as_bytes()),
+            ("word/fragments/recursive.xml", recursive.as_bytes()),
+        ],
+    )
+    .await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{title}}".to_string(), Value::from("Annual report"));
+    data.insert("{{client}}".to_string(), Value::from("Acme Ltd"));
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+
+    // Fragments are spliced in place with their placeholders filled / 片段原地拼接并填充其占位符
+    let document = read_entry(output, "word/document.xml").await?;
+    let position = |text: &str| document.find(text).unwrap();
+    assert!(position("Annual report") < position("Acme Ltd"));
+    assert!(position("Acme Ltd") < position("Figures are unaudited"));
+    assert!(position("Figures are unaudited") < position("End"));
+    assert!(!document.contains("disclaimer.xml"));
+    assert_eq!(document.matches("<w:sectPr>").count(), 1);
+    assert!(!document.contains(r#"w:w="12240""#));
+
+    // Unknown and self-including fragments keep their marker / 未知和包含自身的片段保留其标记
+    assert!(document.contains("[include:word/fragments/missing.xml]"));
+    assert_eq!(
+        document
+            .matches("[include:word/fragments/recursive.xml]")
+            .count(),
+        1
+    );
+    let unresolved = docx
+        .report()
+        .warnings
+        .iter()
+        .filter_map(|warning| match warning {
+            GenerationWarning::UnresolvedInclude { path } => Some(path.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        unresolved,
+        ["word/fragments/missing.xml", "word/fragments/recursive.xml"]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_include_section_break_and_relationships() -> Result<(), DocxError> {
+    let input = "output/tests/includes_relationships_template.docx";
+    let output = "output/tests/includes_relationships.docx";
+    let terms = fragment(&paragraph("Terms apply"));
+    // rId1 is defined by the document, rId9 only by the fragment's own part / rId1 由文档定义，rId9 仅由片段自身的部件定义
+    let styled = fragment(
+        r#"<w:p><w:hyperlink r:id="rId1"><w:r><w:t>Styled</w:t></w:r></w:hyperlink></w:p>"#,
+    );
+    let logo =
+        fragment(r#"<w:p><w:hyperlink r:id="rId9"><w:r><w:t>Logo</w:t></w:r></w:hyperlink></w:p>"#);
+    let body = [
+        r#"<w:p><w:pPr><w:sectPr><w:pgSz w:w="16838" w:h="11906"/></w:sectPr></w:pPr><w:r><w:t>[include:word/fragments/terms.xml]</w:t></w:r></w:p>"#.to_string(),
+        paragraph("[include:word/fragments/styled.xml]"),
+        paragraph("[include:word/fragments/logo.xml]"),
+    ]
+    .concat();
+    write_template_with_parts(
+        input,
+        &body,
+        &[
+            ("word/fragments/terms.xml", terms.as_bytes()),
+            ("word/fragments/styled.xml", styled.as_bytes()),
+            ("word/fragments/logo.xml", logo.as_bytes()),
+        ],
+    )
+    .await?;
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &HashMap::new()).await?;
+    let document = read_entry(output, "word/document.xml").await?;
+
+    // The section break of the include paragraph follows its fragment / 包含段落的分节符位于其片段之后
+    let landscape = document.find(r#"w:w="16838""#).unwrap();
+    assert!(document.find("Terms apply").unwrap() < landscape);
+    assert!(landscape < document.find("Styled").unwrap());
+    assert_eq!(document.matches("<w:sectPr>").count(), 2);
+
+    // A fragment using a relationship of its own is not spliced / 使用自身关系的片段不会被拼接
+    assert!(document.contains("[include:word/fragments/logo.xml]"));
+    assert!(!document.contains("rId9"));
+    assert_eq!(
+        docx.report().warnings,
+        [GenerationWarning::IncludeRelationshipMissing {
+            path: "word/fragments/logo.xml".to_string(),
+            id: "rId9".to_string(),
+        }]
+    );
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use crate::public::error::DocxError;
+use crate::tests::common::{table, table_row, write_template};
+use crate::{DOCX, TableInfo};
+use async_zip::tokio::write::ZipFileWriter;
+use async_zip::{Compression, ZipEntryBuilder};
+
+#[tokio::test]
+async fn test_inspect_sample_template() -> Result<(), DocxError> {
+    let tables = DOCX::inspect_tables("template/test.docx").await?;
+    let users = tables
+        .iter()
+        .find(|table| table.loop_key.as_deref() == Some("{{#users}}"))
+        .expect("users table");
+    assert!(users.column_keys.iter().any(|key| key == "name"));
+    assert!(users.column_keys.iter().any(|key| key == "pets.toys.title"));
+    assert!(tables.iter().any(|table| table.loop_key.is_none()));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_inspect_tables_in_order() -> Result<(), DocxError> {
+    let input = "output/tests/inspect_tables_template.docx";
+    let body = format!(
+        "{}{}",
+        table(
+            2,
+            &[
+                table_row(&["{{#lines|sort:qty}}Item", "Qty"]),
+                table_row(&["[item]", "[r:qty]"]),
+            ],
+        ),
+        table(1, &[table_row(&["Static"])]),
+    );
+    write_template(input, &body).await?;
+
+    let tables = DOCX::inspect_tables(input).await?;
+    assert_eq!(
+        tables,
+        [
+            TableInfo {
+                loop_key: Some("{{#lines}}".to_string()),
+                column_keys: vec!["item".to_string(), "qty".to_string()],
+            },
+            TableInfo {
+                loop_key: None,
+                column_keys: Vec::new(),
+            },
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_inspect_without_document_part() -> Result<(), DocxError> {
+    let input = "output/tests/inspect_tables_no_document.docx";
+    tokio::fs::create_dir_all("output/tests")
+        .await
+        .map_err(|e| DocxError::Xml(e.into()))?;
+    let file = tokio::fs::File::create(input)
+        .await
+        .map_err(|e| DocxError::Xml(e.into()))?;
+    let mut writer = ZipFileWriter::with_tokio(file);
+    let options = ZipEntryBuilder::new(
+        "[Content_Types].xml".to_string().into(),
+        Compression::Deflate,
+    );
+    writer.write_entry_whole(options, b"<Types/>").await?;
+    writer.close().await?;
+
+    assert!(matches!(
+        DOCX::inspect_tables(input).await,
+        Err(DocxError::MissingPart(part)) if part == "word/document.xml"
+    ));
+    Ok(())
+}
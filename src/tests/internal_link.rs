@@ -0,0 +1,30 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, write_template};
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_internal_link_to_bookmark() -> Result<(), DocxError> {
+    let input = "output/tests/internal_link_template.docx";
+    let output = "output/tests/internal_link.docx";
+    let body = [
+        paragraph("[ilink:details|See details]"),
+        paragraph("[ilink:summary]"),
+    ]
+    .concat();
+    write_template(input, &body).await?;
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &HashMap::new()).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    assert!(!xml.contains("[ilink:"));
+    let link = xml.find(r#"<w:hyperlink w:anchor="details""#).unwrap();
+    let display = xml.find(">See details</w:t>").unwrap();
+    assert!(link < display && display < xml[link..].find("</w:hyperlink>").unwrap() + link);
+    assert!(xml.contains(r#"<w:hyperlink w:anchor="summary""#));
+    assert!(xml.contains(">summary</w:t>"));
+    // Internal anchors need no relationship / 内部锚点无需关系
+    assert!(!xml.contains("r:id="));
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use std::collections::HashMap;
+
+async fn generate_from(name: &str, content: &[u8]) -> Result<(), DocxError> {
+    let input = format!("output/tests/{}_template.docx", name);
+    let output = format!("output/tests/{}.docx", name);
+    tokio::fs::create_dir_all("output/tests").await?;
+    tokio::fs::write(&input, content).await?;
+
+    let mut docx = DOCX::default();
+    docx.generate(&input, &output, &HashMap::new()).await
+}
+
+#[tokio::test]
+async fn test_empty_input_is_invalid_package() -> Result<(), DocxError> {
+    let result = generate_from("invalid_package_empty", &[]).await;
+    assert!(matches!(result, Err(DocxError::InvalidPackage(_))));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_truncated_input_is_invalid_package() -> Result<(), DocxError> {
+    let template = tokio::fs::read("template/test.docx").await?;
+    let result = generate_from("invalid_package_truncated", &template[..template.len() / 2]).await;
+    assert!(matches!(result, Err(DocxError::InvalidPackage(_))));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_missing_input_is_io_error() {
+    let mut docx = DOCX::default();
+    let result = docx
+        .generate(
+            "output/tests/invalid_package_missing.docx",
+            "output/tests/invalid_package_missing_out.docx",
+            &HashMap::new(),
+        )
+        .await;
+    assert!(matches!(result, Err(DocxError::Io(_))));
+}
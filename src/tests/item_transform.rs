@@ -0,0 +1,81 @@
+use crate::public::error::DocxError;
+use crate::tests::common::{read_entry, table, table_row, write_template};
+use crate::{DOCX, GenerationWarning};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_item_transform_adds_field() -> Result<(), DocxError> {
+    let input = "output/tests/item_transform_template.docx";
+    let output = "output/tests/item_transform.docx";
+    let body = table(
+        2,
+        &[
+            table_row(&["{{#lines}}product", "total"]),
+            table_row(&["[skipif:total==0][product]", "[total]"]),
+        ],
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#lines}}".to_string(),
+        json!([
+            {"product": "Pen", "price": 1.5, "qty": 4},
+            {"product": "Desk", "price": 120, "qty": 0},
+            {"product": "Lamp", "price": 20, "qty": 2}
+        ]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.set_item_transform(|item| {
+        let price = item["price"].as_f64().unwrap_or_default();
+        let qty = item["qty"].as_f64().unwrap_or_default();
+        item["total"] = Value::from(price * qty);
+    });
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    assert!(xml.contains("<w:t>6.00</w:t>"));
+    assert!(xml.contains("<w:t>40.00</w:t>"));
+    // Skip conditions see the derived field / 跳过条件能看到派生字段
+    assert!(!xml.contains("Desk"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_item_transform_non_object_reported() -> Result<(), DocxError> {
+    let input = "output/tests/item_transform_non_object_template.docx";
+    let output = "output/tests/item_transform_non_object.docx";
+    let body = table(
+        1,
+        &[table_row(&["{{#lines}}product"]), table_row(&["[product]"])],
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#lines}}".to_string(),
+        json!([{"product": "Pen"}, {"product": "Desk"}]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.set_item_transform(|item| {
+        if item["product"] == "Desk" {
+            *item = Value::Null;
+        }
+    });
+    docx.generate(input, output, &data).await?;
+
+    assert_eq!(
+        docx.report().warnings,
+        vec![GenerationWarning::ItemTransformNotObject {
+            table: "{{#lines}}".to_string(),
+            item: 2,
+        }]
+    );
+    let xml = read_entry(output, "word/document.xml").await?;
+    assert!(xml.contains("<w:t>Pen</w:t>"));
+    assert_eq!(xml.matches("<w:tr>").count(), 2);
+    Ok(())
+}
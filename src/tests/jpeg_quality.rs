@@ -0,0 +1,45 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_zip_entry, write_template};
+use async_zip::tokio::read::seek::ZipFileReader;
+use bytes::Bytes;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::io::Cursor;
+
+async fn embedded_jpeg_size(template: &Bytes, quality: u8) -> Result<usize, DocxError> {
+    let logo = tokio::fs::read_to_string("template/logo_base64.txt").await?;
+    let mut data = HashMap::new();
+    data.insert("{{logo}}".to_string(), Value::String(logo));
+
+    let mut docx = DOCX::default();
+    docx.set_jpeg_quality(quality);
+    let output = docx.generate_bytes(template.clone(), &data).await?;
+
+    let zip = ZipFileReader::with_tokio(Cursor::new(output.as_slice())).await?;
+    let name = zip
+        .file()
+        .entries()
+        .iter()
+        .filter_map(|e| e.filename().as_str().ok().map(str::to_string))
+        .find(|name| name.starts_with("word/media/") && name.ends_with(".jpg"))
+        .unwrap();
+    Ok(read_zip_entry(&output, &name).await?.len())
+}
+
+#[tokio::test]
+async fn test_lower_jpeg_quality_is_smaller() -> Result<(), DocxError> {
+    let input = "output/tests/jpeg_quality_template.docx";
+    write_template(input, &paragraph("{{@logo}}")).await?;
+    let template = Bytes::from(tokio::fs::read(input).await?);
+
+    let low = embedded_jpeg_size(&template, 20).await?;
+    let high = embedded_jpeg_size(&template, 90).await?;
+    assert!(
+        low < high,
+        "quality 20: {} bytes, quality 90: {} bytes",
+        low,
+        high
+    );
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, write_template_with_parts};
+use std::collections::HashMap;
+
+const SETTINGS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><w:settings xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:themeFontLang w:val="en-US" w:eastAsia="zh-CN"/></w:settings>"#;
+
+#[tokio::test]
+async fn test_default_language() -> Result<(), DocxError> {
+    let input = "output/tests/language_template.docx";
+    let output = "output/tests/language.docx";
+    let body = [
+        paragraph("before"),
+        paragraph("[pagebreak]"),
+        paragraph("[ilink:details|Details]"),
+    ]
+    .concat();
+    write_template_with_parts(input, &body, &[("word/settings.xml", SETTINGS.as_bytes())]).await?;
+
+    let mut docx = DOCX::default();
+    docx.set_default_language("de-DE");
+    docx.generate(input, output, &HashMap::new()).await?;
+
+    let xml = read_entry(output, "word/document.xml").await?;
+    assert!(
+        xml.contains(r#"<w:r><w:rPr><w:lang w:val="de-DE"/></w:rPr><w:br w:type="page"/></w:r>"#)
+    );
+    assert!(xml.contains(r#"<w:rPr><w:rStyle w:val="Hyperlink"/><w:lang w:val="de-DE"/></w:rPr>"#));
+
+    let settings = read_entry(output, "word/settings.xml").await?;
+    assert!(settings.contains(r#"<w:themeFontLang w:val="de-DE" w:eastAsia="zh-CN"/>"#));
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, write_template};
+use crate::{DOCX, GenerationWarning};
+use std::collections::HashMap;
+
+/// Body with a stray end tag between two paragraphs / 两个段落之间带有多余结束标签的正文
+fn malformed_body() -> String {
+    format!("{}</w:bogus>{}", paragraph("Before"), paragraph("{{name}}"))
+}
+
+fn data() -> HashMap<String, serde_json::Value> {
+    let mut data = HashMap::new();
+    data.insert("{{name}}".to_string(), "Alice".into());
+    data
+}
+
+#[tokio::test]
+async fn test_malformed_xml_fails_by_default() -> Result<(), DocxError> {
+    let input = "output/tests/lenient_xml_strict_template.docx";
+    let output = "output/tests/lenient_xml_strict.docx";
+    write_template(input, &malformed_body()).await?;
+
+    let mut docx = DOCX::default();
+    assert!(docx.generate(input, output, &data()).await.is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_lenient_xml_recovers_and_reports() -> Result<(), DocxError> {
+    let input = "output/tests/lenient_xml_template.docx";
+    let output = "output/tests/lenient_xml.docx";
+    write_template(input, &malformed_body()).await?;
+
+    let mut docx = DOCX::default();
+    docx.set_lenient_xml(true);
+    docx.generate(input, output, &data()).await?;
+
+    let xml = read_entry(output, "word/document.xml").await?;
+    assert!(xml.contains("<w:t>Alice</w:t>"));
+    assert!(!xml.contains("{{name}}"));
+    // The stray end tag is not written / 多余的结束标签不会被写入
+    assert!(!xml.contains("</w:bogus>"));
+
+    let warnings = &docx.report().warnings;
+    assert_eq!(warnings.len(), 1);
+    assert!(matches!(
+        &warnings[0],
+        GenerationWarning::MalformedXml { position, .. } if *position > 0
+    ));
+    Ok(())
+}
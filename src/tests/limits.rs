@@ -0,0 +1,126 @@
+use crate::DOCX;
+use crate::public::error::{DocxError, Limit};
+use crate::tests::common::{
+    paragraph, table, table_row, write_template, write_template_with_parts,
+};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+async fn loop_template(input: &str) -> Result<HashMap<String, Value>, DocxError> {
+    let body = table(1, &[table_row(&["{{#items}}name"]), table_row(&["[name]"])]);
+    write_template(input, &body).await?;
+
+    let items = (0..10).map(|i| json!({"name": i})).collect::<Vec<_>>();
+    let mut data = HashMap::new();
+    data.insert("{{#items}}".to_string(), Value::Array(items));
+    Ok(data)
+}
+
+#[tokio::test]
+async fn test_max_rows() -> Result<(), DocxError> {
+    let input = "output/tests/limits_rows_template.docx";
+    let output = "output/tests/limits_rows.docx";
+    let data = loop_template(input).await?;
+
+    let mut docx = DOCX::default();
+    docx.set_max_rows(10);
+    docx.generate(input, output, &data).await?;
+
+    docx.set_max_rows(5);
+    let result = docx.generate(input, output, &data).await;
+    assert!(matches!(
+        result,
+        Err(DocxError::LimitExceeded(Limit::Rows {
+            max: 5,
+            requested: 10
+        }))
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_max_output_bytes() -> Result<(), DocxError> {
+    let input = "output/tests/limits_bytes_template.docx";
+    let output = "output/tests/limits_bytes.docx";
+    let data = loop_template(input).await?;
+
+    let mut docx = DOCX::default();
+    docx.set_max_output_bytes(1024 * 1024);
+    docx.generate(input, output, &data).await?;
+
+    docx.set_max_output_bytes(64);
+    let result = docx.generate(input, output, &data).await;
+    assert!(matches!(
+        result,
+        Err(DocxError::LimitExceeded(Limit::OutputBytes { max: 64 }))
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_max_rows_ignores_skipped_items() -> Result<(), DocxError> {
+    let input = "output/tests/limits_rows_skipped_template.docx";
+    let output = "output/tests/limits_rows_skipped.docx";
+    let body = table(
+        1,
+        &[
+            table_row(&["{{#items}}name"]),
+            table_row(&["[skipif:hidden==1][name]"]),
+        ],
+    );
+    write_template(input, &body).await?;
+    let items = (0..10)
+        .map(|i| json!({"name": i, "hidden": i % 2}))
+        .collect::<Vec<_>>();
+    let mut data = HashMap::new();
+    data.insert("{{#items}}".to_string(), Value::Array(items));
+
+    let mut docx = DOCX::default();
+    docx.set_max_rows(5);
+    docx.generate(input, output, &data).await?;
+
+    docx.set_max_rows(4);
+    let result = docx.generate(input, output, &data).await;
+    assert!(matches!(
+        result,
+        Err(DocxError::LimitExceeded(Limit::Rows {
+            max: 4,
+            requested: 5
+        }))
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_limit_error_removes_buffered_document() -> Result<(), DocxError> {
+    let input = "output/tests/limits_cleanup_template.docx";
+    let output = "output/tests/limits_cleanup.docx";
+    let marker = "limits_cleanup_marker";
+    let media = vec![0u8; 16 * 1024];
+    write_template_with_parts(
+        input,
+        &paragraph(marker),
+        &[("word/media/image1.png", &media)],
+    )
+    .await?;
+
+    // document.xml is buffered before the media part exceeds the limit / document.xml 在媒体部件超出限制之前被缓冲
+    let mut docx = DOCX::default();
+    docx.set_max_output_bytes(8 * 1024);
+    let result = docx.generate(input, output, &HashMap::new()).await;
+    assert!(matches!(
+        result,
+        Err(DocxError::LimitExceeded(Limit::OutputBytes { .. }))
+    ));
+
+    let leaked = std::fs::read_dir(std::env::temp_dir())?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with("docx_"))
+        })
+        .any(|path| std::fs::read_to_string(path).is_ok_and(|content| content.contains(marker)));
+    assert!(!leaked);
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{read_entry, table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_list_in_cell() -> Result<(), DocxError> {
+    let input = "output/tests/list_cell_template.docx";
+    let output = "output/tests/list_cell.docx";
+    let body = table(
+        2,
+        &[
+            table_row(&["Tags", "[list:tags]"]),
+            table_row(&["Owner", "[list:owner]"]),
+        ],
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{tags}}".to_string(), json!(["red", "A&B", 3]));
+    data.insert("{{owner}}".to_string(), json!("Alice"));
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    // One line per item, separated by breaks inside the same run / 每项一行，在同一运行内以换行分隔
+    assert!(xml.contains(concat!(
+        r#"<w:r><w:t xml:space="preserve">• red</w:t><w:br/>"#,
+        r#"<w:t xml:space="preserve">• A&amp;B</w:t><w:br/>"#,
+        r#"<w:t xml:space="preserve">• 3</w:t></w:r>"#,
+    )));
+    // A single value is a one-item list / 单个值为单项列表
+    assert!(xml.contains(r#"<w:t xml:space="preserve">• Alice</w:t></w:r>"#));
+    assert!(!xml.contains("[list:"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_in_loop_row() -> Result<(), DocxError> {
+    let input = "output/tests/list_cell_loop_template.docx";
+    let output = "output/tests/list_cell_loop.docx";
+    let cell = |text: &str| {
+        format!(
+            r#"<w:tc><w:p><w:r><w:rPr><w:b/></w:rPr><w:t>{}</w:t></w:r></w:p></w:tc>"#,
+            text
+        )
+    };
+    let body = table(
+        1,
+        &[
+            format!("<w:tr>{}</w:tr>", cell("{{#items}}Tags")),
+            // A data row holding only a list marker / 仅包含列表标记的数据行
+            format!("<w:tr>{}</w:tr>", cell("[list:tags]")),
+        ],
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#items}}".to_string(),
+        json!([{ "tags": "red" }, { "tags": "green" }]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    // Every item expands the row, keeping the run formatting / 每一项展开一行，并保留运行格式
+    assert_eq!(xml.matches("<w:tr>").count(), 3);
+    assert!(
+        xml.contains(r#"<w:r><w:rPr><w:b/></w:rPr><w:t xml:space="preserve">• red</w:t></w:r>"#)
+    );
+    assert!(
+        xml.contains(r#"<w:r><w:rPr><w:b/></w:rPr><w:t xml:space="preserve">• green</w:t></w:r>"#)
+    );
+    assert!(!xml.contains("[list:"));
+    Ok(())
+}
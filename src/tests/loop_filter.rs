@@ -0,0 +1,64 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_loop_filter() -> Result<(), DocxError> {
+    let input = "output/tests/loop_filter_template.docx";
+    let output = "output/tests/loop_filter.docx";
+    let body = format!(
+        "{}{}{}",
+        table(
+            2,
+            &[
+                table_row(&["{{#users|status==active}}Active", "Age"]),
+                table_row(&["[name]", "[age]"]),
+            ],
+        ),
+        paragraph("Inactive users"),
+        table(
+            2,
+            &[
+                table_row(&["{{#users|status!='active'}}Inactive", "Age"]),
+                table_row(&["[name]", "[age]"]),
+            ],
+        ),
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#users}}".to_string(),
+        json!([
+            {"name": "Alice", "age": "30", "status": "active"},
+            {"name": "Bob", "age": "41", "status": "inactive"},
+            {"name": "Carol", "age": "25", "status": "active"}
+        ]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    let tables = xml.split("<w:tbl>").skip(1).collect::<Vec<_>>();
+    assert_eq!(tables.len(), 2);
+    assert!(tables[0].contains("<w:t>Active</w:t>"));
+    assert!(tables[0].contains("<w:t>Alice</w:t>"));
+    assert!(tables[0].contains("<w:t>Carol</w:t>"));
+    assert!(!tables[0].contains("<w:t>Bob</w:t>"));
+    assert!(tables[1].contains("<w:t>Inactive</w:t>"));
+    assert!(tables[1].contains("<w:t>Bob</w:t>"));
+    assert!(!tables[1].contains("<w:t>Alice</w:t>"));
+    assert!(!xml.contains("status"));
+
+    let rows = docx
+        .report()
+        .tables
+        .iter()
+        .map(|table| (table.key.as_str(), table.rows))
+        .collect::<Vec<_>>();
+    assert_eq!(rows, [("{{#users}}", 2), ("{{#users}}", 1)]);
+    Ok(())
+}
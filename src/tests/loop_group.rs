@@ -0,0 +1,48 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{read_entry, table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_loop_group() -> Result<(), DocxError> {
+    let input = "output/tests/loop_group_template.docx";
+    let output = "output/tests/loop_group.docx";
+    let body = table(
+        2,
+        &[
+            table_row(&["{{#items|group:category}}Name", "Price"]),
+            table_row(&["[$group]", ""]),
+            table_row(&["[name]", "[price]"]),
+        ],
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#items}}".to_string(),
+        json!([
+            {"name": "Pen", "price": "3", "category": "Office"},
+            {"name": "Apple", "price": "1", "category": "Food"},
+            {"name": "Desk", "price": "120", "category": "Office"},
+            {"name": "Bread", "price": "2", "category": "Food"}
+        ]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    // Header, two group headers and four items / 标题、两个分组标题和四个项
+    assert_eq!(xml.matches("<w:tr>").count(), 7);
+    assert_eq!(xml.matches("<w:t>Office</w:t>").count(), 1);
+    assert_eq!(xml.matches("<w:t>Food</w:t>").count(), 1);
+    let order = ["Office", "Pen", "Desk", "Food", "Apple", "Bread"]
+        .iter()
+        .map(|text| xml.find(&format!("<w:t>{}</w:t>", text)).unwrap())
+        .collect::<Vec<_>>();
+    assert!(order.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(!xml.contains("$group"));
+    assert_eq!(docx.report().tables[0].rows, 4);
+    Ok(())
+}
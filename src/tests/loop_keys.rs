@@ -0,0 +1,54 @@
+use crate::public::error::DocxError;
+use crate::tests::common::{table, table_row, write_template};
+use crate::{DOCX, GenerationWarning};
+use serde_json::json;
+use std::collections::HashMap;
+
+async fn render(name: &str, strict: bool) -> Result<DOCX<'static>, DocxError> {
+    let input = format!("output/tests/{}_template.docx", name);
+    let output = format!("output/tests/{}.docx", name);
+    let body = table(
+        2,
+        &[
+            table_row(&["{{#items}}name", "price"]),
+            table_row(&["[name]", "[price]"]),
+        ],
+    );
+    write_template(&input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#items}}".to_string(),
+        json!([
+            {"name": "Apple", "price": 1.5},
+            {"name": "Pear", "cost": 2}
+        ]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.set_strict_loop_keys(strict);
+    docx.generate(&input, &output, &data).await?;
+    Ok(docx)
+}
+
+fn expected_warning() -> GenerationWarning {
+    GenerationWarning::InconsistentLoopKeys {
+        table: "{{#items}}".to_string(),
+        item: 1,
+        missing: vec!["price".to_string()],
+        extra: vec!["cost".to_string()],
+    }
+}
+
+#[tokio::test]
+async fn test_inconsistent_loop_keys_reported() -> Result<(), DocxError> {
+    let docx = render("loop_keys_reported", false).await?;
+    assert_eq!(docx.report().warnings, vec![expected_warning()]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_inconsistent_loop_keys_strict() {
+    let result = render("loop_keys_strict", true).await;
+    assert!(matches!(result, Err(DocxError::Validation(warning)) if warning == expected_warning()));
+}
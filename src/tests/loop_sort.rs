@@ -0,0 +1,65 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{read_entry, table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+/// Render the items under the given loop marker and return the names in row order / 按给定循环标记渲染项并按行顺序返回名称
+async fn sorted_names(name: &str, marker: &str) -> Result<Vec<String>, DocxError> {
+    let input = format!("output/tests/loop_sort_{}_template.docx", name);
+    let output = format!("output/tests/loop_sort_{}.docx", name);
+    let body = table(
+        2,
+        &[
+            table_row(&[&format!("{}Name", marker), "Price"]),
+            table_row(&["[name]", "[price]"]),
+        ],
+    );
+    write_template(&input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#items}}".to_string(),
+        json!([
+            {"name": "Desk", "price": 120, "vendor": {"name": "Globex"}},
+            {"name": "Pen", "price": 3, "vendor": {"name": "Acme"}},
+            {"name": "Lamp", "price": 45},
+            {"name": "Chair", "price": 80.5, "vendor": {"name": "Initech"}}
+        ]),
+    );
+    let mut docx = DOCX::default();
+    docx.generate(&input, &output, &data).await?;
+    let xml = read_entry(&output, "word/document.xml").await?;
+
+    let names = ["Desk", "Pen", "Lamp", "Chair"];
+    let mut positions = names
+        .iter()
+        .filter_map(|name| Some((xml.find(&format!("<w:t>{}</w:t>", name))?, name.to_string())))
+        .collect::<Vec<_>>();
+    positions.sort();
+    Ok(positions.into_iter().map(|(_, name)| name).collect())
+}
+
+#[tokio::test]
+async fn test_loop_sort() -> Result<(), DocxError> {
+    // Numbers compare numerically, not as text / 数字按数值而非文本比较
+    assert_eq!(
+        sorted_names("asc", "{{#items|sort:price}}").await?,
+        ["Pen", "Lamp", "Chair", "Desk"]
+    );
+    assert_eq!(
+        sorted_names("desc", "{{#items|sort:price:desc}}").await?,
+        ["Desk", "Chair", "Lamp", "Pen"]
+    );
+    // Strings compare as text, items without the field come last / 字符串按文本比较，缺少字段的项排在最后
+    assert_eq!(
+        sorted_names("text", "{{#items|sort:vendor.name:desc}}").await?,
+        ["Chair", "Desk", "Pen", "Lamp"]
+    );
+    // Sorting combines with filters / 排序可与过滤条件组合
+    assert_eq!(
+        sorted_names("filter", "{{#items|price>10|sort:name:asc}}").await?,
+        ["Chair", "Desk", "Lamp"]
+    );
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, write_template};
+use base64::Engine;
+use base64::engine::general_purpose;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Build a PNG header declaring the given pixel size / 构建声明给定像素尺寸的 PNG 头
+fn png_header(width: u32, height: u32) -> String {
+    let mut bytes = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+    bytes.extend_from_slice(&13u32.to_be_bytes());
+    bytes.extend_from_slice(b"IHDR");
+    bytes.extend_from_slice(&width.to_be_bytes());
+    bytes.extend_from_slice(&height.to_be_bytes());
+    bytes.extend_from_slice(&[8, 6, 0, 0, 0]);
+    general_purpose::STANDARD.encode(bytes)
+}
+
+/// Generate a 576x324 diagram and return its extent / 生成 576x324 的图表并返回其尺寸
+async fn diagram_extent(docx: &mut DOCX<'_>, name: &str) -> Result<String, DocxError> {
+    let input = format!("output/tests/{}_template.docx", name);
+    let output = format!("output/tests/{}.docx", name);
+    write_template(&input, &paragraph("{{@diagram}}")).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{diagram}}".to_string(),
+        Value::String(png_header(576, 324)),
+    );
+    docx.generate(&input, &output, &data).await?;
+    let document = read_entry(&output, "word/document.xml").await?;
+    let start = document.find("<wp:extent ").unwrap();
+    Ok(document[start..start + document[start..].find("/>").unwrap()].to_string())
+}
+
+#[tokio::test]
+async fn test_max_image_size_default_clamps() -> Result<(), DocxError> {
+    let extent = diagram_extent(&mut DOCX::default(), "max_image_size_default").await?;
+    assert_eq!(extent, r#"<wp:extent cx="1800000" cy="1012500""#);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_max_image_size_raised() -> Result<(), DocxError> {
+    // A4 text area of 6.3 x 9.7 inches / 6.3 x 9.7 英寸的 A4 正文区域
+    let mut docx = DOCX::default();
+    docx.set_max_image_size(5_760_720, 8_869_680);
+    let extent = diagram_extent(&mut docx, "max_image_size_raised").await?;
+    // 576x324 pixels at 96 DPI fit unscaled / 96 DPI 下的 576x324 像素无需缩放即可适应
+    assert_eq!(extent, r#"<wp:extent cx="5486400" cy="3086100""#);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_max_image_size_fits_box() -> Result<(), DocxError> {
+    let mut docx = DOCX::default();
+    docx.set_max_image_size(0, 1_543_050);
+    let extent = diagram_extent(&mut docx, "max_image_size_box").await?;
+    // Height limited, width following the aspect ratio / 高度受限，宽度遵循宽高比
+    assert_eq!(extent, r#"<wp:extent cx="2743200" cy="1543050""#);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_max_image_size_unlimited() -> Result<(), DocxError> {
+    let mut docx = DOCX::default();
+    docx.set_max_image_size(0, 0);
+    let extent = diagram_extent(&mut docx, "max_image_size_unlimited").await?;
+    assert_eq!(extent, r#"<wp:extent cx="5486400" cy="3086100""#);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_max_image_size_overrides_orientation_limits() -> Result<(), DocxError> {
+    let mut docx = DOCX::default();
+    docx.set_image_orientation_limits(914_400, 914_400);
+    docx.set_max_image_size(0, 1_543_050);
+    let extent = diagram_extent(&mut docx, "max_image_size_precedence").await?;
+    assert_eq!(extent, r#"<wp:extent cx="2743200" cy="1543050""#);
+    Ok(())
+}
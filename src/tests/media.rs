@@ -0,0 +1,25 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+
+#[tokio::test]
+async fn test_list_and_read_media() -> Result<(), DocxError> {
+    let input = "template/test.docx";
+
+    let media = DOCX::list_media(input).await?;
+    assert_eq!(media, vec![("word/media/image1.jpeg".to_string(), 29971)]);
+
+    let image = DOCX::read_media(input, "word/media/image1.jpeg").await?;
+    assert_eq!(image.len(), 29971);
+    assert!(image.starts_with(&[0xFF, 0xD8]));
+
+    // Only media parts can be read / 仅能读取媒体部件
+    assert!(matches!(
+        DOCX::read_media(input, "word/document.xml").await,
+        Err(DocxError::MissingPart(part)) if part == "word/document.xml"
+    ));
+    assert!(matches!(
+        DOCX::read_media(input, "word/media/image9.png").await,
+        Err(DocxError::MissingPart(part)) if part == "word/media/image9.png"
+    ));
+    Ok(())
+}
@@ -0,0 +1,178 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{read_entry, table, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+/// Build a paragraph holding a simple MERGEFIELD / 构建包含简单 MERGEFIELD 的段落
+fn simple_field(name: &str) -> String {
+    format!(
+        r#"<w:p><w:fldSimple w:instr=" MERGEFIELD {name} \* MERGEFORMAT "><w:r><w:rPr><w:b/></w:rPr><w:t>«{name}»</w:t></w:r></w:fldSimple></w:p>"#
+    )
+}
+
+#[tokio::test]
+async fn test_simple_merge_field() -> Result<(), DocxError> {
+    let input = "output/tests/merge_field_template.docx";
+    let output = "output/tests/merge_field.docx";
+    let cell = format!("<w:tr><w:tc>{}</w:tc></w:tr>", simple_field("City"));
+    let body = [simple_field("FirstName"), table(1, &[cell])].concat();
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{FirstName}}".to_string(), json!("Alice"));
+    data.insert("{{City}}".to_string(), json!("Paris"));
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    assert!(!xml.contains("fldSimple"));
+    assert!(!xml.contains('«'));
+    // Run formatting of the field result is kept / 保留域结果的运行格式
+    assert!(
+        xml.contains(r#"<w:r><w:rPr><w:b/></w:rPr><w:t xml:space="preserve">Alice</w:t></w:r>"#)
+    );
+    assert!(xml.contains(">Paris</w:t>"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_complex_merge_field() -> Result<(), DocxError> {
+    let input = "output/tests/merge_field_complex_template.docx";
+    let output = "output/tests/merge_field_complex.docx";
+    // Instruction split across runs as Word often does / 与 Word 常见做法一样，指令被拆分到多个运行中
+    let field = concat!(
+        r#"<w:p><w:r><w:t xml:space="preserve">Dear </w:t></w:r>"#,
+        r#"<w:r><w:fldChar w:fldCharType="begin"/></w:r>"#,
+        r#"<w:r><w:instrText xml:space="preserve"> MERGEFIELD </w:instrText></w:r>"#,
+        r#"<w:r><w:instrText xml:space="preserve">LastName \* MERGEFORMAT </w:instrText></w:r>"#,
+        r#"<w:r><w:fldChar w:fldCharType="separate"/></w:r>"#,
+        r#"<w:r><w:rPr><w:i/></w:rPr><w:t>«LastName»</w:t></w:r>"#,
+        r#"<w:r><w:fldChar w:fldCharType="end"/></w:r>"#,
+        r#"<w:r><w:t>,</w:t></w:r></w:p>"#,
+    );
+    let page = concat!(
+        r#"<w:p><w:r><w:fldChar w:fldCharType="begin"/></w:r>"#,
+        r#"<w:r><w:instrText xml:space="preserve"> PAGE </w:instrText></w:r>"#,
+        r#"<w:r><w:fldChar w:fldCharType="separate"/></w:r>"#,
+        r#"<w:r><w:t>1</w:t></w:r>"#,
+        r#"<w:r><w:fldChar w:fldCharType="end"/></w:r></w:p>"#,
+    );
+    write_template(input, &[field, page].concat()).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{LastName}}".to_string(), json!("Smith"));
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    assert!(!xml.contains("MERGEFIELD"));
+    assert!(!xml.contains('«'));
+    let dear = xml.find(">Dear </w:t>").unwrap();
+    let value = xml
+        .find(r#"<w:r><w:rPr><w:i/></w:rPr><w:t xml:space="preserve">Smith</w:t></w:r>"#)
+        .unwrap();
+    let comma = xml.find(">,</w:t>").unwrap();
+    assert!(dear < value && value < comma);
+    // Other fields are kept as-is / 其他域保持原样
+    assert!(xml.contains(" PAGE </w:instrText>"));
+    assert_eq!(xml.matches(r#"w:fldCharType="begin""#).count(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_simple_merge_field_in_loop_row() -> Result<(), DocxError> {
+    let input = "output/tests/merge_field_loop_template.docx";
+    let output = "output/tests/merge_field_loop.docx";
+    let rows = [
+        "<w:tr><w:tc><w:p><w:r><w:t>{{#people}}Name</w:t></w:r></w:p></w:tc><w:tc><w:p><w:r><w:t>City</w:t></w:r></w:p></w:tc></w:tr>".to_string(),
+        format!(
+            "<w:tr><w:tc><w:p><w:r><w:t>[name]</w:t></w:r></w:p></w:tc><w:tc>{}</w:tc></w:tr>",
+            simple_field("city")
+        ),
+    ];
+    write_template(input, &table(2, &rows)).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#people}}".to_string(),
+        json!([{ "name": "Alice", "city": "Paris" }, { "name": "Bob", "city": "Lyon" }]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    assert!(!xml.contains("fldSimple"));
+    assert!(!xml.contains('«'));
+    for city in ["Paris", "Lyon"] {
+        assert!(xml.contains(&format!(
+            r#"<w:r><w:rPr><w:b/></w:rPr><w:t xml:space="preserve">{}</w:t></w:r>"#,
+            city
+        )));
+    }
+    Ok(())
+}
+
+/// Build the runs of a complex field / 构建复杂域的运行
+fn complex_field(instruction: &str, result: &str) -> String {
+    format!(
+        concat!(
+            r#"<w:r><w:fldChar w:fldCharType="begin"/></w:r>"#,
+            r#"<w:r><w:instrText xml:space="preserve"> {} </w:instrText></w:r>"#,
+            r#"<w:r><w:fldChar w:fldCharType="separate"/></w:r>"#,
+            r#"<w:r><w:t>{}</w:t></w:r>"#,
+            r#"<w:r><w:fldChar w:fldCharType="end"/></w:r>"#,
+        ),
+        instruction, result
+    )
+}
+
+#[tokio::test]
+async fn test_complex_field_result_placeholder() -> Result<(), DocxError> {
+    let input = "output/tests/merge_field_hyperlink_template.docx";
+    let output = "output/tests/merge_field_hyperlink.docx";
+    let link = format!(
+        "<w:p>{}</w:p>",
+        complex_field(r#"HYPERLINK "https://example.com""#, "{{site}}")
+    );
+    let static_cell = format!(
+        "<w:tr><w:tc><w:p>{}</w:p></w:tc></w:tr>",
+        complex_field("MERGEFIELD Country", "«Country»")
+    );
+    let loop_rows = [
+        "<w:tr><w:tc><w:p><w:r><w:t>{{#people}}Name</w:t></w:r></w:p></w:tc><w:tc><w:p><w:r><w:t>City</w:t></w:r></w:p></w:tc></w:tr>".to_string(),
+        format!(
+            "<w:tr><w:tc><w:p><w:r><w:t>[name]</w:t></w:r></w:p></w:tc><w:tc><w:p>{}</w:p></w:tc></w:tr>",
+            complex_field("MERGEFIELD city", "«city»")
+        ),
+    ];
+    let body = [link, table(1, &[static_cell]), table(2, &loop_rows)].concat();
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{site}}".to_string(), json!("Example"));
+    data.insert("{{Country}}".to_string(), json!("France"));
+    data.insert(
+        "{{#people}}".to_string(),
+        json!([{ "name": "Alice", "city": "Paris" }, { "name": "Bob", "city": "Lyon" }]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    // The hyperlink field is kept and its result filled / 保留超链接域并填充其结果
+    assert!(xml.contains(r#" HYPERLINK "https://example.com" </w:instrText>"#));
+    assert!(xml.contains("<w:t>Example</w:t>"));
+    assert!(!xml.contains("{{site}}"));
+    // Merge fields in static and loop rows are replaced / 静态行和循环行中的合并域被替换
+    assert!(!xml.contains("MERGEFIELD"));
+    assert!(!xml.contains('«'));
+    assert!(xml.contains(">France</w:t>"));
+    assert!(xml.contains(">Paris</w:t>"));
+    assert!(xml.contains(">Lyon</w:t>"));
+    Ok(())
+}
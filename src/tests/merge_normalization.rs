@@ -0,0 +1,44 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{read_entry, table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_merge_normalization() -> Result<(), DocxError> {
+    let input = "output/tests/merge_normalization_template.docx";
+    let output = "output/tests/merge_normalization.docx";
+    let body = table(
+        2,
+        &[
+            table_row(&["{{#rows}}country", "city"]),
+            table_row(&["[country]", "[city]"]),
+        ],
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#rows}}".to_string(),
+        json!([
+            {"country": "USA", "city": "Boston"},
+            {"country": "usa ", "city": "Denver"},
+            {"country": "Canada", "city": "Toronto"}
+        ]),
+    );
+
+    // Exact comparison by default / 默认精确比较
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+    assert!(!xml.contains("w:vMerge"));
+
+    docx.set_merge_normalization(true);
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+    assert!(xml.contains(r#"<w:vMerge w:val="restart"/>"#));
+    assert_eq!(xml.matches(r#"<w:vMerge w:val="continue"/>"#).count(), 1);
+    // The first cell of the run keeps its own text / 合并区域的首个单元格保留自身文本
+    assert!(xml.contains("<w:t>USA</w:t>"));
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::public::error::DocxError;
+use crate::tests::common::{table, table_row, write_template};
+use crate::{ColumnMerge, DOCX, TableMergeSummary};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_report_lists_merged_columns() -> Result<(), DocxError> {
+    let input = "output/tests/merge_report_template.docx";
+    let output = "output/tests/merge_report.docx";
+    let body = table(
+        2,
+        &[
+            table_row(&["{{#users}}name", "pet"]),
+            table_row(&["[name]", "[pets.name]"]),
+        ],
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#users}}".to_string(),
+        json!([
+            {"name": "Peter", "pets": [{"name": "Dog"}, {"name": "Cat"}, {"name": "Fish"}]},
+            {"name": "Lisa", "pets": [{"name": "Bird"}]}
+        ]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+
+    assert_eq!(
+        docx.report().tables,
+        vec![TableMergeSummary {
+            key: "{{#users}}".to_string(),
+            rows: 4,
+            columns: vec![ColumnMerge {
+                column: 0,
+                spans: vec![3],
+            }],
+        }]
+    );
+    Ok(())
+}
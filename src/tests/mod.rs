@@ -1,3 +1,105 @@
 mod base;
+mod common;
 
+mod accounting_format;
+mod atomic_output;
+mod attribute_placeholder;
+mod base64_line_breaks;
+mod bookmark;
+mod borrowed_text;
+mod bracket_key;
+mod cancellation;
+mod cant_split;
+mod capacity_hints;
+mod cell_alignment;
+mod channel_output;
+mod chart;
+mod column_map;
+mod compiled_template;
+mod conditional_column;
+mod content_type;
+mod default_handler;
+mod diagnostics;
+mod duplicate_loop_key;
+mod duplicate_placeholder;
+mod empty_map;
+mod empty_rows;
+mod emu_precision;
+mod entity_text;
+mod escaped_loop_marker;
+mod fallback_chain;
+mod flat_opc;
 mod flatten_json;
+mod from_bytes;
+mod generate_from;
+mod gif_bmp_images;
+mod header_images;
+mod header_row;
+mod image_alt;
+mod image_anchor;
+mod image_autoscale;
+#[cfg(feature = "image")]
+mod image_background;
+mod image_border;
+mod image_bytes;
+mod image_default;
+mod image_orientation;
+mod image_resolver;
+mod image_rotate;
+mod image_size;
+mod image_transform;
+mod in_memory;
+mod includes;
+mod inspect_tables;
+mod internal_link;
+mod invalid_package;
+mod item_transform;
+#[cfg(feature = "image")]
+mod jpeg_quality;
+mod language;
+mod lenient_xml;
+mod limits;
+mod list_cell;
+mod loop_filter;
+mod loop_group;
+mod loop_keys;
+mod loop_sort;
+mod max_image_size;
+mod media;
+mod merge_field;
+mod merge_normalization;
+mod merge_report;
+mod multi_paragraph;
+mod multi_row;
+mod nested_index;
+#[cfg(feature = "dates")]
+mod now_marker;
+mod output_dir;
+mod page_break;
+mod part_dpi;
+mod png_dimensions;
+mod preview_changes;
+mod raw_ooxml;
+mod raw_ooxml_validation;
+mod reproducible;
+mod reuse_after_error;
+mod row_count;
+mod section;
+mod section_properties;
+mod skip_row;
+mod stage_timings;
+mod static_brackets;
+mod strict_placeholders;
+mod strip_metadata;
+mod svg;
+mod toc;
+#[cfg(feature = "tracing")]
+mod trace_spans;
+mod transform;
+mod unified_syntax;
+mod value_map;
+mod visible_row;
+mod webp_images;
+mod writer_output;
+mod zebra_shading;
+mod zip_metadata;
@@ -0,0 +1,91 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{read_entry, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_value_with_paragraphs() -> Result<(), DocxError> {
+    let input = "output/tests/multi_paragraph_template.docx";
+    let output = "output/tests/multi_paragraph.docx";
+    let body = r#"<w:p><w:pPr><w:pStyle w:val="Quote"/><w:jc w:val="center"/></w:pPr><w:r><w:rPr><w:b/></w:rPr><w:t>{{body}}</w:t></w:r></w:p><w:p><w:r><w:t>{{single}}</w:t></w:r></w:p>"#;
+    write_template(input, body).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{body}}".to_string(), json!("First part\n\nSecond part"));
+    data.insert("{{single}}".to_string(), json!("one line\nstill one"));
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    let properties = r#"<w:pPr><w:pStyle w:val="Quote"/><w:jc w:val="center"/></w:pPr>"#;
+    assert!(xml.contains(&format!(
+        r#"<w:p>{}<w:r><w:rPr><w:b/></w:rPr><w:t>First part</w:t></w:r></w:p>"#,
+        properties
+    )));
+    assert!(xml.contains(&format!(
+        r#"<w:p>{}<w:r><w:rPr><w:b/></w:rPr><w:t xml:space="preserve">Second part</w:t></w:r></w:p>"#,
+        properties
+    )));
+    assert_eq!(xml.matches("<w:p>").count(), 3);
+    // A single line break does not split the paragraph / 单个换行不会拆分段落
+    assert!(xml.contains("one line\nstill one"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_section_properties_move_to_last_paragraph() -> Result<(), DocxError> {
+    let input = "output/tests/multi_paragraph_section_template.docx";
+    let output = "output/tests/multi_paragraph_section.docx";
+    let section = r#"<w:sectPr><w:pgSz w:w="11906" w:h="16838"/></w:sectPr>"#;
+    let body = format!(
+        r#"<w:p><w:pPr><w:jc w:val="center"/>{}</w:pPr><w:r><w:t>{{{{body}}}}</w:t></w:r></w:p><w:p><w:pPr>{}</w:pPr><w:r><w:t>{{{{single}}}}</w:t></w:r></w:p>"#,
+        section, section
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{body}}".to_string(), json!("a\n\nb\n\nc"));
+    data.insert("{{single}}".to_string(), json!("d"));
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    // Two in the paragraphs, one at the end of the body / 两个在段落中，一个在正文末尾
+    assert_eq!(xml.matches("<w:sectPr>").count(), 3);
+    assert!(
+        xml.contains(r#"<w:p><w:pPr><w:jc w:val="center"/></w:pPr><w:r><w:t>a</w:t></w:r></w:p>"#)
+    );
+    assert!(xml.contains(r#"<w:p><w:pPr><w:jc w:val="center"/></w:pPr><w:r><w:t xml:space="preserve">b</w:t></w:r></w:p>"#));
+    assert!(xml.contains(&format!(
+        r#"<w:p><w:pPr><w:jc w:val="center"/>{}</w:pPr><w:r><w:t xml:space="preserve">c</w:t></w:r></w:p>"#,
+        section
+    )));
+    // A paragraph left whole keeps its section break / 未拆分的段落保留其分节符
+    assert!(xml.contains(&format!(
+        r#"<w:p><w:pPr>{}</w:pPr><w:r><w:t>d</w:t></w:r></w:p>"#,
+        section
+    )));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_split_inside_hyperlink() -> Result<(), DocxError> {
+    let input = "output/tests/multi_paragraph_hyperlink_template.docx";
+    let output = "output/tests/multi_paragraph_hyperlink.docx";
+    let body = r#"<w:p><w:hyperlink w:anchor="top"><w:ins w:id="1" w:author="A"><w:r><w:t>{{body}}</w:t></w:r></w:ins></w:hyperlink></w:p>"#;
+    write_template(input, body).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{body}}".to_string(), json!("first\n\nsecond"));
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    assert!(xml.contains(r#"<w:p><w:hyperlink w:anchor="top"><w:ins w:id="1" w:author="A"><w:r><w:t>first</w:t></w:r></w:ins></w:hyperlink></w:p>"#));
+    assert!(xml.contains(r#"<w:p><w:hyperlink w:anchor="top"><w:ins w:id="1" w:author="A"><w:r><w:t xml:space="preserve">second</w:t></w:r></w:ins></w:hyperlink></w:p>"#));
+    Ok(())
+}
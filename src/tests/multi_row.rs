@@ -0,0 +1,58 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{read_entry, table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_two_row_repeating_unit() -> Result<(), DocxError> {
+    let input = "output/tests/multi_row_template.docx";
+    let output = "output/tests/multi_row.docx";
+    let body = table(
+        2,
+        &[
+            table_row(&["{{#orders}}order", "total"]),
+            table_row(&["[id]", "[total]"]),
+            table_row(&["[details]", "[status]"]),
+        ],
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#orders}}".to_string(),
+        json!([
+            {"id": "A-1", "total": "10", "details": "Two pens", "status": "open"},
+            {"id": "A-2", "total": "10", "details": "One desk", "status": "open"}
+        ]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    let rows = xml
+        .split("<w:tr>")
+        .skip(1)
+        .map(|row| {
+            row.split("<w:t>")
+                .skip(1)
+                .map(|cell| cell.split("</w:t>").next().unwrap_or_default())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    // Both rows repeat per order, in template order / 每个订单按模板顺序重复两行
+    assert_eq!(
+        rows,
+        vec![
+            vec!["order", "total"],
+            vec!["A-1", "10"],
+            vec!["Two pens", "open"],
+            vec!["A-2", "10"],
+            vec!["One desk", "open"],
+        ]
+    );
+    // Equal values are not merged across the units / 相同值不会跨单元合并
+    assert!(!xml.contains("w:vMerge"));
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use crate::DOCX;
+use crate::core::utils::flatten_json;
+use crate::public::error::DocxError;
+use crate::tests::common::{read_entry, table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+fn owner() -> serde_json::Value {
+    json!({
+        "name": "Peter",
+        "pets": [
+            {"name": "Dog", "toys": [{"title": "Ball"}, {"title": "Rope"}]},
+            {"name": "Cat", "toys": [{"title": "Doll"}]}
+        ]
+    })
+}
+
+#[test]
+fn test_flatten_records_nested_positions() {
+    let records = flatten_json(&owner());
+    let positions = records
+        .iter()
+        .map(|record| {
+            (
+                record["pets.$index"].as_str().unwrap(),
+                record["pets.toys.$index"].as_str().unwrap(),
+                record["pets.toys.title"].as_str().unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        positions,
+        vec![("0", "0", "Ball"), ("0", "1", "Rope"), ("1", "0", "Doll")]
+    );
+}
+
+#[tokio::test]
+async fn test_nested_index_in_loop_table() -> Result<(), DocxError> {
+    let input = "output/tests/nested_index_template.docx";
+    let output = "output/tests/nested_index.docx";
+    let body = table(
+        4,
+        &[
+            table_row(&["{{#owners}}row", "pet", "toy", "title"]),
+            table_row(&[
+                "[$index]",
+                "[pets.$index]",
+                "[pets.toys.$index]",
+                "[pets.toys.title]",
+            ]),
+        ],
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{#owners}}".to_string(), json!([owner()]));
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+
+    let xml = read_entry(output, "word/document.xml").await?;
+    let rows = xml
+        .split("<w:tr>")
+        .skip(2)
+        .map(|row| {
+            row.split("<w:t>")
+                .skip(1)
+                .map(|cell| cell.split("</w:t>").next().unwrap_or_default())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    // Pet positions repeat down the column and are merged / 宠物位置沿列重复并被合并
+    assert_eq!(
+        rows,
+        vec![
+            vec!["0", "0", "0", "Ball"],
+            vec!["1", "", "1", "Rope"],
+            vec!["2", "1", "0", "Doll"],
+        ]
+    );
+    Ok(())
+}
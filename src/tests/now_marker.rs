@@ -0,0 +1,36 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, table, table_row, write_template};
+use chrono::Local;
+use serde_json::json;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_now_marker_resolves_current_time() -> Result<(), DocxError> {
+    let input = "output/tests/now_marker_template.docx";
+    let output = "output/tests/now_marker.docx";
+    let body = format!(
+        "{}{}{}",
+        paragraph("[$now:%Y]"),
+        paragraph("[$now]"),
+        table(
+            2,
+            &[
+                table_row(&["{{#lines}}product", "printed"]),
+                table_row(&["[product]", "[$now:%Y]"]),
+            ],
+        )
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{#lines}}".to_string(), json!([{"product": "Pen"}]));
+    DOCX::default().generate(input, output, &data).await?;
+
+    let document = read_entry(output, "word/document.xml").await?;
+    let year = Local::now().format("%Y").to_string();
+    assert_eq!(document.matches(&format!("<w:t>{}</w:t>", year)).count(), 2);
+    assert!(document.contains(&format!("<w:t>{}-", year)));
+    assert!(!document.contains("$now"));
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+use std::path::Path;
+
+#[tokio::test]
+async fn test_generate_to_dir_writes_unzipped_parts() -> Result<(), DocxError> {
+    let input = "output/tests/output_dir_template.docx";
+    let output_dir = "output/tests/output_dir";
+    write_template(input, &paragraph("{{name}}")).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{name}}".to_string(), json!("Alice"));
+
+    let mut docx = DOCX::default();
+    docx.generate_to_dir(input, output_dir, &data).await?;
+
+    let document = Path::new(output_dir).join("word/document.xml");
+    assert!(document.is_file());
+    let xml = tokio::fs::read_to_string(&document).await?;
+    assert!(xml.contains("<w:t>Alice</w:t>"));
+    assert!(!xml.contains("{{name}}"));
+
+    // Every other part is written next to it / 其他所有部件都写在其旁边
+    for part in [
+        "[Content_Types].xml",
+        "_rels/.rels",
+        "word/_rels/document.xml.rels",
+    ] {
+        assert!(Path::new(output_dir).join(part).is_file());
+    }
+    Ok(())
+}
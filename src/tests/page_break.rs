@@ -0,0 +1,94 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+const PAGE_BREAK: &str = r#"<w:r><w:br w:type="page"/></w:r>"#;
+
+#[tokio::test]
+async fn test_page_break_in_text() -> Result<(), DocxError> {
+    let input = "output/tests/page_break_text_template.docx";
+    let output = "output/tests/page_break_text.docx";
+    let body = [
+        paragraph("{{chapter}}"),
+        paragraph("[pagebreak]"),
+        paragraph("end"),
+    ]
+    .concat();
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{chapter}}".to_string(), json!("Chapter 1"));
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    assert!(!xml.contains("[pagebreak]"));
+    let chapter = xml.find("Chapter 1").unwrap();
+    let page_break = xml.find(PAGE_BREAK).unwrap();
+    let end = xml.find(">end<").unwrap();
+    assert!(chapter < page_break && page_break < end);
+    // The break run must not be nested in the template run / 分页运行不得嵌套在模板运行中
+    assert!(xml.contains(&format!("<w:r></w:r>{}<w:r></w:r>", PAGE_BREAK)));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_page_break_in_loop_table() -> Result<(), DocxError> {
+    let input = "output/tests/page_break_loop_template.docx";
+    let output = "output/tests/page_break_loop.docx";
+    let body = table(
+        2,
+        &[
+            table_row(&["{{#users}}name", "break"]),
+            table_row(&["[name]", "[pagebreak]"]),
+        ],
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#users}}".to_string(),
+        json!([{"name": "Alice"}, {"name": "Bob"}]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    assert!(!xml.contains("[pagebreak]"));
+    assert_eq!(xml.matches(PAGE_BREAK).count(), 2);
+    assert!(xml.find("Alice").unwrap() < xml.find(PAGE_BREAK).unwrap());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_page_break_keeps_run_properties() -> Result<(), DocxError> {
+    let input = "output/tests/page_break_format_template.docx";
+    let output = "output/tests/page_break_format.docx";
+    let bold = r#"<w:p><w:r><w:rPr><w:b/></w:rPr><w:t>[pagebreak]</w:t></w:r></w:p>"#;
+    let rows = [
+        table_row(&["{{#users}}name"]),
+        format!("<w:tr><w:tc>{}</w:tc></w:tr>", bold),
+    ];
+    let body = [bold.to_string(), table(1, &rows)].concat();
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{#users}}".to_string(), json!([{"name": "Alice"}]));
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    // The break run and the reopened template run keep the bold formatting / 分页运行和重新打开的模板运行保持加粗格式
+    let expected = concat!(
+        r#"<w:r><w:rPr><w:b/></w:rPr></w:r>"#,
+        r#"<w:r><w:rPr><w:b/></w:rPr><w:br w:type="page"/></w:r>"#,
+        r#"<w:r><w:rPr><w:b/></w:rPr></w:r>"#
+    );
+    assert_eq!(xml.matches(expected).count(), 2);
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, write_template};
+use base64::Engine;
+use base64::engine::general_purpose;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Build a PNG header declaring the given pixel size / 构建声明给定像素尺寸的 PNG 头
+fn png_header(width: u32, height: u32) -> String {
+    let mut bytes = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+    bytes.extend_from_slice(&13u32.to_be_bytes());
+    bytes.extend_from_slice(b"IHDR");
+    bytes.extend_from_slice(&width.to_be_bytes());
+    bytes.extend_from_slice(&height.to_be_bytes());
+    bytes.extend_from_slice(&[8, 6, 0, 0, 0]);
+    general_purpose::STANDARD.encode(bytes)
+}
+
+#[tokio::test]
+async fn test_part_dpi() -> Result<(), DocxError> {
+    let input = "output/tests/part_dpi_template.docx";
+    let output = "output/tests/part_dpi.docx";
+    write_template(input, &paragraph("{{@img}}")).await?;
+    let mut data = HashMap::new();
+    data.insert("{{img}}".to_string(), Value::String(png_header(96, 48)));
+
+    // Overrides of other parts leave the body at the document DPI / 其他部件的覆盖不影响正文使用文档 DPI
+    let mut docx = DOCX::default();
+    docx.set_dpi(96.0);
+    docx.set_part_dpi("word/header1.xml", 300.0);
+    docx.generate(input, output, &data).await?;
+    let document = read_entry(output, "word/document.xml").await?;
+    assert!(document.contains(r#"<wp:extent cx="914400" cy="457200""#));
+
+    docx.set_part_dpi("word/document.xml", 192.0);
+    docx.generate(input, output, &data).await?;
+    let document = read_entry(output, "word/document.xml").await?;
+    assert!(document.contains(r#"<wp:extent cx="457200" cy="228600""#));
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use crate::core::utils::get_image_dimensions;
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+/// Append a chunk with a zero CRC / 追加一个 CRC 为零的块
+fn chunk(bytes: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    bytes.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    bytes.extend_from_slice(kind);
+    bytes.extend_from_slice(data);
+    bytes.extend_from_slice(&[0; 4]);
+}
+
+/// IHDR data declaring the given pixel size / 声明给定像素尺寸的 IHDR 数据
+fn ihdr(width: u32, height: u32) -> Vec<u8> {
+    let mut data = Vec::new();
+    data.extend_from_slice(&width.to_be_bytes());
+    data.extend_from_slice(&height.to_be_bytes());
+    data.extend_from_slice(&[8, 6, 0, 0, 0]);
+    data
+}
+
+#[test]
+fn test_png_ihdr_first() {
+    let mut png = SIGNATURE.to_vec();
+    chunk(&mut png, b"IHDR", &ihdr(640, 480));
+    assert_eq!(get_image_dimensions(&png), Ok((640, 480)));
+}
+
+#[test]
+fn test_png_ihdr_after_leading_chunks() {
+    let mut png = SIGNATURE.to_vec();
+    chunk(&mut png, b"tEXt", b"Software\0scanner");
+    chunk(&mut png, b"pHYs", &[0, 0, 11, 19, 0, 0, 11, 19, 1]);
+    chunk(&mut png, b"IHDR", &ihdr(123, 45));
+    assert_eq!(get_image_dimensions(&png), Ok((123, 45)));
+}
+
+#[test]
+fn test_png_without_reachable_ihdr() {
+    // Truncated IHDR data / 截断的 IHDR 数据
+    let mut png = SIGNATURE.to_vec();
+    chunk(&mut png, b"tEXt", b"");
+    chunk(&mut png, b"IHDR", &ihdr(1, 1));
+    png.truncate(SIGNATURE.len() + 12 + 12);
+    assert!(get_image_dimensions(&png).is_err());
+
+    // A chunk length pointing past the end / 指向末尾之后的块长度
+    let mut png = SIGNATURE.to_vec();
+    png.extend_from_slice(&u32::MAX.to_be_bytes());
+    png.extend_from_slice(b"tEXt");
+    png.extend_from_slice(&[0; 16]);
+    assert!(get_image_dimensions(&png).is_err());
+
+    // IHDR beyond the scanned chunks / 超出扫描块数的 IHDR
+    let mut png = SIGNATURE.to_vec();
+    for _ in 0..32 {
+        chunk(&mut png, b"tEXt", b"x");
+    }
+    chunk(&mut png, b"IHDR", &ihdr(1, 1));
+    assert!(get_image_dimensions(&png).is_err());
+}
@@ -0,0 +1,59 @@
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, table, table_row, write_template};
+use crate::{Change, DOCX};
+use serde_json::json;
+use std::collections::HashMap;
+use std::path::Path;
+
+#[tokio::test]
+async fn test_preview_changes() -> Result<(), DocxError> {
+    let input = "output/tests/preview_changes_template.docx";
+    let body = format!(
+        "{}{}{}",
+        paragraph("{{name}}"),
+        paragraph("Unchanged"),
+        table(
+            1,
+            &[table_row(&["{{#items}}Item"]), table_row(&["[title]"])],
+        ),
+    );
+    write_template(input, &body).await?;
+
+    let placeholders = HashMap::from([
+        ("{{name}}".to_string(), json!("Alice")),
+        (
+            "{{#items}}".to_string(),
+            json!([{ "title": "Pen" }, { "title": "Ink" }]),
+        ),
+    ]);
+    let changes = DOCX::default()
+        .preview_changes(input, &placeholders)
+        .await?;
+
+    let change = |original: &str, replaced: &str| Change {
+        original: original.to_string(),
+        replaced: replaced.to_string(),
+    };
+    assert_eq!(
+        changes,
+        [
+            change("{{name}}", "Alice"),
+            change("[title]", "Pen"),
+            change("[title]", "Ink"),
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_preview_changes_writes_nothing() -> Result<(), DocxError> {
+    let input = "output/tests/preview_changes_nothing_template.docx";
+    write_template(input, &paragraph("Plain text")).await?;
+
+    let changes = DOCX::default()
+        .preview_changes(input, &HashMap::new())
+        .await?;
+    assert!(changes.is_empty());
+    assert!(!Path::new("output/tests/preview_changes_nothing.docx").exists());
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, table, table_row, write_template};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+const RAW_RUN: &str = r#"<w:r><w:rPr><w:b/></w:rPr><w:t>Bold &amp; raw</w:t></w:r>"#;
+
+#[tokio::test]
+async fn test_raw_ooxml() -> Result<(), DocxError> {
+    let input = "output/tests/raw_ooxml_template.docx";
+    let output = "output/tests/raw_ooxml.docx";
+    let body = format!(
+        "{}{}",
+        paragraph("[raw:snippet]"),
+        table(
+            1,
+            &[table_row(&["{{#rows}}Cell"]), table_row(&["[raw:cell]"])],
+        ),
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{snippet}}".to_string(),
+        Value::String(RAW_RUN.to_string()),
+    );
+    data.insert(
+        "{{#rows}}".to_string(),
+        json!([{ "cell": "<w:r><w:t>In a row</w:t></w:r>" }]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.allow_raw_ooxml(true);
+    docx.generate(input, output, &data).await?;
+    let document = read_entry(output, "word/document.xml").await?;
+    assert!(document.contains(RAW_RUN));
+    assert!(document.contains("<w:r><w:t>In a row</w:t></w:r>"));
+    assert!(!document.contains("[raw:"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_raw_ooxml_disabled() -> Result<(), DocxError> {
+    let input = "output/tests/raw_ooxml_disabled_template.docx";
+    let output = "output/tests/raw_ooxml_disabled.docx";
+    write_template(input, &paragraph("[raw:snippet]")).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{snippet}}".to_string(),
+        Value::String(RAW_RUN.to_string()),
+    );
+    DOCX::default().generate(input, output, &data).await?;
+    let document = read_entry(output, "word/document.xml").await?;
+    assert!(!document.contains(RAW_RUN));
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, write_template};
+use serde_json::Value;
+use std::collections::HashMap;
+
+async fn generate(name: &str, snippet: &str) -> Result<String, DocxError> {
+    let input = format!("output/tests/raw_ooxml_validation_{}_template.docx", name);
+    let output = format!("output/tests/raw_ooxml_validation_{}.docx", name);
+    write_template(&input, &paragraph("[raw:snippet]")).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{snippet}}".to_string(),
+        Value::String(snippet.to_string()),
+    );
+    let mut docx = DOCX::default();
+    docx.allow_raw_ooxml(true);
+    docx.set_raw_ooxml_validation(true);
+    docx.generate(&input, &output, &data).await?;
+    read_entry(&output, "word/document.xml").await
+}
+
+#[tokio::test]
+async fn test_raw_ooxml_well_formed() -> Result<(), DocxError> {
+    let snippet = "<w:r><w:rPr><w:i/></w:rPr><w:t>Checked</w:t></w:r>";
+    let document = generate("valid", snippet).await?;
+    assert!(document.contains(snippet));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_raw_ooxml_malformed() {
+    for (name, snippet) in [
+        ("unclosed", "<w:r><w:t>Open"),
+        ("mismatched", "<w:r><w:t>Text</w:r></w:t>"),
+        ("unmatched", "<w:t>Text</w:t></w:r>"),
+    ] {
+        let result = generate(name, snippet).await;
+        assert!(
+            matches!(result, Err(DocxError::Xml(_))),
+            "{}: {:?}",
+            name,
+            result
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, table, table_row, write_template};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_reproducible_output() -> Result<(), DocxError> {
+    let input = "output/tests/reproducible_template.docx";
+    let first = "output/tests/reproducible_first.docx";
+    let second = "output/tests/reproducible_second.docx";
+    let body = format!(
+        "{}{}{}",
+        paragraph("{{@logo}}"),
+        paragraph("{{@seal}}"),
+        table(
+            2,
+            &[
+                table_row(&["{{#items}}Name", "Qty"]),
+                table_row(&["[name]", "[qty]"]),
+            ],
+        ),
+    );
+    write_template(input, &body).await?;
+
+    let logo = tokio::fs::read_to_string("template/logo_base64.txt").await?;
+    let data = HashMap::from([
+        ("{{logo}}".to_string(), Value::String(logo.clone())),
+        ("{{seal}}".to_string(), Value::String(logo)),
+        (
+            "{{#items}}".to_string(),
+            json!([{ "name": "Pen", "qty": 2 }, { "name": "Ink", "qty": 1 }]),
+        ),
+    ]);
+
+    let mut docx = DOCX::default();
+    docx.set_reproducible(true);
+    docx.generate(input, first, &data).await?;
+    docx.generate(input, second, &data).await?;
+    assert_eq!(
+        tokio::fs::read(first).await?,
+        tokio::fs::read(second).await?
+    );
+
+    // A fresh instance yields the same bytes as well / 新实例同样生成相同的字节
+    let mut docx = DOCX::default();
+    docx.set_reproducible(true);
+    docx.generate(input, second, &data).await?;
+    assert_eq!(
+        tokio::fs::read(first).await?,
+        tokio::fs::read(second).await?
+    );
+    assert_eq!(DOCX::list_media(first).await?.len(), 2);
+    Ok(())
+}
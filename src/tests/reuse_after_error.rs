@@ -0,0 +1,58 @@
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, write_template};
+use crate::{DOCX, ValueExt};
+use serde_json::{Value, json};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+/// Handler writing every value in upper case / 将每个值写为大写的处理器
+struct Upper;
+
+impl ValueExt for Upper {
+    fn replace_in_table<'k>(
+        &self,
+        _: usize,
+        key: &'k str,
+        placeholders: &HashMap<String, Value>,
+    ) -> Cow<'k, str> {
+        self.replace(key, placeholders)
+    }
+
+    fn replace<'k>(&self, key: &'k str, placeholders: &HashMap<String, Value>) -> Cow<'k, str> {
+        match placeholders.get(key) {
+            Some(Value::String(value)) => Cow::Owned(value.to_uppercase()),
+            _ => Cow::Borrowed(key),
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_generate_after_error() -> Result<(), DocxError> {
+    let input = "output/tests/reuse_after_error_template.docx";
+    let broken = "output/tests/reuse_after_error_broken_template.docx";
+    let output = "output/tests/reuse_after_error.docx";
+    write_template(input, &paragraph("{{name}}")).await?;
+    // Mismatched end tags abort processing of the document / 不匹配的结束标签会中止文档处理
+    let malformed = "<w:p><w:r><w:t>{{name}}</w:r></w:t></w:p>";
+    write_template(broken, malformed).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{name}}".to_string(), json!("alice"));
+    let mut missing = HashMap::new();
+    missing.insert("{{city}}".to_string(), json!("paris"));
+
+    let mut docx = DOCX::default();
+    docx.set_cell_handler(Box::new(Upper));
+    docx.add_fallback_values(missing);
+
+    docx.generate(input, output, &data).await?;
+    assert!(docx.generate(broken, output, &data).await.is_err());
+
+    // The handler survives the failure and is used by every later run / 处理器在失败后保留并用于之后的每次运行
+    for _ in 0..2 {
+        docx.generate(input, output, &data).await?;
+        let xml = read_entry(output, "word/document.xml").await?;
+        assert!(xml.contains("<w:t>ALICE</w:t>"));
+    }
+    Ok(())
+}
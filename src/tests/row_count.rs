@@ -0,0 +1,50 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_report_counts_generated_rows() -> Result<(), DocxError> {
+    let input = "output/tests/row_count_template.docx";
+    let output = "output/tests/row_count.docx";
+    let body = format!(
+        "{}{}",
+        table(
+            2,
+            &[
+                table_row(&["{{#lines}}product", "qty"]),
+                table_row(&["[product]", "[qty]"]),
+            ],
+        ),
+        table(1, &[table_row(&["{{#empty}}name"]), table_row(&["[name]"])],)
+    );
+    write_template(input, &body).await?;
+
+    let lines = json!([
+        {"product": "Pen", "qty": 2},
+        {"product": "Desk", "qty": 1},
+        {"product": "Lamp", "qty": 3}
+    ]);
+    let mut data = HashMap::new();
+    data.insert("{{#lines}}".to_string(), lines.clone());
+    data.insert("{{#empty}}".to_string(), json!([]));
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+
+    let rows = docx
+        .report()
+        .tables
+        .iter()
+        .map(|table| (table.key.as_str(), table.rows))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rows,
+        vec![
+            ("{{#lines}}", lines.as_array().unwrap().len()),
+            ("{{#empty}}", 0)
+        ]
+    );
+    Ok(())
+}
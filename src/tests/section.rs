@@ -0,0 +1,51 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, table, table_row, write_template};
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_section() -> Result<(), DocxError> {
+    let input = "output/tests/section_template.docx";
+    let output = "output/tests/section.docx";
+    let body = [
+        paragraph("Cover"),
+        r#"<w:p><w:bookmarkStart w:id="7" w:name="summary"/><w:r><w:t>{{title}}</w:t></w:r></w:p>"#
+            .to_string(),
+        table(1, &[table_row(&["{{total}}"])]),
+        r#"<w:p><w:r><w:t>Last line</w:t></w:r><w:bookmarkEnd w:id="7"/></w:p>"#.to_string(),
+        paragraph("Appendix"),
+    ]
+    .concat();
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{title}}".to_string(), Value::from("Summary"));
+    data.insert("{{total}}".to_string(), Value::from("42"));
+
+    let mut docx = DOCX::default();
+    docx.set_section("summary");
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+    assert!(xml.contains("<w:t>Summary</w:t>"));
+    assert!(xml.contains("<w:t>42</w:t>"));
+    assert!(xml.contains("<w:t>Last line</w:t>"));
+    assert!(!xml.contains("Cover"));
+    assert!(!xml.contains("Appendix"));
+    // The result is still a complete document / 结果仍是完整的文档
+    assert!(xml.contains("<w:sectPr>"));
+    assert!(xml.ends_with("</w:body></w:document>"));
+
+    // An unknown bookmark leaves an empty body / 未知书签留下空正文
+    docx.set_section("missing");
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+    assert!(!xml.contains("<w:p>"));
+    assert!(xml.contains("<w:sectPr>"));
+
+    docx.clear_section();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+    assert!(xml.contains("Cover") && xml.contains("Appendix"));
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, table, table_row, write_template_with_parts};
+use serde_json::json;
+use std::collections::HashMap;
+
+// Final section properties of the test template / 测试模板的最终节属性
+const BODY_SECTION: &str = r#"<w:sectPr><w:pgSz w:w="11906" w:h="16838"/></w:sectPr>"#;
+
+// Section break of a paragraph / 段落的分节符
+const LANDSCAPE_SECTION: &str = r#"<w:sectPr><w:pgSz w:w="16838" w:h="11906"/></w:sectPr>"#;
+
+#[tokio::test]
+async fn test_body_section_properties_stay_singular() -> Result<(), DocxError> {
+    let input = "output/tests/section_properties_template.docx";
+    let output = "output/tests/section_properties.docx";
+    let fragment = format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>{}<w:sectPr><w:pgSz w:w="12240" w:h="15840"/></w:sectPr></w:body></w:document>"#,
+        paragraph("Terms apply")
+    );
+    // A section break on a paragraph split right before the final section properties / 紧挨最终节属性之前被拆分的段落上的分节符
+    let split = format!(
+        r#"<w:p><w:pPr>{}</w:pPr><w:r><w:t>{{{{notes}}}}</w:t></w:r></w:p>"#,
+        LANDSCAPE_SECTION
+    );
+    let body = [
+        paragraph("[include:word/fragments/terms.xml]"),
+        table(1, &[table_row(&["{{#lines}}Line"]), table_row(&["[line]"])]),
+        split,
+    ]
+    .concat();
+    write_template_with_parts(
+        input,
+        &body,
+        &[("word/fragments/terms.xml", fragment.as_bytes())],
+    )
+    .await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#lines}}".to_string(),
+        json!([{"line": "a"}, {"line": "b"}, {"line": "c"}]),
+    );
+    data.insert("{{notes}}".to_string(), json!("First\n\nSecond\n\nThird"));
+    DOCX::default().generate(input, output, &data).await?;
+
+    let xml = read_entry(output, "word/document.xml").await?;
+    assert_eq!(xml.matches("Terms apply").count(), 1);
+    assert_eq!(xml.matches("<w:tr>").count(), 4);
+
+    // The body keeps exactly one final w:sectPr, fragments add none / 正文仅保留一个最终 w:sectPr，片段不添加任何节属性
+    assert_eq!(xml.matches(BODY_SECTION).count(), 1);
+    assert!(xml.ends_with(&format!("{}</w:body></w:document>", BODY_SECTION)));
+    assert!(!xml.contains(r#"w:w="12240""#));
+
+    // The split paragraph keeps a single section break, on its last part / 被拆分的段落只保留一个分节符，位于其最后一部分上
+    assert_eq!(xml.matches("<w:sectPr>").count(), 2);
+    assert_eq!(xml.matches(LANDSCAPE_SECTION).count(), 1);
+    let landscape = xml.find(LANDSCAPE_SECTION).unwrap();
+    assert!(xml.find("Second").unwrap() < landscape);
+    assert!(landscape < xml.find("Third").unwrap());
+    Ok(())
+}
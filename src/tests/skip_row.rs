@@ -0,0 +1,68 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{read_entry, table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+/// Render the loop table and return its data rows as cell texts / 渲染循环表格并以单元格文本返回其数据行
+async fn render(name: &str, condition: &str) -> Result<Vec<Vec<String>>, DocxError> {
+    let input = format!("output/tests/skip_row_{}_template.docx", name);
+    let output = format!("output/tests/skip_row_{}.docx", name);
+    let body = table(
+        3,
+        &[
+            table_row(&["{{#lines}}product", "qty", "index"]),
+            table_row(&[
+                &format!("[skipif:{}][product]", condition),
+                "[qty]",
+                "[$index]",
+            ]),
+        ],
+    );
+    write_template(&input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#lines}}".to_string(),
+        json!([
+            {"product": "Pen", "qty": 2, "status": "open"},
+            {"product": "Desk", "qty": 0, "status": "done"},
+            {"product": "Desk", "qty": 5, "status": "open"},
+            {"product": "Lamp", "qty": "0", "status": "done"}
+        ]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.generate(&input, &output, &data).await?;
+    let xml = read_entry(&output, "word/document.xml").await?;
+    assert!(!xml.contains("skipif"));
+
+    Ok(xml
+        .split("<w:tr>")
+        .skip(2)
+        .map(|row| {
+            row.split("<w:t>")
+                .skip(1)
+                .map(|cell| cell.split("</w:t>").next().unwrap_or_default().to_string())
+                .collect()
+        })
+        .collect())
+}
+
+#[tokio::test]
+async fn test_skip_rows_where_field_is_zero() -> Result<(), DocxError> {
+    // Skipped rows are neither indexed nor merged with the Desk row / 被跳过的行既不计入索引也不与 Desk 行合并
+    assert_eq!(
+        render("zero", "qty==0").await?,
+        vec![vec!["Pen", "2.00", "0"], vec!["Desk", "5.00", "1"]]
+    );
+    assert_eq!(
+        render("text", "status=='done'").await?,
+        vec![vec!["Pen", "2.00", "0"], vec!["Desk", "5.00", "1"]]
+    );
+    assert_eq!(
+        render("greater", "qty>1").await?,
+        vec![vec!["Desk", "0.00", "0"], vec!["Lamp", "0", "1"]]
+    );
+    Ok(())
+}
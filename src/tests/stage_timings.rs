@@ -0,0 +1,40 @@
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, write_template};
+use crate::{DOCX, StageTimings};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_stage_timings_are_populated() -> Result<(), DocxError> {
+    let input = "output/tests/stage_timings_template.docx";
+    let output = "output/tests/stage_timings.docx";
+    let body = [paragraph("{{name}}"), paragraph("{{@logo}}")].concat();
+    write_template(input, &body).await?;
+
+    let logo = tokio::fs::read_to_string("template/logo_base64.txt").await?;
+    let mut data = HashMap::new();
+    data.insert("{{name}}".to_string(), Value::String("Alice".to_string()));
+    data.insert("{{logo}}".to_string(), Value::String(logo));
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+
+    let StageTimings {
+        zip_read,
+        document,
+        images,
+        zip_write,
+    } = docx.report().timings;
+    assert!(zip_read > Duration::ZERO);
+    assert!(document > Duration::ZERO);
+    assert!(images > Duration::ZERO);
+    assert!(zip_write > Duration::ZERO);
+
+    // Without images nothing is attributed to embedding / 没有图片时不计入嵌入时间
+    data.remove("{{logo}}");
+    docx.generate(input, output, &data).await?;
+    assert_eq!(docx.report().timings.images, Duration::ZERO);
+    assert!(docx.report().timings.document > Duration::ZERO);
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use crate::DOCX;
+use crate::core::docx_processor::REGEX;
+use crate::public::error::DocxError;
+use crate::tests::common::{read_entry, table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[test]
+fn test_incidental_brackets_are_not_placeholders() {
+    for text in [
+        "see note]",
+        "Ref [1]",
+        "[ ]",
+        "a] b]",
+        "[",
+        "{{#items}}Name",
+    ] {
+        assert!(!REGEX.is_match(text), "{text}");
+    }
+    for text in [
+        "[name]",
+        "[@photo|alt=Logo]",
+        "[acct:amount]",
+        "Total: [$index]",
+    ] {
+        assert!(REGEX.is_match(text), "{text}");
+    }
+}
+
+#[tokio::test]
+async fn test_static_brackets_stay_header_rows() -> Result<(), DocxError> {
+    let input = "output/tests/static_brackets_template.docx";
+    let output = "output/tests/static_brackets.docx";
+    let body = table(
+        2,
+        &[
+            table_row(&["{{#items}}Name", "Qty, see note]"]),
+            table_row(&["Ref [1]", "[ ]"]),
+            table_row(&["[name]", "[qty]"]),
+        ],
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#items}}".to_string(),
+        json!([{"name": "Pen", "qty": 2}, {"name": "Desk", "qty": 1}]),
+    );
+    DOCX::default().generate(input, output, &data).await?;
+
+    // Both bracketed static rows are written once, ahead of the data rows / 两个含括号的静态行各写入一次，位于数据行之前
+    let xml = read_entry(output, "word/document.xml").await?;
+    let rows = xml.split("<w:tr>").skip(1).collect::<Vec<_>>();
+    assert_eq!(rows.len(), 4);
+    assert!(rows[0].contains("Qty, see note]"));
+    assert!(rows[1].contains("Ref [1]") && rows[1].contains("[ ]"));
+    assert!(rows[2].contains("Pen") && rows[2].contains("2.00"));
+    assert!(rows[3].contains("Desk") && rows[3].contains("1.00"));
+    Ok(())
+}
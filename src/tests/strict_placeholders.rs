@@ -0,0 +1,73 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, table, table_row, write_template};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_strict_missing_placeholders() -> Result<(), DocxError> {
+    let input = "output/tests/strict_placeholders_template.docx";
+    let output = "output/tests/strict_placeholders.docx";
+    let body = format!(
+        "{}{}{}",
+        paragraph("{{title}}"),
+        paragraph("{{titel}}"),
+        table(
+            2,
+            &[
+                table_row(&["{{#items}}Name", "Qty"]),
+                table_row(&["[name]", "[qyt]"]),
+            ],
+        )
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{title}}".to_string(), Value::from("Order"));
+    data.insert(
+        "{{#items}}".to_string(),
+        json!([{"name": "Pen", "qty": 2}, {"name": "Desk", "qty": 1}]),
+    );
+
+    // Missing values are left blank by default / 默认情况下缺失的值留空
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    assert!(docx.report().missing_placeholders.is_empty());
+
+    // Strict mode reports every bad key once / 严格模式下每个错误的键报告一次
+    docx.set_strict(true);
+    match docx.generate(input, output, &data).await {
+        Err(DocxError::MissingPlaceholders(keys)) => assert_eq!(keys, ["{{titel}}", "[qyt]"]),
+        other => panic!("unexpected result: {:?}", other.err()),
+    }
+    assert_eq!(docx.report().missing_placeholders.len(), 2);
+
+    // Fixed keys generate normally / 修正键后正常生成
+    data.insert("{{titel}}".to_string(), Value::from("Draft"));
+    data.insert(
+        "{{#items}}".to_string(),
+        json!([{"name": "Pen", "qyt": 2}, {"name": "Desk", "qyt": 1}]),
+    );
+    docx.generate(input, output, &data).await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_strict_missing_merge_fields() -> Result<(), DocxError> {
+    let input = "output/tests/strict_merge_fields_template.docx";
+    let output = "output/tests/strict_merge_fields.docx";
+    let body = concat!(
+        r#"<w:p><w:fldSimple w:instr=" MERGEFIELD City "><w:r><w:t>«City»</w:t></w:r></w:fldSimple></w:p>"#,
+        r#"<w:p><w:r><w:fldChar w:fldCharType="begin"/></w:r><w:r><w:instrText> MERGEFIELD Zip </w:instrText></w:r>"#,
+        r#"<w:r><w:fldChar w:fldCharType="separate"/></w:r><w:r><w:t>«Zip»</w:t></w:r><w:r><w:fldChar w:fldCharType="end"/></w:r></w:p>"#,
+    );
+    write_template(input, body).await?;
+
+    let mut docx = DOCX::default();
+    docx.set_strict(true);
+    match docx.generate(input, output, &HashMap::new()).await {
+        Err(DocxError::MissingPlaceholders(keys)) => assert_eq!(keys, ["{{City}}", "{{Zip}}"]),
+        other => panic!("unexpected result: {:?}", other.err()),
+    }
+    Ok(())
+}
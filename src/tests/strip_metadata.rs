@@ -0,0 +1,65 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, write_template};
+use base64::Engine;
+use base64::engine::general_purpose;
+use serde_json::Value;
+use std::collections::HashMap;
+
+const GPS: &[u8] = b"GPSLatitude=48.8584";
+
+/// Minimal JPEG with an EXIF segment in front of the frame header / 在帧头之前带有 EXIF 段的最小 JPEG
+fn jpeg_with_exif() -> Vec<u8> {
+    let exif = [b"Exif\0\0".as_slice(), GPS].concat();
+    let mut bytes = vec![0xFF, 0xD8];
+    // APP1 / APP1 段
+    bytes.extend_from_slice(&[0xFF, 0xE1]);
+    bytes.extend_from_slice(&(exif.len() as u16 + 2).to_be_bytes());
+    bytes.extend_from_slice(&exif);
+    // SOF0, 16x8 pixels, one component / SOF0，16x8 像素，一个分量
+    bytes.extend_from_slice(&[0xFF, 0xC0, 0x00, 0x0B, 0x08, 0x00, 0x08, 0x00, 0x10, 0x01]);
+    bytes.extend_from_slice(&[0x01, 0x11, 0x00]);
+    // SOS followed by scan data and EOI / SOS 之后为扫描数据和 EOI
+    bytes.extend_from_slice(&[0xFF, 0xDA, 0x00, 0x08, 0x01, 0x01, 0x00, 0x00, 0x3F, 0x00]);
+    bytes.extend_from_slice(&[0x12, 0x34, 0xFF, 0xD9]);
+    bytes
+}
+
+async fn embedded_jpeg(docx: &mut DOCX<'_>, name: &str) -> Result<Vec<u8>, DocxError> {
+    let input = format!("output/tests/{}_template.docx", name);
+    let output = format!("output/tests/{}.docx", name);
+    write_template(&input, &paragraph("{{@photo}}")).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{photo}}".to_string(),
+        Value::String(general_purpose::STANDARD.encode(jpeg_with_exif())),
+    );
+    docx.generate(&input, &output, &data).await?;
+    let media = DOCX::list_media(&output).await?;
+    Ok(DOCX::read_media(&output, &media[0].0).await?.to_vec())
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+#[tokio::test]
+async fn test_strip_jpeg_metadata() -> Result<(), DocxError> {
+    // Kept by default / 默认保留
+    let mut docx = DOCX::default();
+    let kept = embedded_jpeg(&mut docx, "strip_metadata_off").await?;
+    assert_eq!(kept, jpeg_with_exif());
+
+    docx.set_strip_metadata(true);
+    let stripped = embedded_jpeg(&mut docx, "strip_metadata").await?;
+    assert!(!contains(&stripped, GPS));
+    assert!(!contains(&stripped, &[0xFF, 0xE1]));
+    // Frame header and scan data are untouched / 帧头和扫描数据保持不变
+    assert_eq!(stripped.len(), kept.len() - (4 + 6 + GPS.len()));
+    assert!(stripped.starts_with(&[0xFF, 0xD8, 0xFF, 0xC0]));
+    assert!(stripped.ends_with(&[0x12, 0x34, 0xFF, 0xD9]));
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::public::report::GenerationWarning;
+use crate::tests::common::{paragraph, read_zip_entry, write_template};
+use async_zip::base::read::seek::ZipFileReader;
+use base64::Engine;
+use base64::engine::general_purpose;
+use bytes::Bytes;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::io::Cursor;
+use tokio_util::compat::TokioAsyncReadCompatExt;
+
+const LOGO_SVG: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<svg xmlns="http://www.w3.org/2000/svg" width="120px" height="60" viewBox="0 0 240 120">
+  <rect width="240" height="120" fill="#1f6feb"/>
+</svg>"##;
+
+#[tokio::test]
+async fn test_svg_image_with_png_fallback() -> Result<(), DocxError> {
+    let input = "output/tests/svg_template.docx";
+    write_template(input, &paragraph("{{@logo}}")).await?;
+    let template = Bytes::from(tokio::fs::read(input).await?);
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{logo}}".to_string(),
+        Value::String(general_purpose::STANDARD.encode(LOGO_SVG)),
+    );
+    let mut docx = DOCX::default();
+    let output = docx.generate_bytes(template, &data).await?;
+
+    let document = read_zip_entry(&output, "word/document.xml").await?;
+    let document = String::from_utf8_lossy(&document);
+    let blip = Regex::new(r#"<a:blip r:embed="(rId\d+)"><a:extLst><a:ext uri="\{96DAC541-7B7A-43D3-8B79-37D633B846F1\}"><asvg:svgBlip xmlns:asvg="[^"]+" r:embed="(rId\d+)"/>"#).unwrap();
+    let caps = blip.captures(&document).expect("svgBlip markup");
+    // 120x60 px at 96 DPI / 96 DPI 下的 120x60 像素
+    assert!(document.contains(r#"<wp:extent cx="1143000" cy="571500"/>"#));
+
+    let rels = read_zip_entry(&output, "word/_rels/document.xml.rels").await?;
+    let rels = String::from_utf8_lossy(&rels);
+    let target = |id: &str| {
+        let re = Regex::new(&format!(r#"Id="{}"[^>]*Target="(media/[^"]+)""#, id)).unwrap();
+        re.captures(&rels).unwrap()[1].to_string()
+    };
+    let png = target(&caps[1]);
+    let svg = target(&caps[2]);
+    assert!(png.ends_with(".png"));
+    assert!(svg.ends_with(".svg"));
+
+    let svg_part = read_zip_entry(&output, &format!("word/{}", svg)).await?;
+    assert_eq!(svg_part, LOGO_SVG.as_bytes());
+    let png_part = read_zip_entry(&output, &format!("word/{}", png)).await?;
+    assert!(png_part.starts_with(&[0x89, b'P', b'N', b'G']));
+    // The fallback is rendered at the SVG size with the `svg` feature / 启用 `svg` 特性时按 SVG 尺寸渲染回退图片
+    #[cfg(feature = "svg")]
+    assert_eq!(&png_part[16..24], &[0, 0, 0, 120, 0, 0, 0, 60]);
+
+    let types = read_zip_entry(&output, "[Content_Types].xml").await?;
+    assert!(
+        String::from_utf8_lossy(&types)
+            .contains(r#"<Default Extension="svg" ContentType="image/svg+xml"/>"#)
+    );
+
+    let zip = ZipFileReader::new(Cursor::new(output.to_vec()).compat()).await?;
+    assert_eq!(
+        zip.file()
+            .entries()
+            .iter()
+            .filter(|e| e.filename().as_str().unwrap().starts_with("word/media/"))
+            .count(),
+        2
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_svg_blank_fallback_warning() -> Result<(), DocxError> {
+    let input = "output/tests/svg_blank_fallback_template.docx";
+    write_template(input, &paragraph("{{@logo}}")).await?;
+    let template = Bytes::from(tokio::fs::read(input).await?);
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{logo}}".to_string(),
+        Value::String(general_purpose::STANDARD.encode(LOGO_SVG)),
+    );
+    let mut docx = DOCX::default();
+    docx.generate_bytes(template, &data).await?;
+
+    let blank = docx
+        .report()
+        .warnings
+        .iter()
+        .any(|warning| matches!(warning, GenerationWarning::BlankSvgFallback { .. }));
+    // Only the transparent placeholder is reported / 仅报告透明占位图
+    assert_eq!(blank, cfg!(not(feature = "svg")));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_base64_xml_not_svg() -> Result<(), DocxError> {
+    let input = "output/tests/svg_xml_template.docx";
+    write_template(input, &paragraph("{{note}}")).await?;
+    let template = Bytes::from(tokio::fs::read(input).await?);
+
+    let encoded = general_purpose::STANDARD
+        .encode(r#"<?xml version="1.0" encoding="UTF-8"?><note>hi</note>"#);
+    let mut data = HashMap::new();
+    data.insert("{{note}}".to_string(), Value::String(encoded.clone()));
+    let mut docx = DOCX::default();
+    let output = docx.generate_bytes(template, &data).await?;
+
+    let document = read_zip_entry(&output, "word/document.xml").await?;
+    let document = String::from_utf8_lossy(&document);
+    assert!(document.contains(&encoded));
+    assert!(!document.contains("<w:drawing>"));
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, write_template};
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_toc_field() -> Result<(), DocxError> {
+    let input = "output/tests/toc_template.docx";
+    let output = "output/tests/toc.docx";
+    let body = [
+        paragraph("Contents"),
+        paragraph("[toc]"),
+        paragraph("Intro"),
+    ]
+    .concat();
+    write_template(input, &body).await?;
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &HashMap::new()).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    assert!(!xml.contains("[toc]"));
+    assert!(
+        xml.contains(r#"<w:instrText xml:space="preserve"> TOC \o "1-3" \h \z \u </w:instrText>"#)
+    );
+    // Field chars must appear in begin, separate, end order / 域字符必须按开始、分隔、结束的顺序出现
+    let begin = xml.find(r#"w:fldCharType="begin""#).unwrap();
+    let separate = xml.find(r#"w:fldCharType="separate""#).unwrap();
+    let end = xml.find(r#"w:fldCharType="end""#).unwrap();
+    assert!(begin < separate && separate < end);
+    assert!(xml.find("Contents").unwrap() < begin && end < xml.find("Intro").unwrap());
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// Subscriber recording span names and event messages / 记录 span 名称和事件消息的订阅者
+#[derive(Default)]
+struct Recorder {
+    spans: Arc<Mutex<Vec<&'static str>>>,
+    events: Arc<Mutex<Vec<String>>>,
+    next_id: AtomicU64,
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        self.spans.lock().unwrap().push(span.metadata().name());
+        Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut message = String::new();
+        event.record(
+            &mut |field: &tracing::field::Field, value: &dyn std::fmt::Debug| {
+                if field.name() == "message" {
+                    message = format!("{:?}", value);
+                }
+            },
+        );
+        self.events.lock().unwrap().push(message);
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[tokio::test]
+async fn test_generation_emits_spans() -> Result<(), DocxError> {
+    let input = "output/tests/trace_spans_template.docx";
+    let output = "output/tests/trace_spans.docx";
+    let logo = tokio::fs::read_to_string("template/logo_base64.txt").await?;
+    let body = format!(
+        "{}{}{}",
+        paragraph("{{name}}"),
+        paragraph("{{@logo}}"),
+        table(
+            1,
+            &[table_row(&["{{#lines}}product"]), table_row(&["[product]"])],
+        )
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{name}}".to_string(), json!("Alice"));
+    data.insert("{{logo}}".to_string(), json!(logo));
+    data.insert("{{#lines}}".to_string(), json!([{"product": "Pen"}]));
+
+    let recorder = Recorder::default();
+    let spans = recorder.spans.clone();
+    let events = recorder.events.clone();
+    {
+        let _guard = tracing::subscriber::set_default(recorder);
+        let mut docx = DOCX::default();
+        docx.generate(input, output, &data).await?;
+    }
+
+    let spans = spans.lock().unwrap();
+    for name in ["generate", "process_table", "process_base64_image"] {
+        assert!(spans.contains(&name), "{:?}", spans);
+    }
+    let events = events.lock().unwrap();
+    for message in [
+        "processing entry",
+        "placeholder replaced",
+        "loop table rendered",
+        "image embedded",
+    ] {
+        assert!(events.iter().any(|e| e == message), "{:?}", events);
+    }
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, table, table_row, write_template};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_register_transform() -> Result<(), DocxError> {
+    let input = "output/tests/transform_template.docx";
+    let output = "output/tests/transform.docx";
+    let body = [
+        paragraph("{{reverse:title}}"),
+        paragraph("{{lower:title}}"),
+        table(
+            2,
+            &[
+                table_row(&["{{#users}}name", "code"]),
+                table_row(&["[reverse:name]", "[upper:code]"]),
+            ],
+        ),
+    ]
+    .concat();
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{title}}".to_string(), Value::from("Report"));
+    data.insert(
+        "{{#users}}".to_string(),
+        json!([{"name": "Alice", "code": "ab1"}]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.register_transform("reverse", |s| s.chars().rev().collect());
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+    assert!(xml.contains("<w:t>tropeR</w:t>"));
+    assert!(xml.contains("<w:t>report</w:t>"));
+    assert!(xml.contains("<w:t>ecilA</w:t>"));
+    // Built-in transforms stay registered / 内置变换保持注册
+    assert!(xml.contains("<w:t>AB1</w:t>"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transform_leaves_static_text() -> Result<(), DocxError> {
+    let input = "output/tests/transform_static_template.docx";
+    let output = "output/tests/transform_static.docx";
+    let body = [
+        paragraph("[upper:title] and the [lower:Static Note]"),
+        paragraph("{{upper:title}}"),
+    ]
+    .concat();
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{title}}".to_string(), Value::from("Report"));
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+    assert!(xml.contains("<w:t>[upper:title] and the [lower:Static Note]</w:t>"));
+    assert!(xml.contains("<w:t>REPORT</w:t>"));
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+fn data() -> HashMap<String, serde_json::Value> {
+    let mut data = HashMap::new();
+    data.insert("{{name}}".to_string(), json!("Alice"));
+    data.insert("{{city}}".to_string(), json!("Paris"));
+    data.insert(
+        "{{#lines}}".to_string(),
+        json!([
+            {"product": "Pen", "qty": 2},
+            {"product": "Desk", "qty": 1}
+        ]),
+    );
+    data
+}
+
+fn body() -> String {
+    format!(
+        "{}{}{}",
+        paragraph("[name]"),
+        paragraph("{{city}}"),
+        table(
+            2,
+            &[
+                table_row(&["{{#lines}}product", "qty"]),
+                table_row(&["{{product}}", "[qty]"]),
+            ],
+        )
+    )
+}
+
+#[tokio::test]
+async fn test_unified_syntax_mixes_both_forms() -> Result<(), DocxError> {
+    let input = "output/tests/unified_syntax_template.docx";
+    let output = "output/tests/unified_syntax.docx";
+    write_template(input, &body()).await?;
+
+    let mut docx = DOCX::default();
+    docx.set_unified_syntax(true);
+    docx.generate(input, output, &data()).await?;
+
+    let document = read_entry(output, "word/document.xml").await?;
+    assert!(document.contains("<w:t>Alice</w:t>"));
+    assert!(document.contains("<w:t>Paris</w:t>"));
+    assert!(document.contains("<w:t>Pen</w:t>"));
+    assert!(document.contains("<w:t>Desk</w:t>"));
+    assert!(document.contains("<w:t>2.00</w:t>"));
+    assert!(document.contains("<w:t>1.00</w:t>"));
+    assert!(!document.contains("{{"));
+    assert!(!document.contains('['));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mixed_syntax_kept_without_option() -> Result<(), DocxError> {
+    let input = "output/tests/unified_syntax_off_template.docx";
+    let output = "output/tests/unified_syntax_off.docx";
+    write_template(input, &body()).await?;
+
+    DOCX::default().generate(input, output, &data()).await?;
+
+    let document = read_entry(output, "word/document.xml").await?;
+    assert!(document.contains("<w:t>[name]</w:t>"));
+    assert!(document.contains("<w:t>Paris</w:t>"));
+    assert!(!document.contains("<w:t>Pen</w:t>"));
+    Ok(())
+}
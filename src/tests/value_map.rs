@@ -0,0 +1,53 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, table, table_row, write_template};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_value_map() -> Result<(), DocxError> {
+    let input = "output/tests/value_map_template.docx";
+    let output = "output/tests/value_map.docx";
+    let body = [
+        paragraph("{{type}}"),
+        table(
+            2,
+            &[
+                table_row(&["{{#pets}}name", "type"]),
+                table_row(&["[name]", "[type]"]),
+            ],
+        ),
+    ]
+    .concat();
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert("{{type}}".to_string(), Value::from("Dog"));
+    data.insert(
+        "{{#pets}}".to_string(),
+        json!([
+            {"name": "Tom", "type": "Cat"},
+            {"name": "Rex", "type": "Dog"},
+            {"name": "Nemo", "type": "Fish"}
+        ]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.set_value_map(
+        "type",
+        HashMap::from([
+            ("Cat".to_string(), "Katze".to_string()),
+            ("Dog".to_string(), "Hund".to_string()),
+        ]),
+    );
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+    assert_eq!(xml.matches("<w:t>Hund</w:t>").count(), 2);
+    assert!(xml.contains("<w:t>Katze</w:t>"));
+    assert!(!xml.contains("<w:t>Cat</w:t>"));
+    // Unmapped values are rendered as usual / 未映射的值照常渲染
+    assert!(xml.contains("<w:t>Fish</w:t>"));
+    // Other keys are untouched / 其他键不受影响
+    assert!(xml.contains("<w:t>Tom</w:t>"));
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{read_entry, table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn test_visible_row_skips_merged_rows() -> Result<(), DocxError> {
+    let input = "output/tests/visible_row_template.docx";
+    let output = "output/tests/visible_row.docx";
+    let body = table(
+        4,
+        &[
+            table_row(&["{{#users}}name", "line", "index", "pet"]),
+            table_row(&["[name]", "[$visrow]", "[$index]", "[pets.name]"]),
+        ],
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#users}}".to_string(),
+        json!([
+            {"name": "Peter", "pets": [{"name": "Dog"}, {"name": "Cat"}]},
+            {"name": "Lisa", "pets": [{"name": "Fish"}]},
+            {"name": "Tom", "pets": [{"name": "Bird"}]}
+        ]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    // Source rows are indexed 0..4, visible rows 0..3 / 源行索引为 0..4，可见行为 0..3
+    assert_eq!(
+        row_texts(&xml),
+        vec![
+            vec!["Peter", "0", "0", "Dog"],
+            vec!["", "", "1", "Cat"],
+            vec!["Lisa", "1", "2", "Fish"],
+            vec!["Tom", "2", "3", "Bird"],
+        ]
+    );
+    Ok(())
+}
+
+/// Cell texts of every row after the header / 标题之后每一行的单元格文本
+fn row_texts(xml: &str) -> Vec<Vec<&str>> {
+    xml.split("<w:tr>")
+        .skip(2)
+        .map(|row| {
+            row.split("<w:t>")
+                .skip(1)
+                .map(|cell| cell.split("</w:t>").next().unwrap_or_default())
+                .collect()
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_visible_row_in_multi_row_template() -> Result<(), DocxError> {
+    let input = "output/tests/visible_row_multi_template.docx";
+    let output = "output/tests/visible_row_multi.docx";
+    let body = table(
+        2,
+        &[
+            table_row(&["{{#users}}name", "line"]),
+            table_row(&["[name]", "[$visrow]"]),
+            table_row(&["[city]", "[$index]"]),
+        ],
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#users}}".to_string(),
+        json!([
+            {"name": "Peter", "city": "Oslo"},
+            {"name": "Peter", "city": "Rome"},
+            {"name": "Lisa", "city": "Lima"}
+        ]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    // Multi-row templates never merge, so every item is visible / 多行模板从不合并，因此每项都可见
+    assert_eq!(
+        row_texts(&xml),
+        vec![
+            vec!["Peter", "0"],
+            vec!["Oslo", "0"],
+            vec!["Peter", "1"],
+            vec!["Rome", "1"],
+            vec!["Lisa", "2"],
+            vec!["Lima", "2"],
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_visible_row_with_merge_normalization() -> Result<(), DocxError> {
+    let input = "output/tests/visible_row_normalized_template.docx";
+    let output = "output/tests/visible_row_normalized.docx";
+    let body = table(
+        2,
+        &[
+            table_row(&["{{#users}}country", "line"]),
+            table_row(&["[country]", "[$visrow]"]),
+        ],
+    );
+    write_template(input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#users}}".to_string(),
+        json!([{"country": "USA"}, {"country": "usa "}, {"country": "France"}]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.set_merge_normalization(true);
+    docx.generate(input, output, &data).await?;
+    let xml = read_entry(output, "word/document.xml").await?;
+
+    // "USA" and "usa " merge, so they share one visible row / "USA" 与 "usa " 合并，因此共用一个可见行
+    assert_eq!(
+        row_texts(&xml),
+        vec![vec!["USA", "0"], vec!["", ""], vec!["France", "1"]]
+    );
+    Ok(())
+}
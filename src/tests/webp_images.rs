@@ -0,0 +1,86 @@
+use crate::DOCX;
+use crate::core::utils::get_image_dimensions;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_entry, write_template};
+use base64::Engine;
+use base64::engine::general_purpose;
+use serde_json::json;
+use std::collections::HashMap;
+
+/// RIFF container holding one WebP chunk / 包含一个 WebP 块的 RIFF 容器
+fn webp(chunk: &[u8; 4], data: &[u8]) -> Vec<u8> {
+    let mut bytes = b"RIFF".to_vec();
+    bytes.extend_from_slice(&(data.len() as u32 + 12).to_le_bytes());
+    bytes.extend_from_slice(b"WEBP");
+    bytes.extend_from_slice(chunk);
+    bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(data);
+    bytes
+}
+
+/// Lossy key frame header / 有损关键帧头部
+fn vp8(width: u16, height: u16) -> Vec<u8> {
+    let mut data = vec![0x50, 0x01, 0x00, 0x9D, 0x01, 0x2A];
+    data.extend_from_slice(&width.to_le_bytes());
+    data.extend_from_slice(&height.to_le_bytes());
+    webp(b"VP8 ", &data)
+}
+
+/// Lossless header with 14-bit sizes minus one / 带减一的 14 位尺寸的无损头部
+fn vp8l(width: u32, height: u32) -> Vec<u8> {
+    let bits = (width - 1) | ((height - 1) << 14);
+    let mut data = vec![0x2F];
+    data.extend_from_slice(&bits.to_le_bytes());
+    webp(b"VP8L", &data)
+}
+
+/// Extended header with 24-bit canvas sizes minus one / 带减一的 24 位画布尺寸的扩展头部
+fn vp8x(width: u32, height: u32) -> Vec<u8> {
+    let mut data = vec![0; 4];
+    data.extend_from_slice(&(width - 1).to_le_bytes()[..3]);
+    data.extend_from_slice(&(height - 1).to_le_bytes()[..3]);
+    webp(b"VP8X", &data)
+}
+
+#[test]
+fn test_webp_lossy_dimensions() {
+    assert_eq!(get_image_dimensions(&vp8(320, 240)), Ok((320, 240)));
+}
+
+#[test]
+fn test_webp_lossless_dimensions() {
+    assert_eq!(get_image_dimensions(&vp8l(1000, 16383)), Ok((1000, 16383)));
+}
+
+#[test]
+fn test_webp_extended_dimensions() {
+    assert_eq!(get_image_dimensions(&vp8x(20000, 300)), Ok((20000, 300)));
+}
+
+#[tokio::test]
+async fn test_webp_media_type() -> Result<(), DocxError> {
+    let input = "output/tests/webp_images_template.docx";
+    let output = "output/tests/webp_images.docx";
+    write_template(input, &paragraph("{{@thumbnail}}")).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{thumbnail}}".to_string(),
+        json!(general_purpose::STANDARD.encode(vp8(96, 48))),
+    );
+    DOCX::default().generate(input, output, &data).await?;
+
+    let media = DOCX::list_media(output).await?;
+    assert_eq!(media.len(), 1);
+    assert!(media[0].0.ends_with(".webp"));
+    assert!(
+        read_entry(output, "[Content_Types].xml")
+            .await?
+            .contains("image/webp")
+    );
+
+    // 96x48 pixels at 96 DPI / 96 DPI 下的 96x48 像素
+    let document = read_entry(output, "word/document.xml").await?;
+    assert!(document.contains(r#"cx="914400" cy="457200""#));
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, read_zip_entry, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+use std::io::Cursor;
+
+#[tokio::test]
+async fn test_generate_to_writer() -> Result<(), DocxError> {
+    let input = "output/tests/writer_output_template.docx";
+    write_template(input, &paragraph("{{name}}")).await?;
+
+    let mut docx = DOCX::default();
+    let mut data = HashMap::new();
+    data.insert("{{name}}".to_string(), json!("Alice"));
+    let mut output = Cursor::new(Vec::new());
+    docx.generate_to_writer(input, &mut output, &data).await?;
+
+    // The written bytes reopen as a zip / 写入的字节可重新作为 zip 打开
+    let document = read_zip_entry(output.get_ref(), "word/document.xml").await?;
+    let document = String::from_utf8_lossy(&document);
+    assert!(document.contains("Alice"));
+    assert!(!document.contains("{{name}}"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_missing_input_keeps_existing_output() -> Result<(), DocxError> {
+    let output = "output/tests/writer_output_missing_input.docx";
+    tokio::fs::create_dir_all("output/tests").await?;
+    tokio::fs::write(output, b"previous output").await?;
+
+    let mut docx = DOCX::default();
+    let result = docx
+        .generate(
+            "output/tests/writer_output_absent.docx",
+            output,
+            &HashMap::new(),
+        )
+        .await;
+    assert!(matches!(result, Err(DocxError::Io(_))));
+    assert_eq!(tokio::fs::read(output).await?, b"previous output");
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use crate::DOCX;
+use crate::public::error::DocxError;
+use crate::tests::common::{read_entry, table, table_row, write_template};
+use serde_json::json;
+use std::collections::HashMap;
+
+const SHADING: &str = r#"<w:shd w:val="clear" w:color="auto" w:fill="F2F2F2"/>"#;
+
+/// Render a two-column loop table and return the generated rows split into cells / 渲染两列循环表格并返回拆分为单元格的生成行
+async fn render(name: &str, color: Option<String>) -> Result<Vec<Vec<String>>, DocxError> {
+    let input = format!("output/tests/zebra_shading_{}_template.docx", name);
+    let output = format!("output/tests/zebra_shading_{}.docx", name);
+    let body = table(
+        2,
+        &[
+            table_row(&["{{#lines}}group", "item"]),
+            table_row(&["[group]", "[item]"]),
+        ],
+    );
+    write_template(&input, &body).await?;
+
+    let mut data = HashMap::new();
+    data.insert(
+        "{{#lines}}".to_string(),
+        json!([
+            {"group": "A", "item": "Pen"},
+            {"group": "A", "item": "Ink"},
+            {"group": "B", "item": "Desk"},
+            {"group": "C", "item": "Lamp"}
+        ]),
+    );
+
+    let mut docx = DOCX::default();
+    docx.set_zebra_shading(color);
+    docx.generate(&input, &output, &data).await?;
+    let xml = read_entry(&output, "word/document.xml").await?;
+    Ok(xml
+        .split("<w:tr>")
+        .skip(2)
+        .map(|row| row.split("<w:tc>").skip(1).map(str::to_string).collect())
+        .collect())
+}
+
+#[tokio::test]
+async fn test_zebra_shading_on_odd_rows() -> Result<(), DocxError> {
+    let rows = render("odd", Some("#F2F2F2".to_string())).await?;
+    assert_eq!(rows.len(), 4);
+
+    // The item column alternates / 项目列交替
+    let items = rows
+        .iter()
+        .map(|cells| cells[1].contains(SHADING))
+        .collect::<Vec<_>>();
+    assert_eq!(items, vec![false, true, false, true]);
+
+    // The merged group keeps the shading of its first row / 合并的分组保持其首行的底纹
+    let groups = rows
+        .iter()
+        .map(|cells| cells[0].contains(SHADING))
+        .collect::<Vec<_>>();
+    assert_eq!(groups, vec![false, false, false, true]);
+    assert!(rows[1][0].contains(r#"<w:vMerge w:val="continue"/>"#));
+
+    // Merge and shading join the template's cell properties in schema order / 合并和底纹按架构顺序并入模板的单元格属性
+    assert!(
+        rows.iter()
+            .flatten()
+            .all(|cell| cell.matches("<w:tcPr>").count() == 1)
+    );
+    assert!(rows[0][0].contains(
+        r#"<w:tcPr><w:tcW w:w="1000" w:type="dxa"/><w:vMerge w:val="restart"/></w:tcPr>"#
+    ));
+    assert!(rows[3][0].contains(concat!(
+        r#"<w:tcPr><w:tcW w:w="1000" w:type="dxa"/>"#,
+        r#"<w:shd w:val="clear" w:color="auto" w:fill="F2F2F2"/></w:tcPr>"#
+    )));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_zebra_shading_invalid_color() -> Result<(), DocxError> {
+    let rows = render("invalid", Some(r#"F2F2F2"/><w:b"#.to_string())).await?;
+    assert!(rows.iter().flatten().all(|cell| !cell.contains("w:shd")));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_zebra_shading_disabled() -> Result<(), DocxError> {
+    let rows = render("disabled", None).await?;
+    assert!(rows.iter().flatten().all(|cell| !cell.contains("w:shd")));
+    Ok(())
+}
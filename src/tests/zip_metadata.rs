@@ -0,0 +1,42 @@
+use crate::public::error::DocxError;
+use crate::tests::common::{paragraph, write_template};
+use crate::{DOCX, ZipDateTimeBuilder};
+use async_zip::tokio::read::seek::ZipFileReader;
+use serde_json::json;
+use std::collections::HashMap;
+use tokio::fs::File;
+use tokio::io::BufReader;
+
+#[tokio::test]
+async fn test_zip_comment_and_timestamps() -> Result<(), DocxError> {
+    let input = "output/tests/zip_metadata_template.docx";
+    let output = "output/tests/zip_metadata.docx";
+    write_template(input, &paragraph("{{name}}")).await?;
+    let data = HashMap::from([("{{name}}".to_string(), json!("Alice"))]);
+
+    let modified = ZipDateTimeBuilder::new()
+        .year(2020)
+        .month(1)
+        .day(2)
+        .hour(3)
+        .minute(4)
+        .second(6)
+        .build();
+    let mut docx = DOCX::default();
+    docx.set_zip_comment(Some("generation-42"));
+    docx.set_entry_modified(Some(modified));
+    docx.generate(input, output, &data).await?;
+
+    let zip = ZipFileReader::with_tokio(BufReader::new(File::open(output).await?)).await?;
+    assert_eq!(zip.file().comment().as_str()?, "generation-42");
+    assert!(!zip.file().entries().is_empty());
+    for entry in zip.file().entries() {
+        assert_eq!(*entry.last_modification_date(), modified);
+    }
+
+    // The same settings produce the same package / 相同设置生成相同的包
+    let first = tokio::fs::read(output).await?;
+    docx.generate(input, output, &data).await?;
+    assert_eq!(first, tokio::fs::read(output).await?);
+    Ok(())
+}